@@ -91,6 +91,11 @@ impl ConfigManager {
         Ok(Self::config_dir()?.join("claude-code-sync.log"))
     }
 
+    /// Get the hook log path (hook-debug.log)
+    pub fn hook_log_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("hook-debug.log"))
+    }
+
     /// Get the user data file path (user_data.json)
     pub fn user_data_path() -> Result<PathBuf> {
         Ok(Self::config_dir()?.join("user_data.json"))
@@ -101,6 +106,11 @@ impl ConfigManager {
         Ok(Self::config_dir()?.join("delete-unlock.json"))
     }
 
+    /// Get the lock file path held while a push or pull runs (sync.lock)
+    pub fn sync_lock_path() -> Result<PathBuf> {
+        Ok(Self::config_dir()?.join("sync.lock"))
+    }
+
     /// Ensure the configuration directory exists
     pub fn ensure_config_dir() -> Result<PathBuf> {
         let config_dir = Self::config_dir()?;
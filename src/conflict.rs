@@ -91,6 +91,37 @@ pub struct Conflict {
     pub resolution: ConflictResolution,
 }
 
+/// Policy for settling conflicts during a sync (`ccs sync --prefer`).
+///
+/// `Interactive` is the default: conflicts are smart merged, and those that
+/// cannot be merged are resolved by asking (or by keeping both versions when
+/// there is no terminal). The other policies pick one version per session
+/// without merging, which makes unattended syncs deterministic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Prefer {
+    /// Keep the local version
+    Local,
+    /// Take the remote version
+    Remote,
+    /// Keep whichever version has the later last message
+    Newer,
+    /// Smart merge, then ask for the rest
+    #[default]
+    Interactive,
+}
+
+impl Prefer {
+    /// Name as used on the command line
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Prefer::Local => "local",
+            Prefer::Remote => "remote",
+            Prefer::Newer => "newer",
+            Prefer::Interactive => "interactive",
+        }
+    }
+}
+
 /// Represents the resolution strategy for a conversation conflict.
 ///
 /// When a conflict is detected between local and remote versions of the same conversation,
@@ -288,6 +319,43 @@ impl Conflict {
     pub fn is_real_conflict(&self) -> bool {
         self.local_hash != self.remote_hash
     }
+
+    /// Resolve the conflict by picking one version according to `prefer`
+    ///
+    /// Sets the resolution to `KeepLocal` or `KeepRemote`. `Prefer::Newer`
+    /// compares the latest message timestamps and keeps the local version on
+    /// a tie or when the remote one has none. `Prefer::Interactive` leaves
+    /// the resolution unchanged.
+    pub fn resolve_by_preference(&mut self, prefer: Prefer) {
+        let keep_remote = match prefer {
+            Prefer::Local => false,
+            Prefer::Remote => true,
+            Prefer::Newer => is_later(
+                self.remote_timestamp.as_deref(),
+                self.local_timestamp.as_deref(),
+            ),
+            Prefer::Interactive => return,
+        };
+        self.resolution = if keep_remote {
+            ConflictResolution::KeepRemote
+        } else {
+            ConflictResolution::KeepLocal
+        };
+    }
+}
+
+/// Whether timestamp `a` is later than `b`; a missing timestamp counts as
+/// the earliest. Unparseable timestamps are compared as text.
+fn is_later(a: Option<&str>, b: Option<&str>) -> bool {
+    let parse = |ts: &str| chrono::DateTime::parse_from_rfc3339(ts).ok();
+    match (a, b) {
+        (Some(_), None) => true,
+        (Some(a), Some(b)) => match (parse(a), parse(b)) {
+            (Some(a), Some(b)) => a > b,
+            _ => a > b,
+        },
+        (None, _) => false,
+    }
 }
 
 /// Conflict detector for conversation sessions
@@ -474,4 +542,40 @@ mod tests {
 
         assert!(!detector.has_conflicts());
     }
+
+    #[test]
+    fn test_resolve_by_preference() {
+        let local_session = create_test_session("session-1", 5);
+        let remote_session = create_test_session("session-1", 6);
+        let resolution_for = |prefer| {
+            let mut conflict = Conflict::new(&local_session, &remote_session);
+            conflict.resolve_by_preference(prefer);
+            conflict.resolution
+        };
+
+        assert!(matches!(
+            resolution_for(Prefer::Local),
+            ConflictResolution::KeepLocal
+        ));
+        assert!(matches!(
+            resolution_for(Prefer::Remote),
+            ConflictResolution::KeepRemote
+        ));
+        // The remote version has a later last message
+        assert!(matches!(
+            resolution_for(Prefer::Newer),
+            ConflictResolution::KeepRemote
+        ));
+        assert!(matches!(
+            resolution_for(Prefer::Interactive),
+            ConflictResolution::Pending
+        ));
+
+        // Offsets are compared as instants, not as text
+        assert!(!is_later(
+            Some("2025-01-01T10:00:00+02:00"),
+            Some("2025-01-01T09:00:00Z")
+        ));
+        assert!(!is_later(None, Some("2025-01-01T09:00:00Z")));
+    }
 }
@@ -0,0 +1,112 @@
+//! Typed errors for failures callers react to
+//!
+//! Most failures are `anyhow` errors with context attached. The few that a
+//! caller may handle differently are raised as a [`SyncError`] inside the
+//! `anyhow::Error`, so [`SyncError::find`] still sees them after context has
+//! been added. At the CLI boundary each variant maps to its own exit code
+//! ([`exit_code`]), which is how the hooks tell "not set up" from "offline"
+//! when they run `ccs` as a subprocess.
+
+use std::fmt;
+use std::path::PathBuf;
+
+use crate::BINARY_NAME;
+
+/// Exit code for errors without a more specific one
+pub const EXIT_FAILURE: i32 = 1;
+/// Exit code for [`SyncError::NotInitialized`]
+pub const EXIT_NOT_INITIALIZED: i32 = 3;
+/// Exit code for [`SyncError::NotFound`]
+pub const EXIT_NOT_FOUND: i32 = 4;
+/// Exit code for [`SyncError::RemoteUnreachable`]
+pub const EXIT_REMOTE_UNREACHABLE: i32 = 5;
+/// Exit code for [`SyncError::ConfigParse`]
+pub const EXIT_CONFIG_PARSE: i32 = 6;
+
+/// Failures with a well-defined meaning for callers
+#[derive(Debug)]
+pub enum SyncError {
+    /// No sync repository is configured (`ccs init` has not been run)
+    NotInitialized,
+    /// A session, project or other item looked up by ID or name does not exist
+    NotFound { kind: &'static str, name: String },
+    /// The remote could not be reached (DNS failure, refused or timed-out
+    /// connection)
+    RemoteUnreachable { remote: String, detail: String },
+    /// A config or state file exists but could not be parsed
+    ConfigParse { path: PathBuf, message: String },
+}
+
+impl SyncError {
+    /// A [`SyncError::NotFound`] for a session ID
+    pub fn session_not_found(id: &str) -> Self {
+        SyncError::NotFound {
+            kind: "Session",
+            name: id.to_string(),
+        }
+    }
+
+    /// The `SyncError` in `err` or any of its causes
+    pub fn find(err: &anyhow::Error) -> Option<&SyncError> {
+        err.chain()
+            .find_map(|cause| cause.downcast_ref::<SyncError>())
+    }
+
+    /// Process exit code for this error
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            SyncError::NotInitialized => EXIT_NOT_INITIALIZED,
+            SyncError::NotFound { .. } => EXIT_NOT_FOUND,
+            SyncError::RemoteUnreachable { .. } => EXIT_REMOTE_UNREACHABLE,
+            SyncError::ConfigParse { .. } => EXIT_CONFIG_PARSE,
+        }
+    }
+}
+
+impl fmt::Display for SyncError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SyncError::NotInitialized => {
+                write!(f, "Sync not initialized. Run '{} init' first.", BINARY_NAME)
+            }
+            SyncError::NotFound { kind, name } => write!(f, "{} not found: {}", kind, name),
+            SyncError::RemoteUnreachable { remote, detail } => {
+                write!(f, "Cannot reach remote '{}': {}", remote, detail)
+            }
+            SyncError::ConfigParse { path, message } => {
+                write!(f, "Failed to parse {}: {}", path.display(), message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SyncError {}
+
+/// Process exit code for an error returned to the CLI
+pub fn exit_code(err: &anyhow::Error) -> i32 {
+    SyncError::find(err).map_or(EXIT_FAILURE, SyncError::exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_find_through_context_and_exit_codes() {
+        let err = Err::<(), _>(SyncError::NotInitialized)
+            .context("Failed to load sync state")
+            .unwrap_err();
+        assert!(matches!(
+            SyncError::find(&err),
+            Some(SyncError::NotInitialized)
+        ));
+        assert_eq!(exit_code(&err), EXIT_NOT_INITIALIZED);
+
+        let err: anyhow::Error = SyncError::session_not_found("abc").into();
+        assert_eq!(err.to_string(), "Session not found: abc");
+        assert_eq!(exit_code(&err), EXIT_NOT_FOUND);
+
+        assert_eq!(exit_code(&anyhow::anyhow!("other")), EXIT_FAILURE);
+    }
+}
@@ -1,9 +1,13 @@
 use anyhow::{bail, Context, Result};
 use colored::Colorize;
+use ignore::gitignore::{Gitignore, GitignoreBuilder};
 use serde::{Deserialize, Serialize};
 use std::fs;
 use std::path::{Path, PathBuf};
 
+use crate::error::SyncError;
+use crate::output::icon;
+use crate::parser::ConversationSession;
 use crate::scm::Backend;
 
 /// Configuration sync settings stored in FilterConfig
@@ -94,16 +98,80 @@ pub struct AutoMemorySettings {
     /// Enable auto memory sync
     #[serde(default = "default_true")]
     pub enabled: bool,
+
+    /// Name of the memory directory inside each project directory
+    /// Default: "memory"
+    #[serde(default = "default_memory_dir_name")]
+    pub memory_dir_name: String,
+}
+
+fn default_memory_dir_name() -> String {
+    "memory".to_string()
 }
 
 impl Default for AutoMemorySettings {
     fn default() -> Self {
-        Self { enabled: true }
+        Self {
+            enabled: true,
+            memory_dir_name: default_memory_dir_name(),
+        }
+    }
+}
+
+/// What the session manager treats as noise: such sessions are hidden from
+/// session lists and offered for removal by the Cleanup action
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CleanupSettings {
+    /// Sessions with fewer messages than this are cleanable.
+    /// Values below 1 behave like 1 (empty sessions are always cleanable).
+    /// Default: 1
+    #[serde(default = "default_cleanup_min_messages")]
+    pub min_messages: usize,
+
+    /// Sessions without a real title are cleanable
+    /// Default: true
+    #[serde(default = "default_true")]
+    pub require_title: bool,
+}
+
+fn default_cleanup_min_messages() -> usize {
+    1
+}
+
+impl Default for CleanupSettings {
+    fn default() -> Self {
+        Self {
+            min_messages: default_cleanup_min_messages(),
+            require_title: true,
+        }
+    }
+}
+
+impl CleanupSettings {
+    /// Whether a session with the given message count and title state is
+    /// kept (not cleanable)
+    pub fn keeps(&self, message_count: usize, has_title: bool) -> bool {
+        message_count >= self.min_messages.max(1) && (has_title || !self.require_title)
+    }
+
+    /// Short description of what is cleanable, e.g. "fewer than 2 messages or no title"
+    pub fn describe(&self) -> String {
+        let min = self.min_messages.max(1);
+        let messages = if min == 1 {
+            "empty".to_string()
+        } else {
+            format!("fewer than {min} messages")
+        };
+        if self.require_title {
+            format!("{messages} or no title")
+        } else {
+            messages
+        }
     }
 }
 
 /// Sanitize device name: replace non-ASCII and special characters with `-`
-fn sanitize_device_name(name: &str) -> String {
+pub(crate) fn sanitize_device_name(name: &str) -> String {
     let sanitized: String = name
         .chars()
         .map(|c| {
@@ -178,6 +246,83 @@ fn get_friendly_computer_name() -> Option<String> {
     None
 }
 
+/// Why [`FilterConfig::exclusion_reason`] drops a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FilterReason {
+    /// Not a `.jsonl` file while attachments are excluded
+    Attachment,
+    /// Matched `.ccsignore` or the exclude patterns, or missed the include patterns
+    Pattern,
+    /// Larger than `max_file_size_bytes`; carries the file size
+    Size(u64),
+    /// Not modified within `exclude_older_than_days` (with `age_by = mtime`)
+    Age,
+}
+
+/// What `exclude_older_than_days` measures a session's age by
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AgeBy {
+    /// File modification time (cheap, but reset by a git clone or copy)
+    #[default]
+    Mtime,
+    /// Timestamp of the last message in the session
+    Content,
+}
+
+impl AgeBy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            AgeBy::Mtime => "mtime",
+            AgeBy::Content => "content",
+        }
+    }
+}
+
+/// What push does with local projects that share a name in
+/// project-name-only mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CollisionStrategy {
+    /// Store their sessions in one folder
+    #[default]
+    Merge,
+    /// Give each its own folder, `<name>-<hash of its path>`
+    Suffix,
+    /// Leave their sessions out of the push
+    Skip,
+}
+
+impl CollisionStrategy {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CollisionStrategy::Merge => "merge",
+            CollisionStrategy::Suffix => "suffix",
+            CollisionStrategy::Skip => "skip",
+        }
+    }
+}
+
+/// How session listings show when a session was last active
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TimeFormat {
+    /// "3 hours ago", "Yesterday", ...
+    #[default]
+    Relative,
+    /// Local date and time, e.g. `2025-01-15 14:30`
+    Absolute,
+}
+
+impl TimeFormat {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeFormat::Relative => "relative",
+            TimeFormat::Absolute => "absolute",
+        }
+    }
+}
+
 /// Filter configuration for syncing Claude Code history
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FilterConfig {
@@ -185,6 +330,13 @@ pub struct FilterConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub exclude_older_than_days: Option<u32>,
 
+    /// Whether `exclude_older_than_days` uses the file mtime or the last
+    /// message timestamp. `content` needs the session parsed, so it is
+    /// applied by session discovery after parsing.
+    /// Default: mtime
+    #[serde(default)]
+    pub age_by: AgeBy,
+
     /// Include only these project path patterns (glob-style)
     #[serde(default)]
     pub include_patterns: Vec<String>,
@@ -201,6 +353,32 @@ pub struct FilterConfig {
     #[serde(default)]
     pub exclude_attachments: bool,
 
+    /// Remove images and documents embedded inside session `.jsonl` files
+    /// from the copies written to the sync repo (local files are untouched).
+    /// Unlike `exclude_attachments`, which skips separate attachment files,
+    /// this rewrites the conversation content itself.
+    /// Default: false
+    #[serde(default)]
+    pub redact_on_push: bool,
+
+    /// Scan new and modified sessions for API keys and tokens before pushing
+    /// and warn about the ones that match (`ccs push --strict` blocks instead).
+    /// Default: false
+    #[serde(default)]
+    pub scan_secrets: bool,
+
+    /// Extra regexes treated as secrets, on top of the built-in detectors
+    #[serde(default)]
+    pub secret_patterns: Vec<String>,
+
+    /// Follow symlinked project directories in `~/.claude/projects`
+    /// (e.g. projects kept on an external drive). Symlink loops are detected
+    /// and skipped; a directory reachable through several links is read once
+    /// per link, and the duplicate sessions are merged by session ID.
+    /// Default: false
+    #[serde(default)]
+    pub follow_symlinks: bool,
+
     /// Enable Git LFS for large files
     /// When enabled, files matching lfs_patterns will be stored via LFS
     #[serde(default)]
@@ -228,6 +406,18 @@ pub struct FilterConfig {
     #[serde(default = "default_use_project_name_only")]
     pub use_project_name_only: bool,
 
+    /// How push handles local projects with the same name when
+    /// `use_project_name_only` is on: `merge`, `suffix` or `skip`
+    /// Default: merge
+    #[serde(default)]
+    pub collision_strategy: CollisionStrategy,
+
+    /// Folder of this device's projects inside the sync subdirectory when
+    /// `use_project_name_only` is on (`<namespace>/<project>/`), so several
+    /// people can share one sync repo. Unset stores projects directly.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub device_namespace: Option<String>,
+
     /// Configuration sync settings (settings.json, CLAUDE.md, hooks, etc.)
     #[serde(default)]
     pub config_sync: ConfigSyncSettings,
@@ -235,6 +425,86 @@ pub struct FilterConfig {
     /// Auto memory sync settings (memory/ directory)
     #[serde(default)]
     pub auto_memory: AutoMemorySettings,
+
+    /// SSH private key to use for push/pull/fetch (e.g. "~/.ssh/id_work")
+    /// When set, git network operations run with
+    /// `GIT_SSH_COMMAND="ssh -i <key> -o IdentitiesOnly=yes"`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ssh_identity: Option<String>,
+
+    /// Template for the push commit message when `--message` is not given.
+    /// Placeholders: `{count}`, `{added}`, `{modified}`, `{deleted}`,
+    /// `{device}`, `{date}`. Unset uses the built-in default message.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_message_template: Option<String>,
+
+    /// Warn about conversation files at least this large (in MB) during
+    /// push and conflicted pulls. 0 disables the warning.
+    /// Default: 10
+    #[serde(default = "default_large_file_warning_mb")]
+    pub large_file_warning_mb: u64,
+
+    /// Minimum seconds between pushes triggered by the Stop hook. Responses
+    /// arriving sooner are coalesced into the next push; 0 pushes after every
+    /// response. `ccs hook-stop --now` ignores it.
+    /// Default: 60
+    #[serde(default = "default_stop_push_interval_secs")]
+    pub stop_push_interval_secs: u64,
+
+    /// Seconds a push or pull started by a hook may run before it is killed,
+    /// so a hung remote cannot block Claude Code until its own hook timeout
+    /// (60s). 0 disables the limit.
+    /// Default: 45
+    #[serde(default = "default_hook_timeout_secs")]
+    pub hook_timeout_secs: u64,
+
+    /// Attempts for pushing to the remote before giving up; transient
+    /// failures are retried with exponential backoff (1s, 2s, 4s, ...).
+    /// Default: 3
+    #[serde(default = "default_push_attempts")]
+    pub push_attempts: u32,
+
+    /// Which sessions count as noise for the session manager's Cleanup
+    #[serde(default)]
+    pub cleanup: CleanupSettings,
+
+    /// How session listings show last activity: `relative` or `absolute`
+    /// (local time). `ccs session --absolute` overrides it for one run.
+    /// Default: relative
+    #[serde(default)]
+    pub time_format: TimeFormat,
+
+    /// Compiled `~/.claude/projects/.ccsignore` (gitignore syntax), filled in
+    /// by [`FilterConfig::load`]; not stored in config.toml
+    #[serde(skip)]
+    pub ignore_file: Option<Gitignore>,
+}
+
+/// Name of the gitignore-style file in `~/.claude/projects/` listing
+/// projects or sessions that are never synced
+pub const IGNORE_FILE_NAME: &str = ".ccsignore";
+
+/// Compile the ignore file in `root`, if there is one.
+///
+/// Patterns are relative to `root`, so `scratch-project/` or `*-tmp-*/`
+/// exclude whole project directories. Invalid lines are skipped with a warning.
+fn load_ignore_file(root: &Path) -> Option<Gitignore> {
+    let path = root.join(IGNORE_FILE_NAME);
+    if !path.is_file() {
+        return None;
+    }
+
+    let mut builder = GitignoreBuilder::new(root);
+    if let Some(e) = builder.add(&path) {
+        log::warn!("Some lines in {} were ignored: {}", path.display(), e);
+    }
+    match builder.build() {
+        Ok(ignore) => Some(ignore),
+        Err(e) => {
+            log::warn!("Failed to load {}: {}", path.display(), e);
+            None
+        }
+    }
 }
 
 fn default_lfs_patterns() -> Vec<String> {
@@ -245,6 +515,22 @@ fn default_max_file_size() -> u64 {
     10 * 1024 * 1024 // 10MB
 }
 
+fn default_large_file_warning_mb() -> u64 {
+    10
+}
+
+fn default_stop_push_interval_secs() -> u64 {
+    60
+}
+
+fn default_hook_timeout_secs() -> u64 {
+    45
+}
+
+fn default_push_attempts() -> u32 {
+    3
+}
+
 fn default_scm_backend() -> String {
     "git".to_string()
 }
@@ -261,17 +547,33 @@ impl Default for FilterConfig {
     fn default() -> Self {
         FilterConfig {
             exclude_older_than_days: None,
+            age_by: AgeBy::default(),
             include_patterns: Vec::new(),
             exclude_patterns: Vec::new(),
             max_file_size_bytes: default_max_file_size(),
             exclude_attachments: false,
+            redact_on_push: false,
+            scan_secrets: false,
+            secret_patterns: Vec::new(),
+            follow_symlinks: false,
             enable_lfs: false,
             lfs_patterns: default_lfs_patterns(),
             scm_backend: default_scm_backend(),
             sync_subdirectory: default_sync_subdirectory(),
             use_project_name_only: true, // Default to multi-device mode
+            collision_strategy: CollisionStrategy::default(),
+            device_namespace: None,
             config_sync: ConfigSyncSettings::default(),
             auto_memory: AutoMemorySettings::default(),
+            ssh_identity: None,
+            commit_message_template: None,
+            large_file_warning_mb: default_large_file_warning_mb(),
+            stop_push_interval_secs: default_stop_push_interval_secs(),
+            hook_timeout_secs: default_hook_timeout_secs(),
+            push_attempts: default_push_attempts(),
+            cleanup: CleanupSettings::default(),
+            time_format: TimeFormat::default(),
+            ignore_file: None,
         }
     }
 }
@@ -292,15 +594,21 @@ impl FilterConfig {
     pub fn load() -> Result<Self> {
         let config_path = Self::config_path()?;
 
-        if !config_path.exists() {
-            return Ok(Self::default());
-        }
-
-        let content = fs::read_to_string(&config_path)
-            .with_context(|| format!("Failed to read config file: {}", config_path.display()))?;
+        let mut config = if config_path.exists() {
+            let content = fs::read_to_string(&config_path).with_context(|| {
+                format!("Failed to read config file: {}", config_path.display())
+            })?;
+            toml::from_str::<FilterConfig>(&content).map_err(|e| SyncError::ConfigParse {
+                path: config_path.clone(),
+                message: e.to_string(),
+            })?
+        } else {
+            Self::default()
+        };
 
-        let config: FilterConfig =
-            toml::from_str(&content).context("Failed to parse config file")?;
+        if let Ok(projects_dir) = crate::sync::discovery::claude_projects_dir() {
+            config.ignore_file = load_ignore_file(&projects_dir);
+        }
 
         Ok(config)
     }
@@ -323,6 +631,23 @@ impl FilterConfig {
         Ok(())
     }
 
+    /// Read a configuration written by `ccs config export`
+    ///
+    /// Fails if the file does not parse, or holds a value that
+    /// [`validate`](Self::validate), the SCM backend check, the sync
+    /// subdirectory check or the secret pattern compiler would reject.
+    pub fn read_from(path: &Path) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let config = toml::from_str::<FilterConfig>(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        config.validate()?;
+        config.backend()?;
+        validate_sync_subdirectory(&config.sync_subdirectory)?;
+        crate::secrets::SecretScanner::new(&config.secret_patterns)?;
+        Ok(config)
+    }
+
     /// Get the path to the config file
     fn config_path() -> Result<PathBuf> {
         crate::config::ConfigManager::filter_config_path()
@@ -330,20 +655,32 @@ impl FilterConfig {
 
     /// Check if a file should be included based on filters
     pub fn should_include(&self, file_path: &Path) -> bool {
+        self.exclusion_reason(file_path).is_none()
+    }
+
+    /// Why the filters drop `file_path`, or `None` if it is included
+    ///
+    /// Pattern checks come first, so an excluded project is reported as such
+    /// even when its files are also too large or too old.
+    pub fn exclusion_reason(&self, file_path: &Path) -> Option<FilterReason> {
         // Only process .jsonl files (exclude attachments if configured)
         if self.exclude_attachments {
             if let Some(ext) = file_path.extension() {
                 if ext != "jsonl" {
                     // This is an attachment (image, PDF, etc.)
-                    return false;
+                    return Some(FilterReason::Attachment);
                 }
             }
         }
 
-        // Check file size
-        if let Ok(metadata) = fs::metadata(file_path) {
-            if metadata.len() > self.max_file_size_bytes {
-                return false;
+        // Check .ccsignore (only applies to files under the directory it lives in)
+        if let Some(ref ignore) = self.ignore_file {
+            if file_path.starts_with(ignore.path())
+                && ignore
+                    .matched_path_or_any_parents(file_path, false)
+                    .is_ignore()
+            {
+                return Some(FilterReason::Pattern);
             }
         }
 
@@ -353,7 +690,7 @@ impl FilterConfig {
         if !self.exclude_patterns.is_empty() {
             for pattern in &self.exclude_patterns {
                 if glob_match(pattern, &path_str) {
-                    return false;
+                    return Some(FilterReason::Pattern);
                 }
             }
         }
@@ -368,12 +705,17 @@ impl FilterConfig {
                 }
             }
             if !matches_include {
-                return false;
+                return Some(FilterReason::Pattern);
             }
         }
 
-        // Check age filter
-        if let Some(max_days) = self.exclude_older_than_days {
+        // Check file size
+        if let Some(size) = self.exceeds_size_limit(file_path) {
+            return Some(FilterReason::Size(size));
+        }
+
+        // Check age filter (content age is checked after parsing)
+        if let (Some(max_days), AgeBy::Mtime) = (self.exclude_older_than_days, self.age_by) {
             if let Ok(metadata) = fs::metadata(file_path) {
                 if let Ok(modified) = metadata.modified() {
                     let age = std::time::SystemTime::now()
@@ -382,17 +724,73 @@ impl FilterConfig {
 
                     let max_age = std::time::Duration::from_secs((max_days as u64) * 24 * 60 * 60);
                     if age > max_age {
-                        return false;
+                        return Some(FilterReason::Age);
                     }
                 }
             }
         }
 
-        true
+        None
+    }
+
+    /// Whether a parsed session's last message is older than
+    /// `exclude_older_than_days`, when ages are measured by content
+    ///
+    /// Sessions without any timestamp are kept, since their age is unknown.
+    pub fn is_too_old_by_content(&self, session: &ConversationSession) -> bool {
+        let (Some(max_days), AgeBy::Content) = (self.exclude_older_than_days, self.age_by) else {
+            return false;
+        };
+        session
+            .latest_timestamp()
+            .and_then(|ts| chrono::DateTime::parse_from_rfc3339(&ts).ok())
+            .is_some_and(|latest| {
+                chrono::Utc::now().signed_duration_since(latest)
+                    > chrono::Duration::days(i64::from(max_days))
+            })
+    }
+
+    /// Size of `file_path` if it is larger than `max_file_size_bytes`
+    pub fn exceeds_size_limit(&self, file_path: &Path) -> Option<u64> {
+        fs::metadata(file_path)
+            .ok()
+            .map(|metadata| metadata.len())
+            .filter(|&size| size > self.max_file_size_bytes)
+    }
+
+    /// Large-file warning threshold in bytes (0 means disabled)
+    pub fn large_file_warning_bytes(&self) -> u64 {
+        self.large_file_warning_mb.saturating_mul(1024 * 1024)
+    }
+
+    /// Directory of this device's sessions in the sync repo: the sync
+    /// subdirectory, plus `device_namespace` in project-name-only mode
+    pub fn repo_projects_dir(&self, sync_repo_path: &Path) -> PathBuf {
+        let projects_dir = sync_repo_path.join(&self.sync_subdirectory);
+        match self.device_namespace.as_deref() {
+            Some(namespace) if self.use_project_name_only => projects_dir.join(namespace),
+            _ => projects_dir,
+        }
+    }
+
+    /// Resolve `ssh_identity` to an absolute path (expanding a leading `~`).
+    pub fn ssh_identity_path(&self) -> Option<PathBuf> {
+        let identity = self.ssh_identity.as_deref()?.trim();
+        if identity.is_empty() {
+            return None;
+        }
+        if let Some(rest) = identity
+            .strip_prefix("~/")
+            .or_else(|| identity.strip_prefix("~\\"))
+        {
+            if let Some(home) = dirs::home_dir() {
+                return Some(home.join(rest));
+            }
+        }
+        Some(PathBuf::from(identity))
     }
 
     /// Get the configured SCM backend.
-    #[allow(dead_code)]
     pub fn backend(&self) -> Result<Backend> {
         match self.scm_backend.to_lowercase().as_str() {
             "git" => Ok(Backend::Git),
@@ -406,7 +804,8 @@ impl FilterConfig {
 
     /// Validate the configuration.
     ///
-    /// Returns an error if LFS is enabled with a non-git backend.
+    /// Returns an error if LFS is enabled with a non-git backend, or if a
+    /// setting holds a value that cannot work (e.g. zero push attempts).
     pub fn validate(&self) -> Result<()> {
         if self.enable_lfs && self.scm_backend.to_lowercase() != "git" {
             bail!(
@@ -415,10 +814,64 @@ impl FilterConfig {
                 self.scm_backend
             );
         }
+        if self.push_attempts == 0 {
+            bail!("push_attempts must be at least 1");
+        }
+        let memory_dir_name = &self.auto_memory.memory_dir_name;
+        if memory_dir_name.is_empty()
+            || memory_dir_name == "."
+            || memory_dir_name == ".."
+            || memory_dir_name.contains(['/', '\\', ':'])
+        {
+            bail!(
+                "Memory directory name must be a single directory name, got '{}'",
+                memory_dir_name
+            );
+        }
         Ok(())
     }
 }
 
+/// Validate a sync subdirectory (relative to the sync repo root)
+///
+/// Rejects empty values, absolute paths and any `..` component so the
+/// projects directory can never escape the sync repository.
+pub fn validate_sync_subdirectory(subdir: &str) -> Result<()> {
+    let subdir = subdir.trim();
+    if subdir.is_empty() {
+        bail!("Sync subdirectory cannot be empty");
+    }
+    // Check both separators and drive letters so Windows-style absolute
+    // paths are rejected on every platform.
+    if subdir.starts_with(['/', '\\']) || subdir.contains(':') || Path::new(subdir).has_root() {
+        bail!(
+            "Sync subdirectory must be relative to the sync repo, got absolute path '{}'",
+            subdir
+        );
+    }
+    if subdir.split(['/', '\\']).any(|part| part == "..") {
+        bail!("Sync subdirectory must not contain '..': '{}'", subdir);
+    }
+    Ok(())
+}
+
+/// Check a `device_namespace` is a single plain directory name
+pub fn validate_device_namespace(namespace: &str) -> Result<()> {
+    if namespace.is_empty() {
+        bail!("Device namespace cannot be empty");
+    }
+    if namespace.contains(['/', '\\', ':']) {
+        bail!(
+            "Device namespace must be a single directory name, got '{}'",
+            namespace
+        );
+    }
+    if namespace.starts_with('.') {
+        bail!("Device namespace must not start with '.': '{}'", namespace);
+    }
+    Ok(())
+}
+
 /// Simple glob pattern matching
 fn glob_match(pattern: &str, text: &str) -> bool {
     // Simple implementation - for production, use the `glob` crate
@@ -453,19 +906,75 @@ fn glob_match(pattern: &str, text: &str) -> bool {
     }
 }
 
+/// Settings changed by `ccs config` flags; `None` leaves a setting as it is
+///
+/// List-valued settings (`include_projects`, `lfs_patterns`, ...) are
+/// comma-separated, as given on the command line.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConfigUpdate {
+    pub exclude_older_than: Option<u32>,
+    pub age_by: Option<AgeBy>,
+    pub include_projects: Option<String>,
+    pub exclude_projects: Option<String>,
+    pub exclude_attachments: Option<bool>,
+    pub redact_on_push: Option<bool>,
+    pub scan_secrets: Option<bool>,
+    pub secret_patterns: Option<String>,
+    pub follow_symlinks: Option<bool>,
+    pub enable_lfs: Option<bool>,
+    pub lfs_patterns: Option<String>,
+    pub scm_backend: Option<String>,
+    pub sync_subdirectory: Option<String>,
+    pub use_project_name_only: Option<bool>,
+    pub collision_strategy: Option<CollisionStrategy>,
+    pub device_namespace: Option<String>,
+    pub ssh_identity: Option<String>,
+    pub commit_message_template: Option<String>,
+    pub large_file_warning_mb: Option<u64>,
+    pub stop_push_interval: Option<u64>,
+    pub hook_timeout: Option<u64>,
+    pub push_attempts: Option<u32>,
+    pub cleanup_min_messages: Option<usize>,
+    pub memory_dir_name: Option<String>,
+    pub time_format: Option<TimeFormat>,
+}
+
+impl ConfigUpdate {
+    /// Whether no setting would change
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
 /// Update the filter configuration
-#[allow(clippy::too_many_arguments)]
-pub fn update_config(
-    exclude_older_than: Option<u32>,
-    include_projects: Option<String>,
-    exclude_projects: Option<String>,
-    exclude_attachments: Option<bool>,
-    enable_lfs: Option<bool>,
-    lfs_patterns: Option<String>,
-    scm_backend: Option<String>,
-    sync_subdirectory: Option<String>,
-    use_project_name_only: Option<bool>,
-) -> Result<()> {
+pub fn update_config(update: ConfigUpdate) -> Result<()> {
+    let ConfigUpdate {
+        exclude_older_than,
+        age_by,
+        include_projects,
+        exclude_projects,
+        exclude_attachments,
+        redact_on_push,
+        scan_secrets,
+        secret_patterns,
+        follow_symlinks,
+        enable_lfs,
+        lfs_patterns,
+        scm_backend,
+        sync_subdirectory,
+        use_project_name_only,
+        collision_strategy,
+        device_namespace,
+        ssh_identity,
+        commit_message_template,
+        large_file_warning_mb,
+        stop_push_interval,
+        hook_timeout,
+        push_attempts,
+        cleanup_min_messages,
+        memory_dir_name,
+        time_format,
+    } = update;
     let mut config = FilterConfig::load()?;
 
     if let Some(days) = exclude_older_than {
@@ -476,6 +985,11 @@ pub fn update_config(
         );
     }
 
+    if let Some(age_by) = age_by {
+        config.age_by = age_by;
+        println!("{}", format!("Set age_by to {}", age_by.as_str()).green());
+    }
+
     if let Some(includes) = include_projects {
         config.include_patterns = includes
             .split(',')
@@ -505,7 +1019,46 @@ pub fn update_config(
         println!("{}", format!("Exclude attachments: {exclude_att}").green());
     }
 
+    if let Some(redact) = redact_on_push {
+        config.redact_on_push = redact;
+        println!(
+            "{}",
+            format!("Strip embedded attachments on push: {redact}").green()
+        );
+    }
+
+    if let Some(scan) = scan_secrets {
+        config.scan_secrets = scan;
+        println!(
+            "{}",
+            format!("Scan for secrets before push: {scan}").green()
+        );
+    }
+
+    if let Some(patterns) = secret_patterns {
+        let patterns: Vec<String> = patterns
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.is_empty())
+            .collect();
+        // Reject invalid regexes now rather than on the next push
+        crate::secrets::SecretScanner::new(&patterns)?;
+        config.secret_patterns = patterns;
+        println!(
+            "{}",
+            format!("Set secret patterns: {:?}", config.secret_patterns).green()
+        );
+    }
+
+    if let Some(follow) = follow_symlinks {
+        config.follow_symlinks = follow;
+        println!("{}", format!("Follow symlinked projects: {follow}").green());
+    }
+
     if let Some(lfs) = enable_lfs {
+        if lfs {
+            crate::scm::lfs::ensure_installed()?;
+        }
         config.enable_lfs = lfs;
         println!(
             "{}",
@@ -542,9 +1095,7 @@ pub fn update_config(
 
     if let Some(subdir) = sync_subdirectory {
         let subdir_trimmed = subdir.trim().to_string();
-        if subdir_trimmed.is_empty() {
-            bail!("Sync subdirectory cannot be empty");
-        }
+        validate_sync_subdirectory(&subdir_trimmed)?;
         config.sync_subdirectory = subdir_trimmed;
         println!(
             "{}",
@@ -569,12 +1120,12 @@ pub fn update_config(
                 println!("影响：");
                 println!(
                     "  {} 新推送的文件将使用项目名格式（如 {}）",
-                    "•".cyan(),
+                    icon("•").cyan(),
                     "n8n-workflow/".green()
                 );
                 println!(
                     "  {} 已有的完整路径格式目录（如 {}）不会自动清理",
-                    "•".cyan(),
+                    icon("•").cyan(),
                     "-Users-.../".dimmed()
                 );
                 println!();
@@ -589,12 +1140,12 @@ pub fn update_config(
                 println!("影响：");
                 println!(
                     "  {} 新推送的文件将使用完整路径格式（如 {}）",
-                    "•".cyan(),
+                    icon("•").cyan(),
                     "-Users-xxx-project/".dimmed()
                 );
                 println!(
                     "  {} 已有的项目名格式目录（如 {}）不会自动清理",
-                    "•".cyan(),
+                    icon("•").cyan(),
                     "n8n-workflow/".green()
                 );
                 println!();
@@ -619,6 +1170,142 @@ pub fn update_config(
         );
     }
 
+    if let Some(strategy) = collision_strategy {
+        config.collision_strategy = strategy;
+        println!(
+            "{}",
+            format!("Set collision_strategy to {}", strategy.as_str()).green()
+        );
+    }
+
+    if let Some(namespace) = device_namespace {
+        let namespace = namespace.trim();
+        if namespace.is_empty() {
+            config.device_namespace = None;
+            println!("{}", "Cleared device namespace".green());
+        } else {
+            validate_device_namespace(namespace)?;
+            config.device_namespace = Some(namespace.to_string());
+            println!("{}", format!("Set device namespace: {namespace}").green());
+            if !config.use_project_name_only {
+                println!(
+                    "{}",
+                    "⚠️  The namespace only applies with --use-project-name-only true".yellow()
+                );
+            }
+        }
+    }
+
+    if let Some(identity) = ssh_identity {
+        let identity = identity.trim().to_string();
+        if identity.is_empty() {
+            config.ssh_identity = None;
+            println!(
+                "{}",
+                "Cleared SSH identity (using default SSH keys)".green()
+            );
+        } else {
+            config.ssh_identity = Some(identity);
+            if let Some(path) = config.ssh_identity_path() {
+                if !path.exists() {
+                    println!(
+                        "{}",
+                        format!("⚠️  SSH identity file not found: {}", path.display()).yellow()
+                    );
+                }
+                println!(
+                    "{}",
+                    format!("Set SSH identity: {}", path.display()).green()
+                );
+            }
+        }
+    }
+
+    if let Some(template) = commit_message_template {
+        if template.trim().is_empty() {
+            config.commit_message_template = None;
+            println!(
+                "{}",
+                "Cleared commit message template (using default message)".green()
+            );
+        } else {
+            println!(
+                "{}",
+                format!("Set commit message template: {template}").green()
+            );
+            config.commit_message_template = Some(template);
+        }
+    }
+
+    if let Some(mb) = large_file_warning_mb {
+        config.large_file_warning_mb = mb;
+        if mb == 0 {
+            println!("{}", "Disabled large file warnings".green());
+        } else {
+            println!(
+                "{}",
+                format!("Set large file warning threshold to {mb} MB").green()
+            );
+        }
+    }
+
+    if let Some(secs) = stop_push_interval {
+        config.stop_push_interval_secs = secs;
+        if secs == 0 {
+            println!("{}", "Stop hook pushes after every response".green());
+        } else {
+            println!(
+                "{}",
+                format!("Stop hook pushes at most once every {secs}s").green()
+            );
+        }
+    }
+
+    if let Some(secs) = hook_timeout {
+        config.hook_timeout_secs = secs;
+        if secs == 0 {
+            println!("{}", "Disabled the hook push/pull timeout".green());
+        } else {
+            println!(
+                "{}",
+                format!("Hook pushes and pulls are stopped after {secs}s").green()
+            );
+        }
+    }
+
+    if let Some(attempts) = push_attempts {
+        config.push_attempts = attempts;
+        println!("{}", format!("Set push attempts to {attempts}").green());
+    }
+
+    if let Some(min) = cleanup_min_messages {
+        config.cleanup.min_messages = min;
+        println!(
+            "{}",
+            format!("Set cleanup threshold: sessions with fewer than {min} messages").green()
+        );
+    }
+
+    if let Some(name) = memory_dir_name {
+        config.auto_memory.memory_dir_name = name.trim().to_string();
+        println!(
+            "{}",
+            format!(
+                "Set auto memory directory name: {}",
+                config.auto_memory.memory_dir_name
+            )
+            .green()
+        );
+    }
+
+    if let Some(format) = time_format {
+        config.time_format = format;
+        println!(
+            "{}",
+            format!("Set time_format to {}", format.as_str()).green()
+        );
+    }
+
     // Validate configuration before saving
     config.validate()?;
 
@@ -638,7 +1325,7 @@ pub fn show_config() -> Result<()> {
         "Exclude older than".cyan(),
         config
             .exclude_older_than_days
-            .map(|d| format!("{d} days"))
+            .map(|d| format!("{d} days (by {})", config.age_by.as_str()))
             .unwrap_or_else(|| "Not set".to_string())
     );
     println!(
@@ -674,6 +1361,35 @@ pub fn show_config() -> Result<()> {
             "No (all files)".yellow()
         }
     );
+    println!(
+        "  {}: {}",
+        "Redact on push".cyan(),
+        if config.redact_on_push {
+            "Yes (embedded images/documents removed)".green()
+        } else {
+            "No".dimmed()
+        }
+    );
+    println!(
+        "  {}: {}",
+        "Scan for secrets".cyan(),
+        if !config.scan_secrets {
+            "No".dimmed()
+        } else if config.secret_patterns.is_empty() {
+            "Yes (built-in detectors)".green()
+        } else {
+            format!("Yes (+ {})", config.secret_patterns.join(", ")).green()
+        }
+    );
+    println!(
+        "  {}: {}",
+        "Follow symlinks".cyan(),
+        if config.follow_symlinks {
+            "Yes".green()
+        } else {
+            "No".dimmed()
+        }
+    );
     println!(
         "  {}: {}",
         "Git LFS".cyan(),
@@ -698,6 +1414,90 @@ pub fn show_config() -> Result<()> {
             "No (full path mode)".yellow()
         }
     );
+    if config.use_project_name_only {
+        println!(
+            "  {}: {}",
+            "Name collisions".cyan(),
+            config.collision_strategy.as_str()
+        );
+        println!(
+            "  {}: {}",
+            "Device namespace".cyan(),
+            config
+                .device_namespace
+                .as_deref()
+                .map(|s| s.green())
+                .unwrap_or_else(|| "None".dimmed())
+        );
+    }
+    println!(
+        "  {}: {}",
+        "SSH identity".cyan(),
+        config
+            .ssh_identity
+            .as_deref()
+            .map(|s| s.green())
+            .unwrap_or_else(|| "Default".dimmed())
+    );
+    println!(
+        "  {}: {}",
+        "Large file warning".cyan(),
+        if config.large_file_warning_mb == 0 {
+            "Disabled".dimmed()
+        } else {
+            format!("{} MB", config.large_file_warning_mb).green()
+        }
+    );
+    println!(
+        "  {}: {}",
+        "Stop hook push interval".cyan(),
+        if config.stop_push_interval_secs == 0 {
+            "Every response".yellow()
+        } else {
+            format!("{}s", config.stop_push_interval_secs).green()
+        }
+    );
+    println!(
+        "  {}: {}",
+        "Hook push/pull timeout".cyan(),
+        if config.hook_timeout_secs == 0 {
+            "Disabled".yellow()
+        } else {
+            format!("{}s", config.hook_timeout_secs).green()
+        }
+    );
+    println!(
+        "  {}: {}",
+        "Push attempts".cyan(),
+        config.push_attempts.to_string().green()
+    );
+    println!(
+        "  {}: {}",
+        IGNORE_FILE_NAME.cyan(),
+        match config.ignore_file {
+            Some(ref ignore) => format!("{} patterns", ignore.num_ignores()).green(),
+            None => "Not found".dimmed(),
+        }
+    );
+    println!(
+        "  {}: {}",
+        "Cleanup".cyan(),
+        config.cleanup.describe().green()
+    );
+    println!(
+        "  {}: {}",
+        "Time format".cyan(),
+        config.time_format.as_str()
+    );
+    println!(
+        "  {}: {}",
+        "Commit message template".cyan(),
+        config
+            .commit_message_template
+            .as_deref()
+            .map(|s| s.green())
+            .unwrap_or_else(|| "Default".dimmed())
+    );
 
     // Show config sync settings
     println!();
@@ -776,6 +1576,11 @@ pub fn show_config() -> Result<()> {
             "Disabled".yellow()
         }
     );
+    println!(
+        "  {}: {}",
+        "Memory directory".cyan(),
+        config.auto_memory.memory_dir_name
+    );
 
     Ok(())
 }
@@ -792,6 +1597,33 @@ mod tests {
         assert!(!glob_match("test*", "no match"));
     }
 
+    #[test]
+    fn test_read_from_validates_imported_config() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("exported.toml");
+
+        let config = FilterConfig {
+            exclude_patterns: vec!["*scratch*".to_string()],
+            stop_push_interval_secs: 120,
+            ..FilterConfig::default()
+        };
+        fs::write(&path, toml::to_string_pretty(&config).unwrap()).unwrap();
+        let imported = FilterConfig::read_from(&path).unwrap();
+        assert_eq!(imported.exclude_patterns, config.exclude_patterns);
+        assert_eq!(imported.stop_push_interval_secs, 120);
+
+        for invalid in [
+            "push_attempts = 0",
+            "scm_backend = \"svn\"",
+            "sync_subdirectory = \"../outside\"",
+            "secret_patterns = [\"(unclosed\"]",
+            "not toml",
+        ] {
+            fs::write(&path, invalid).unwrap();
+            assert!(FilterConfig::read_from(&path).is_err(), "{invalid}");
+        }
+    }
+
     #[test]
     fn test_filter_config_default() {
         let config = FilterConfig::default();
@@ -868,4 +1700,161 @@ mod tests {
         assert!(deserialized.exclude_attachments);
         assert_eq!(deserialized.exclude_older_than_days, Some(30));
     }
+
+    #[test]
+    fn test_ssh_identity_path() {
+        let mut config = FilterConfig::default();
+        assert_eq!(config.ssh_identity_path(), None);
+
+        config.ssh_identity = Some("  ".to_string());
+        assert_eq!(config.ssh_identity_path(), None);
+
+        config.ssh_identity = Some("/keys/id_work".to_string());
+        assert_eq!(
+            config.ssh_identity_path(),
+            Some(PathBuf::from("/keys/id_work"))
+        );
+
+        config.ssh_identity = Some("~/.ssh/id_work".to_string());
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(config.ssh_identity_path(), Some(home.join(".ssh/id_work")));
+
+        // Not serialized when unset
+        let serialized = toml::to_string(&FilterConfig::default()).unwrap();
+        assert!(!serialized.contains("ssh_identity"));
+    }
+
+    #[test]
+    fn test_ccsignore_excludes_matching_projects() {
+        let root = tempfile::TempDir::new().unwrap();
+        fs::write(
+            root.path().join(IGNORE_FILE_NAME),
+            "# scratch work\n*-scratch-*/\nprivate/secret.jsonl\n",
+        )
+        .unwrap();
+
+        let config = FilterConfig {
+            ignore_file: load_ignore_file(root.path()),
+            ..Default::default()
+        };
+
+        let path = |rel: &str| root.path().join(rel);
+        assert!(!config.should_include(&path("-Users-me-scratch-a/s1.jsonl")));
+        assert!(!config.should_include(&path("private/secret.jsonl")));
+        assert!(config.should_include(&path("private/other.jsonl")));
+        assert!(config.should_include(&path("-Users-me-work/s1.jsonl")));
+        // Files outside the ignore file's directory are never matched
+        assert!(config.should_include(Path::new("/elsewhere/-Users-me-scratch-a/s1.jsonl")));
+
+        assert!(load_ignore_file(&path("missing")).is_none());
+    }
+
+    #[test]
+    fn test_cleanup_settings_keeps() {
+        let rules = CleanupSettings::default();
+        assert!(!rules.keeps(0, true));
+        assert!(rules.keeps(1, true));
+        assert!(!rules.keeps(5, false));
+
+        let rules = CleanupSettings {
+            min_messages: 2,
+            require_title: false,
+        };
+        assert!(!rules.keeps(1, true));
+        assert!(rules.keeps(2, false));
+        assert_eq!(rules.describe(), "fewer than 2 messages");
+
+        // Missing [cleanup] table falls back to the old behavior
+        let config: FilterConfig = toml::from_str("").unwrap();
+        assert_eq!(config.cleanup.min_messages, 1);
+        assert!(config.cleanup.require_title);
+    }
+
+    #[test]
+    fn test_memory_dir_name_default_and_validation() {
+        let config: FilterConfig = toml::from_str("[auto_memory]\nenabled = true\n").unwrap();
+        assert_eq!(config.auto_memory.memory_dir_name, "memory");
+        assert!(config.validate().is_ok());
+
+        for bad in ["", "..", "a/b", "a\\b"] {
+            let mut config = FilterConfig::default();
+            config.auto_memory.memory_dir_name = bad.to_string();
+            assert!(config.validate().is_err(), "{bad:?} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_validate_sync_subdirectory() {
+        assert!(validate_sync_subdirectory("projects").is_ok());
+        assert!(validate_sync_subdirectory("history/work").is_ok());
+        assert!(validate_sync_subdirectory("a..b").is_ok());
+
+        assert!(validate_sync_subdirectory("").is_err());
+        assert!(validate_sync_subdirectory("   ").is_err());
+        assert!(validate_sync_subdirectory("/etc").is_err());
+        assert!(validate_sync_subdirectory("\\server\\share").is_err());
+        assert!(validate_sync_subdirectory("C:\\history").is_err());
+        assert!(validate_sync_subdirectory("..").is_err());
+        assert!(validate_sync_subdirectory("projects/../../outside").is_err());
+        assert!(validate_sync_subdirectory("..\\outside").is_err());
+    }
+
+    #[test]
+    fn test_config_update_is_empty() {
+        assert!(ConfigUpdate::default().is_empty());
+        let update = ConfigUpdate {
+            time_format: Some(TimeFormat::Absolute),
+            ..Default::default()
+        };
+        assert!(!update.is_empty());
+    }
+
+    #[test]
+    fn test_device_namespace_scopes_repo_projects_dir() {
+        let repo = Path::new("/repo");
+        let mut config = FilterConfig {
+            device_namespace: Some("alice".to_string()),
+            ..FilterConfig::default()
+        };
+        assert_eq!(
+            config.repo_projects_dir(repo),
+            repo.join("projects").join("alice")
+        );
+        config.use_project_name_only = false;
+        assert_eq!(config.repo_projects_dir(repo), repo.join("projects"));
+
+        assert!(validate_device_namespace("alice").is_ok());
+        assert!(validate_device_namespace("").is_err());
+        assert!(validate_device_namespace("a/b").is_err());
+        assert!(validate_device_namespace("..").is_err());
+    }
+
+    #[test]
+    fn test_age_by_content_uses_last_message() {
+        let root = tempfile::TempDir::new().unwrap();
+        let path = root.path().join("s.jsonl");
+        let old = r#"{"type":"user","sessionId":"s","timestamp":"2020-01-01T00:00:00Z"}"#;
+        fs::write(&path, old).unwrap();
+        let session = ConversationSession::from_file(&path).unwrap();
+
+        let mut config = FilterConfig {
+            exclude_older_than_days: Some(30),
+            ..Default::default()
+        };
+        // By mtime the freshly written file is recent
+        assert!(config.should_include(&path));
+        assert!(!config.is_too_old_by_content(&session));
+
+        config.age_by = AgeBy::Content;
+        assert!(config.should_include(&path));
+        assert!(config.is_too_old_by_content(&session));
+
+        let recent = format!(
+            r#"{{"type":"user","sessionId":"s","timestamp":"{}"}}"#,
+            chrono::Utc::now().to_rfc3339()
+        );
+        fs::write(&path, format!("{old}\n{recent}\n")).unwrap();
+        let session = ConversationSession::from_file(&path).unwrap();
+        assert!(!config.is_too_old_by_content(&session));
+    }
 }
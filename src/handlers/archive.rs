@@ -0,0 +1,396 @@
+//! Session archive handlers
+//!
+//! Handles `ccs session archive/unarchive`. Archiving moves a session file
+//! out of `~/.claude/projects/<project>/` into `~/.claude/archive/<project>/`
+//! (optionally gzip-compressed) so it disappears from session listings and
+//! scans without being deleted. The sync repo copy is left untouched.
+
+use anyhow::{anyhow, bail, Context, Result};
+use colored::Colorize;
+use std::collections::HashSet;
+use std::fs;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+use crate::error::SyncError;
+use crate::interactive_conflict;
+use crate::output::icon;
+use crate::sync::discovery::{claude_dir, claude_projects_dir};
+use crate::BINARY_NAME;
+
+use super::session::{scan_all_projects, scan_project_sessions, SessionSummary};
+
+/// Extension appended to archived files when compressed.
+const GZIP_EXTENSION: &str = "gz";
+
+/// Directory holding archived sessions: `~/.claude/archive`
+pub(crate) fn claude_archive_dir() -> Result<PathBuf> {
+    Ok(claude_dir()?.join("archive"))
+}
+
+/// Session ID of an archived file name (`<id>.jsonl` or `<id>.jsonl.gz`)
+fn archived_session_id(file_name: &str) -> Option<&str> {
+    let name = file_name.strip_suffix(".gz").unwrap_or(file_name);
+    name.strip_suffix(".jsonl")
+}
+
+/// Collect the IDs of all sessions in the archive directory
+///
+/// Used by pull so archived sessions are not downloaded back from the sync
+/// repo, and by push so they are not reported as accidentally missing.
+pub(crate) fn archived_session_ids() -> HashSet<String> {
+    let mut ids = HashSet::new();
+    let Ok(archive_dir) = claude_archive_dir() else {
+        return ids;
+    };
+    let Ok(projects) = fs::read_dir(&archive_dir) else {
+        return ids;
+    };
+
+    for project in projects.filter_map(|e| e.ok()) {
+        let Ok(files) = fs::read_dir(project.path()) else {
+            continue;
+        };
+        for file in files.filter_map(|f| f.ok()) {
+            if let Some(id) = file.file_name().to_str().and_then(archived_session_id) {
+                ids.insert(id.to_string());
+            }
+        }
+    }
+
+    ids
+}
+
+/// Move a session file (and its sibling `<id>/` subagent directory, if any)
+/// into `archive_project_dir`, gzip-compressing the `.jsonl` when requested.
+///
+/// Returns the path of the archived session file.
+fn archive_file(session_file: &Path, archive_project_dir: &Path, gzip: bool) -> Result<PathBuf> {
+    let file_name = session_file
+        .file_name()
+        .context("Session path has no file name")?;
+    fs::create_dir_all(archive_project_dir).with_context(|| {
+        format!(
+            "Failed to create archive directory: {}",
+            archive_project_dir.display()
+        )
+    })?;
+
+    let dest = if gzip {
+        let mut name = file_name.to_os_string();
+        name.push(".");
+        name.push(GZIP_EXTENSION);
+        archive_project_dir.join(name)
+    } else {
+        archive_project_dir.join(file_name)
+    };
+    if dest.exists() {
+        bail!("Archive already contains {}", dest.display());
+    }
+
+    if gzip {
+        let input = fs::File::open(session_file)
+            .with_context(|| format!("Failed to open {}", session_file.display()))?;
+        let output = fs::File::create(&dest)
+            .with_context(|| format!("Failed to create {}", dest.display()))?;
+        let mut encoder =
+            flate2::write::GzEncoder::new(BufWriter::new(output), flate2::Compression::default());
+        std::io::copy(&mut BufReader::new(input), &mut encoder)?;
+        encoder.finish()?;
+        fs::remove_file(session_file)?;
+    } else {
+        move_path(session_file, &dest)?;
+    }
+
+    // Subagent transcripts live next to the session in `<id>/`
+    if let Some(stem) = session_file.file_stem() {
+        let sibling_dir = session_file.with_file_name(stem);
+        if sibling_dir.is_dir() {
+            move_path(&sibling_dir, &archive_project_dir.join(stem))?;
+        }
+    }
+
+    Ok(dest)
+}
+
+/// Restore an archived session file into `project_dir`, decompressing `.gz`
+///
+/// Returns the path of the restored `.jsonl` file.
+fn restore_file(archived: &Path, project_dir: &Path) -> Result<PathBuf> {
+    let file_name = archived
+        .file_name()
+        .and_then(|n| n.to_str())
+        .context("Archived path has no file name")?;
+    let session_id = archived_session_id(file_name).context("Not an archived session file")?;
+    let dest = project_dir.join(format!("{session_id}.jsonl"));
+    if dest.exists() {
+        bail!("Session already exists locally: {}", dest.display());
+    }
+    fs::create_dir_all(project_dir)?;
+
+    if file_name.ends_with(".gz") {
+        let input = fs::File::open(archived)
+            .with_context(|| format!("Failed to open {}", archived.display()))?;
+        let mut decoder = flate2::read::GzDecoder::new(BufReader::new(input));
+        let mut output = BufWriter::new(
+            fs::File::create(&dest)
+                .with_context(|| format!("Failed to create {}", dest.display()))?,
+        );
+        std::io::copy(&mut decoder, &mut output)?;
+        drop(output);
+        fs::remove_file(archived)?;
+    } else {
+        move_path(archived, &dest)?;
+    }
+
+    if let Some(archive_project_dir) = archived.parent() {
+        let sibling_dir = archive_project_dir.join(session_id);
+        if sibling_dir.is_dir() && !project_dir.join(session_id).exists() {
+            move_path(&sibling_dir, &project_dir.join(session_id))?;
+        }
+    }
+
+    Ok(dest)
+}
+
+/// Rename, falling back to copy + delete for single files across filesystems
+fn move_path(from: &Path, to: &Path) -> Result<()> {
+    if fs::rename(from, to).is_ok() {
+        return Ok(());
+    }
+    if from.is_file() {
+        fs::copy(from, to)
+            .with_context(|| format!("Failed to copy {} to {}", from.display(), to.display()))?;
+        fs::remove_file(from)?;
+        return Ok(());
+    }
+    Err(anyhow!(
+        "Failed to move {} to {}",
+        from.display(),
+        to.display()
+    ))
+}
+
+/// Whether a session's last activity is older than `days` days
+fn is_older_than(session: &SessionSummary, days: u32) -> bool {
+    let cutoff = chrono::Utc::now() - chrono::Duration::days(days as i64);
+    session
+        .last_activity
+        .as_deref()
+        .and_then(|ts| chrono::DateTime::parse_from_rfc3339(ts).ok())
+        .is_some_and(|ts| ts < cutoff)
+}
+
+/// Archive one session by ID, or every session inactive for `older_than` days
+pub fn handle_session_archive(
+    session_id: Option<&str>,
+    older_than: Option<u32>,
+    gzip: bool,
+    force: bool,
+) -> Result<()> {
+    let claude_dir = claude_projects_dir()?;
+    let archive_dir = claude_archive_dir()?;
+
+    let mut targets: Vec<SessionSummary> = Vec::new();
+    for project in scan_all_projects()? {
+        for session in scan_project_sessions(&project)? {
+            let matched = match (session_id, older_than) {
+                (Some(id), _) => session.session_id == id,
+                (None, Some(days)) => is_older_than(&session, days),
+                (None, None) => false,
+            };
+            if matched {
+                targets.push(session);
+            }
+        }
+    }
+
+    if targets.is_empty() {
+        if let Some(id) = session_id {
+            return Err(SyncError::session_not_found(id).into());
+        }
+        println!("{}", "No sessions to archive.".yellow());
+        return Ok(());
+    }
+
+    if session_id.is_none() && !force {
+        println!(
+            "{} {} session(s) inactive for more than {} days:",
+            "Archive".cyan().bold(),
+            targets.len(),
+            older_than.unwrap_or_default()
+        );
+        for session in &targets {
+            println!(
+                "  {} {} {}",
+                icon("•").dimmed(),
+                session.display_title(50),
+                format!("({})", session.project_name).dimmed()
+            );
+        }
+        println!();
+
+        let confirm = interactive_conflict::confirm(
+            "Move these sessions to the archive?",
+            false,
+            interactive_conflict::assume_yes(),
+        );
+        if !matches!(confirm, Ok(true)) {
+            println!("{}", "Archive cancelled.".yellow());
+            return Ok(());
+        }
+    }
+
+    let mut archived = 0;
+    for session in &targets {
+        let project_dir_name = session
+            .file_path
+            .parent()
+            .and_then(|p| p.strip_prefix(&claude_dir).ok())
+            .map(Path::to_path_buf)
+            .context("Session is not under the Claude projects directory")?;
+
+        match archive_file(
+            &session.file_path,
+            &archive_dir.join(&project_dir_name),
+            gzip,
+        ) {
+            Ok(dest) => {
+                archived += 1;
+                log::info!("Archived {} -> {}", session.session_id, dest.display());
+            }
+            Err(e) => println!(
+                "  {} Failed to archive {}: {}",
+                icon("⚠").yellow(),
+                session.session_id,
+                e
+            ),
+        }
+    }
+
+    println!(
+        "{} Archived {} session(s) to {}",
+        icon("✓").green().bold(),
+        archived,
+        archive_dir.display()
+    );
+    println!(
+        "  Restore with '{}'",
+        format!("{} session unarchive <SESSION_ID>", BINARY_NAME).cyan()
+    );
+
+    Ok(())
+}
+
+/// Move an archived session back into `~/.claude/projects`
+pub fn handle_session_unarchive(session_id: &str) -> Result<()> {
+    let claude_dir = claude_projects_dir()?;
+    let archive_dir = claude_archive_dir()?;
+
+    let projects = fs::read_dir(&archive_dir)
+        .map_err(|_| anyhow!("No archived sessions found in {}", archive_dir.display()))?;
+    for project in projects.filter_map(|e| e.ok()) {
+        for name in [
+            format!("{session_id}.jsonl"),
+            format!("{session_id}.jsonl.{GZIP_EXTENSION}"),
+        ] {
+            let archived = project.path().join(&name);
+            if !archived.is_file() {
+                continue;
+            }
+
+            let dest = restore_file(&archived, &claude_dir.join(project.file_name()))?;
+            if fs::read_dir(project.path())
+                .map(|mut d| d.next().is_none())
+                .unwrap_or(false)
+            {
+                let _ = fs::remove_dir(project.path());
+            }
+
+            println!(
+                "{} Restored session {} to {}",
+                icon("✓").green().bold(),
+                session_id.cyan(),
+                dest.display()
+            );
+            return Ok(());
+        }
+    }
+
+    bail!("Archived session not found: {}", session_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_archived_session_id() {
+        assert_eq!(archived_session_id("abc.jsonl"), Some("abc"));
+        assert_eq!(archived_session_id("abc.jsonl.gz"), Some("abc"));
+        assert_eq!(archived_session_id("notes.txt"), None);
+    }
+
+    #[test]
+    fn test_archive_and_restore_roundtrip() {
+        for gzip in [false, true] {
+            let temp = TempDir::new().unwrap();
+            let project_dir = temp.path().join("projects").join("-home-me-app");
+            let archive_project_dir = temp.path().join("archive").join("-home-me-app");
+            fs::create_dir_all(project_dir.join("sess-1").join("subagents")).unwrap();
+            let session_file = project_dir.join("sess-1.jsonl");
+            let content = "{\"type\":\"user\",\"sessionId\":\"sess-1\"}\n";
+            fs::write(&session_file, content).unwrap();
+
+            let archived = archive_file(&session_file, &archive_project_dir, gzip).unwrap();
+            assert!(!session_file.exists());
+            assert!(!project_dir.join("sess-1").exists());
+            assert!(archive_project_dir.join("sess-1").is_dir());
+            assert_eq!(
+                archived.extension().and_then(|e| e.to_str()),
+                Some(if gzip { "gz" } else { "jsonl" })
+            );
+
+            let restored = restore_file(&archived, &project_dir).unwrap();
+            assert_eq!(restored, session_file);
+            assert_eq!(fs::read_to_string(&session_file).unwrap(), content);
+            assert!(project_dir.join("sess-1").join("subagents").is_dir());
+            assert!(!archived.exists());
+        }
+    }
+
+    #[test]
+    fn test_discovery_does_not_see_archive() {
+        let temp = TempDir::new().unwrap();
+        let projects = temp.path().join("projects").join("p");
+        let archive = temp.path().join("archive").join("p");
+        fs::create_dir_all(&projects).unwrap();
+        fs::create_dir_all(&archive).unwrap();
+        let entry = "{\"type\":\"user\",\"uuid\":\"1\",\"sessionId\":\"live\"}\n";
+        fs::write(projects.join("live.jsonl"), entry).unwrap();
+        fs::write(archive.join("old.jsonl"), entry.replace("live", "old")).unwrap();
+
+        let filter = crate::filter::FilterConfig::no_size_limit();
+        let sessions =
+            crate::sync::discovery::discover_sessions(&temp.path().join("projects"), &filter)
+                .unwrap();
+        let ids: Vec<_> = sessions.iter().map(|s| s.session_id.as_str()).collect();
+        assert_eq!(ids, vec!["live"]);
+    }
+
+    #[test]
+    fn test_restore_refuses_to_overwrite() {
+        let temp = TempDir::new().unwrap();
+        let project_dir = temp.path().join("projects").join("p");
+        let archive_project_dir = temp.path().join("archive").join("p");
+        fs::create_dir_all(&project_dir).unwrap();
+        let session_file = project_dir.join("s.jsonl");
+        fs::write(&session_file, "{}\n").unwrap();
+
+        let archived = archive_file(&session_file, &archive_project_dir, false).unwrap();
+        fs::write(&session_file, "{}\n").unwrap();
+        assert!(restore_file(&archived, &project_dir).is_err());
+        assert!(archived.exists());
+    }
+}
@@ -6,9 +6,12 @@
 use anyhow::Result;
 use colored::Colorize;
 
+use crate::output::icon;
 use crate::BINARY_NAME;
 
-use super::hooks::{are_hooks_installed, handle_hooks_install, handle_hooks_uninstall};
+use super::hooks::{
+    handle_hooks_install, handle_hooks_uninstall, installed_hook_events, HOOK_EVENTS,
+};
 use super::wrapper::{
     get_wrapper_path, handle_wrapper_install, handle_wrapper_uninstall, is_wrapper_installed,
 };
@@ -42,14 +45,30 @@ pub fn handle_automate_status() -> Result<()> {
     println!("{}", "═".repeat(40).dimmed());
     println!();
 
-    // Check hooks
-    let hooks_installed = are_hooks_installed()?;
+    // Check hooks, event by event as found in settings.json
+    let installed_events = installed_hook_events()?;
+    let hooks_installed = installed_events.len() == HOOK_EVENTS.len();
     if hooks_installed {
         println!("{} {}", "Hooks:".bold(), "INSTALLED".green());
-        println!("  {} SessionEnd (sync on exit)", "•".green());
-        println!("  {} UserPromptSubmit (new project detection)", "•".green());
-    } else {
+    } else if installed_events.is_empty() {
         println!("{} {}", "Hooks:".bold(), "NOT INSTALLED".yellow());
+    } else {
+        println!("{} {}", "Hooks:".bold(), "PARTIALLY INSTALLED".yellow());
+    }
+    if !installed_events.is_empty() {
+        for (event, _, purpose) in HOOK_EVENTS {
+            if installed_events.contains(event) {
+                println!("  {} {}: {}", icon("•").green(), event, purpose);
+            } else {
+                println!(
+                    "  {} {}: {} {}",
+                    icon("✗").red(),
+                    event,
+                    purpose,
+                    "(missing)".yellow()
+                );
+            }
+        }
     }
     println!();
 
@@ -149,13 +168,16 @@ fn print_success_message(wrapper_path: &std::path::Path) -> Result<()> {
     println!("{}", "What happens:".bold());
     println!(
         "  {} On startup: Pull latest conversation history from remote",
-        "•".cyan()
+        icon("•").cyan()
     );
     println!(
         "  {} New project: Detect and pull remote history on first message",
-        "•".cyan()
+        icon("•").cyan()
+    );
+    println!(
+        "  {} After each response: Push conversations to remote",
+        icon("•").cyan()
     );
-    println!("  {} On exit: Sync conversations to remote", "•".cyan());
     println!();
 
     println!("{}", "Commands:".bold());
@@ -0,0 +1,229 @@
+//! CLAUDE.md platform block tools
+//!
+//! Handles `ccs claude-md analyze`, which shows how the local
+//! `~/.claude/CLAUDE.md` is split into common content and platform blocks so
+//! it can be audited before pushing it to other devices, and
+//! `ccs claude-md init`, which scaffolds a platform-tagged starter file.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::PathBuf;
+
+use super::platform_filter::{
+    common_content, extract_other_blocks, extract_platform_blocks, filter_for_platform,
+    unknown_platform_tags, Platform,
+};
+use crate::output::icon;
+use crate::sync::discovery::claude_dir;
+
+/// Platforms a CLAUDE.md can be applied on
+const ALL_PLATFORMS: [Platform; 3] = [Platform::MacOS, Platform::Windows, Platform::Linux];
+
+/// Maximum characters shown for a block preview
+const PREVIEW_CHARS: usize = 60;
+
+/// Starter CLAUDE.md written by `ccs claude-md init`
+const CLAUDE_MD_TEMPLATE: &str = r#"# Personal instructions
+
+<!--
+  Content outside platform blocks is shared by every device.
+
+  Content inside a platform block only lands on that platform when the
+  config is applied with `ccs config-sync apply`. Supported tags:
+  macos (or mac, darwin), windows (or win), linux, and other (used on
+  platforms that have no block of their own). See the blocks below for
+  the syntax.
+
+  Run `ccs claude-md analyze` to check what each platform receives.
+-->
+
+## Common
+
+- Reply in English
+- Prefer small, focused commits
+
+<!-- platform:macos -->
+## macOS
+
+- Homebrew lives in /opt/homebrew/bin
+<!-- end-platform -->
+
+<!-- platform:windows -->
+## Windows
+
+- Use PowerShell syntax for shell commands
+<!-- end-platform -->
+
+<!-- platform:linux -->
+## Linux
+
+- Use the system package manager for dependencies
+<!-- end-platform -->
+"#;
+
+fn claude_md_path() -> Result<PathBuf> {
+    Ok(claude_dir()?.join("CLAUDE.md"))
+}
+
+/// First non-empty line of a block, truncated for display
+fn preview(block: &str) -> String {
+    let line = block
+        .lines()
+        .map(str::trim)
+        .find(|l| !l.is_empty())
+        .unwrap_or("(empty)");
+    if line.chars().count() > PREVIEW_CHARS {
+        format!(
+            "{}...",
+            line.chars().take(PREVIEW_CHARS).collect::<String>()
+        )
+    } else {
+        line.to_string()
+    }
+}
+
+/// Size summary as "N lines, M bytes"
+fn size(text: &str) -> String {
+    let text = text.trim();
+    format!("{} lines, {} bytes", text.lines().count(), text.len())
+}
+
+/// Handle `ccs claude-md analyze`
+pub fn handle_claude_md_analyze() -> Result<()> {
+    let path = claude_md_path()?;
+    if !path.exists() {
+        println!(
+            "{} {} not found",
+            icon("ℹ").cyan(),
+            path.display().to_string().dimmed()
+        );
+        return Ok(());
+    }
+    let content =
+        fs::read_to_string(&path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    println!("{}", format!("CLAUDE.md: {}", path.display()).cyan().bold());
+    println!();
+    println!(
+        "{} {}",
+        "Common content:".bold(),
+        size(&common_content(&content))
+    );
+
+    let blocks = extract_platform_blocks(&content);
+    let other_blocks = extract_other_blocks(&content);
+    println!(
+        "{} {}",
+        "Platform blocks:".bold(),
+        blocks.len() + other_blocks.len()
+    );
+    let labelled = blocks
+        .iter()
+        .map(|(platform, block)| (platform.tag_name(), block))
+        .chain(other_blocks.iter().map(|block| ("other", block)));
+    for (tag, block) in labelled {
+        println!(
+            "  {:<8} {:<22} {}",
+            tag.cyan(),
+            size(block).dimmed(),
+            preview(block)
+        );
+    }
+
+    let unknown = unknown_platform_tags(&content);
+    if !unknown.is_empty() {
+        println!();
+        println!(
+            "{} {}",
+            icon("⚠").yellow(),
+            "Unknown platform tags (kept on every platform):".yellow()
+        );
+        for (line, tag) in &unknown {
+            println!("  line {:<5} platform:{}", line, tag.yellow());
+        }
+    }
+
+    println!();
+    println!("{}", "Result per platform:".bold());
+    let current = Platform::current();
+    for platform in ALL_PLATFORMS {
+        let marker = if platform == current {
+            " (this device)"
+        } else {
+            ""
+        };
+        println!(
+            "  {:<8} {}{}",
+            platform.tag_name().cyan(),
+            size(&filter_for_platform(&content, platform)),
+            marker.dimmed()
+        );
+    }
+
+    Ok(())
+}
+
+/// Handle `ccs claude-md init`
+///
+/// Writes a starter CLAUDE.md with example platform blocks. An existing file
+/// is only replaced with `force`, after backing it up to `CLAUDE.md.backup`.
+pub fn handle_claude_md_init(force: bool) -> Result<()> {
+    let path = claude_md_path()?;
+    if path.exists() {
+        if !force {
+            println!(
+                "{} {} already exists (use --force to replace it)",
+                icon("ℹ").cyan(),
+                path.display()
+            );
+            return Ok(());
+        }
+        let backup = path.with_file_name("CLAUDE.md.backup");
+        fs::copy(&path, &backup)
+            .with_context(|| format!("Failed to back up {}", path.display()))?;
+        println!("  {} Backed up to {}", icon("ℹ").blue(), backup.display());
+    }
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(&path, CLAUDE_MD_TEMPLATE)
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!("{} Created {}", icon("✓").green(), path.display());
+    println!(
+        "  {}",
+        "Edit the example blocks, then check them with 'ccs claude-md analyze'".dimmed()
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preview_truncates_first_line() {
+        assert_eq!(preview("\n\n## macOS\n- fnm"), "## macOS");
+        assert_eq!(preview(""), "(empty)");
+        let long = "x".repeat(100);
+        assert_eq!(preview(&long).chars().count(), PREVIEW_CHARS + 3);
+    }
+
+    #[test]
+    fn test_template_has_a_block_for_every_platform() {
+        assert!(unknown_platform_tags(CLAUDE_MD_TEMPLATE).is_empty());
+        let tags: Vec<_> = extract_platform_blocks(CLAUDE_MD_TEMPLATE)
+            .into_iter()
+            .map(|(platform, _)| platform)
+            .collect();
+        assert_eq!(tags, ALL_PLATFORMS);
+
+        for platform in ALL_PLATFORMS {
+            let filtered = filter_for_platform(CLAUDE_MD_TEMPLATE, platform);
+            assert!(filtered.contains("## Common"));
+        }
+    }
+}
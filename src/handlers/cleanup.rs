@@ -1,34 +1,64 @@
 //! Snapshot cleanup handler
 //!
 //! Handles cleaning up old snapshot files based on age and count limits.
+//! `--keep N` always retains the newest N snapshots of each type and
+//! `--older-than D` deletes the rest once they are more than D days old.
+//! Either one alone applies only that rule; without both, the automatic
+//! cleanup policy after push/pull (5 per type, 7 days) is used.
 
 use anyhow::{Context, Result};
 use colored::Colorize;
-use inquire::Confirm;
 use std::fs;
 
 use crate::history::OperationType;
 use crate::interactive_conflict;
+use crate::output::icon;
 use crate::undo;
 
+/// Resolve `--keep` / `--older-than` into a cleanup policy
+///
+/// A count of 0 keeps nothing by count and an age of 0 keeps nothing by age,
+/// so a single flag applies only its own rule.
+fn cleanup_config(keep: Option<usize>, older_than: Option<i64>) -> undo::SnapshotCleanupConfig {
+    match (keep, older_than) {
+        (None, None) => undo::SnapshotCleanupConfig::default(),
+        (keep, older_than) => undo::SnapshotCleanupConfig {
+            max_count_per_type: keep.unwrap_or(0),
+            max_age_days: older_than.unwrap_or(0),
+        },
+    }
+}
+
+fn format_kb(bytes: u64) -> String {
+    format!("{:.1} KB", bytes as f64 / 1024.0)
+}
+
 /// Handle cleanup snapshots command
 pub fn handle_cleanup_snapshots(
     dry_run: bool,
-    max_count: usize,
-    max_age_days: i64,
+    keep: Option<usize>,
+    older_than: Option<i64>,
     interactive: bool,
     verbosity: crate::VerbosityLevel,
 ) -> Result<()> {
+    let config = cleanup_config(keep, older_than);
+    let max_count = config.max_count_per_type;
+    let max_age_days = config.max_age_days;
+
     if verbosity != crate::VerbosityLevel::Quiet {
         if dry_run {
             println!("{}", "Snapshot cleanup (dry run)".cyan().bold());
         } else {
             println!("{}", "Cleaning up old snapshots...".cyan().bold());
         }
-        println!(
-            "  Keeping: last {} snapshots per type OR last {} days",
-            max_count, max_age_days
-        );
+        match (max_count, max_age_days) {
+            (0, days) => println!("  Deleting snapshots older than {} days", days),
+            (count, 0) => println!("  Keeping: last {} snapshots per type", count),
+            (count, days) => println!(
+                "  Keeping: last {} snapshots per type OR last {} days",
+                count, days
+            ),
+        }
         println!();
     }
 
@@ -39,11 +69,12 @@ pub fn handle_cleanup_snapshots(
 
     // If interactive mode and not dry run, ask for confirmation
     if interactive && !dry_run && interactive_conflict::is_interactive() {
-        let confirm = Confirm::new("Do you want to proceed with deleting these snapshots?")
-            .with_default(false)
-            .with_help_message("This cannot be undone")
-            .prompt()
-            .context("Failed to get confirmation")?;
+        let confirm = interactive_conflict::confirm_with_help(
+            "Do you want to proceed with deleting these snapshots?",
+            "This cannot be undone",
+            false,
+            interactive_conflict::assume_yes(),
+        )?;
 
         if !confirm {
             println!("\n{}", "Cleanup cancelled.".yellow());
@@ -52,30 +83,36 @@ pub fn handle_cleanup_snapshots(
         println!();
     }
 
-    let config = undo::SnapshotCleanupConfig {
-        max_count_per_type: max_count,
-        max_age_days,
-    };
-
-    let deleted_count = undo::cleanup_old_snapshots(Some(config), dry_run)
+    let report = undo::cleanup_old_snapshots(Some(config), dry_run)
         .context("Failed to cleanup snapshots")?;
+    let deleted_count = report.deleted;
 
     if verbosity == crate::VerbosityLevel::Quiet {
         if !dry_run && deleted_count > 0 {
-            println!("Deleted {} snapshots", deleted_count);
+            println!(
+                "Deleted {} snapshots ({} reclaimed)",
+                deleted_count,
+                format_kb(report.reclaimed_bytes)
+            );
         }
     } else if dry_run {
         if deleted_count > 0 {
             println!(
-                "{} {} snapshots would be deleted",
-                "✓".green(),
-                deleted_count
+                "{} {} snapshots would be deleted ({} would be reclaimed)",
+                icon("✓").green(),
+                deleted_count,
+                format_kb(report.reclaimed_bytes)
             );
         } else {
             println!("{}", "No snapshots to delete".dimmed());
         }
     } else if deleted_count > 0 {
-        println!("{} Deleted {} old snapshots", "✓".green(), deleted_count);
+        println!(
+            "{} Deleted {} old snapshots ({} reclaimed)",
+            icon("✓").green(),
+            deleted_count,
+            format_kb(report.reclaimed_bytes)
+        );
     } else {
         println!("{}", "No old snapshots to delete".dimmed());
     }
@@ -117,7 +154,10 @@ fn show_snapshot_details(
         if let Ok(content) = fs::read_to_string(&path) {
             if let Ok(snapshot) = serde_json::from_str::<undo::Snapshot>(&content) {
                 match snapshot.operation_type {
-                    OperationType::Pull => {
+                    // Session delete snapshots restore local files, like pull snapshots
+                    OperationType::Pull
+                    | OperationType::SessionDelete
+                    | OperationType::SessionRename => {
                         pull_snapshots.push((path, snapshot.timestamp, file_size))
                     }
                     OperationType::Push => {
@@ -249,12 +289,16 @@ fn show_snapshot_details(
 
     println!(
         "  {} Total snapshots: {}",
-        "•".cyan(),
+        icon("•").cyan(),
         (pull_snapshots.len() + push_snapshots.len())
     );
-    println!("  {} Will keep: {}", "•".green(), total_keep);
-    println!("  {} Will delete: {}", "•".red(), total_delete);
-    println!("  {} Total disk space: {:.2} MB", "•".cyan(), total_size);
+    println!("  {} Will keep: {}", icon("•").green(), total_keep);
+    println!("  {} Will delete: {}", icon("•").red(), total_delete);
+    println!(
+        "  {} Total disk space: {:.2} MB",
+        icon("•").cyan(),
+        total_size
+    );
 
     if total_delete > 0 {
         let freed_space = ((pull_delete_count as u64
@@ -264,7 +308,7 @@ fn show_snapshot_details(
             / (1024.0 * 1024.0);
         println!(
             "  {} Space to be freed: ~{:.2} MB",
-            "•".yellow(),
+            icon("•").yellow(),
             freed_space
         );
     }
@@ -8,11 +8,15 @@ use colored::Colorize;
 use inquire::{Confirm, MultiSelect, Select, Text};
 
 use crate::config::ConfigManager;
+use crate::error::SyncError;
 use crate::filter::FilterConfig;
+use crate::output::icon;
 use crate::scm;
 use crate::sync::{MultiRepoState, RepoConfig};
 use crate::BINARY_NAME;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
 /// Handle interactive configuration menu
 ///
@@ -80,7 +84,7 @@ pub fn handle_config_interactive() -> Result<()> {
 
                 if input.trim().is_empty() {
                     modified_config.exclude_older_than_days = None;
-                    println!("  {} Unset exclude_older_than_days", "✓".green());
+                    println!("  {} Unset exclude_older_than_days", icon("✓").green());
                 } else {
                     let days: u32 = input
                         .trim()
@@ -89,7 +93,7 @@ pub fn handle_config_interactive() -> Result<()> {
                     modified_config.exclude_older_than_days = Some(days);
                     println!(
                         "  {} Set exclude_older_than_days to {} days",
-                        "✓".green(),
+                        icon("✓").green(),
                         days
                     );
                 }
@@ -111,7 +115,7 @@ pub fn handle_config_interactive() -> Result<()> {
 
                 if input.trim().is_empty() {
                     modified_config.include_patterns = Vec::new();
-                    println!("  {} Cleared include patterns", "✓".green());
+                    println!("  {} Cleared include patterns", icon("✓").green());
                 } else {
                     modified_config.include_patterns = input
                         .split(',')
@@ -120,7 +124,7 @@ pub fn handle_config_interactive() -> Result<()> {
                         .collect();
                     println!(
                         "  {} Set include patterns: {:?}",
-                        "✓".green(),
+                        icon("✓").green(),
                         modified_config.include_patterns
                     );
                 }
@@ -142,7 +146,7 @@ pub fn handle_config_interactive() -> Result<()> {
 
                 if input.trim().is_empty() {
                     modified_config.exclude_patterns = Vec::new();
-                    println!("  {} Cleared exclude patterns", "✓".green());
+                    println!("  {} Cleared exclude patterns", icon("✓").green());
                 } else {
                     modified_config.exclude_patterns = input
                         .split(',')
@@ -151,7 +155,7 @@ pub fn handle_config_interactive() -> Result<()> {
                         .collect();
                     println!(
                         "  {} Set exclude patterns: {:?}",
-                        "✓".green(),
+                        icon("✓").green(),
                         modified_config.exclude_patterns
                     );
                 }
@@ -169,7 +173,11 @@ pub fn handle_config_interactive() -> Result<()> {
                     .prompt()?;
 
                 modified_config.exclude_attachments = exclude;
-                println!("  {} Set exclude_attachments to {}", "✓".green(), exclude);
+                println!(
+                    "  {} Set exclude_attachments to {}",
+                    icon("✓").green(),
+                    exclude
+                );
             }
 
             "Max file size" => {
@@ -186,7 +194,11 @@ pub fn handle_config_interactive() -> Result<()> {
                     .context("Invalid number. Must be a positive number.")?;
 
                 modified_config.max_file_size_bytes = (size_mb * 1024.0 * 1024.0) as u64;
-                println!("  {} Set max_file_size to {:.1} MB", "✓".green(), size_mb);
+                println!(
+                    "  {} Set max_file_size to {:.1} MB",
+                    icon("✓").green(),
+                    size_mb
+                );
             }
 
             _ => {}
@@ -207,7 +219,10 @@ pub fn handle_config_interactive() -> Result<()> {
         modified_config
             .save()
             .context("Failed to save configuration")?;
-        println!("\n{} Configuration saved successfully!", "✓".green().bold());
+        println!(
+            "\n{} Configuration saved successfully!",
+            icon("✓").green().bold()
+        );
     } else {
         println!("\n{}", "Configuration not saved.".yellow());
     }
@@ -265,12 +280,12 @@ pub fn handle_config_wizard() -> Result<()> {
         modified_config.exclude_older_than_days = Some(days);
         println!(
             "  {} Will exclude projects older than {} days\n",
-            "✓".green(),
+            icon("✓").green(),
             days
         );
     } else {
         modified_config.exclude_older_than_days = None;
-        println!("  {} Age filter disabled\n", "✓".green());
+        println!("  {} Age filter disabled\n", icon("✓").green());
     }
 
     // 2. Include patterns
@@ -301,12 +316,12 @@ pub fn handle_config_wizard() -> Result<()> {
             .collect();
         println!(
             "  {} Include patterns set: {:?}\n",
-            "✓".green(),
+            icon("✓").green(),
             modified_config.include_patterns
         );
     } else {
         modified_config.include_patterns = Vec::new();
-        println!("  {} All projects will be included\n", "✓".green());
+        println!("  {} All projects will be included\n", icon("✓").green());
     }
 
     // 3. Exclude patterns
@@ -337,12 +352,12 @@ pub fn handle_config_wizard() -> Result<()> {
             .collect();
         println!(
             "  {} Exclude patterns set: {:?}\n",
-            "✓".green(),
+            icon("✓").green(),
             modified_config.exclude_patterns
         );
     } else {
         modified_config.exclude_patterns = Vec::new();
-        println!("  {} No exclusion patterns\n", "✓".green());
+        println!("  {} No exclusion patterns\n", icon("✓").green());
     }
 
     // 4. Exclude attachments
@@ -364,7 +379,7 @@ pub fn handle_config_wizard() -> Result<()> {
     modified_config.exclude_attachments = exclude_attachments;
     println!(
         "  {} Attachments will be {}\n",
-        "✓".green(),
+        icon("✓").green(),
         if exclude_attachments {
             "excluded"
         } else {
@@ -392,9 +407,13 @@ pub fn handle_config_wizard() -> Result<()> {
             .context("Invalid number. Must be a positive number.")?;
 
         modified_config.max_file_size_bytes = (size_mb * 1024.0 * 1024.0) as u64;
-        println!("  {} Max file size set to {:.1} MB\n", "✓".green(), size_mb);
+        println!(
+            "  {} Max file size set to {:.1} MB\n",
+            icon("✓").green(),
+            size_mb
+        );
     } else {
-        println!("  {} Keeping current max file size\n", "✓".green());
+        println!("  {} Keeping current max file size\n", icon("✓").green());
     }
 
     // Summary and confirmation
@@ -412,7 +431,10 @@ pub fn handle_config_wizard() -> Result<()> {
         modified_config
             .save()
             .context("Failed to save configuration")?;
-        println!("\n{} Configuration saved successfully!", "✓".green().bold());
+        println!(
+            "\n{} Configuration saved successfully!",
+            icon("✓").green().bold()
+        );
     } else {
         println!("\n{}", "Configuration not saved.".yellow());
     }
@@ -516,6 +538,9 @@ fn try_recover_existing_repo() -> Result<Option<MultiRepoState>> {
         is_cloned_repo: false, // We can't know this for sure
         remote_url,
         description: Some("Recovered from existing repository".to_string()),
+        last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
     };
 
     let mut repos = HashMap::new();
@@ -533,6 +558,59 @@ fn try_recover_existing_repo() -> Result<Option<MultiRepoState>> {
     Ok(Some(state))
 }
 
+/// Handle `ccs config export`: write the current filter configuration to
+/// `path` so it can be imported on another machine
+pub fn handle_config_export(path: &Path) -> Result<()> {
+    let config = FilterConfig::load().context("Failed to load current configuration")?;
+    let content = toml::to_string_pretty(&config).context("Failed to serialize config")?;
+
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(path, content).with_context(|| format!("Failed to write {}", path.display()))?;
+
+    println!(
+        "{} Exported configuration to {}",
+        icon("✓").green(),
+        path.display()
+    );
+    Ok(())
+}
+
+/// Handle `ccs config import`: validate the configuration in `path` and make
+/// it the current one
+///
+/// The device name and SSH identity describe this machine, so the local
+/// values are kept. The previous config file is saved as `config.toml.bak`.
+pub fn handle_config_import(path: &Path) -> Result<()> {
+    let mut imported = FilterConfig::read_from(path)?;
+    let current = FilterConfig::load().context("Failed to load current configuration")?;
+    imported.config_sync.device_name = current.config_sync.device_name;
+    imported.ssh_identity = current.ssh_identity;
+
+    let config_path = ConfigManager::filter_config_path()?;
+    let backup = config_path.with_extension("toml.bak");
+    if config_path.exists() {
+        fs::copy(&config_path, &backup)
+            .with_context(|| format!("Failed to back up {}", config_path.display()))?;
+    }
+    imported.save()?;
+
+    println!(
+        "{} Imported configuration from {}",
+        icon("✓").green(),
+        path.display()
+    );
+    println!("  Kept this machine's device name and SSH identity");
+    if backup.exists() {
+        println!("  Previous configuration: {}", backup.display());
+    }
+    println!();
+    display_config_summary(&imported);
+    Ok(())
+}
+
 /// Handle the repository selector menu
 ///
 /// Shows when `ccs config` is run with no arguments.
@@ -546,10 +624,7 @@ pub fn handle_repo_selector() -> Result<()> {
     let mut state = match MultiRepoState::load() {
         Ok(s) => s,
         Err(e) => {
-            let err_msg = e.to_string();
-            if err_msg.contains("not initialized")
-                || err_msg.contains(&format!("Run '{} init'", BINARY_NAME))
-            {
+            if matches!(SyncError::find(&e), Some(SyncError::NotInitialized)) {
                 // Check if there's an existing repo in the default location that we can recover
                 if let Some(recovered) = try_recover_existing_repo()? {
                     println!(
@@ -650,7 +725,7 @@ pub fn handle_repo_selector() -> Result<()> {
         println!();
         println!(
             "{} '{}' is already the active repository.",
-            "ℹ".blue(),
+            icon("ℹ").blue(),
             repo_name.cyan()
         );
         return Ok(());
@@ -664,7 +739,7 @@ pub fn handle_repo_selector() -> Result<()> {
         println!();
         println!(
             "{} Switched to repository '{}'",
-            "✓".green().bold(),
+            icon("✓").green().bold(),
             repo_name.cyan()
         );
 
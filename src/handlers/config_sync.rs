@@ -11,16 +11,24 @@ use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::io::Write;
+use std::path::{Component, Path, PathBuf};
 
+use super::hook_paths::{hook_commands, missing_command_paths, missing_script_paths};
 use super::platform_filter::{has_platform_blocks, merge_claude_md, Platform};
-use crate::scm;
-use crate::sync::SyncState;
+use crate::filter::FilterConfig;
+use crate::lang::t;
+use crate::output::icon;
+use crate::sync::discovery::claude_dir;
+use crate::sync::{open_sync_repo, SyncLock, SyncState};
 use crate::BINARY_NAME;
 
 // Re-export ConfigSyncSettings from filter module
 pub use crate::filter::ConfigSyncSettings;
 
+/// Devices that haven't pushed config for this many days are shown as stale
+pub const STALE_DEVICE_DAYS: i64 = 30;
+
 /// Sync metadata for a device
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DeviceSyncInfo {
@@ -36,12 +44,6 @@ pub struct SkillsList {
     pub skills: HashMap<String, String>,
 }
 
-/// Get the Claude config directory
-fn claude_dir() -> Result<PathBuf> {
-    let home = dirs::home_dir().context("Cannot find home directory")?;
-    Ok(home.join(".claude"))
-}
-
 /// Get the configs subdirectory in sync repo
 fn configs_dir(sync_repo: &Path) -> PathBuf {
     sync_repo.join("_configs")
@@ -151,17 +153,24 @@ pub fn push_config_files(settings: &ConfigSyncSettings) -> Result<Vec<String>> {
 }
 
 /// Push configuration to sync repository (with commit and push)
-pub fn handle_config_push(settings: &ConfigSyncSettings) -> Result<()> {
+///
+/// `message` overrides the default `Sync config from <device>` commit
+/// message. Returns whether a commit was made; `false` means the config was
+/// already up to date (or there was nothing to sync).
+pub fn handle_config_push(settings: &ConfigSyncSettings, message: Option<&str>) -> Result<bool> {
     let device_name = settings.get_device_name();
 
+    let _lock = SyncLock::acquire("config-push")?;
     let synced_files = push_config_files(settings)?;
 
     // Commit and push
     if !synced_files.is_empty() {
         let sync_state = SyncState::load()?;
         let sync_repo = sync_state.sync_repo_path.clone();
-        let message = format!("Sync config from {}", device_name);
-        let repo = scm::open(&sync_repo)?;
+        let message = message
+            .map(|m| m.to_string())
+            .unwrap_or_else(|| format!("Sync config from {}", device_name));
+        let repo = open_sync_repo(&sync_repo, &FilterConfig::load()?)?;
 
         // Stage all changes
         repo.stage_all()?;
@@ -176,38 +185,241 @@ pub fn handle_config_push(settings: &ConfigSyncSettings) -> Result<()> {
                 repo.push("origin", &branch)?;
             }
 
-            println!("{}", "✓ 配置已推送".green());
+            println!("{}", t!("config_sync.pushed").green());
             for file in &synced_files {
                 println!("  - {}", file);
             }
-        } else {
-            println!("{}", "配置无变化".dimmed());
+            return Ok(true);
         }
+        println!("{}", t!("config_sync.no_changes").dimmed());
     } else {
-        println!("{}", "⚠️  没有找到可同步的配置文件".yellow());
+        println!("{}", t!("config_sync.nothing_to_sync").yellow());
     }
 
+    Ok(false)
+}
+
+/// Remove device config folders whose last sync is older than `older_than_days`.
+///
+/// The current device is always kept, as are devices without a readable
+/// `.sync-info.json` (their age is unknown). With `dry_run` only the
+/// candidates are listed; otherwise the deletion is confirmed (unless
+/// `force`) and committed/pushed as a single change.
+pub fn handle_config_prune_devices(
+    older_than_days: u32,
+    force: bool,
+    dry_run: bool,
+    settings: &ConfigSyncSettings,
+) -> Result<()> {
+    let sync_state = SyncState::load()?;
+    let sync_repo = sync_state.sync_repo_path.clone();
+    let current_device = settings.get_device_name();
+    let now = chrono::Utc::now();
+    let threshold = chrono::Duration::days(older_than_days as i64);
+
+    let candidates: Vec<(String, chrono::DateTime<chrono::Utc>)> =
+        list_device_sync_times(&sync_repo)
+            .into_iter()
+            .filter(|(name, _)| name != &current_device)
+            .filter_map(|(name, time)| time.map(|t| (name, t)))
+            .filter(|(_, time)| now - *time > threshold)
+            .collect();
+
+    if candidates.is_empty() {
+        println!(
+            "{}",
+            t!("config_sync.prune_none", days = older_than_days).green()
+        );
+        return Ok(());
+    }
+
+    println!(
+        "{}",
+        t!("config_sync.prune_header", days = older_than_days).bold()
+    );
+    for (name, time) in &candidates {
+        println!(
+            "  {} {}",
+            name.cyan(),
+            t!(
+                "config_sync.prune_last_sync",
+                time = time.format("%Y-%m-%d %H:%M UTC"),
+                days = (now - *time).num_days()
+            )
+            .dimmed()
+        );
+    }
+
+    if dry_run {
+        println!();
+        println!("{}", t!("config_sync.prune_dry_run").dimmed());
+        return Ok(());
+    }
+
+    if !force {
+        println!();
+        let confirm = crate::interactive_conflict::confirm(
+            &t!("config_sync.prune_confirm", count = candidates.len()),
+            false,
+            crate::interactive_conflict::assume_yes(),
+        )
+        .unwrap_or(false);
+        if !confirm {
+            println!("{}", t!("common.cancelled").yellow());
+            return Ok(());
+        }
+    }
+
+    let _lock = SyncLock::acquire("prune-devices")?;
+    for (name, _) in &candidates {
+        let dir = device_config_dir(&sync_repo, name);
+        fs::remove_dir_all(&dir)
+            .with_context(|| format!("Failed to remove device config: {}", dir.display()))?;
+    }
+
+    let repo = open_sync_repo(&sync_repo, &FilterConfig::load()?)?;
+    repo.stage_all()?;
+    if repo.has_changes()? {
+        let names: Vec<&str> = candidates.iter().map(|(n, _)| n.as_str()).collect();
+        repo.commit(&format!("Prune stale device configs: {}", names.join(", ")))?;
+        if sync_state.has_remote {
+            let branch = repo.current_branch()?;
+            repo.push("origin", &branch)?;
+        }
+    }
+
+    println!(
+        "{}",
+        t!("config_sync.pruned", count = candidates.len()).green()
+    );
+    Ok(())
+}
+
+/// Move `_configs/<old>` to `_configs/<new>` and update the device field in
+/// its `.sync-info.json`. Fails if the source is missing or the destination
+/// already exists.
+fn rename_device_dir(sync_repo: &Path, old: &str, new: &str) -> Result<()> {
+    // `old` comes straight from the command line; anything but a single
+    // folder name (`..`, `a/b`, empty) would move a directory outside
+    // `_configs/<device>`
+    let mut components = Path::new(old).components();
+    let single_name = matches!(
+        (components.next(), components.next()),
+        (Some(Component::Normal(_)), None)
+    );
+    if !single_name || old.contains('\\') {
+        anyhow::bail!(t!("config_sync.rename_invalid_old", device = old));
+    }
+
+    let source = device_config_dir(sync_repo, old);
+    let target = device_config_dir(sync_repo, new);
+    if !source.is_dir() {
+        anyhow::bail!(t!(
+            "config_sync.device_not_found",
+            device = old,
+            command = format!("{} config-sync list", BINARY_NAME)
+        ));
+    }
+    if target.exists() {
+        anyhow::bail!(t!("config_sync.rename_target_exists", device = new));
+    }
+
+    fs::rename(&source, &target).with_context(|| {
+        format!(
+            "Failed to move {} to {}",
+            source.display(),
+            target.display()
+        )
+    })?;
+
+    let info_path = target.join(".sync-info.json");
+    if let Ok(content) = fs::read_to_string(&info_path) {
+        match serde_json::from_str::<DeviceSyncInfo>(&content) {
+            Ok(mut info) => {
+                info.device = new.to_string();
+                fs::write(&info_path, serde_json::to_string_pretty(&info)?)?;
+            }
+            Err(e) => log::warn!("Skipping invalid {}: {}", info_path.display(), e),
+        }
+    }
+    Ok(())
+}
+
+/// Rename a device's config folder in the sync repo, keeping its history
+///
+/// When the renamed device is this machine (`old` or `new` matches the
+/// current device name), `device_name` is pinned to `new` in config.toml so
+/// later pushes keep using the new folder.
+pub fn handle_config_rename_device(
+    old: &str,
+    new: &str,
+    settings: &ConfigSyncSettings,
+) -> Result<()> {
+    let new = crate::filter::sanitize_device_name(new);
+    if new.is_empty() {
+        anyhow::bail!(t!("config_sync.rename_invalid_name"));
+    }
+    if old == new {
+        println!("{}", t!("config_sync.rename_same", device = old).dimmed());
+        return Ok(());
+    }
+
+    let sync_state = SyncState::load()?;
+    let sync_repo = sync_state.sync_repo_path.clone();
+    let _lock = SyncLock::acquire("rename-device")?;
+    rename_device_dir(&sync_repo, old, &new)?;
+
+    let current_device = settings.get_device_name();
+    if current_device == old || current_device == new {
+        let mut config = FilterConfig::load()?;
+        config.config_sync.device_name = Some(new.clone());
+        config.save()?;
+        println!(
+            "  {} {}",
+            icon("ℹ").blue(),
+            t!("config_sync.device_name_pinned", device = new)
+        );
+    }
+
+    let repo = open_sync_repo(&sync_repo, &FilterConfig::load()?)?;
+    repo.stage_all()?;
+    if repo.has_changes()? {
+        repo.commit(&format!("Rename device config: {} -> {}", old, new))?;
+        if sync_state.has_remote {
+            let branch = repo.current_branch()?;
+            repo.push("origin", &branch)?;
+        }
+    }
+
+    println!(
+        "{}",
+        t!("config_sync.renamed_device", old = old, new = new).green()
+    );
     Ok(())
 }
 
 /// List available device configurations
-pub fn handle_config_list() -> Result<()> {
+pub fn handle_config_list(out: &mut dyn Write) -> Result<()> {
     let sync_state = SyncState::load()?;
     let configs = configs_dir(&sync_state.sync_repo_path);
 
     if !configs.exists() {
-        println!("{}", "没有找到配置同步目录".yellow());
-        println!(
-            "运行 {} 推送当前设备配置",
-            format!("{} config push", BINARY_NAME).cyan()
-        );
+        writeln!(out, "{}", t!("config_sync.no_configs_dir").yellow())?;
+        writeln!(
+            out,
+            "{}",
+            t!(
+                "config_sync.run_push_hint",
+                command = format!("{} config push", BINARY_NAME).cyan()
+            )
+        )?;
         return Ok(());
     }
 
     let current_device = ConfigSyncSettings::default().get_device_name();
 
-    println!("{}", "可用的设备配置:".bold());
-    println!();
+    writeln!(out, "{}", t!("config_sync.available_devices").bold())?;
+    writeln!(out)?;
 
     let mut found_any = false;
     for entry in fs::read_dir(&configs)? {
@@ -231,14 +443,27 @@ pub fn handle_config_list() -> Result<()> {
 
         // Display device
         if device_name == current_device {
-            println!("  {} (当前设备)", device_name.green());
+            writeln!(
+                out,
+                "  {} {}",
+                device_name.green(),
+                t!("config_sync.current_device")
+            )?;
         } else {
-            println!("  {}", device_name.cyan());
+            writeln!(out, "  {}", device_name.cyan())?;
         }
 
         if let Some(info) = sync_info {
-            println!("    平台: {}", info.platform);
-            println!("    最后同步: {}", info.last_sync);
+            writeln!(
+                out,
+                "    {}",
+                t!("config_sync.platform", platform = info.platform)
+            )?;
+            writeln!(
+                out,
+                "    {}",
+                t!("config_sync.last_sync", time = info.last_sync)
+            )?;
         }
 
         // Show available files
@@ -260,45 +485,446 @@ pub fn handle_config_list() -> Result<()> {
         }
 
         if !available.is_empty() {
-            println!("    文件: {}", available.join(", ").dimmed());
+            writeln!(
+                out,
+                "    {}",
+                t!("config_sync.files", files = available.join(", ").dimmed())
+            )?;
         }
-        println!();
+        writeln!(out)?;
     }
 
     if !found_any {
-        println!("{}", "  没有找到设备配置".dimmed());
-        println!();
+        writeln!(out, "  {}", t!("config_sync.no_device_configs").dimmed())?;
+        writeln!(out)?;
+        writeln!(
+            out,
+            "{}",
+            t!(
+                "config_sync.run_push_hint",
+                command = format!("{} config push", BINARY_NAME).cyan()
+            )
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Merge remote settings.json into the local one key by key.
+///
+/// Local `hooks` are always kept and keys present on only one side are taken
+/// as-is; for top-level keys whose values differ, `keep_local(key, local,
+/// remote)` decides which value wins.
+fn merge_settings(
+    local: &serde_json::Value,
+    remote: &serde_json::Value,
+    mut keep_local: impl FnMut(&str, &serde_json::Value, &serde_json::Value) -> Result<bool>,
+) -> Result<serde_json::Value> {
+    let mut merged = remote.clone();
+    let (Some(merged_obj), Some(local_obj)) = (merged.as_object_mut(), local.as_object()) else {
+        return Ok(merged);
+    };
+
+    for (key, local_value) in local_obj {
+        let use_local = match merged_obj.get(key) {
+            None => true,
+            Some(_) if key == "hooks" => true,
+            Some(remote_value) if remote_value != local_value => {
+                keep_local(key, local_value, remote_value)?
+            }
+            Some(_) => false,
+        };
+        if use_local {
+            merged_obj.insert(key.clone(), local_value.clone());
+        }
+    }
+    Ok(merged)
+}
+
+/// Ask whether to keep the local or remote value of a conflicting settings key
+fn prompt_settings_conflict(
+    key: &str,
+    local: &serde_json::Value,
+    remote: &serde_json::Value,
+) -> Result<bool> {
+    /// Compact one-line rendering of a JSON value for the prompt
+    fn preview(value: &serde_json::Value) -> String {
+        let text = value.to_string();
+        if text.chars().count() > 120 {
+            format!("{}...", text.chars().take(117).collect::<String>())
+        } else {
+            text
+        }
+    }
+
+    println!(
+        "\n{} {}",
+        t!("config_sync.settings_conflict").yellow().bold(),
+        key.bold()
+    );
+    println!(
+        "  {}: {}",
+        t!("config_sync.local_value").cyan(),
+        preview(local)
+    );
+    println!(
+        "  {}: {}",
+        t!("config_sync.remote_value").cyan(),
+        preview(remote)
+    );
+
+    let keep_local = t!("config_sync.keep_local_value");
+    let choice = inquire::Select::new(
+        t!("config_sync.settings_conflict_prompt"),
+        vec![keep_local, t!("config_sync.take_remote_value")],
+    )
+    .prompt()
+    .context("Failed to get settings conflict resolution")?;
+    Ok(choice == keep_local)
+}
+
+/// Check the applied hook commands (and the scripts in `hooks/` when
+/// `check_scripts`) for paths that do not exist on this device, printing an
+/// OK/MISSING line per hook
+fn report_hook_paths(claude: &Path, check_scripts: bool) -> Result<()> {
+    let home = dirs::home_dir().context("Cannot find home directory")?;
+
+    let commands = fs::read_to_string(claude.join("settings.json"))
+        .ok()
+        .and_then(|content| serde_json::from_str::<serde_json::Value>(&content).ok())
+        .map(|json| hook_commands(&json))
+        .unwrap_or_default();
+
+    let mut scripts: Vec<PathBuf> = Vec::new();
+    if check_scripts {
+        if let Ok(entries) = fs::read_dir(claude.join("hooks")) {
+            scripts = entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_file())
+                .collect();
+            scripts.sort();
+        }
+    }
+
+    if commands.is_empty() && scripts.is_empty() {
+        return Ok(());
+    }
+
+    println!();
+    println!("{}", t!("config_sync.hooks_path_check").cyan());
+
+    let mut failed = 0;
+    let mut print_result = |label: String, missing: Vec<String>| {
+        if missing.is_empty() {
+            println!(
+                "  {} {:<8} {}",
+                icon("✓").green(),
+                t!("config_sync.hooks_path_ok").green(),
+                label
+            );
+        } else {
+            failed += 1;
+            println!(
+                "  {} {:<8} {}",
+                icon("✗").red(),
+                t!("config_sync.hooks_path_missing").red(),
+                label
+            );
+            for path in missing {
+                println!("      {} {}", icon("→").dimmed(), path.yellow());
+            }
+        }
+    };
+
+    for (event, cmd) in commands {
+        let missing = missing_command_paths(&cmd, &home);
+        print_result(format!("{}: {}", event, cmd), missing);
+    }
+    for script in scripts {
+        // Binary files cannot reference paths in a way we can check
+        let Ok(content) = fs::read_to_string(&script) else {
+            continue;
+        };
+        let name = script
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_default();
+        print_result(
+            format!("hooks/{}", name),
+            missing_script_paths(&content, &home),
+        );
+    }
+
+    if failed > 0 {
         println!(
-            "运行 {} 推送当前设备配置",
-            format!("{} config push", BINARY_NAME).cyan()
+            "{}",
+            t!("config_sync.hooks_paths_missing", count = failed).yellow()
         );
+    } else {
+        println!("{}", t!("config_sync.hooks_paths_ok").green());
+    }
+    Ok(())
+}
+
+/// Pick the most recently synced other device for `config-sync apply`
+/// without a device argument, after the user confirms it
+///
+/// Returns `None` when the user declines. `flags` are the other `apply`
+/// options, repeated in the hint shown when there is no terminal.
+fn choose_latest_device(
+    sync_repo: &Path,
+    settings: &ConfigSyncSettings,
+    flags: &[&str],
+) -> Result<Option<String>> {
+    let current_device = settings.get_device_name();
+    let Some(device) = find_latest_device_config(sync_repo, &current_device) else {
+        anyhow::bail!(t!(
+            "config_sync.no_other_device",
+            command = format!("{} config-sync push", BINARY_NAME)
+        ));
+    };
+
+    let time = get_device_sync_time(sync_repo, &device)
+        .map(|t| {
+            t.with_timezone(&chrono::Local)
+                .format("%Y-%m-%d %H:%M")
+                .to_string()
+        })
+        .unwrap_or_default();
+    println!(
+        "{}",
+        t!(
+            "config_sync.latest_device",
+            device = device.cyan(),
+            time = time
+        )
+    );
+
+    let assume_yes = crate::interactive_conflict::assume_yes();
+    if !crate::interactive_conflict::is_interactive() && !assume_yes {
+        let command = [BINARY_NAME, "config-sync", "apply", device.as_str()]
+            .iter()
+            .chain(flags)
+            .copied()
+            .collect::<Vec<_>>()
+            .join(" ");
+        anyhow::bail!(t!("config_sync.latest_device_hint", command = command));
     }
+    let confirmed = crate::interactive_conflict::confirm(
+        &t!("config_sync.latest_device_confirm", device = device),
+        true,
+        assume_yes,
+    )?;
+    Ok(confirmed.then_some(device))
+}
+
+/// Skills and plugins recorded for a device, to be installed with the
+/// `claude` CLI
+#[derive(Debug, Default)]
+struct InstallCommands {
+    /// Skill URLs, sorted
+    skills: Vec<String>,
+    /// Plugin names
+    plugins: Vec<String>,
+}
+
+impl InstallCommands {
+    /// Read `installed_skills.json` and `installed_plugins.json` of a device
+    /// config directory; missing or unreadable lists are treated as empty
+    fn load(source_dir: &Path) -> Self {
+        let mut commands = Self::default();
+
+        if let Ok(content) = fs::read_to_string(source_dir.join("installed_skills.json")) {
+            if let Ok(skills_list) = serde_json::from_str::<SkillsList>(&content) {
+                commands.skills = skills_list.skills.into_values().collect();
+                commands.skills.sort();
+            }
+        }
+
+        if let Ok(content) = fs::read_to_string(source_dir.join("installed_plugins.json")) {
+            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+                if let Some(plugins) = json.get("plugins").and_then(|p| p.as_object()) {
+                    commands.plugins = plugins.keys().cloned().collect();
+                }
+            }
+        }
+
+        commands
+    }
+
+    fn is_empty(&self) -> bool {
+        self.skills.is_empty() && self.plugins.is_empty()
+    }
+
+    /// Arguments of every `claude` invocation, skills first
+    fn args(&self) -> Vec<[&str; 3]> {
+        let skills = self.skills.iter().map(|url| ["skill", "install", url]);
+        let plugins = self.plugins.iter().map(|name| ["plugin", "install", name]);
+        skills.chain(plugins).collect()
+    }
+}
+
+/// Quote `arg` for a POSIX shell unless it only has characters that need none
+fn shell_quote(arg: &str) -> String {
+    let safe = !arg.is_empty()
+        && arg
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "@%+=:,./-_".contains(c));
+    if safe {
+        arg.to_string()
+    } else {
+        format!("'{}'", arg.replace('\'', r"'\''"))
+    }
+}
 
+/// Shell script that runs the install commands of `source_device`
+fn install_script(source_device: &str, commands: &InstallCommands) -> String {
+    // The device name comes from the sync repo and ends up in a comment;
+    // quoting would not stop a newline from starting a command line
+    let device: String = source_device
+        .chars()
+        .map(|c| if c.is_control() { '?' } else { c })
+        .collect();
+    let mut script = format!(
+        "#!/bin/sh\n# Install skills and plugins of device '{}'\n# Generated by {} config-sync apply\nset -e\n\n",
+        device, BINARY_NAME
+    );
+    for args in commands.args() {
+        let quoted: Vec<String> = args.iter().map(|arg| shell_quote(arg)).collect();
+        script.push_str(&format!("claude {}\n", quoted.join(" ")));
+    }
+    script
+}
+
+/// Write the install commands to an executable script at `path`
+fn write_install_script(
+    path: &Path,
+    source_device: &str,
+    commands: &InstallCommands,
+) -> Result<()> {
+    fs::write(path, install_script(source_device, commands))
+        .with_context(|| format!("Failed to write install script: {}", path.display()))?;
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o755))?;
+    }
+
+    println!(
+        "{} {}",
+        icon("✓").green(),
+        t!("config_sync.install_script_written", path = path.display())
+    );
+    Ok(())
+}
+
+/// Run the install commands one by one after confirmation, continuing past
+/// failures and reporting them at the end
+fn run_install_commands(commands: &InstallCommands) -> Result<()> {
+    if commands.is_empty() {
+        println!("{}", t!("config_sync.install_none").dimmed());
+        return Ok(());
+    }
+
+    let args = commands.args();
+    let confirm = crate::interactive_conflict::confirm(
+        &t!("config_sync.install_confirm", count = args.len()),
+        true,
+        crate::interactive_conflict::assume_yes(),
+    )
+    .unwrap_or(false);
+    if !confirm {
+        println!("{}", t!("common.cancelled").yellow());
+        return Ok(());
+    }
+
+    let mut failed = 0;
+    for args in &args {
+        println!("{} claude {}", icon("→").cyan(), args.join(" "));
+        let status = std::process::Command::new("claude")
+            .args(args)
+            .status()
+            .context("Failed to run the claude CLI")?;
+        if !status.success() {
+            println!("  {} {}", icon("✗").red(), status);
+            failed += 1;
+        }
+    }
+
+    if failed > 0 {
+        anyhow::bail!(t!("config_sync.install_failed", count = failed));
+    }
+    println!("{} {}", icon("✓").green(), t!("config_sync.install_done"));
     Ok(())
 }
 
 /// Apply configuration from another device
+///
+/// Without `source_device`, the most recently synced other device is used
+/// once the user confirms it. With `claude_md_only`, only CLAUDE.md is
+/// applied, the same way auto-apply does; `force` then applies it even when
+/// this device's config is newer. The device's skills and plugins install
+/// commands are printed, and optionally written to `install_script` and/or
+/// run (`run_install`).
 pub fn handle_config_apply(
-    source_device: &str,
+    source_device: Option<&str>,
     with_hooks: bool,
+    claude_md_only: bool,
+    force: bool,
+    install_script: Option<&Path>,
+    run_install: bool,
     settings: &ConfigSyncSettings,
 ) -> Result<()> {
     let sync_state = SyncState::load()?;
+    let source_device = match source_device {
+        Some(device) => device.to_string(),
+        None => {
+            let script_flag =
+                install_script.map(|path| format!("--emit-install-script {}", path.display()));
+            let flags: Vec<&str> = [
+                (with_hooks, "--with-hooks"),
+                (claude_md_only, "--claude-md"),
+                (force, "--force"),
+                (run_install, "--run-install"),
+            ]
+            .into_iter()
+            .filter_map(|(set, flag)| set.then_some(flag))
+            .chain(script_flag.as_deref())
+            .collect();
+            match choose_latest_device(&sync_state.sync_repo_path, settings, &flags)? {
+                Some(device) => device,
+                None => {
+                    println!("{}", t!("common.cancelled").yellow());
+                    return Ok(());
+                }
+            }
+        }
+    };
+    let source_device = source_device.as_str();
     let source_dir = device_config_dir(&sync_state.sync_repo_path, source_device);
 
     if !source_dir.exists() {
-        return Err(anyhow::anyhow!(
-            "设备配置不存在: {}\n运行 `{} config list` 查看可用配置",
-            source_device,
-            BINARY_NAME
-        ));
+        return Err(anyhow::anyhow!(t!(
+            "config_sync.device_not_found",
+            device = source_device,
+            command = format!("{} config list", BINARY_NAME)
+        )));
+    }
+
+    if claude_md_only {
+        return apply_claude_md_only(&sync_state.sync_repo_path, source_device, settings, force);
     }
 
     let claude = claude_dir()?;
     let current_platform = Platform::current();
     let mut applied_files = Vec::new();
 
-    println!("{}", format!("从 {} 应用配置...", source_device).cyan());
+    println!(
+        "{}",
+        t!("config_sync.applying", device = source_device).cyan()
+    );
 
     // Apply settings.json
     if settings.sync_settings {
@@ -316,7 +942,11 @@ pub fn handle_config_apply(
             if target_settings.exists() {
                 let backup = claude.join("settings.json.backup");
                 fs::copy(&target_settings, &backup)?;
-                println!("  {} 已备份到 settings.json.backup", "ℹ".blue());
+                println!(
+                    "  {} {}",
+                    icon("ℹ").blue(),
+                    t!("config_sync.settings_backed_up")
+                );
             }
 
             if with_hooks {
@@ -334,15 +964,23 @@ pub fn handle_config_apply(
                 let source_json: serde_json::Value = serde_json::from_str(&source_content)?;
                 let target_json: serde_json::Value = serde_json::from_str(&target_content)?;
 
-                // Merge: source settings + local hooks
-                let mut merged = source_json.clone();
-                if let (Some(merged_obj), Some(target_obj)) =
-                    (merged.as_object_mut(), target_json.as_object())
+                let merged = if crate::interactive_conflict::is_interactive()
+                    && !crate::interactive_conflict::assume_yes()
                 {
-                    if let Some(hooks) = target_obj.get("hooks") {
-                        merged_obj.insert("hooks".to_string(), hooks.clone());
+                    // Merge key by key, asking about keys changed on both sides
+                    merge_settings(&target_json, &source_json, prompt_settings_conflict)?
+                } else {
+                    // Merge: source settings + local hooks
+                    let mut merged = source_json.clone();
+                    if let (Some(merged_obj), Some(target_obj)) =
+                        (merged.as_object_mut(), target_json.as_object())
+                    {
+                        if let Some(hooks) = target_obj.get("hooks") {
+                            merged_obj.insert("hooks".to_string(), hooks.clone());
+                        }
                     }
-                }
+                    merged
+                };
 
                 let merged_content = serde_json::to_string_pretty(&merged)?;
                 fs::write(&target_settings, merged_content)?;
@@ -352,9 +990,9 @@ pub fn handle_config_apply(
                 "{} ({})",
                 "settings.json",
                 if with_hooks {
-                    "含 hooks"
+                    t!("config_sync.with_hooks")
                 } else {
-                    "保留本地 hooks"
+                    t!("config_sync.keep_local_hooks")
                 }
             ));
         }
@@ -386,9 +1024,9 @@ pub fn handle_config_apply(
             {
                 let merged = merge_claude_md(&source_content, &target_content, current_platform);
                 println!(
-                    "  {} 已合并 CLAUDE.md（保留本地 {} 平台内容）",
-                    "ℹ".blue(),
-                    current_platform
+                    "  {} {}",
+                    icon("ℹ").blue(),
+                    t!("config_sync.claude_md_merged", platform = current_platform)
                 );
                 merged
             } else {
@@ -424,60 +1062,49 @@ pub fn handle_config_apply(
             }
 
             applied_files.push("hooks/".to_string());
-
-            println!();
-            println!(
-                "{}",
-                "⚠️  Hooks 已应用，请检查以下路径是否适用于本设备:".yellow()
-            );
-            println!("    - ~/.claude/hooks/ 中的脚本内容");
-            println!("    - settings.json 中的 hooks 命令路径");
         }
     }
 
-    // Show skills to install
-    let skills_path = source_dir.join("installed_skills.json");
-    if skills_path.exists() {
-        let content = fs::read_to_string(&skills_path)?;
-        if let Ok(skills_list) = serde_json::from_str::<SkillsList>(&content) {
-            if !skills_list.skills.is_empty() {
-                println!();
-                println!("{}", "Skills 安装命令:".cyan());
-                for url in skills_list.skills.values() {
-                    println!("  claude skill install {}", url);
-                }
-            }
-        }
+    if with_hooks {
+        report_hook_paths(&claude, settings.sync_hooks)?;
     }
 
-    // Show plugins to install
-    let plugins_path = source_dir.join("installed_plugins.json");
-    if plugins_path.exists() {
-        if let Ok(content) = fs::read_to_string(&plugins_path) {
-            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                if let Some(plugins) = json.get("plugins").and_then(|p| p.as_object()) {
-                    if !plugins.is_empty() {
-                        println!();
-                        println!("{}", "Plugins 安装命令:".cyan());
-                        for name in plugins.keys() {
-                            println!("  claude plugin install {}", name);
-                        }
-                    }
-                }
-            }
+    // Show skills and plugins to install
+    let install_commands = InstallCommands::load(&source_dir);
+    if !install_commands.skills.is_empty() {
+        println!();
+        println!("{}", t!("config_sync.skills_install").cyan());
+        for url in &install_commands.skills {
+            println!("  claude skill install {}", url);
+        }
+    }
+    if !install_commands.plugins.is_empty() {
+        println!();
+        println!("{}", t!("config_sync.plugins_install").cyan());
+        for name in &install_commands.plugins {
+            println!("  claude plugin install {}", name);
         }
     }
 
     println!();
     if !applied_files.is_empty() {
-        println!("{}", "✓ 配置已应用".green());
+        println!("{}", t!("config_sync.applied").green());
         for file in &applied_files {
             println!("  - {}", file);
         }
         println!();
-        println!("{}", "请重启 Claude Code 使配置生效".cyan());
+        println!("{}", t!("config_sync.restart_hint").cyan());
     } else {
-        println!("{}", "没有应用任何配置".yellow());
+        println!("{}", t!("config_sync.nothing_applied").yellow());
+    }
+
+    if let Some(path) = install_script {
+        println!();
+        write_install_script(path, source_device, &install_commands)?;
+    }
+    if run_install {
+        println!();
+        run_install_commands(&install_commands)?;
     }
 
     Ok(())
@@ -488,15 +1115,24 @@ pub fn handle_config_status(settings: &ConfigSyncSettings) -> Result<()> {
     let device_name = settings.get_device_name();
     let claude = claude_dir()?;
 
-    println!("{}", "配置同步状态".bold());
+    println!("{}", t!("config_sync.status_title").bold());
     println!("{}", "━".repeat(40));
     println!();
 
-    println!("设备名称: {}", device_name.cyan());
-    println!("平台: {}", Platform::current().to_string().cyan());
+    println!(
+        "{}",
+        t!("config_sync.device_name", name = device_name.cyan())
+    );
+    println!(
+        "{}",
+        t!(
+            "config_sync.platform",
+            platform = Platform::current().to_string().cyan()
+        )
+    );
     println!();
 
-    println!("{}", "本地配置文件:".bold());
+    println!("{}", t!("config_sync.local_files").bold());
     let files = [
         ("settings.json", claude.join("settings.json")),
         ("CLAUDE.md", claude.join("CLAUDE.md")),
@@ -505,9 +1141,9 @@ pub fn handle_config_status(settings: &ConfigSyncSettings) -> Result<()> {
 
     for (name, path) in files {
         let status = if path.exists() {
-            "✓".green()
+            icon("✓").green()
         } else {
-            "✗".red()
+            icon("✗").red()
         };
         println!("  {} {}", status, name);
     }
@@ -519,51 +1155,70 @@ pub fn handle_config_status(settings: &ConfigSyncSettings) -> Result<()> {
             .filter_map(|e| e.ok())
             .filter(|e| e.file_type().map(|t| t.is_dir()).unwrap_or(false))
             .count();
-        println!("  {} skills: {} 个", "✓".green(), count);
+        println!(
+            "  {} {}",
+            icon("✓").green(),
+            t!("config_sync.skills_count", count = count)
+        );
     }
 
     // Show sync settings
     println!();
-    println!("{}", "同步设置:".bold());
+    println!("{}", t!("config_sync.settings_header").bold());
     println!(
-        "  配置同步: {}",
-        if settings.enabled {
-            "启用".green()
-        } else {
-            "禁用".red()
-        }
+        "  {}",
+        t!(
+            "config_sync.setting_enabled",
+            value = if settings.enabled {
+                t!("common.enabled").green()
+            } else {
+                t!("common.disabled").red()
+            }
+        )
     );
     println!(
-        "  同步 settings.json: {}",
-        if settings.sync_settings {
-            "是".green()
-        } else {
-            "否".dimmed()
-        }
+        "  {}",
+        t!(
+            "config_sync.setting_sync_settings",
+            value = if settings.sync_settings {
+                t!("common.yes").green()
+            } else {
+                t!("common.no").dimmed()
+            }
+        )
     );
     println!(
-        "  同步 CLAUDE.md: {}",
-        if settings.sync_claude_md {
-            "是".green()
-        } else {
-            "否".dimmed()
-        }
+        "  {}",
+        t!(
+            "config_sync.setting_sync_claude_md",
+            value = if settings.sync_claude_md {
+                t!("common.yes").green()
+            } else {
+                t!("common.no").dimmed()
+            }
+        )
     );
     println!(
-        "  同步 hooks: {}",
-        if settings.sync_hooks {
-            "是".green()
-        } else {
-            "否".dimmed()
-        }
+        "  {}",
+        t!(
+            "config_sync.setting_sync_hooks",
+            value = if settings.sync_hooks {
+                t!("common.yes").green()
+            } else {
+                t!("common.no").dimmed()
+            }
+        )
     );
     println!(
-        "  同步 skills 列表: {}",
-        if settings.sync_skills_list {
-            "是".green()
-        } else {
-            "否".dimmed()
-        }
+        "  {}",
+        t!(
+            "config_sync.setting_sync_skills_list",
+            value = if settings.sync_skills_list {
+                t!("common.yes").green()
+            } else {
+                t!("common.no").dimmed()
+            }
+        )
     );
 
     Ok(())
@@ -622,7 +1277,6 @@ fn copy_dir_recursive(src: &Path, dst: &Path) -> Result<()> {
 }
 
 /// Find the most recently updated device config (excluding current device)
-#[allow(dead_code)]
 pub fn find_latest_device_config(sync_repo: &Path, current_device: &str) -> Option<String> {
     find_latest_device_config_with_time(sync_repo, current_device).map(|(name, _)| name)
 }
@@ -633,43 +1287,41 @@ fn find_latest_device_config_with_time(
     sync_repo: &Path,
     current_device: &str,
 ) -> Option<(String, chrono::DateTime<chrono::Utc>)> {
-    let configs = configs_dir(sync_repo);
-    if !configs.exists() {
-        return None;
-    }
-
-    let mut latest: Option<(String, chrono::DateTime<chrono::Utc>)> = None;
-
-    for entry in fs::read_dir(&configs).ok()?.filter_map(|e| e.ok()) {
-        if !entry.path().is_dir() {
-            continue;
-        }
-
-        let device_name = match entry.file_name().into_string() {
-            Ok(name) => name,
-            Err(_) => continue,
-        };
+    list_device_sync_times(sync_repo)
+        .into_iter()
+        .filter(|(name, _)| name != current_device)
+        .filter_map(|(name, time)| time.map(|t| (name, t)))
+        .max_by_key(|(_, time)| *time)
+}
 
-        // Skip current device
-        if device_name == current_device {
-            continue;
-        }
+/// List all device config dirs in the sync repo with their last sync time
+/// (None when `.sync-info.json` is missing or unreadable), sorted by name.
+pub fn list_device_sync_times(
+    sync_repo: &Path,
+) -> Vec<(String, Option<chrono::DateTime<chrono::Utc>>)> {
+    let Ok(entries) = fs::read_dir(configs_dir(sync_repo)) else {
+        return Vec::new();
+    };
 
-        // Read .sync-info.json
-        let info_path = entry.path().join(".sync-info.json");
-        if let Ok(content) = fs::read_to_string(&info_path) {
-            if let Ok(info) = serde_json::from_str::<DeviceSyncInfo>(&content) {
-                if let Ok(sync_time) = chrono::DateTime::parse_from_rfc3339(&info.last_sync) {
-                    let sync_time = sync_time.with_timezone(&chrono::Utc);
-                    if latest.is_none() || sync_time > latest.as_ref().unwrap().1 {
-                        latest = Some((device_name, sync_time));
-                    }
-                }
-            }
-        }
-    }
+    let mut devices: Vec<(String, Option<chrono::DateTime<chrono::Utc>>)> = entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .filter_map(|e| e.file_name().into_string().ok())
+        .map(|name| {
+            let time = get_device_sync_time(sync_repo, &name);
+            (name, time)
+        })
+        .collect();
+    devices.sort_by(|a, b| a.0.cmp(&b.0));
+    devices
+}
 
-    latest
+/// Whether a device's last sync is older than [`STALE_DEVICE_DAYS`]
+pub fn is_device_stale(
+    last_sync: chrono::DateTime<chrono::Utc>,
+    now: chrono::DateTime<chrono::Utc>,
+) -> bool {
+    now - last_sync > chrono::Duration::days(STALE_DEVICE_DAYS)
 }
 
 /// Get the sync timestamp of a specific device from its .sync-info.json.
@@ -682,75 +1334,156 @@ fn get_device_sync_time(sync_repo: &Path, device: &str) -> Option<chrono::DateTi
         .map(|t| t.with_timezone(&chrono::Utc))
 }
 
-/// Auto-apply CLAUDE.md from the most recently updated device
-/// Only applies CLAUDE.md, not other config files (settings, hooks, skills)
-/// Only applies if the other device's config is newer than the current device's config
-pub fn auto_apply_claude_md(settings: &ConfigSyncSettings) -> Result<()> {
-    if !settings.enabled || !settings.auto_apply_claude_md {
-        log::debug!("Auto-apply CLAUDE.md is disabled");
-        return Ok(());
-    }
-
-    let sync_state = SyncState::load()?;
-    let current_device = settings.get_device_name();
+/// Outcome of merging another device's CLAUDE.md into the local one
+#[derive(Debug, PartialEq, Eq)]
+enum ClaudeMdUpdate {
+    /// The local CLAUDE.md was rewritten
+    Applied,
+    /// The merged content already matches the local file
+    Unchanged,
+    /// The current device's config is at least as new (only without `force`)
+    LocalNewer,
+    /// The source device has no CLAUDE.md
+    Missing,
+}
 
-    // Find most recently updated device (with timestamp)
-    let (latest_device, latest_time) =
-        match find_latest_device_config_with_time(&sync_state.sync_repo_path, &current_device) {
-            Some(d) => d,
-            None => {
-                log::debug!("No other device configs found for auto-apply");
-                return Ok(());
-            }
-        };
+/// Merge CLAUDE.md from `source_device` into `claude/CLAUDE.md`
+///
+/// Skipped when the current device's config was synced at or after the
+/// source device's, unless `force` is set. The previous file is kept as
+/// `CLAUDE.md.backup` whenever it is rewritten.
+fn apply_claude_md_from(
+    sync_repo: &Path,
+    source_device: &str,
+    current_device: &str,
+    claude: &Path,
+    force: bool,
+) -> Result<ClaudeMdUpdate> {
+    let source_claude_md = device_config_dir(sync_repo, source_device).join("CLAUDE.md");
+    if !source_claude_md.exists() {
+        return Ok(ClaudeMdUpdate::Missing);
+    }
 
     // Only apply if the other device's config is newer than current device's
-    if let Some(current_time) = get_device_sync_time(&sync_state.sync_repo_path, &current_device) {
-        if latest_time <= current_time {
-            log::debug!(
-                "Current device config ({}) is newer than {} ({}), skipping auto-apply",
-                current_time,
-                latest_device,
-                latest_time
-            );
-            return Ok(());
+    if !force {
+        if let (Some(source_time), Some(current_time)) = (
+            get_device_sync_time(sync_repo, source_device),
+            get_device_sync_time(sync_repo, current_device),
+        ) {
+            if source_time <= current_time {
+                return Ok(ClaudeMdUpdate::LocalNewer);
+            }
         }
     }
 
-    let source_dir = device_config_dir(&sync_state.sync_repo_path, &latest_device);
-    let source_claude_md = source_dir.join("CLAUDE.md");
-
-    if !source_claude_md.exists() {
-        log::debug!("No CLAUDE.md found in device config: {}", latest_device);
-        return Ok(());
-    }
-
-    let claude = claude_dir()?;
     let target_claude_md = claude.join("CLAUDE.md");
     let source_content = fs::read_to_string(&source_claude_md)?;
-
-    // Read target content
     let target_content = if target_claude_md.exists() {
         fs::read_to_string(&target_claude_md)?
     } else {
         String::new()
     };
 
-    // Only apply if there are platform blocks to merge
-    if has_platform_blocks(&source_content) || has_platform_blocks(&target_content) {
-        let current_platform = Platform::current();
-        let merged = merge_claude_md(&source_content, &target_content, current_platform);
+    // Merge platform blocks when either side has them, otherwise take the
+    // source as is
+    let merged = if has_platform_blocks(&source_content) || has_platform_blocks(&target_content) {
+        merge_claude_md(&source_content, &target_content, Platform::current())
+    } else {
+        source_content
+    };
+    if merged == target_content {
+        return Ok(ClaudeMdUpdate::Unchanged);
+    }
+
+    if target_claude_md.exists() {
+        fs::copy(&target_claude_md, claude.join("CLAUDE.md.backup"))?;
+    }
+    fs::write(&target_claude_md, &merged)?;
+    Ok(ClaudeMdUpdate::Applied)
+}
 
-        // Only write if content changed
-        if merged != target_content {
-            fs::write(&target_claude_md, &merged)?;
-            log::info!("Auto-applied CLAUDE.md from device: {}", latest_device);
+/// Handle `config-sync apply --claude-md`: apply only CLAUDE.md from
+/// `source_device`, bypassing the newer-config check with `force`
+fn apply_claude_md_only(
+    sync_repo: &Path,
+    source_device: &str,
+    settings: &ConfigSyncSettings,
+    force: bool,
+) -> Result<()> {
+    let update = apply_claude_md_from(
+        sync_repo,
+        source_device,
+        &settings.get_device_name(),
+        &claude_dir()?,
+        force,
+    )?;
+    match update {
+        ClaudeMdUpdate::Applied => {
+            println!(
+                "{}",
+                t!("config_sync.claude_md_applied", device = source_device).green()
+            );
+            println!(
+                "  {} {}",
+                icon("ℹ").blue(),
+                t!("config_sync.claude_md_backed_up")
+            );
+            println!();
+            println!("{}", t!("config_sync.restart_hint").cyan());
         }
-    } else {
-        // No platform blocks - check if source is different and update
-        if source_content != target_content {
-            fs::write(&target_claude_md, &source_content)?;
-            log::info!("Auto-applied CLAUDE.md from device: {}", latest_device);
+        ClaudeMdUpdate::Unchanged => println!(
+            "{}",
+            t!("config_sync.claude_md_unchanged", device = source_device).green()
+        ),
+        ClaudeMdUpdate::LocalNewer => println!(
+            "{}",
+            t!("config_sync.claude_md_local_newer", device = source_device).yellow()
+        ),
+        ClaudeMdUpdate::Missing => println!(
+            "{}",
+            t!("config_sync.claude_md_missing", device = source_device).yellow()
+        ),
+    }
+    Ok(())
+}
+
+/// Auto-apply CLAUDE.md from the most recently updated device
+/// Only applies CLAUDE.md, not other config files (settings, hooks, skills)
+/// Only applies if the other device's config is newer than the current device's config
+pub fn auto_apply_claude_md(settings: &ConfigSyncSettings) -> Result<()> {
+    if !settings.enabled || !settings.auto_apply_claude_md {
+        log::debug!("Auto-apply CLAUDE.md is disabled");
+        return Ok(());
+    }
+
+    let sync_state = SyncState::load()?;
+    let current_device = settings.get_device_name();
+
+    // Find most recently updated device
+    let Some(latest_device) =
+        find_latest_device_config(&sync_state.sync_repo_path, &current_device)
+    else {
+        log::debug!("No other device configs found for auto-apply");
+        return Ok(());
+    };
+
+    match apply_claude_md_from(
+        &sync_state.sync_repo_path,
+        &latest_device,
+        &current_device,
+        &claude_dir()?,
+        false,
+    )? {
+        ClaudeMdUpdate::Applied => {
+            log::info!("Auto-applied CLAUDE.md from device: {}", latest_device)
+        }
+        ClaudeMdUpdate::Unchanged => {}
+        ClaudeMdUpdate::LocalNewer => log::debug!(
+            "Current device config is newer than {}, skipping auto-apply",
+            latest_device
+        ),
+        ClaudeMdUpdate::Missing => {
+            log::debug!("No CLAUDE.md found in device config: {}", latest_device)
         }
     }
 
@@ -761,6 +1494,59 @@ pub fn auto_apply_claude_md(settings: &ConfigSyncSettings) -> Result<()> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_install_script_quotes_arguments() {
+        let commands = InstallCommands {
+            skills: vec!["https://example.com/skills/pdf".to_string()],
+            plugins: vec!["review@team's market".to_string()],
+        };
+
+        let script = install_script("laptop", &commands);
+        assert!(script.starts_with("#!/bin/sh\n"));
+        assert!(script.contains("set -e\n"));
+        assert!(script.contains("claude skill install https://example.com/skills/pdf\n"));
+        assert!(script.contains("claude plugin install 'review@team'\\''s market'\n"));
+        assert_eq!(shell_quote(""), "''");
+    }
+
+    #[test]
+    fn test_install_script_strips_control_characters_from_device() {
+        let commands = InstallCommands {
+            skills: Vec::new(),
+            plugins: Vec::new(),
+        };
+
+        let script = install_script("laptop\nrm -rf ~\r", &commands);
+        assert!(script.contains("# Install skills and plugins of device 'laptop?rm -rf ~?'\n"));
+        assert!(!script.lines().any(|line| line.starts_with("rm ")));
+    }
+
+    #[test]
+    fn test_install_commands_load() {
+        let dir = tempfile::TempDir::new().unwrap();
+        fs::write(
+            dir.path().join("installed_skills.json"),
+            r#"{"skills": {"b": "https://b", "a": "https://a"}}"#,
+        )
+        .unwrap();
+        fs::write(
+            dir.path().join("installed_plugins.json"),
+            r#"{"plugins": {"fmt@market": {}}}"#,
+        )
+        .unwrap();
+
+        let commands = InstallCommands::load(dir.path());
+        assert_eq!(
+            commands.args(),
+            vec![
+                ["skill", "install", "https://a"],
+                ["skill", "install", "https://b"],
+                ["plugin", "install", "fmt@market"],
+            ]
+        );
+        assert!(InstallCommands::load(&dir.path().join("missing")).is_empty());
+    }
+
     #[test]
     fn test_device_name_fallback() {
         let settings = ConfigSyncSettings::default();
@@ -778,4 +1564,157 @@ mod tests {
         assert!(settings.sync_skills_list);
         assert!(!settings.auto_apply_claude_md);
     }
+
+    #[test]
+    fn test_merge_settings_prompts_only_for_conflicting_keys() {
+        let local = serde_json::json!({
+            "model": "opus",
+            "theme": "dark",
+            "localOnly": true,
+            "hooks": {"Stop": []}
+        });
+        let remote = serde_json::json!({
+            "model": "sonnet",
+            "theme": "dark",
+            "remoteOnly": 1,
+            "env": {"A": "1"}
+        });
+
+        let mut asked = Vec::new();
+        let merged = merge_settings(&local, &remote, |key, _, _| {
+            asked.push(key.to_string());
+            Ok(true)
+        })
+        .unwrap();
+
+        assert_eq!(asked, vec!["model"]);
+        assert_eq!(merged["model"], "opus");
+        assert_eq!(merged["theme"], "dark");
+        assert_eq!(merged["localOnly"], true);
+        assert_eq!(merged["remoteOnly"], 1);
+        assert_eq!(merged["hooks"], serde_json::json!({"Stop": []}));
+
+        let merged = merge_settings(&local, &remote, |_, _, _| Ok(false)).unwrap();
+        assert_eq!(merged["model"], "sonnet");
+    }
+
+    #[test]
+    fn test_list_device_sync_times_and_staleness() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let now = chrono::Utc::now();
+        let write_info = |device: &str, last_sync: Option<chrono::DateTime<chrono::Utc>>| {
+            let dir = device_config_dir(temp.path(), device);
+            fs::create_dir_all(&dir).unwrap();
+            if let Some(t) = last_sync {
+                let info = DeviceSyncInfo {
+                    device: device.to_string(),
+                    platform: "linux".to_string(),
+                    last_sync: t.to_rfc3339(),
+                };
+                fs::write(
+                    dir.join(".sync-info.json"),
+                    serde_json::to_string(&info).unwrap(),
+                )
+                .unwrap();
+            }
+        };
+        write_info("laptop", Some(now - chrono::Duration::days(45)));
+        write_info("desktop", Some(now - chrono::Duration::days(1)));
+        write_info("broken", None);
+
+        let devices = list_device_sync_times(temp.path());
+        let names: Vec<&str> = devices.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["broken", "desktop", "laptop"]);
+        assert!(devices[0].1.is_none());
+        assert!(!is_device_stale(devices[1].1.unwrap(), now));
+        assert!(is_device_stale(devices[2].1.unwrap(), now));
+
+        let latest = find_latest_device_config_with_time(temp.path(), "desktop");
+        assert_eq!(latest.map(|(n, _)| n), Some("laptop".to_string()));
+    }
+
+    #[test]
+    fn test_apply_claude_md_from_respects_newer_local_unless_forced() {
+        let repo = tempfile::TempDir::new().unwrap();
+        let claude = tempfile::TempDir::new().unwrap();
+        let now = chrono::Utc::now();
+        for (device, age_days) in [("laptop", 2), ("desktop", 1)] {
+            let dir = device_config_dir(repo.path(), device);
+            fs::create_dir_all(&dir).unwrap();
+            let info = DeviceSyncInfo {
+                device: device.to_string(),
+                platform: "linux".to_string(),
+                last_sync: (now - chrono::Duration::days(age_days)).to_rfc3339(),
+            };
+            fs::write(
+                dir.join(".sync-info.json"),
+                serde_json::to_string(&info).unwrap(),
+            )
+            .unwrap();
+        }
+        fs::write(
+            device_config_dir(repo.path(), "laptop").join("CLAUDE.md"),
+            "remote\n",
+        )
+        .unwrap();
+        fs::write(claude.path().join("CLAUDE.md"), "local\n").unwrap();
+
+        let apply = |source: &str, force: bool| {
+            apply_claude_md_from(repo.path(), source, "desktop", claude.path(), force).unwrap()
+        };
+        assert_eq!(apply("laptop", false), ClaudeMdUpdate::LocalNewer);
+        assert_eq!(apply("desktop", true), ClaudeMdUpdate::Missing);
+        assert_eq!(apply("laptop", true), ClaudeMdUpdate::Applied);
+        assert_eq!(
+            fs::read_to_string(claude.path().join("CLAUDE.md")).unwrap(),
+            "remote\n"
+        );
+        assert_eq!(
+            fs::read_to_string(claude.path().join("CLAUDE.md.backup")).unwrap(),
+            "local\n"
+        );
+        assert_eq!(apply("laptop", true), ClaudeMdUpdate::Unchanged);
+    }
+
+    #[test]
+    fn test_rename_device_dir() {
+        let temp = tempfile::TempDir::new().unwrap();
+        for device in ["old-mac", "desktop"] {
+            let dir = device_config_dir(temp.path(), device);
+            fs::create_dir_all(&dir).unwrap();
+            let info = DeviceSyncInfo {
+                device: device.to_string(),
+                platform: "macos".to_string(),
+                last_sync: chrono::Utc::now().to_rfc3339(),
+            };
+            fs::write(
+                dir.join(".sync-info.json"),
+                serde_json::to_string(&info).unwrap(),
+            )
+            .unwrap();
+        }
+
+        assert!(rename_device_dir(temp.path(), "old-mac", "desktop").is_err());
+        assert!(rename_device_dir(temp.path(), "missing", "new-mac").is_err());
+
+        // Names that would leave `_configs/<device>` are rejected
+        fs::create_dir_all(temp.path().join("projects")).unwrap();
+        for old in ["../projects", "", ".", "old-mac/..", "/tmp", "..\\projects"] {
+            assert!(
+                rename_device_dir(temp.path(), old, "new-mac").is_err(),
+                "{}",
+                old
+            );
+        }
+        assert!(temp.path().join("projects").is_dir());
+        assert!(configs_dir(temp.path()).is_dir());
+
+        rename_device_dir(temp.path(), "old-mac", "new-mac").unwrap();
+        assert!(!device_config_dir(temp.path(), "old-mac").exists());
+        let content =
+            fs::read_to_string(device_config_dir(temp.path(), "new-mac").join(".sync-info.json"))
+                .unwrap();
+        let info: DeviceSyncInfo = serde_json::from_str(&content).unwrap();
+        assert_eq!(info.device, "new-mac");
+    }
 }
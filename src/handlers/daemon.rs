@@ -0,0 +1,420 @@
+//! Background sync daemon
+//!
+//! `ccs daemon start` runs a background process that watches
+//! `~/.claude/projects` and pushes at most once per interval, after changes
+//! have settled, instead of one push per assistant response. While it runs
+//! the Stop hook only touches a dirty marker (see [`mark_dirty`]).
+//!
+//! Changes are reported by a filesystem watcher (`notify`), so an idle daemon
+//! does no work. The pidfile doubles as a heartbeat: a separate thread
+//! rewrites it every few seconds, so a long push does not make the daemon
+//! look dead, while a crashed daemon is never mistaken for a running one.
+
+use anyhow::{Context, Result};
+use colored::Colorize;
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::time::{Duration, Instant};
+
+use super::hooks::{spawn_ccs_subcommand, timestamp_is_recent};
+use crate::config::ConfigManager;
+use crate::output::icon;
+use crate::sync::discovery::{claude_dir_override, claude_projects_dir, CLAUDE_CONFIG_DIR_ENV};
+use crate::BINARY_NAME;
+
+/// Default minimum seconds between daemon pushes
+pub const DEFAULT_INTERVAL_SECS: u64 = 60;
+
+/// How often the daemon checks the stop and dirty markers and whether a
+/// push is due, and the heartbeat thread refreshes the pidfile
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Quiet period after the last change before pushing, so a response that is
+/// still being written is not pushed half-way
+const SETTLE_TIME: Duration = Duration::from_secs(10);
+
+/// A pidfile older than this means the daemon is no longer running
+const HEARTBEAT_STALE_SECS: u64 = 30;
+
+fn pid_path() -> Result<PathBuf> {
+    Ok(ConfigManager::config_dir()?.join("daemon.pid"))
+}
+
+fn dirty_path() -> Result<PathBuf> {
+    Ok(ConfigManager::config_dir()?.join("daemon.dirty"))
+}
+
+fn stop_path() -> Result<PathBuf> {
+    Ok(ConfigManager::config_dir()?.join("daemon.stop"))
+}
+
+fn log_path() -> Result<PathBuf> {
+    Ok(ConfigManager::config_dir()?.join("daemon.log"))
+}
+
+/// Whether a daemon is running, judged by a fresh heartbeat in the pidfile
+pub fn is_running() -> bool {
+    pid_path().is_ok_and(|path| timestamp_is_recent(&path, HEARTBEAT_STALE_SECS))
+}
+
+/// Read the daemon's pid from the pidfile
+fn running_pid() -> Option<u32> {
+    fs::read_to_string(pid_path().ok()?)
+        .ok()?
+        .trim()
+        .parse()
+        .ok()
+}
+
+/// Tell a running daemon that there are changes to push
+pub fn mark_dirty() -> Result<()> {
+    let path = dirty_path()?;
+    fs::write(&path, "").with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Whether a watcher event changes what a push would upload; reads and
+/// other access events do not
+fn is_content_change(event: &Event) -> bool {
+    matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    )
+}
+
+/// Rewrite the pidfile every `every` until `stop` is set
+fn spawn_heartbeat(
+    pid_path: PathBuf,
+    pid: String,
+    stop: Arc<AtomicBool>,
+    every: Duration,
+) -> std::thread::JoinHandle<()> {
+    std::thread::spawn(move || {
+        while !stop.load(Ordering::Relaxed) {
+            let _ = fs::write(&pid_path, &pid);
+            std::thread::sleep(every);
+        }
+    })
+}
+
+/// Decides when accumulated changes should be pushed
+#[derive(Debug)]
+struct Scheduler {
+    interval: Duration,
+    settle: Duration,
+    last_change: Option<Instant>,
+    last_push: Option<Instant>,
+}
+
+impl Scheduler {
+    fn new(interval: Duration, settle: Duration) -> Self {
+        Self {
+            interval,
+            settle,
+            last_change: None,
+            last_push: None,
+        }
+    }
+
+    fn note_change(&mut self, now: Instant) {
+        self.last_change = Some(now);
+    }
+
+    /// Push once changes are pending, have settled, and the interval since
+    /// the previous push attempt has passed
+    fn should_push(&self, now: Instant) -> bool {
+        let Some(changed) = self.last_change else {
+            return false;
+        };
+        now.duration_since(changed) >= self.settle
+            && self
+                .last_push
+                .is_none_or(|pushed| now.duration_since(pushed) >= self.interval)
+    }
+
+    /// Record a push attempt; failed pushes stay pending and are retried
+    /// after the next interval
+    fn pushed(&mut self, now: Instant, success: bool) {
+        self.last_push = Some(now);
+        if success {
+            self.last_change = None;
+        }
+    }
+}
+
+/// Run the watch loop in the current process until `ccs daemon stop`
+fn run(interval: u64) -> Result<()> {
+    ConfigManager::ensure_config_dir()?;
+    let pid_path = pid_path()?;
+    let dirty_path = dirty_path()?;
+    let stop_path = stop_path()?;
+    let _ = fs::remove_file(&stop_path);
+
+    let pid = std::process::id().to_string();
+    fs::write(&pid_path, &pid)
+        .with_context(|| format!("Failed to write {}", pid_path.display()))?;
+
+    let projects_dir = claude_projects_dir()?;
+    log::info!(
+        "Daemon {} watching {} (push interval {}s)",
+        pid,
+        projects_dir.display(),
+        interval
+    );
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).context("Failed to create file system watcher")?;
+    watcher
+        .watch(&projects_dir, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", projects_dir.display()))?;
+
+    let stop_heartbeat = Arc::new(AtomicBool::new(false));
+    let heartbeat = spawn_heartbeat(
+        pid_path.clone(),
+        pid.clone(),
+        stop_heartbeat.clone(),
+        POLL_INTERVAL,
+    );
+
+    let mut scheduler = Scheduler::new(Duration::from_secs(interval), SETTLE_TIME);
+
+    while !stop_path.exists() {
+        let mut changed = false;
+        match rx.recv_timeout(POLL_INTERVAL) {
+            Ok(first) => {
+                // Drain the rest of the burst
+                for result in std::iter::once(first).chain(rx.try_iter()) {
+                    match result {
+                        Ok(event) => changed |= is_content_change(&event),
+                        Err(e) => log::warn!("Watch error: {}", e),
+                    }
+                }
+            }
+            Err(mpsc::RecvTimeoutError::Timeout) => {}
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                log::warn!("File system watcher stopped");
+                break;
+            }
+        }
+
+        let now = Instant::now();
+        if changed || dirty_path.exists() {
+            let _ = fs::remove_file(&dirty_path);
+            scheduler.note_change(now);
+        }
+
+        if scheduler.should_push(now) {
+            let success = match spawn_ccs_subcommand("push", &["--quiet"], None) {
+                Ok(status) if status.success() => {
+                    log::info!("Daemon push completed");
+                    true
+                }
+                Ok(status) => {
+                    log::warn!("Daemon push exited with {}", status);
+                    false
+                }
+                Err(e) => {
+                    log::warn!("Daemon push failed to execute: {}", e);
+                    false
+                }
+            };
+            scheduler.pushed(now, success);
+        }
+    }
+
+    log::info!("Daemon {} stopping", pid);
+    stop_heartbeat.store(true, Ordering::Relaxed);
+    let _ = heartbeat.join();
+    let _ = fs::remove_file(&stop_path);
+    let _ = fs::remove_file(&pid_path);
+    Ok(())
+}
+
+/// Handle `ccs daemon start`
+///
+/// Without `foreground` the daemon re-executes itself in the background with
+/// output appended to `daemon.log` in the config directory.
+pub fn handle_daemon_start(interval: u64, foreground: bool) -> Result<()> {
+    if is_running() {
+        println!(
+            "{} Daemon is already running (pid {})",
+            icon("ℹ").cyan(),
+            running_pid().map_or("?".to_string(), |pid| pid.to_string())
+        );
+        return Ok(());
+    }
+
+    if foreground {
+        return run(interval);
+    }
+
+    ConfigManager::ensure_config_dir()?;
+    let log_path = log_path()?;
+    let log_file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .with_context(|| format!("Failed to open {}", log_path.display()))?;
+
+    let exe = std::env::current_exe().unwrap_or_else(|_| PathBuf::from(BINARY_NAME));
+    let mut command = std::process::Command::new(exe);
+    if let Some(dir) = claude_dir_override() {
+        command.env(CLAUDE_CONFIG_DIR_ENV, dir);
+    }
+    let child = command
+        .args(["daemon", "start", "--foreground", "--interval"])
+        .arg(interval.to_string())
+        .stdin(std::process::Stdio::null())
+        .stdout(log_file.try_clone()?)
+        .stderr(log_file)
+        .spawn()
+        .context("Failed to start daemon")?;
+
+    println!(
+        "{} Daemon started (pid {}), pushing at most every {}s",
+        icon("✓").green(),
+        child.id(),
+        interval
+    );
+    println!(
+        "  {}",
+        "The Stop hook now only marks changes; the daemon does the pushing.".dimmed()
+    );
+    println!("  Log: {}", log_path.display().to_string().dimmed());
+    Ok(())
+}
+
+/// Handle `ccs daemon stop`
+pub fn handle_daemon_stop() -> Result<()> {
+    if !is_running() {
+        // Clean up after a daemon that died without removing its pidfile
+        let _ = fs::remove_file(pid_path()?);
+        println!("{} Daemon is not running", icon("ℹ").cyan());
+        return Ok(());
+    }
+
+    let stop_path = stop_path()?;
+    fs::write(&stop_path, "")
+        .with_context(|| format!("Failed to write {}", stop_path.display()))?;
+
+    // The daemon checks for the stop file once per poll
+    let deadline = Instant::now() + POLL_INTERVAL * 3;
+    let pid_path = pid_path()?;
+    while pid_path.exists() && Instant::now() < deadline {
+        std::thread::sleep(Duration::from_millis(200));
+    }
+
+    if pid_path.exists() {
+        println!(
+            "{} Stop requested; the daemon will exit after its current push",
+            icon("⚠").yellow()
+        );
+    } else {
+        println!("{} Daemon stopped", icon("✓").green());
+    }
+    Ok(())
+}
+
+/// Handle `ccs daemon status`
+pub fn handle_daemon_status() -> Result<()> {
+    if is_running() {
+        println!(
+            "{} Daemon running (pid {})",
+            icon("✓").green(),
+            running_pid().map_or("?".to_string(), |pid| pid.to_string())
+        );
+        if dirty_path()?.exists() {
+            println!("  {}", "Changes pending".yellow());
+        }
+        println!("  Log: {}", log_path()?.display().to_string().dimmed());
+    } else {
+        println!(
+            "{} Daemon not running (start with '{}')",
+            icon("ℹ").cyan(),
+            format!("{} daemon start", BINARY_NAME).cyan()
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scheduler_waits_for_settle_and_interval() {
+        let start = Instant::now();
+        let secs = |n| start + Duration::from_secs(n);
+        let mut scheduler = Scheduler::new(Duration::from_secs(60), Duration::from_secs(10));
+
+        assert!(!scheduler.should_push(secs(100)), "nothing pending");
+
+        scheduler.note_change(secs(0));
+        assert!(!scheduler.should_push(secs(5)), "not settled yet");
+        assert!(scheduler.should_push(secs(10)));
+        scheduler.pushed(secs(10), true);
+        assert!(
+            !scheduler.should_push(secs(30)),
+            "nothing pending after push"
+        );
+
+        scheduler.note_change(secs(20));
+        assert!(!scheduler.should_push(secs(40)), "interval not elapsed");
+        assert!(scheduler.should_push(secs(70)));
+    }
+
+    #[test]
+    fn test_scheduler_retries_failed_push_after_interval() {
+        let start = Instant::now();
+        let secs = |n| start + Duration::from_secs(n);
+        let mut scheduler = Scheduler::new(Duration::from_secs(60), Duration::from_secs(10));
+
+        scheduler.note_change(secs(0));
+        scheduler.pushed(secs(10), false);
+        assert!(!scheduler.should_push(secs(20)));
+        assert!(scheduler.should_push(secs(70)));
+    }
+
+    #[test]
+    fn test_is_content_change() {
+        use notify::event::{AccessKind, CreateKind, ModifyKind, RemoveKind};
+
+        assert!(is_content_change(&Event::new(EventKind::Create(
+            CreateKind::File
+        ))));
+        assert!(is_content_change(&Event::new(EventKind::Modify(
+            ModifyKind::Any
+        ))));
+        assert!(is_content_change(&Event::new(EventKind::Remove(
+            RemoveKind::File
+        ))));
+        assert!(!is_content_change(&Event::new(EventKind::Access(
+            AccessKind::Read
+        ))));
+    }
+
+    #[test]
+    fn test_heartbeat_refreshes_pidfile_until_stopped() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let pid_path = temp.path().join("daemon.pid");
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let heartbeat = spawn_heartbeat(
+            pid_path.clone(),
+            "42".to_string(),
+            stop.clone(),
+            Duration::from_millis(10),
+        );
+        let deadline = Instant::now() + Duration::from_secs(5);
+        while !pid_path.exists() && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(fs::read_to_string(&pid_path).unwrap(), "42");
+
+        stop.store(true, Ordering::Relaxed);
+        heartbeat.join().unwrap();
+    }
+}
@@ -0,0 +1,367 @@
+//! Installation diagnostics
+//!
+//! Handles `ccs doctor`, which runs every health check that is otherwise
+//! scattered across setup, status, hooks and wrapper commands and prints a
+//! single pass/warn/fail checklist with suggested fixes.
+
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::path::Path;
+
+use crate::config::ConfigManager;
+use crate::filter::FilterConfig;
+use crate::handlers::hooks::are_hooks_installed;
+use crate::handlers::onboarding::is_initialized;
+use crate::handlers::wrapper::is_wrapper_installed;
+use crate::output::icon;
+use crate::scm;
+use crate::sync::discovery::check_directory_structure_consistency;
+use crate::sync::{open_sync_repo, SyncState};
+use crate::BINARY_NAME;
+
+/// Outcome of a single diagnostic check
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckStatus {
+    Pass,
+    Warn,
+    Fail,
+}
+
+/// A single line of the doctor checklist
+#[derive(Debug)]
+struct DoctorCheck {
+    name: &'static str,
+    status: CheckStatus,
+    detail: String,
+    /// Suggested fix, shown for warnings and failures
+    fix: Option<String>,
+}
+
+impl DoctorCheck {
+    fn pass(name: &'static str, detail: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Pass,
+            detail: detail.into(),
+            fix: None,
+        }
+    }
+
+    fn warn(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Warn,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn fail(name: &'static str, detail: impl Into<String>, fix: impl Into<String>) -> Self {
+        Self {
+            name,
+            status: CheckStatus::Fail,
+            detail: detail.into(),
+            fix: Some(fix.into()),
+        }
+    }
+
+    fn print(&self) {
+        let marker = match self.status {
+            CheckStatus::Pass => icon("✓").green().bold(),
+            CheckStatus::Warn => icon("⚠").yellow().bold(),
+            CheckStatus::Fail => icon("✗").red().bold(),
+        };
+        println!("{} {:<20} {}", marker, self.name.bold(), self.detail);
+        if let Some(ref fix) = self.fix {
+            println!("  {} {}", icon("→").cyan(), fix);
+        }
+    }
+}
+
+/// Check the sync state file exists and parses
+fn check_state() -> (DoctorCheck, Option<SyncState>) {
+    let setup_fix = format!(
+        "Run '{}' to configure sync",
+        format!("{} setup", BINARY_NAME).cyan()
+    );
+
+    match is_initialized() {
+        Ok(true) => {}
+        Ok(false) => {
+            return (
+                DoctorCheck::fail("State file", "state.json not found", setup_fix),
+                None,
+            )
+        }
+        Err(e) => {
+            return (
+                DoctorCheck::fail("State file", e.to_string(), setup_fix),
+                None,
+            )
+        }
+    }
+
+    match SyncState::load() {
+        Ok(state) => (
+            DoctorCheck::pass("State file", "state.json is valid"),
+            Some(state),
+        ),
+        Err(e) => {
+            let path = ConfigManager::state_file_path()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|_| "state.json".to_string());
+            (
+                DoctorCheck::fail(
+                    "State file",
+                    format!("{:#}", e),
+                    format!(
+                        "Fix or remove {} and run '{} setup' again",
+                        path, BINARY_NAME
+                    ),
+                ),
+                None,
+            )
+        }
+    }
+}
+
+/// Check the sync repository path exists and is a Git/Mercurial repository
+fn check_sync_repo(path: &Path) -> DoctorCheck {
+    if !path.exists() {
+        return DoctorCheck::fail(
+            "Sync repository",
+            format!("{} does not exist", path.display()),
+            format!(
+                "Run '{}' to recreate or re-clone it",
+                format!("{} setup", BINARY_NAME).cyan()
+            ),
+        );
+    }
+    if !scm::is_repo(path) {
+        return DoctorCheck::fail(
+            "Sync repository",
+            format!("{} is not a Git/Mercurial repository", path.display()),
+            format!(
+                "Run '{}' to initialize it",
+                format!("{} init --local {}", BINARY_NAME, path.display()).cyan()
+            ),
+        );
+    }
+    let backend = scm::detect_backend(path)
+        .map(|b| format!("{:?}", b))
+        .unwrap_or_else(|| "Unknown".to_string());
+    DoctorCheck::pass(
+        "Sync repository",
+        format!("{} ({})", path.display(), backend),
+    )
+}
+
+/// Check the `origin` remote is configured and reachable
+fn check_remote(state: &SyncState, filter: &FilterConfig) -> DoctorCheck {
+    if !state.has_remote {
+        return DoctorCheck::warn(
+            "Remote",
+            "no remote configured (local backup only)",
+            format!(
+                "Run '{}' to sync across devices",
+                format!("{} remote set <url>", BINARY_NAME).cyan()
+            ),
+        );
+    }
+
+    let repo = match open_sync_repo(&state.sync_repo_path, filter) {
+        Ok(repo) => repo,
+        Err(e) => {
+            return DoctorCheck::fail(
+                "Remote",
+                format!("{:#}", e),
+                "Fix the sync repository first",
+            )
+        }
+    };
+    let url = repo
+        .get_remote_url("origin")
+        .unwrap_or_else(|_| "origin".to_string());
+
+    match repo.check_remote("origin") {
+        Ok(()) => DoctorCheck::pass("Remote", format!("{} is reachable", url)),
+        Err(e) => DoctorCheck::fail(
+            "Remote",
+            format!("{:#}", e),
+            "Check network access and credentials (HTTPS: 'gh auth login', SSH: 'ssh -T git@github.com')",
+        ),
+    }
+}
+
+/// Check the sync repo layout matches the `use_project_name_only` setting
+fn check_directory_structure(state: &SyncState, filter: &FilterConfig) -> DoctorCheck {
+    let projects_dir = state.sync_repo_path.join(&filter.sync_subdirectory);
+    if !projects_dir.exists() {
+        return DoctorCheck::pass(
+            "Directory structure",
+            format!("{} not created yet", filter.sync_subdirectory),
+        );
+    }
+
+    let check = check_directory_structure_consistency(&projects_dir, filter.use_project_name_only);
+    if check.is_consistent {
+        DoctorCheck::pass(
+            "Directory structure",
+            format!(
+                "{} project directories match the configured mode",
+                check.full_path_dirs.len() + check.project_name_dirs.len()
+            ),
+        )
+    } else {
+        DoctorCheck::warn(
+            "Directory structure",
+            check
+                .warning
+                .unwrap_or_else(|| "inconsistent directory formats".to_string())
+                .replace('\n', " "),
+            format!(
+                "Review with '{}' and clean up the sync repo",
+                format!("{} config --show", BINARY_NAME).cyan()
+            ),
+        )
+    }
+}
+
+/// Count checks by status as (pass, warn, fail)
+fn tally(checks: &[DoctorCheck]) -> (usize, usize, usize) {
+    let count = |status| checks.iter().filter(|c| c.status == status).count();
+    (
+        count(CheckStatus::Pass),
+        count(CheckStatus::Warn),
+        count(CheckStatus::Fail),
+    )
+}
+
+/// Validate the whole installation and print a checklist
+///
+/// Returns an error (non-zero exit) when any check fails, so the command can
+/// be used from scripts.
+pub fn handle_doctor() -> Result<()> {
+    println!("{}", "ccs doctor".cyan().bold());
+    println!("{}", "═".repeat(60).dimmed());
+
+    let mut checks = Vec::new();
+
+    let (state_check, state) = check_state();
+    checks.push(state_check);
+
+    let filter = match FilterConfig::load() {
+        Ok(filter) => {
+            checks.push(DoctorCheck::pass("Filter config", "config.toml parses"));
+            Some(filter)
+        }
+        Err(e) => {
+            let path = ConfigManager::filter_config_path()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|_| "config.toml".to_string());
+            checks.push(DoctorCheck::fail(
+                "Filter config",
+                format!("{:#}", e),
+                format!(
+                    "Fix or remove {} (defaults are used when it is missing)",
+                    path
+                ),
+            ));
+            None
+        }
+    };
+
+    if let Some(ref state) = state {
+        let repo_check = check_sync_repo(&state.sync_repo_path);
+        let repo_ok = repo_check.status == CheckStatus::Pass;
+        checks.push(repo_check);
+
+        if repo_ok {
+            let filter = filter.clone().unwrap_or_default();
+            checks.push(check_remote(state, &filter));
+            checks.push(check_directory_structure(state, &filter));
+        }
+    }
+
+    checks.push(match are_hooks_installed() {
+        Ok(true) => DoctorCheck::pass("Hooks", "installed in ~/.claude/settings.json"),
+        Ok(false) => DoctorCheck::warn(
+            "Hooks",
+            "not installed (no automatic push)",
+            format!("Run '{}'", format!("{} hooks install", BINARY_NAME).cyan()),
+        ),
+        Err(e) => DoctorCheck::fail(
+            "Hooks",
+            format!("{:#}", e),
+            "Check that ~/.claude/settings.json is valid JSON",
+        ),
+    });
+
+    checks.push(match is_wrapper_installed() {
+        Ok(true) => DoctorCheck::pass("Wrapper", "claude-sync is installed"),
+        Ok(false) => DoctorCheck::warn(
+            "Wrapper",
+            "not installed (no automatic pull on startup)",
+            format!(
+                "Run '{}'",
+                format!("{} wrapper install", BINARY_NAME).cyan()
+            ),
+        ),
+        Err(e) => DoctorCheck::fail(
+            "Wrapper",
+            format!("{:#}", e),
+            format!(
+                "Run '{}'",
+                format!("{} wrapper install", BINARY_NAME).cyan()
+            ),
+        ),
+    });
+
+    for check in &checks {
+        check.print();
+    }
+
+    let (passed, warned, failed) = tally(&checks);
+    println!("{}", "═".repeat(60).dimmed());
+    println!(
+        "{} passed, {} warnings, {} failed",
+        passed.to_string().green(),
+        warned.to_string().yellow(),
+        failed.to_string().red()
+    );
+
+    if failed > 0 {
+        bail!("{} check(s) failed", failed);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_check_sync_repo() {
+        let temp = TempDir::new().unwrap();
+
+        let missing = temp.path().join("missing");
+        assert_eq!(check_sync_repo(&missing).status, CheckStatus::Fail);
+
+        assert_eq!(check_sync_repo(temp.path()).status, CheckStatus::Fail);
+
+        scm::init(temp.path()).unwrap();
+        assert_eq!(check_sync_repo(temp.path()).status, CheckStatus::Pass);
+    }
+
+    #[test]
+    fn test_tally() {
+        let checks = vec![
+            DoctorCheck::pass("a", ""),
+            DoctorCheck::warn("b", "", "fix"),
+            DoctorCheck::fail("c", "", "fix"),
+            DoctorCheck::pass("d", ""),
+        ];
+        assert_eq!(tally(&checks), (2, 1, 1));
+    }
+}
@@ -0,0 +1,386 @@
+//! Whole-history export
+//!
+//! Handles `ccs export-all`, which bundles every discovered session (after the
+//! configured filters) into a single `.tar.gz` organized by project. The
+//! archive is independent of the sync repo and of git, so it works as a
+//! portable backup. Sessions are stored either as their original `.jsonl`
+//! files or rendered to Markdown, optionally with an `index.json` describing
+//! each one.
+//!
+//! The tar stream is written and read directly (ustar headers plus GNU long
+//! names for deep project paths), so no archive dependency is needed beyond
+//! `flate2`. `ccs import` (see [`super::import`]) reads the archive back.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::fs;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::UNIX_EPOCH;
+
+use super::session::session_markdown;
+use crate::filter::FilterConfig;
+use crate::output::icon;
+use crate::sync::discovery::{claude_projects_dir, discover_sessions};
+
+/// Top-level directory inside the archive
+pub(super) const ARCHIVE_ROOT: &str = "claude-history";
+
+/// Name of the optional session index inside the archive root
+const INDEX_FILE: &str = "index.json";
+
+/// Tar block size
+const BLOCK: usize = 512;
+
+/// Longest path that fits the ustar `name` field
+const USTAR_NAME_LEN: usize = 100;
+
+/// Longest GNU long name accepted when reading; real paths are far shorter
+const MAX_LONG_NAME_LEN: u64 = 64 * 1024;
+
+/// How sessions are stored in the export
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    /// Original `.jsonl` files, byte for byte
+    Jsonl,
+    /// Rendered conversation text
+    Markdown,
+}
+
+impl ExportFormat {
+    fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Jsonl => "jsonl",
+            ExportFormat::Markdown => "md",
+        }
+    }
+}
+
+/// Minimal tar writer for regular files
+pub(super) struct TarWriter<W: Write> {
+    inner: W,
+}
+
+impl<W: Write> TarWriter<W> {
+    pub(super) fn new(inner: W) -> Self {
+        Self { inner }
+    }
+
+    /// Append a regular file; paths longer than the ustar name field are
+    /// preceded by a GNU long-name entry
+    pub(super) fn append(&mut self, path: &str, data: &[u8], mtime: u64) -> Result<()> {
+        let name = path.as_bytes();
+        if name.len() > USTAR_NAME_LEN {
+            let mut long_name = name.to_vec();
+            long_name.push(0);
+            self.write_entry(b"././@LongLink", &long_name, 0, b'L')?;
+        }
+        self.write_entry(&name[..name.len().min(USTAR_NAME_LEN)], data, mtime, b'0')
+    }
+
+    fn write_entry(&mut self, name: &[u8], data: &[u8], mtime: u64, kind: u8) -> Result<()> {
+        self.inner
+            .write_all(&tar_header(name, data.len() as u64, mtime, kind))?;
+        self.inner.write_all(data)?;
+        let padding = (BLOCK - data.len() % BLOCK) % BLOCK;
+        self.inner.write_all(&[0u8; BLOCK][..padding])?;
+        Ok(())
+    }
+
+    /// Write the two empty end-of-archive blocks
+    pub(super) fn finish(mut self) -> Result<W> {
+        self.inner.write_all(&[0u8; BLOCK * 2])?;
+        Ok(self.inner)
+    }
+}
+
+/// Write `value` as a NUL-terminated, zero-padded octal number
+fn write_octal(field: &mut [u8], value: u64) {
+    let width = field.len() - 1;
+    let digits = format!("{value:0width$o}");
+    field[..width].copy_from_slice(&digits.as_bytes()[digits.len() - width..]);
+    field[width] = 0;
+}
+
+/// A ustar header block for a file owned by uid/gid 0 with mode 0644
+fn tar_header(name: &[u8], size: u64, mtime: u64, kind: u8) -> [u8; BLOCK] {
+    let mut header = [0u8; BLOCK];
+    header[..name.len()].copy_from_slice(name);
+    write_octal(&mut header[100..108], 0o644);
+    write_octal(&mut header[108..116], 0);
+    write_octal(&mut header[116..124], 0);
+    write_octal(&mut header[124..136], size);
+    write_octal(&mut header[136..148], mtime);
+    header[156] = kind;
+    header[257..263].copy_from_slice(b"ustar\0");
+    header[263..265].copy_from_slice(b"00");
+
+    // The checksum is computed with its own field filled with spaces
+    header[148..156].fill(b' ');
+    let checksum: u64 = header.iter().map(|&b| u64::from(b)).sum();
+    write_octal(&mut header[148..155], checksum);
+    header
+}
+
+/// Parse a NUL- or space-terminated octal header field
+fn parse_octal(field: &[u8]) -> Result<u64> {
+    let text = std::str::from_utf8(field).context("Invalid tar header")?;
+    let text = text.trim_matches(|c| c == '\0' || c == ' ');
+    if text.is_empty() {
+        return Ok(0);
+    }
+    u64::from_str_radix(text, 8).with_context(|| format!("Invalid tar header field: {text}"))
+}
+
+/// Text of a NUL-padded header field
+fn field_str(field: &[u8]) -> String {
+    let end = field.iter().position(|&b| b == 0).unwrap_or(field.len());
+    String::from_utf8_lossy(&field[..end]).into_owned()
+}
+
+/// Read the next `size` bytes of an entry
+///
+/// `size` comes from the archive, so the buffer only grows with the bytes
+/// actually present instead of being allocated up front.
+fn read_entry<R: Read>(reader: &mut R, size: u64) -> Result<Vec<u8>> {
+    let mut data = Vec::new();
+    reader.take(size).read_to_end(&mut data)?;
+    if (data.len() as u64) < size {
+        bail!("Archive ended unexpectedly");
+    }
+    Ok(data)
+}
+
+/// Discard the next `size` bytes of the stream
+fn skip_bytes<R: Read>(reader: &mut R, size: u64) -> Result<()> {
+    if io::copy(&mut reader.take(size), &mut io::sink())? < size {
+        bail!("Archive ended unexpectedly");
+    }
+    Ok(())
+}
+
+/// Read a tar stream and call `visit` with the path and content of every
+/// regular file. Directories, links and other entry types are skipped.
+pub(super) fn read_tar<R: Read>(
+    mut reader: R,
+    mut visit: impl FnMut(&str, Vec<u8>) -> Result<()>,
+) -> Result<()> {
+    let mut long_name: Option<String> = None;
+    loop {
+        let mut header = [0u8; BLOCK];
+        reader
+            .read_exact(&mut header)
+            .context("Archive ended unexpectedly")?;
+        if header.iter().all(|&b| b == 0) {
+            return Ok(());
+        }
+
+        let size = parse_octal(&header[124..136])?;
+        let padding = (BLOCK as u64 - size % BLOCK as u64) % BLOCK as u64;
+
+        match header[156] {
+            b'L' => {
+                if size > MAX_LONG_NAME_LEN {
+                    bail!("Invalid tar header: long name of {size} bytes");
+                }
+                long_name = Some(field_str(&read_entry(&mut reader, size)?));
+            }
+            b'0' | 0 => {
+                let data = read_entry(&mut reader, size)?;
+                let name = long_name.take().unwrap_or_else(|| {
+                    let prefix = field_str(&header[345..500]);
+                    let name = field_str(&header[..USTAR_NAME_LEN]);
+                    if prefix.is_empty() {
+                        name
+                    } else {
+                        format!("{prefix}/{name}")
+                    }
+                });
+                visit(&name, data)?;
+            }
+            _ => {
+                long_name = None;
+                skip_bytes(&mut reader, size)?;
+            }
+        }
+        skip_bytes(&mut reader, padding)?;
+    }
+}
+
+fn modified_secs(path: &Path) -> u64 {
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map_or(0, |d| d.as_secs())
+}
+
+/// Archive path of a session: `<root>/<project dir>/<file>.<ext>`, using `/`
+/// separators on every platform
+fn archive_path(relative: &Path, format: ExportFormat) -> String {
+    let relative = relative.with_extension(format.extension());
+    let parts: Vec<_> = relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect();
+    format!("{}/{}", ARCHIVE_ROOT, parts.join("/"))
+}
+
+/// Handle `ccs export-all`
+pub fn handle_export_all(output: &Path, format: ExportFormat, with_index: bool) -> Result<()> {
+    if output.is_dir() {
+        bail!("{} is a directory", output.display());
+    }
+
+    let projects_dir = claude_projects_dir()?;
+    let filter = FilterConfig::load()?;
+    let mut sessions = discover_sessions(&projects_dir, &filter)?;
+    sessions.sort_by(|a, b| a.file_path.cmp(&b.file_path));
+
+    if let Some(parent) = output.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    let file = fs::File::create(output)
+        .with_context(|| format!("Failed to create {}", output.display()))?;
+    let encoder =
+        flate2::write::GzEncoder::new(BufWriter::new(file), flate2::Compression::default());
+    let mut tar = TarWriter::new(encoder);
+
+    let mut index = Vec::new();
+    let mut newest = 0;
+    for session in &sessions {
+        let source = PathBuf::from(&session.file_path);
+        let relative = source.strip_prefix(&projects_dir).unwrap_or(&source);
+        let path = archive_path(relative, format);
+        let mtime = modified_secs(&source);
+        newest = newest.max(mtime);
+
+        let data = match format {
+            ExportFormat::Jsonl => {
+                fs::read(&source).with_context(|| format!("Failed to read {}", source.display()))?
+            }
+            ExportFormat::Markdown => session_markdown(session).into_bytes(),
+        };
+        tar.append(&path, &data, mtime)?;
+
+        if with_index {
+            index.push(serde_json::json!({
+                "session_id": session.session_id,
+                "path": path.strip_prefix(&format!("{ARCHIVE_ROOT}/")).unwrap_or(&path),
+                "project": session.cwd(),
+                "title": session.title(),
+                "messages": session.message_count(),
+                "first_timestamp": session.first_timestamp(),
+                "latest_timestamp": session.latest_timestamp(),
+                "estimated_tokens": session.estimated_tokens(),
+            }));
+        }
+    }
+
+    if with_index {
+        let json = serde_json::to_vec_pretty(&index)?;
+        tar.append(&format!("{ARCHIVE_ROOT}/{INDEX_FILE}"), &json, newest)?;
+    }
+
+    tar.finish()?
+        .finish()
+        .context("Failed to finish compression")?
+        .flush()?;
+
+    let size = fs::metadata(output).map_or(0, |m| m.len());
+    println!(
+        "{} Exported {} sessions to {} ({:.1} MB)",
+        icon("✓").green(),
+        sessions.len(),
+        output.display(),
+        size as f64 / (1024.0 * 1024.0)
+    );
+    if with_index {
+        println!("  Index: {}/{}", ARCHIVE_ROOT, INDEX_FILE);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tar_layout_and_checksum() {
+        let mut tar = TarWriter::new(Vec::new());
+        tar.append("a/b.jsonl", b"hello", 1_700_000_000).unwrap();
+        let bytes = tar.finish().unwrap();
+
+        // header + one data block + two end blocks
+        assert_eq!(bytes.len(), BLOCK * 4);
+        assert_eq!(&bytes[..9], b"a/b.jsonl");
+        assert_eq!(&bytes[124..136], b"00000000005\0");
+        assert_eq!(&bytes[BLOCK..BLOCK + 5], b"hello");
+
+        let header = &bytes[..BLOCK];
+        let stored = std::str::from_utf8(&header[148..154]).unwrap();
+        let mut blank = header.to_vec();
+        blank[148..156].fill(b' ');
+        let sum: u64 = blank.iter().map(|&b| u64::from(b)).sum();
+        assert_eq!(u64::from_str_radix(stored, 8).unwrap(), sum);
+    }
+
+    #[test]
+    fn test_long_paths_use_gnu_long_name() {
+        let long = format!("{}/{}.jsonl", "-Users-me-".repeat(12), "x");
+        let mut tar = TarWriter::new(Vec::new());
+        tar.append(&long, b"{}", 0).unwrap();
+        let bytes = tar.finish().unwrap();
+
+        assert_eq!(bytes[156], b'L');
+        assert_eq!(&bytes[BLOCK..BLOCK + long.len()], long.as_bytes());
+        assert_eq!(bytes[BLOCK * 2 + 156], b'0');
+    }
+
+    #[test]
+    fn test_read_tar_round_trip() {
+        let long = format!("{}/{}.jsonl", "-Users-me-".repeat(12), "x");
+        let mut tar = TarWriter::new(Vec::new());
+        tar.append("a/b.jsonl", b"hello", 0).unwrap();
+        tar.append(&long, b"{}", 0).unwrap();
+        let bytes = tar.finish().unwrap();
+
+        let mut files = Vec::new();
+        read_tar(bytes.as_slice(), |name, data| {
+            files.push((name.to_string(), data));
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            files,
+            vec![
+                ("a/b.jsonl".to_string(), b"hello".to_vec()),
+                (long, b"{}".to_vec())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_tar_rejects_sizes_beyond_the_stream() {
+        // A header claiming far more data than the archive holds must fail
+        // cleanly instead of allocating the claimed size
+        let mut bytes = tar_header(b"a/b.jsonl", u64::MAX >> 31, 0, b'0').to_vec();
+        bytes.extend_from_slice(&[0u8; BLOCK]);
+        let err = read_tar(bytes.as_slice(), |_, _| Ok(())).unwrap_err();
+        assert!(err.to_string().contains("ended unexpectedly"), "{err}");
+
+        let bytes = tar_header(b"././@LongLink", MAX_LONG_NAME_LEN + 1, 0, b'L');
+        let err = read_tar(bytes.as_slice(), |_, _| Ok(())).unwrap_err();
+        assert!(err.to_string().contains("long name"), "{err}");
+    }
+
+    #[test]
+    fn test_archive_path() {
+        let relative = Path::new("-tmp-p").join("abc.jsonl");
+        assert_eq!(
+            archive_path(&relative, ExportFormat::Markdown),
+            "claude-history/-tmp-p/abc.md"
+        );
+    }
+}
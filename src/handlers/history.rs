@@ -2,27 +2,135 @@
 //!
 //! Handles viewing and managing sync operation history including
 //! listing operations, viewing details, and clearing history.
+//! `history list` and `history review` can be narrowed down by operation
+//! type, time range and branch with [`HistoryFilter`].
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
+use chrono::{DateTime, NaiveDate, Utc};
 use colored::Colorize;
 use inquire::Select;
 
+use crate::handlers::session::parse_duration_filter;
 use crate::history;
 use crate::interactive_conflict;
 
-/// Handle history list command
-pub fn handle_history_list(limit: usize) -> Result<()> {
+/// Filters for `history list` and `history review`
+#[derive(Debug, Default)]
+pub struct HistoryFilter {
+    pub operation_type: Option<history::OperationType>,
+    /// Earliest operation time: a date, an RFC 3339 timestamp or a duration
+    pub since: Option<String>,
+    /// Latest operation time, in the same formats; a date includes that day
+    pub until: Option<String>,
+    pub branch: Option<String>,
+}
+
+impl HistoryFilter {
+    fn is_empty(&self) -> bool {
+        self.operation_type.is_none()
+            && self.since.is_none()
+            && self.until.is_none()
+            && self.branch.is_none()
+    }
+
+    /// The matching operations, newest first like the history itself
+    fn select<'a>(
+        &self,
+        operations: &'a [history::OperationRecord],
+    ) -> Result<Vec<&'a history::OperationRecord>> {
+        let since = self
+            .since
+            .as_deref()
+            .map(|s| parse_time_bound(s, false))
+            .transpose()?;
+        let until = self
+            .until
+            .as_deref()
+            .map(|s| parse_time_bound(s, true))
+            .transpose()?;
+
+        Ok(operations
+            .iter()
+            .filter(|op| self.operation_type.is_none_or(|t| op.operation_type == t))
+            .filter(|op| since.is_none_or(|since| op.timestamp >= since))
+            .filter(|op| until.is_none_or(|until| op.timestamp <= until))
+            .filter(|op| {
+                self.branch
+                    .as_deref()
+                    .is_none_or(|branch| op.branch.as_deref() == Some(branch))
+            })
+            .collect())
+    }
+}
+
+/// Parse a `--since` / `--until` value
+///
+/// Accepts a date (`2026-10-13`, UTC like the history output), an RFC 3339
+/// timestamp, or a duration before now (`3d`, `12h`, `2w`). With `end_of_day`
+/// a plain date stands for the last moment of that day.
+fn parse_time_bound(value: &str, end_of_day: bool) -> Result<DateTime<Utc>> {
+    let value = value.trim();
+    if let Ok(timestamp) = DateTime::parse_from_rfc3339(value) {
+        return Ok(timestamp.with_timezone(&Utc));
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y-%m-%d") {
+        let start = date
+            .and_hms_opt(0, 0, 0)
+            .expect("midnight is valid")
+            .and_utc();
+        return Ok(if end_of_day {
+            start + chrono::Duration::days(1) - chrono::Duration::nanoseconds(1)
+        } else {
+            start
+        });
+    }
+    match parse_duration_filter(value) {
+        Ok(cutoff) => Ok(cutoff),
+        Err(_) => bail!(
+            "Invalid time '{}'. Use a date (2026-10-13), an RFC 3339 timestamp or a duration like '3d'",
+            value
+        ),
+    }
+}
+
+/// Load the history and apply `filter`; prints a message and returns `None`
+/// when there is nothing to show
+fn load_filtered(filter: &HistoryFilter) -> Result<Option<Vec<history::OperationRecord>>> {
     let history = history::OperationHistory::load().context("Failed to load operation history")?;
 
     if history.is_empty() {
         println!("{}", "No operations in history.".yellow());
-        return Ok(());
+        return Ok(None);
     }
 
+    let operations: Vec<history::OperationRecord> = filter
+        .select(history.list_operations())?
+        .into_iter()
+        .cloned()
+        .collect();
+    if operations.is_empty() {
+        println!(
+            "{}",
+            format!(
+                "No operations match the filters ({} in history).",
+                history.len()
+            )
+            .yellow()
+        );
+        return Ok(None);
+    }
+    Ok(Some(operations))
+}
+
+/// Handle history list command
+pub fn handle_history_list(limit: usize, filter: &HistoryFilter) -> Result<()> {
+    let Some(operations) = load_filtered(filter)? else {
+        return Ok(());
+    };
+
     println!("{}", "Operation History".cyan().bold());
     println!("{}", "=".repeat(80).cyan());
 
-    let operations = history.list_operations();
     let display_count = operations.len().min(limit);
 
     for (idx, op) in operations.iter().take(display_count).enumerate() {
@@ -30,6 +138,8 @@ pub fn handle_history_list(limit: usize) -> Result<()> {
         let op_type = match op.operation_type {
             history::OperationType::Pull => "PULL".green(),
             history::OperationType::Push => "PUSH".blue(),
+            history::OperationType::SessionDelete => "SESSION DELETE".red(),
+            history::OperationType::SessionRename => "SESSION RENAME".yellow(),
         };
 
         println!("\n{} {}", num.bold(), op_type.bold());
@@ -67,10 +177,11 @@ pub fn handle_history_list(limit: usize) -> Result<()> {
 
     if operations.len() > display_count {
         println!(
-            "\n{} Showing {} of {} operations",
+            "\n{} Showing {} of {} {}operations",
             "Note:".yellow(),
             display_count,
-            operations.len()
+            operations.len(),
+            if filter.is_empty() { "" } else { "matching " }
         );
     }
 
@@ -78,7 +189,10 @@ pub fn handle_history_list(limit: usize) -> Result<()> {
 }
 
 /// Handle history last command
-pub fn handle_history_last(operation_type: Option<&str>) -> Result<()> {
+///
+/// With `json`, the operation record is printed as stored in the history
+/// file instead of as prose.
+pub fn handle_history_last(operation_type: Option<&str>, json: bool) -> Result<()> {
     let history = history::OperationHistory::load().context("Failed to load operation history")?;
 
     let operation = if let Some(op_type) = operation_type {
@@ -105,12 +219,22 @@ pub fn handle_history_last(operation_type: Option<&str>) -> Result<()> {
             .ok_or_else(|| anyhow::anyhow!("No operations in history."))?
     };
 
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(operation).context("Failed to serialize operation")?
+        );
+        return Ok(());
+    }
+
     println!("{}", "Last Operation Details".cyan().bold());
     println!("{}", "=".repeat(80).cyan());
 
     let op_type = match operation.operation_type {
         history::OperationType::Pull => "PULL".green(),
         history::OperationType::Push => "PUSH".blue(),
+        history::OperationType::SessionDelete => "SESSION DELETE".red(),
+        history::OperationType::SessionRename => "SESSION RENAME".yellow(),
     };
 
     println!("\n{} {}", "Type:".bold(), op_type.bold());
@@ -218,7 +342,7 @@ pub fn handle_history_clear() -> Result<()> {
 }
 
 /// Handle history review command (interactive selection)
-pub fn handle_history_review(limit: usize) -> Result<()> {
+pub fn handle_history_review(limit: usize, filter: &HistoryFilter) -> Result<()> {
     // Check if we're in an interactive terminal
     if !interactive_conflict::is_interactive() {
         println!(
@@ -232,18 +356,14 @@ pub fn handle_history_review(limit: usize) -> Result<()> {
         return Ok(());
     }
 
-    let history = history::OperationHistory::load().context("Failed to load operation history")?;
-
-    if history.is_empty() {
-        println!("{}", "No operations in history.".yellow());
+    let Some(operations) = load_filtered(filter)? else {
         return Ok(());
-    }
+    };
 
     println!("{}", "Interactive Operation Review".cyan().bold());
     println!("{}", "=".repeat(80).cyan());
     println!();
 
-    let operations = history.list_operations();
     let display_count = operations.len().min(limit);
 
     // Create selection options
@@ -255,6 +375,8 @@ pub fn handle_history_review(limit: usize) -> Result<()> {
             let op_type = match op.operation_type {
                 history::OperationType::Pull => "PULL".to_string(),
                 history::OperationType::Push => "PUSH".to_string(),
+                history::OperationType::SessionDelete => "SESSION DELETE".to_string(),
+                history::OperationType::SessionRename => "SESSION RENAME".to_string(),
             };
 
             let timestamp = op.timestamp.format("%Y-%m-%d %H:%M").to_string();
@@ -304,6 +426,8 @@ pub fn handle_history_review(limit: usize) -> Result<()> {
                         let op_type = match operation.operation_type {
                             history::OperationType::Pull => "PULL".green(),
                             history::OperationType::Push => "PUSH".blue(),
+                            history::OperationType::SessionDelete => "SESSION DELETE".red(),
+                            history::OperationType::SessionRename => "SESSION RENAME".yellow(),
                         };
 
                         println!("\n{} {}", "Type:".bold(), op_type.bold());
@@ -402,3 +526,62 @@ pub fn handle_history_review(limit: usize) -> Result<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn record(
+        operation_type: history::OperationType,
+        day: u32,
+        branch: &str,
+    ) -> history::OperationRecord {
+        let mut record =
+            history::OperationRecord::new(operation_type, Some(branch.to_string()), Vec::new());
+        record.timestamp = Utc.with_ymd_and_hms(2026, 10, day, 12, 0, 0).unwrap();
+        record
+    }
+
+    #[test]
+    fn test_parse_time_bound() {
+        let start = parse_time_bound("2026-10-13", false).unwrap();
+        let end = parse_time_bound("2026-10-13", true).unwrap();
+        assert_eq!(start, Utc.with_ymd_and_hms(2026, 10, 13, 0, 0, 0).unwrap());
+        assert!(end > Utc.with_ymd_and_hms(2026, 10, 13, 23, 59, 59).unwrap());
+        assert!(end < Utc.with_ymd_and_hms(2026, 10, 14, 0, 0, 0).unwrap());
+
+        let exact = parse_time_bound("2026-10-13T08:30:00+02:00", true).unwrap();
+        assert_eq!(exact, Utc.with_ymd_and_hms(2026, 10, 13, 6, 30, 0).unwrap());
+
+        assert!(parse_time_bound("3d", false).unwrap() < Utc::now());
+        assert!(parse_time_bound("last tuesday", false).is_err());
+    }
+
+    #[test]
+    fn test_filter_by_type_date_and_branch() {
+        use history::OperationType::{Pull, Push};
+        let operations = vec![
+            record(Push, 15, "main"),
+            record(Pull, 13, "main"),
+            record(Push, 13, "work"),
+            record(Push, 13, "main"),
+            record(Push, 10, "main"),
+        ];
+
+        let filter = HistoryFilter {
+            operation_type: Some(Push),
+            since: Some("2026-10-13".to_string()),
+            until: Some("2026-10-13".to_string()),
+            branch: Some("main".to_string()),
+        };
+        let selected = filter.select(&operations).unwrap();
+        assert_eq!(selected.len(), 1);
+        assert!(std::ptr::eq(selected[0], &operations[3]));
+
+        assert_eq!(
+            HistoryFilter::default().select(&operations).unwrap().len(),
+            5
+        );
+    }
+}
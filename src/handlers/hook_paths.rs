@@ -0,0 +1,194 @@
+//! Local path checks for hooks applied from another device
+//!
+//! `ccs config-sync apply --with-hooks` copies another device's hook commands
+//! and scripts verbatim, so absolute paths (binary locations, home
+//! directories) often point at places that only exist on the source machine.
+//! This module resolves every path a hook references and reports the ones
+//! that are missing locally.
+
+use regex::Regex;
+use std::path::{Path, PathBuf};
+use std::sync::LazyLock;
+
+/// Absolute or home-relative paths inside a script, preceded by start of line,
+/// whitespace, a quote, `=` or `(`
+static SCRIPT_PATH_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r#"(?m)(?:^|[\s"'=(])((?:~|\$HOME|\$\{HOME\})?/[A-Za-z0-9._\-/]+)"#)
+        .expect("Invalid regex pattern")
+});
+
+/// System locations scripts commonly reference that are not worth reporting
+const IGNORED_PREFIXES: &[&str] = &["/dev/", "/tmp/", "/proc/"];
+
+/// Hook commands in a settings.json value as `(event, command)` pairs
+pub fn hook_commands(settings: &serde_json::Value) -> Vec<(String, String)> {
+    let Some(events) = settings.get("hooks").and_then(|h| h.as_object()) else {
+        return Vec::new();
+    };
+
+    let mut commands = Vec::new();
+    for (event, groups) in events {
+        for group in groups.as_array().into_iter().flatten() {
+            let hooks = group.get("hooks").and_then(|h| h.as_array());
+            for hook in hooks.into_iter().flatten() {
+                if let Some(cmd) = hook.get("command").and_then(|c| c.as_str()) {
+                    commands.push((event.clone(), cmd.to_string()));
+                }
+            }
+        }
+    }
+    commands
+}
+
+/// Split a command line on whitespace, keeping quoted segments together
+fn command_tokens(cmd: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut quote = None;
+
+    for c in cmd.chars() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), c) => current.push(c),
+            (None, '"' | '\'') => quote = Some(c),
+            (None, c) if c.is_whitespace() => {
+                if !current.is_empty() {
+                    tokens.push(std::mem::take(&mut current));
+                }
+            }
+            (None, c) => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        tokens.push(current);
+    }
+    tokens
+}
+
+/// Whether a token is an absolute or home-relative path
+fn is_path_like(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    token.starts_with('/')
+        || token.starts_with("~/")
+        || token.starts_with("$HOME/")
+        || token.starts_with("${HOME}/")
+        || (bytes.len() > 2 && bytes[0].is_ascii_alphabetic() && token[1..].starts_with(":\\"))
+}
+
+/// Resolve `~`, `$HOME` and `${HOME}` against `home`
+fn expand_home(token: &str, home: &Path) -> PathBuf {
+    for prefix in ["~/", "$HOME/", "${HOME}/"] {
+        if let Some(rest) = token.strip_prefix(prefix) {
+            return home.join(rest);
+        }
+    }
+    PathBuf::from(token)
+}
+
+/// Whether a bare program name can be found on `PATH`
+fn find_in_path(program: &str) -> bool {
+    std::env::var_os("PATH").is_some_and(|paths| {
+        std::env::split_paths(&paths).any(|dir| {
+            dir.join(program).is_file()
+                || (cfg!(windows) && dir.join(format!("{program}.exe")).is_file())
+        })
+    })
+}
+
+/// Paths referenced by a hook command that do not exist on this machine
+///
+/// Checks every absolute or home-relative argument, and the program itself
+/// via `PATH` when it is a bare name.
+pub fn missing_command_paths(cmd: &str, home: &Path) -> Vec<String> {
+    let mut missing = Vec::new();
+    for (i, token) in command_tokens(cmd).into_iter().enumerate() {
+        let exists = if is_path_like(&token) {
+            expand_home(&token, home).exists()
+        } else if i == 0 && !token.contains(['/', '\\', '=']) {
+            find_in_path(&token)
+        } else {
+            true
+        };
+        if !exists {
+            missing.push(token);
+        }
+    }
+    missing
+}
+
+/// Absolute or home-relative paths in a hook script that do not resolve
+pub fn missing_script_paths(content: &str, home: &Path) -> Vec<String> {
+    let mut missing: Vec<String> = Vec::new();
+    for caps in SCRIPT_PATH_REGEX.captures_iter(content) {
+        let path = caps[1].trim_end_matches('/');
+        if path.is_empty()
+            || path.ends_with("/.")
+            || IGNORED_PREFIXES.iter().any(|p| path.starts_with(p))
+        {
+            continue;
+        }
+        if !expand_home(path, home).exists() && !missing.iter().any(|m| m == path) {
+            missing.push(path.to_string());
+        }
+    }
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_command_tokens_keeps_quoted_paths() {
+        assert_eq!(
+            command_tokens(r#""/a b/ccs" hook-stop"#),
+            vec!["/a b/ccs", "hook-stop"]
+        );
+        assert_eq!(
+            command_tokens("bash  ~/.claude/hooks/x.sh"),
+            vec!["bash", "~/.claude/hooks/x.sh"]
+        );
+    }
+
+    #[test]
+    fn test_hook_commands_and_missing_paths() {
+        let home = TempDir::new().unwrap();
+        std::fs::create_dir_all(home.path().join(".claude/hooks")).unwrap();
+        std::fs::write(home.path().join(".claude/hooks/ok.sh"), "").unwrap();
+
+        let settings = serde_json::json!({
+            "hooks": {
+                "Stop": [{"hooks": [{"type": "command", "command": "~/.claude/hooks/ok.sh"}]}],
+                "SessionStart": [{"hooks": [
+                    {"type": "command", "command": "\"/Users/other/.cargo/bin/ccs\" hook-session-start"}
+                ]}]
+            }
+        });
+        let commands = hook_commands(&settings);
+        assert_eq!(commands.len(), 2);
+
+        let missing: Vec<_> = commands
+            .iter()
+            .map(|(_, cmd)| missing_command_paths(cmd, home.path()))
+            .collect();
+        assert!(missing.contains(&vec![]));
+        assert!(missing.contains(&vec!["/Users/other/.cargo/bin/ccs".to_string()]));
+    }
+
+    #[test]
+    fn test_missing_script_paths() {
+        let home = TempDir::new().unwrap();
+        std::fs::create_dir_all(home.path().join("bin")).unwrap();
+
+        let script = "#!/bin/sh\n\
+                      \"$HOME/bin\" >/dev/null\n\
+                      /Users/other/.cargo/bin/ccs push --quiet\n\
+                      curl https://example.com/x\n\
+                      cp a/b /Users/other/.cargo/bin/ccs\n";
+        assert_eq!(
+            missing_script_paths(script, home.path()),
+            vec!["/Users/other/.cargo/bin/ccs"]
+        );
+    }
+}
@@ -6,37 +6,145 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
 use serde_json::{json, Value};
-use std::path::PathBuf;
-
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::error::{EXIT_CONFIG_PARSE, EXIT_NOT_INITIALIZED, EXIT_REMOTE_UNREACHABLE};
+use crate::logger::log_hook;
+use crate::output::icon;
+use crate::scm;
+use crate::sync::discovery::{claude_dir, claude_dir_override, CLAUDE_CONFIG_DIR_ENV};
 use crate::BINARY_NAME;
 
 /// Identifiers for hooks installed by us (old name + new name)
 const HOOK_MARKERS: &[&str] = &["claude-code-sync", "ccs"];
 
-/// Spawn a ccs subcommand as a detached child process.
+/// Hook events installed by `hooks install`, as (event, subcommand, purpose)
+pub const HOOK_EVENTS: &[(&str, &str, &str)] = &[
+    (
+        "SessionStart",
+        "hook-session-start",
+        "Pull on startup (IDE support)",
+    ),
+    ("Stop", "hook-stop", "Push after each response"),
+    (
+        "UserPromptSubmit",
+        "hook-new-project-check",
+        "New project detection",
+    ),
+];
+
+/// Timeout Claude Code gives the SessionStart and Stop hooks
+const HOOK_TIMEOUT_SECS: u64 = 60;
+
+/// Timeout Claude Code gives the UserPromptSubmit hook
+const PROMPT_HOOK_TIMEOUT_SECS: u64 = 30;
+
+/// Time kept between giving up on a hook's push/pull and Claude Code's own
+/// timeout, so the hook still exits (and logs) on its own
+const HOOK_TIMEOUT_MARGIN_SECS: u64 = 5;
+
+/// How often a child with a time limit is checked for exit
+const CHILD_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Time limit for a push or pull run by a hook whose own timeout is
+/// `hook_timeout_secs`: the configured `hook_timeout_secs`, capped so the
+/// hook returns before Claude Code gives up on it. `None` when the limit is
+/// disabled.
+fn child_timeout(hook_timeout_secs: u64) -> Option<Duration> {
+    let configured = crate::filter::FilterConfig::load()
+        .map(|filter| filter.hook_timeout_secs)
+        .unwrap_or_else(|_| crate::filter::FilterConfig::default().hook_timeout_secs);
+    (configured > 0).then(|| {
+        let cap = hook_timeout_secs.saturating_sub(HOOK_TIMEOUT_MARGIN_SECS);
+        Duration::from_secs(configured.min(cap))
+    })
+}
+
+/// Wait for `child`, giving up once `timeout` has passed
+///
+/// An overdue child is reported as a `TimedOut` error but left running: it
+/// holds the sync lock and possibly git's index lock, and its remote
+/// operation is bounded by [`scm::REMOTE_TIMEOUT_ENV`], so it exits on its
+/// own shortly after and releases both on the normal path.
+fn wait_with_timeout(
+    child: &mut std::process::Child,
+    timeout: Duration,
+) -> std::io::Result<std::process::ExitStatus> {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+        if Instant::now() >= deadline {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::TimedOut,
+                format!("still running after {}s", timeout.as_secs_f32()),
+            ));
+        }
+        std::thread::sleep(CHILD_POLL_INTERVAL);
+    }
+}
+
+/// `ccs <subcommand> <args>` with detached stdio, resolved via `current_exe()`
+/// (see [`spawn_ccs_subcommand`])
+fn ccs_command(subcommand: &str, args: &[&str]) -> std::process::Command {
+    let exe = std::env::current_exe().unwrap_or_else(|_| PathBuf::from(BINARY_NAME));
+    let mut command = std::process::Command::new(exe);
+    if let Some(dir) = claude_dir_override() {
+        command.env(CLAUDE_CONFIG_DIR_ENV, dir);
+    }
+    command
+        .arg(subcommand)
+        .args(args)
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null());
+    command
+}
+
+/// Spawn a ccs subcommand as a detached child process and wait for it.
+///
+/// With a `timeout`, the child's remote operations are limited to it and the
+/// wait ends after it, whether or not the child has exited.
 ///
 /// Uses `current_exe()` so the child resolves to the same binary regardless of
 /// the ambient PATH — important in Claude Code hook environments where PATH
 /// may not include the cargo bin directory. Falls back to the bare binary name
 /// if `current_exe()` fails (keeps old behavior, never worse).
-fn spawn_ccs_subcommand(
+pub(super) fn spawn_ccs_subcommand(
     subcommand: &str,
     args: &[&str],
+    timeout: Option<Duration>,
 ) -> std::io::Result<std::process::ExitStatus> {
-    let exe = std::env::current_exe().unwrap_or_else(|_| PathBuf::from(BINARY_NAME));
-    std::process::Command::new(exe)
-        .arg(subcommand)
-        .args(args)
-        .stdin(std::process::Stdio::null())
-        .stdout(std::process::Stdio::null())
-        .stderr(std::process::Stdio::null())
-        .status()
+    let mut command = ccs_command(subcommand, args);
+    if let Some(timeout) = timeout {
+        command.env(
+            scm::REMOTE_TIMEOUT_ENV,
+            timeout.as_secs().max(1).to_string(),
+        );
+    }
+    let mut child = command.spawn()?;
+    match timeout {
+        Some(timeout) => wait_with_timeout(&mut child, timeout),
+        None => child.wait(),
+    }
+}
+
+/// Hook log text for the exit status of a failed `ccs` subcommand, naming
+/// the typed failures from [`crate::error`]
+fn describe_failure(status: std::process::ExitStatus) -> String {
+    match status.code() {
+        Some(EXIT_NOT_INITIALIZED) => "sync not initialized".to_string(),
+        Some(EXIT_REMOTE_UNREACHABLE) => "remote unreachable".to_string(),
+        Some(EXIT_CONFIG_PARSE) => "config or state file could not be parsed".to_string(),
+        _ => format!("exit code {}", status),
+    }
 }
 
 /// Get the path to Claude settings file
 fn claude_settings_path() -> Result<PathBuf> {
-    let home = dirs::home_dir().context("Cannot find home directory")?;
-    Ok(home.join(".claude").join("settings.json"))
+    Ok(claude_dir()?.join("settings.json"))
 }
 
 /// Build the command string written into settings.json for a hook subcommand.
@@ -67,7 +175,7 @@ fn get_hooks_config() -> Value {
                     {
                         "type": "command",
                         "command": hook_command("hook-session-start"),
-                        "timeout": 60,
+                        "timeout": HOOK_TIMEOUT_SECS,
                         "statusMessage": "Syncing conversation history..."
                     }
                 ]
@@ -79,7 +187,7 @@ fn get_hooks_config() -> Value {
                     {
                         "type": "command",
                         "command": hook_command("hook-stop"),
-                        "timeout": 60
+                        "timeout": HOOK_TIMEOUT_SECS
                     }
                 ]
             }
@@ -90,7 +198,7 @@ fn get_hooks_config() -> Value {
                     {
                         "type": "command",
                         "command": hook_command("hook-new-project-check"),
-                        "timeout": 30
+                        "timeout": PROMPT_HOOK_TIMEOUT_SECS
                     }
                 ]
             }
@@ -206,7 +314,7 @@ pub fn handle_hooks_install() -> Result<()> {
                 if update_our_hook_command(existing_array, subcommand, new_command) {
                     println!(
                         "  {} {} hook refreshed (absolute path)",
-                        "↻".cyan(),
+                        icon("↻").cyan(),
                         event_name
                     );
                     continue;
@@ -216,12 +324,12 @@ pub fn handle_hooks_install() -> Result<()> {
                 for hook in new_hooks_array {
                     existing_array.push(hook.clone());
                 }
-                println!("  {} {} hook added", "✓".green(), event_name);
+                println!("  {} {} hook added", icon("✓").green(), event_name);
             }
         } else {
             // Create new hook array
             hooks_obj.insert(event_name.clone(), new_hooks.clone());
-            println!("  {} {} hook installed", "✓".green(), event_name);
+            println!("  {} {} hook installed", icon("✓").green(), event_name);
         }
     }
 
@@ -231,7 +339,7 @@ pub fn handle_hooks_install() -> Result<()> {
 
     println!(
         "\n{} Hooks installed to {}",
-        "✓".green(),
+        icon("✓").green(),
         settings_path.display()
     );
 
@@ -284,7 +392,7 @@ pub fn handle_hooks_uninstall() -> Result<()> {
 
                 if hooks_array.len() < original_len {
                     removed_count += original_len - hooks_array.len();
-                    println!("  {} Removed {} hook", "✓".green(), event_name);
+                    println!("  {} Removed {} hook", icon("✓").green(), event_name);
                 }
 
                 // Remove empty arrays
@@ -302,7 +410,7 @@ pub fn handle_hooks_uninstall() -> Result<()> {
         } else {
             // Write back
             std::fs::write(&settings_path, serde_json::to_string_pretty(&settings)?)?;
-            println!("\n{} {} hook(s) removed", "✓".green(), removed_count);
+            println!("\n{} {} hook(s) removed", icon("✓").green(), removed_count);
         }
     } else {
         println!("{}", "No hooks configured, nothing to uninstall.".yellow());
@@ -329,38 +437,7 @@ pub fn handle_hooks_show() -> Result<()> {
         return Ok(());
     }
 
-    let content = std::fs::read_to_string(&settings_path)?;
-    let settings: Value = serde_json::from_str(&content)?;
-
-    let hooks_installed = if let Some(hooks_obj) = settings.get("hooks").and_then(|v| v.as_object())
-    {
-        let mut found = Vec::new();
-
-        // Check SessionStart
-        if let Some(hooks_array) = hooks_obj.get("SessionStart").and_then(|v| v.as_array()) {
-            if contains_our_hook(hooks_array, "hook-session-start") {
-                found.push("SessionStart");
-            }
-        }
-
-        // Check Stop
-        if let Some(hooks_array) = hooks_obj.get("Stop").and_then(|v| v.as_array()) {
-            if contains_our_hook(hooks_array, "hook-stop") {
-                found.push("Stop");
-            }
-        }
-
-        // Check UserPromptSubmit
-        if let Some(hooks_array) = hooks_obj.get("UserPromptSubmit").and_then(|v| v.as_array()) {
-            if contains_our_hook(hooks_array, "hook-new-project-check") {
-                found.push("UserPromptSubmit");
-            }
-        }
-
-        found
-    } else {
-        Vec::new()
-    };
+    let hooks_installed = installed_hook_events()?;
 
     if hooks_installed.is_empty() {
         println!(
@@ -376,17 +453,13 @@ pub fn handle_hooks_show() -> Result<()> {
         println!("{}", format!("{} hooks: INSTALLED", BINARY_NAME).green());
         println!();
         println!("Installed hooks:");
-        for hook in &hooks_installed {
-            let description = match *hook {
-                "SessionStart" => "Pull on startup (IDE support)",
-                "Stop" => "Push after each response",
-                "UserPromptSubmit" => "New project detection",
-                _ => "",
-            };
-            println!("  {} {} ({})", "•".green(), hook.cyan(), description);
+        for (event, _, purpose) in HOOK_EVENTS {
+            if hooks_installed.contains(event) {
+                println!("  {} {} ({})", icon("•").green(), event.cyan(), purpose);
+            }
         }
 
-        if hooks_installed.len() < 3 {
+        if hooks_installed.len() < HOOK_EVENTS.len() {
             println!();
             println!(
                 "{}",
@@ -402,6 +475,290 @@ pub fn handle_hooks_show() -> Result<()> {
     Ok(())
 }
 
+/// A Claude Code hook event handled by `ccs`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HookEvent {
+    SessionStart,
+    Stop,
+    UserPromptSubmit,
+}
+
+/// What a hook does for one event
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HookDecision {
+    Pull,
+    Push,
+    /// Leave the push to the running `ccs daemon`
+    MarkDirty,
+    /// Do nothing, for the given reason
+    Skip(String),
+}
+
+/// Project name from the `cwd` of a hook payload (Unix or Windows path)
+fn input_project_name(input: &Value) -> Option<&str> {
+    let cwd = input.get("cwd").and_then(|v| v.as_str())?;
+    Some(
+        cwd.split(&['/', '\\'])
+            .rfind(|s| !s.is_empty())
+            .unwrap_or("unknown"),
+    )
+}
+
+/// UserPromptSubmit: pull when the prompt comes from a project with no local
+/// history
+fn new_project_decision(input: &Value) -> HookDecision {
+    use crate::sync::discovery::{claude_projects_dir, find_local_project_by_name};
+
+    let Some(project_name) = input_project_name(input) else {
+        return HookDecision::Skip("no cwd in hook input".to_string());
+    };
+    let claude_dir = match claude_projects_dir() {
+        Ok(dir) => dir,
+        Err(e) => return HookDecision::Skip(format!("no projects directory: {e}")),
+    };
+    match find_local_project_by_name(&claude_dir, project_name) {
+        Some(dir) => HookDecision::Skip(format!(
+            "project {} already exists locally at {}",
+            project_name,
+            dir.display()
+        )),
+        None => HookDecision::Pull,
+    }
+}
+
+/// Stop: push unless a daemon batches pushes or the debounce is active
+fn stop_decision(now: bool, daemon_running: bool, debounce_active: bool) -> HookDecision {
+    if !now && daemon_running {
+        HookDecision::MarkDirty
+    } else if debounce_active {
+        HookDecision::Skip("debounce active".to_string())
+    } else {
+        HookDecision::Push
+    }
+}
+
+/// SessionStart: pull only on the first startup (see [`handle_session_start`])
+fn session_start_decision(
+    source: &str,
+    process_count: usize,
+    debounce_active: bool,
+) -> HookDecision {
+    if process_count > 1 {
+        HookDecision::Skip(format!("other instances: {}", process_count))
+    } else if source != "startup" {
+        HookDecision::Skip(format!("source: {} != startup", source))
+    } else if debounce_active {
+        HookDecision::Skip("debounce active".to_string())
+    } else {
+        HookDecision::Pull
+    }
+}
+
+/// Configured `stop_push_interval_secs`; 0 (no debounce) when unreadable
+fn stop_push_interval() -> u64 {
+    crate::filter::FilterConfig::load()
+        .map(|filter| filter.stop_push_interval_secs)
+        .unwrap_or(0)
+}
+
+/// Debounce file of the Stop hook and whether it is active
+fn stop_debounce(now: bool) -> (Result<PathBuf>, bool) {
+    let timestamp_file =
+        crate::config::ConfigManager::config_dir().map(|d| d.join("last-stop-push"));
+    let interval = stop_push_interval();
+    let debounce_active = !now
+        && timestamp_file
+            .as_ref()
+            .is_ok_and(|ts_path| timestamp_is_recent(ts_path, interval));
+    (timestamp_file, debounce_active)
+}
+
+/// Marker file recording that a trailing push is scheduled for debounced
+/// Stop hooks; removed by whichever push runs first
+const STOP_PUSH_PENDING: &str = "stop-push-pending";
+
+/// Extra time after the debounce interval before a pending marker is assumed
+/// to belong to a trailing push that never ran (e.g. the machine slept)
+const TRAILING_PUSH_SLACK_SECS: u64 = 60;
+
+fn stop_push_pending_marker() -> Result<PathBuf> {
+    crate::config::ConfigManager::config_dir().map(|d| d.join(STOP_PUSH_PENDING))
+}
+
+/// Schedule a push for the end of the debounce interval, so responses
+/// skipped by the debounce are pushed even if no further Stop hook fires
+///
+/// Starts `ccs hook-stop --trailing` in the background; at most one is
+/// pending at a time.
+fn schedule_trailing_push(interval_secs: u64) {
+    let Ok(marker) = stop_push_pending_marker() else {
+        return;
+    };
+    if timestamp_is_recent(&marker, interval_secs + TRAILING_PUSH_SLACK_SECS) {
+        return;
+    }
+    if let Err(e) = std::fs::write(&marker, "") {
+        log::warn!("Failed to write {}: {}", marker.display(), e);
+        return;
+    }
+    let _ = log_hook(&match ccs_command("hook-stop", &["--trailing"]).spawn() {
+        Ok(_) => "Stop push deferred: trailing push scheduled".to_string(),
+        Err(e) => {
+            let _ = std::fs::remove_file(&marker);
+            format!(
+                "Stop push deferred, but trailing push failed to start: {}",
+                e
+            )
+        }
+    });
+}
+
+/// Time left until `ts_path` is `secs` seconds old; zero when it is missing
+fn interval_remaining(ts_path: &std::path::Path, secs: u64) -> Duration {
+    std::fs::metadata(ts_path)
+        .and_then(|metadata| metadata.modified())
+        .map(|modified| {
+            let elapsed = std::time::SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default();
+            Duration::from_secs(secs).saturating_sub(elapsed)
+        })
+        .unwrap_or_default()
+}
+
+/// Debounce file of the SessionStart hook and whether it is active
+fn session_start_debounce() -> (Result<PathBuf>, bool) {
+    let timestamp_file =
+        crate::config::ConfigManager::config_dir().map(|d| d.join("last-session-pull"));
+    let debounce_active = timestamp_file
+        .as_ref()
+        .is_ok_and(|ts_path| timestamp_is_recent(ts_path, SESSION_START_DEBOUNCE_SECS));
+    (timestamp_file, debounce_active)
+}
+
+/// Marker file, next to the hook log, recording that the hooks already logged
+/// running without a sync setup
+const UNINITIALIZED_MARKER: &str = "hook-uninitialized";
+
+/// Whether sync is set up, so the hooks have anything to pull or push
+///
+/// Hooks fire on every prompt and response, so on a machine without a sync
+/// setup the skip is logged only the first time; the marker is cleared once
+/// sync is initialized.
+fn hook_sync_initialized(hook: &str) -> bool {
+    let initialized = super::onboarding::is_initialized().unwrap_or(false);
+    let Ok(marker) =
+        crate::config::ConfigManager::config_dir().map(|d| d.join(UNINITIALIZED_MARKER))
+    else {
+        return initialized;
+    };
+    if initialized {
+        let _ = std::fs::remove_file(&marker);
+    } else if !marker.exists() {
+        let _ = log_hook(&format!(
+            "{} skipped: sync not initialized (run `{} init`; further skips are not logged)",
+            hook, BINARY_NAME
+        ));
+        let _ = std::fs::write(&marker, "");
+    }
+    initialized
+}
+
+/// Source of a SessionStart payload
+fn input_source(input: &Value) -> &str {
+    input
+        .get("source")
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown")
+}
+
+/// Handle `ccs hooks test`
+///
+/// Builds the payload Claude Code would send for `event` and runs it through
+/// the same checks as the real hook, printing what the hook would do and why.
+/// Nothing is pulled or pushed and no debounce timestamp is touched.
+pub fn handle_hook_test(event: HookEvent, source: Option<&str>, cwd: Option<&Path>) -> Result<()> {
+    let cwd = match cwd {
+        Some(cwd) => cwd.to_path_buf(),
+        None => std::env::current_dir().context("Failed to get current directory")?,
+    };
+    let cwd = cwd.to_string_lossy();
+    let input = match event {
+        HookEvent::SessionStart => json!({
+            "hook_event_name": "SessionStart",
+            "source": source.unwrap_or("startup"),
+            "cwd": cwd,
+        }),
+        HookEvent::Stop => json!({"hook_event_name": "Stop", "cwd": cwd}),
+        HookEvent::UserPromptSubmit => json!({
+            "hook_event_name": "UserPromptSubmit",
+            "cwd": cwd,
+            "prompt": "",
+        }),
+    };
+    if source.is_some() && event != HookEvent::SessionStart {
+        println!(
+            "{} --source only applies to SessionStart; ignored",
+            icon("⚠").yellow()
+        );
+    }
+
+    println!("{}", "Hook input:".bold());
+    println!("  {}", serde_json::to_string(&input)?);
+    println!("{}", "Conditions:".bold());
+    let initialized = super::onboarding::is_initialized().unwrap_or(false);
+    println!("  initialized:      {}", initialized);
+    let decision = match event {
+        _ if !initialized => HookDecision::Skip("sync not initialized".to_string()),
+        HookEvent::SessionStart => {
+            let source = input_source(&input);
+            let process_count = count_claude_processes();
+            let (_, debounce_active) = session_start_debounce();
+            println!("  source:           {}", source);
+            println!("  Claude processes: {}", process_count);
+            println!(
+                "  debounce:         {} ({}s)",
+                debounce_active, SESSION_START_DEBOUNCE_SECS
+            );
+            session_start_decision(source, process_count, debounce_active)
+        }
+        HookEvent::Stop => {
+            let daemon_running = super::daemon::is_running();
+            let (_, debounce_active) = stop_debounce(false);
+            println!("  daemon running:   {}", daemon_running);
+            println!("  debounce:         {}", debounce_active);
+            stop_decision(false, daemon_running, debounce_active)
+        }
+        HookEvent::UserPromptSubmit => {
+            println!(
+                "  project:          {}",
+                input_project_name(&input).unwrap_or("-")
+            );
+            new_project_decision(&input)
+        }
+    };
+
+    println!("{}", "Decision:".bold());
+    match decision {
+        HookDecision::Pull => println!(
+            "  {} would run `{} pull --quiet`",
+            icon("→").green(),
+            BINARY_NAME
+        ),
+        HookDecision::Push => println!(
+            "  {} would run `{} push --quiet`",
+            icon("→").green(),
+            BINARY_NAME
+        ),
+        HookDecision::MarkDirty => println!(
+            "  {} would leave the push to the running daemon",
+            icon("→").green()
+        ),
+        HookDecision::Skip(reason) => println!("  {} would skip ({})", icon("•").dimmed(), reason),
+    }
+    Ok(())
+}
+
 /// Handle the hook-new-project-check command
 /// This is called by the UserPromptSubmit hook to detect new projects
 /// Reads JSON from stdin, outputs JSON to stdout
@@ -412,36 +769,33 @@ pub fn handle_new_project_check() -> Result<()> {
     let input: Value = serde_json::from_reader(std::io::stdin())
         .context("Failed to read hook input from stdin")?;
 
-    let cwd = match input.get("cwd").and_then(|v| v.as_str()) {
-        Some(cwd) => cwd,
-        None => {
-            // No cwd provided, silently exit
-            return Ok(());
-        }
-    };
-
-    // Extract project name from cwd (handle both Unix and Windows paths)
-    let project_name = cwd
-        .split(&['/', '\\'])
-        .rfind(|s| !s.is_empty())
-        .unwrap_or("unknown");
+    if !hook_sync_initialized("UserPromptSubmit") {
+        return Ok(());
+    }
 
-    let claude_dir = match claude_projects_dir() {
-        Ok(dir) => dir,
-        Err(_) => return Ok(()), // Silently exit if we can't find the projects dir
+    // Silently exit without a cwd or a projects dir
+    let (Some(project_name), Ok(claude_dir)) = (input_project_name(&input), claude_projects_dir())
+    else {
+        return Ok(());
     };
 
-    // Check if local project directory exists
-    let has_local_project = find_local_project_by_name(&claude_dir, project_name).is_some();
-
-    if !has_local_project {
+    if new_project_decision(&input) == HookDecision::Pull {
         // This is a new project, try to pull from remote
         log::info!("New project detected: {}", project_name);
 
         // Spawn via current_exe() so it works even when the hook environment
         // PATH does not include the cargo bin directory.
-        let pull_result = spawn_ccs_subcommand("pull", &["--quiet"]);
+        let pull_result = spawn_ccs_subcommand(
+            "pull",
+            &["--quiet"],
+            child_timeout(PROMPT_HOOK_TIMEOUT_SECS),
+        );
 
+        if let Ok(status) = &pull_result {
+            if !status.success() {
+                log::debug!("New project pull failed: {}", describe_failure(*status));
+            }
+        }
         if pull_result.is_ok() {
             // Check if we now have a local project after pull
             if find_local_project_by_name(&claude_dir, project_name).is_some() {
@@ -462,82 +816,133 @@ pub fn handle_new_project_check() -> Result<()> {
     Ok(())
 }
 
+/// Whether the timestamp file was touched less than `secs` seconds ago
+pub(super) fn timestamp_is_recent(ts_path: &std::path::Path, secs: u64) -> bool {
+    std::fs::metadata(ts_path)
+        .and_then(|metadata| metadata.modified())
+        .map(|modified| {
+            let elapsed = std::time::SystemTime::now()
+                .duration_since(modified)
+                .unwrap_or_default();
+            elapsed.as_secs() < secs
+        })
+        .unwrap_or(false)
+}
+
 /// Handle the hook-stop command
 /// This is called by the Stop hook after each AI response to push history
 /// Reads JSON from stdin
-pub fn handle_stop() -> Result<()> {
-    use std::io::Write;
+///
+/// Pushes are debounced: at most one per `stop_push_interval_secs`. A
+/// debounced response schedules a trailing push for the end of the interval,
+/// so rapid responses are coalesced into one push that happens even if the
+/// conversation stops there. `now` (`ccs hook-stop --now`) bypasses the
+/// debounce.
+pub fn handle_stop(now: bool) -> Result<()> {
+    // Get timestamp file path for debouncing
+    let (timestamp_file, debounce_active) = stop_debounce(now);
 
     // Log hook execution for debugging
-    if let Ok(home) = std::env::var("HOME") {
-        let debug_log = std::path::PathBuf::from(&home)
-            .join("Library/Application Support/claude-code-sync/hook-debug.log");
-        if let Ok(mut file) = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&debug_log)
-        {
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            let _ = writeln!(file, "[{}] Stop hook executed", timestamp);
-        }
-    }
+    let _ = log_hook(&format!(
+        "Stop hook executed (debounce: {})",
+        debounce_active
+    ));
 
     // Read hook input from stdin (required by Claude Code hooks)
     let _input: Value = serde_json::from_reader(std::io::stdin()).unwrap_or(json!({}));
 
+    // Nothing to push to, and the config push below needs a sync repo too
+    if !hook_sync_initialized("Stop") {
+        return Ok(());
+    }
+
+    match stop_decision(now, super::daemon::is_running(), debounce_active) {
+        // A running `ccs daemon` batches pushes; just flag the change for it
+        HookDecision::MarkDirty => return super::daemon::mark_dirty(),
+        HookDecision::Skip(_) => {
+            schedule_trailing_push(stop_push_interval());
+            return Ok(());
+        }
+        HookDecision::Pull | HookDecision::Push => {}
+    }
+
+    stop_push(timestamp_file, "Stop push")
+}
+
+/// Handle `ccs hook-stop --trailing`, started by [`schedule_trailing_push`]
+///
+/// Waits until the debounce interval since the last push has passed, then
+/// pushes unless a Stop hook has pushed in the meantime.
+pub fn handle_stop_trailing() -> Result<()> {
+    let (timestamp_file, _) = stop_debounce(false);
+    if let Ok(ref ts_path) = timestamp_file {
+        std::thread::sleep(interval_remaining(ts_path, stop_push_interval()));
+    }
+
+    // The marker is gone when a regular Stop push already covered the changes
+    let pending = stop_push_pending_marker()
+        .map(|marker| std::fs::remove_file(marker).is_ok())
+        .unwrap_or(false);
+    if !pending {
+        let _ = log_hook("Trailing Stop push skipped: already pushed");
+        return Ok(());
+    }
+    if super::daemon::is_running() {
+        return super::daemon::mark_dirty();
+    }
+
+    stop_push(timestamp_file, "Trailing Stop push")
+}
+
+/// Push after a Stop hook and sync config, advancing the debounce timestamp
+/// on success; `label` names the push in the hook log
+fn stop_push(timestamp_file: Result<PathBuf>, label: &str) -> Result<()> {
+    // This push covers everything a scheduled trailing push would
+    if let Ok(marker) = stop_push_pending_marker() {
+        let _ = std::fs::remove_file(marker);
+    }
+
     // Execute push quietly after each response.
     // Spawn via current_exe() so it works even when the hook environment
     // PATH does not include the cargo bin directory.
-    let push_result = spawn_ccs_subcommand("push", &["--quiet"]);
+    let push_result = spawn_ccs_subcommand("push", &["--quiet"], child_timeout(HOOK_TIMEOUT_SECS));
 
     // Log result
-    if let Ok(home) = std::env::var("HOME") {
-        let debug_log = std::path::PathBuf::from(&home)
-            .join("Library/Application Support/claude-code-sync/hook-debug.log");
-        if let Ok(mut file) = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&debug_log)
-        {
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            match &push_result {
-                Ok(status) if status.success() => {
-                    let _ = writeln!(
-                        file,
-                        "[{}] Stop push completed: exit code {}",
-                        timestamp, status
-                    );
-                }
-                Ok(status) => {
-                    let _ = writeln!(
-                        file,
-                        "[{}] Stop push FAILED: exit code {}",
-                        timestamp, status
-                    );
-                }
-                Err(e) => {
-                    let _ = writeln!(file, "[{}] Stop push failed to execute: {}", timestamp, e);
-                }
+    let _ = log_hook(&match &push_result {
+        Ok(status) if status.success() => format!("{} completed: exit code {}", label, status),
+        Ok(status) => format!("{} FAILED: {}", label, describe_failure(*status)),
+        Err(e) => format!("{} failed to execute: {}", label, e),
+    });
+
+    // Also sync config if enabled, but only after a successful push: a push
+    // that timed out is still running and holds the sync lock and the repo
+    // index, and this in-process call has no remote timeout of its own
+    let push_succeeded = matches!(&push_result, Ok(status) if status.success());
+    if push_succeeded {
+        if let Ok(filter) = crate::filter::FilterConfig::load() {
+            if filter.config_sync.enabled {
+                let _ = super::config_sync::handle_config_push(&filter.config_sync, None);
             }
         }
     }
 
-    // Also sync config if enabled. config_sync is a direct function call (not a
-    // spawned subprocess), so it is unaffected by PATH issues that can break
-    // the push above — keep running it regardless of push outcome.
-    if let Ok(filter) = crate::filter::FilterConfig::load() {
-        if filter.config_sync.enabled {
-            let _ = super::config_sync::handle_config_push(&filter.config_sync);
-        }
-    }
-
     // Propagate push failure so the throttled-stop.sh wrapper sees a non-zero
     // exit code and does NOT advance the throttle timestamp — otherwise the
     // next 5 minutes of Stop hooks would be silently skipped despite the push
     // never succeeding. `ccs push` returns Ok (exit 0) when there is nothing to
     // push, so this only fires on real failure.
+    //
+    // For the same reason the debounce timestamp only advances on success.
+    // An uninitialized setup is not a failure to retry: there is nothing to
+    // push until `ccs init` runs.
     match push_result {
-        Ok(status) if status.success() => Ok(()),
+        Ok(status) if status.success() => {
+            if let Ok(ref ts_path) = timestamp_file {
+                let _ = std::fs::write(ts_path, "");
+            }
+            Ok(())
+        }
+        Ok(status) if status.code() == Some(EXIT_NOT_INITIALIZED) => Ok(()),
         Ok(status) => {
             log::warn!("ccs push exited with {}", status);
             Err(anyhow::anyhow!("ccs push exited with {}", status))
@@ -581,120 +986,33 @@ fn count_claude_processes() -> usize {
 /// 2. source = "startup" (not resume/compact)
 /// 3. Debounce not active (extra protection)
 pub fn handle_session_start() -> Result<()> {
-    use std::io::Write;
-
     // Read hook input from stdin (required by Claude Code hooks)
     let input: Value = serde_json::from_reader(std::io::stdin()).unwrap_or(json!({}));
 
+    if !hook_sync_initialized("SessionStart") {
+        return Ok(());
+    }
+
     // Extract source field
-    let source = input
-        .get("source")
-        .and_then(|v| v.as_str())
-        .unwrap_or("unknown");
+    let source = input_source(&input);
 
     // Count Claude Code processes
     let process_count = count_claude_processes();
-    let is_first_instance = process_count <= 1;
-    let is_startup = source == "startup";
-
-    // Get timestamp file path for debouncing
-    let timestamp_file =
-        crate::config::ConfigManager::config_dir().map(|d| d.join("last-session-pull"));
 
-    // Check debounce
-    let debounce_active = if let Ok(ref ts_path) = timestamp_file {
-        if ts_path.exists() {
-            if let Ok(metadata) = std::fs::metadata(ts_path) {
-                if let Ok(modified) = metadata.modified() {
-                    let elapsed = std::time::SystemTime::now()
-                        .duration_since(modified)
-                        .unwrap_or_default();
-                    elapsed.as_secs() < SESSION_START_DEBOUNCE_SECS
-                } else {
-                    false
-                }
-            } else {
-                false
-            }
-        } else {
-            false
-        }
-    } else {
-        false
-    };
+    // Get timestamp file path for debouncing and check it
+    let (timestamp_file, debounce_active) = session_start_debounce();
 
     // Log hook execution with all conditions
-    if let Ok(home) = std::env::var("HOME") {
-        let debug_log = std::path::PathBuf::from(&home)
-            .join("Library/Application Support/claude-code-sync/hook-debug.log");
-        if let Ok(mut file) = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&debug_log)
-        {
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            let _ = writeln!(
-                file,
-                "[{}] SessionStart (source: {}, processes: {}, debounce: {})",
-                timestamp, source, process_count, debounce_active
-            );
-        }
-    }
+    let _ = log_hook(&format!(
+        "SessionStart (source: {}, processes: {}, debounce: {})",
+        source, process_count, debounce_active
+    ));
 
     // Triple-condition check: first instance + startup + no debounce
-    if !is_first_instance {
-        if let Ok(home) = std::env::var("HOME") {
-            let debug_log = std::path::PathBuf::from(&home)
-                .join("Library/Application Support/claude-code-sync/hook-debug.log");
-            if let Ok(mut file) = std::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&debug_log)
-            {
-                let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-                let _ = writeln!(
-                    file,
-                    "[{}] pull skipped (other instances: {})",
-                    timestamp, process_count
-                );
-            }
-        }
-        return Ok(());
-    }
-
-    if !is_startup {
-        if let Ok(home) = std::env::var("HOME") {
-            let debug_log = std::path::PathBuf::from(&home)
-                .join("Library/Application Support/claude-code-sync/hook-debug.log");
-            if let Ok(mut file) = std::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&debug_log)
-            {
-                let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-                let _ = writeln!(
-                    file,
-                    "[{}] pull skipped (source: {} != startup)",
-                    timestamp, source
-                );
-            }
-        }
-        return Ok(());
-    }
-
-    if debounce_active {
-        if let Ok(home) = std::env::var("HOME") {
-            let debug_log = std::path::PathBuf::from(&home)
-                .join("Library/Application Support/claude-code-sync/hook-debug.log");
-            if let Ok(mut file) = std::fs::OpenOptions::new()
-                .create(true)
-                .append(true)
-                .open(&debug_log)
-            {
-                let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-                let _ = writeln!(file, "[{}] pull skipped (debounce active)", timestamp);
-            }
-        }
+    if let HookDecision::Skip(reason) =
+        session_start_decision(source, process_count, debounce_active)
+    {
+        let _ = log_hook(&format!("pull skipped ({})", reason));
         return Ok(());
     }
 
@@ -706,32 +1024,16 @@ pub fn handle_session_start() -> Result<()> {
     // Execute pull quietly (first start confirmed).
     // Spawn via current_exe() so it works even when the hook environment
     // PATH does not include the cargo bin directory.
-    let pull_result = spawn_ccs_subcommand("pull", &["--quiet"]);
+    let pull_result = spawn_ccs_subcommand("pull", &["--quiet"], child_timeout(HOOK_TIMEOUT_SECS));
 
     // Log result
-    if let Ok(home) = std::env::var("HOME") {
-        let debug_log = std::path::PathBuf::from(&home)
-            .join("Library/Application Support/claude-code-sync/hook-debug.log");
-        if let Ok(mut file) = std::fs::OpenOptions::new()
-            .create(true)
-            .append(true)
-            .open(&debug_log)
-        {
-            let timestamp = chrono::Local::now().format("%Y-%m-%d %H:%M:%S");
-            match &pull_result {
-                Ok(status) => {
-                    let _ = writeln!(
-                        file,
-                        "[{}] SessionStart pull completed: exit code {}",
-                        timestamp, status
-                    );
-                }
-                Err(e) => {
-                    let _ = writeln!(file, "[{}] SessionStart pull failed: {}", timestamp, e);
-                }
-            }
+    let _ = log_hook(&match &pull_result {
+        Ok(status) if status.success() => {
+            format!("SessionStart pull completed: exit code {}", status)
         }
-    }
+        Ok(status) => format!("SessionStart pull FAILED: {}", describe_failure(*status)),
+        Err(e) => format!("SessionStart pull failed: {}", e),
+    });
 
     // If pull succeeded and we got new content, we could notify the user
     // But for SessionStart, we just silently sync - the user will see the history
@@ -750,41 +1052,39 @@ pub fn handle_session_start() -> Result<()> {
     Ok(())
 }
 
-/// Check if hooks are installed
-pub fn are_hooks_installed() -> Result<bool> {
+/// Events from [`HOOK_EVENTS`] that have our hook in `settings`
+fn hook_events_in(settings: &Value) -> Vec<&'static str> {
+    let Some(hooks_obj) = settings.get("hooks").and_then(|v| v.as_object()) else {
+        return Vec::new();
+    };
+    HOOK_EVENTS
+        .iter()
+        .filter(|(event, subcommand, _)| {
+            hooks_obj
+                .get(*event)
+                .and_then(|v| v.as_array())
+                .is_some_and(|arr| contains_our_hook(arr, subcommand))
+        })
+        .map(|(event, _, _)| *event)
+        .collect()
+}
+
+/// Hook events that currently have our hook installed, in [`HOOK_EVENTS`] order
+pub fn installed_hook_events() -> Result<Vec<&'static str>> {
     let settings_path = claude_settings_path()?;
 
     if !settings_path.exists() {
-        return Ok(false);
+        return Ok(Vec::new());
     }
 
     let content = std::fs::read_to_string(&settings_path)?;
     let settings: Value = serde_json::from_str(&content)?;
+    Ok(hook_events_in(&settings))
+}
 
-    if let Some(hooks_obj) = settings.get("hooks").and_then(|v| v.as_object()) {
-        // Check all required hooks
-        let has_session_start = hooks_obj
-            .get("SessionStart")
-            .and_then(|v| v.as_array())
-            .map(|arr| contains_our_hook(arr, "hook-session-start"))
-            .unwrap_or(false);
-
-        let has_stop = hooks_obj
-            .get("Stop")
-            .and_then(|v| v.as_array())
-            .map(|arr| contains_our_hook(arr, "hook-stop"))
-            .unwrap_or(false);
-
-        let has_prompt_submit = hooks_obj
-            .get("UserPromptSubmit")
-            .and_then(|v| v.as_array())
-            .map(|arr| contains_our_hook(arr, "hook-new-project-check"))
-            .unwrap_or(false);
-
-        Ok(has_session_start && has_stop && has_prompt_submit)
-    } else {
-        Ok(false)
-    }
+/// Check if all hooks are installed
+pub fn are_hooks_installed() -> Result<bool> {
+    Ok(installed_hook_events()?.len() == HOOK_EVENTS.len())
 }
 
 #[cfg(test)]
@@ -798,12 +1098,85 @@ mod tests {
     /// the Stop hook and reading hook-debug.log.
     #[test]
     fn spawn_ccs_subcommand_returns_result_without_panic() {
-        let _ = spawn_ccs_subcommand("__definitely_not_a_subcommand__", &[]);
+        let _ = spawn_ccs_subcommand("__definitely_not_a_subcommand__", &[], None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn wait_with_timeout_leaves_hung_child_running() {
+        let mut child = std::process::Command::new("sleep")
+            .arg("30")
+            .spawn()
+            .unwrap();
+        let started = Instant::now();
+        let err = wait_with_timeout(&mut child, Duration::from_millis(200)).unwrap_err();
+        assert_eq!(err.kind(), std::io::ErrorKind::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(child.try_wait().unwrap().is_none());
+        child.kill().unwrap();
+        child.wait().unwrap();
+
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let status = wait_with_timeout(&mut child, Duration::from_secs(5)).unwrap();
+        assert!(status.success());
+    }
+
+    #[test]
+    fn timestamp_is_recent_respects_interval() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let ts_path = temp.path().join("last-stop-push");
+        assert!(!timestamp_is_recent(&ts_path, 60));
+
+        std::fs::write(&ts_path, "").unwrap();
+        assert!(timestamp_is_recent(&ts_path, 60));
+        assert!(!timestamp_is_recent(&ts_path, 0));
+    }
+
+    #[test]
+    fn interval_remaining_counts_down_from_timestamp() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let ts_path = temp.path().join("last-stop-push");
+        assert_eq!(interval_remaining(&ts_path, 60), Duration::ZERO);
+
+        std::fs::write(&ts_path, "").unwrap();
+        let remaining = interval_remaining(&ts_path, 60);
+        assert!(remaining > Duration::from_secs(55) && remaining <= Duration::from_secs(60));
+        assert_eq!(interval_remaining(&ts_path, 0), Duration::ZERO);
     }
 
     /// The command written to settings.json must be an absolute, double-quoted
     /// path plus the subcommand — never a bare `ccs` (which fails in the hook
     /// shell whose PATH excludes the cargo bin dir).
+    #[test]
+    fn hook_events_in_reports_installed_events_only() {
+        let settings = json!({
+            "hooks": {
+                "SessionStart": [{"hooks": [{"type": "command", "command": "\"/bin/ccs\" hook-session-start"}]}],
+                "Stop": [{"hooks": [{"type": "command", "command": "throttled-stop.sh"}]}],
+                "UserPromptSubmit": [{"hooks": [{"type": "command", "command": "ccs hook-new-project-check"}]}],
+                "SessionEnd": [{"hooks": [{"type": "command", "command": "ccs sync"}]}]
+            }
+        });
+        assert_eq!(
+            hook_events_in(&settings),
+            vec!["SessionStart", "UserPromptSubmit"]
+        );
+        assert!(hook_events_in(&json!({})).is_empty());
+
+        // Every installed event is described
+        let config = get_hooks_config();
+        let installed: Vec<&str> = config
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        for (event, _, _) in HOOK_EVENTS {
+            assert!(installed.contains(event), "{event} is not installed");
+        }
+        assert_eq!(installed.len(), HOOK_EVENTS.len());
+    }
+
     #[test]
     fn hook_command_is_quoted_absolute_path() {
         let cmd = hook_command("hook-stop");
@@ -864,4 +1237,62 @@ mod tests {
             .unwrap_or("");
         assert_eq!(sub, "hook-session-start");
     }
+
+    #[test]
+    fn session_start_pulls_only_on_first_startup() {
+        assert_eq!(
+            session_start_decision("startup", 1, false),
+            HookDecision::Pull
+        );
+        assert_eq!(
+            session_start_decision("startup", 2, false),
+            HookDecision::Skip("other instances: 2".to_string())
+        );
+        assert_eq!(
+            session_start_decision("resume", 1, false),
+            HookDecision::Skip("source: resume != startup".to_string())
+        );
+        assert_eq!(
+            session_start_decision("startup", 1, true),
+            HookDecision::Skip("debounce active".to_string())
+        );
+    }
+
+    #[test]
+    fn stop_defers_to_daemon_unless_now() {
+        assert_eq!(stop_decision(false, false, false), HookDecision::Push);
+        assert_eq!(stop_decision(false, true, false), HookDecision::MarkDirty);
+        assert_eq!(stop_decision(true, true, false), HookDecision::Push);
+        assert!(matches!(
+            stop_decision(false, false, true),
+            HookDecision::Skip(_)
+        ));
+    }
+
+    #[test]
+    fn input_project_name_handles_both_separators() {
+        let name = |cwd: &str| input_project_name(&json!({ "cwd": cwd })).map(str::to_string);
+        assert_eq!(name("/Users/me/app/").as_deref(), Some("app"));
+        assert_eq!(name("C:\\work\\tool").as_deref(), Some("tool"));
+        assert_eq!(input_project_name(&json!({})), None);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_uninitialized_skip_is_logged_once() {
+        let temp = tempfile::TempDir::new().unwrap();
+        std::env::set_var(crate::config::CONFIG_DIR_ENV, temp.path());
+
+        assert!(!hook_sync_initialized("Stop"));
+        assert!(!hook_sync_initialized("Stop"));
+        let log = crate::config::ConfigManager::hook_log_path().unwrap();
+        let contents = std::fs::read_to_string(&log).unwrap();
+        assert_eq!(contents.matches("sync not initialized").count(), 1);
+
+        std::fs::write(temp.path().join("state.json"), "{}").unwrap();
+        assert!(hook_sync_initialized("Stop"));
+        assert!(!temp.path().join(UNINITIALIZED_MARKER).exists());
+
+        std::env::remove_var(crate::config::CONFIG_DIR_ENV);
+    }
 }
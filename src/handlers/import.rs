@@ -0,0 +1,263 @@
+//! Import of exported history
+//!
+//! Handles `ccs import`, the counterpart of `ccs export-all`. Sessions from a
+//! `.tar.gz` export are written back into `~/.claude/projects/`, which moves
+//! history onto a new machine without a sync repo or git remote.
+//!
+//! Exports are organized by the encoded project directories of the source
+//! machine, which rarely exist as-is on the target. Each session therefore
+//! goes to the local directory of the same project (found by name, as pull
+//! does in project-name-only mode) when there is exactly one, and otherwise
+//! to a new directory with the exported name.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::fs;
+use std::io::BufReader;
+use std::path::{Component, Path, PathBuf};
+
+use super::export::{read_tar, ARCHIVE_ROOT};
+use crate::output::icon;
+use crate::sync::discovery::{
+    claude_projects_dir, extract_project_name, find_local_project_by_name,
+};
+use walkdir::WalkDir;
+
+/// Working directory of an exported `.jsonl` file, from its first entry
+/// that has one
+fn session_cwd(data: &[u8]) -> Option<String> {
+    String::from_utf8_lossy(data)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .find_map(|entry| entry.get("cwd")?.as_str().map(str::to_string))
+}
+
+/// Project name of an exported session: the last component of its `cwd`,
+/// or the last segment of the encoded directory name
+fn project_name(cwd: Option<&str>, exported_dir: &str) -> String {
+    cwd.and_then(|cwd| cwd.split(['/', '\\']).rfind(|s| !s.is_empty()))
+        .map(str::to_string)
+        .unwrap_or_else(|| extract_project_name(exported_dir).to_string())
+}
+
+/// Whether a `/`-separated path from the archive stays inside the directory
+/// it is joined to: every part must be a single normal component.
+///
+/// Backslashes are rejected outright since Windows treats them as
+/// separators, as are `.`, `..`, empty parts, roots and drive prefixes.
+fn is_safe_archive_path(relative: &str) -> bool {
+    !relative.contains('\\')
+        && relative.split('/').all(|part| {
+            let mut components = Path::new(part).components();
+            matches!(
+                (components.next(), components.next()),
+                (Some(Component::Normal(_)), None)
+            )
+        })
+}
+
+/// Handle `ccs import`
+///
+/// Sessions whose file name already exists locally are skipped unless
+/// `overwrite` is set, in which case the existing file is replaced in place.
+/// Files are matched by name rather than by the `sessionId` inside them:
+/// resumed sessions and agent transcripts carry another session's ID.
+pub fn handle_import(archive: &Path, overwrite: bool) -> Result<()> {
+    if !archive.is_file() {
+        bail!("Archive not found: {}", archive.display());
+    }
+
+    let claude_dir = claude_projects_dir()?;
+    fs::create_dir_all(&claude_dir)
+        .with_context(|| format!("Failed to create {}", claude_dir.display()))?;
+
+    // Every local session file by name (Claude names them after the session
+    // ID), not just the ones that pass the sync filters
+    let existing: HashMap<String, PathBuf> = WalkDir::new(&claude_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let path = e.into_path();
+            let stem = path.file_stem()?.to_str()?.to_string();
+            (path.extension()? == "jsonl").then_some((stem, path))
+        })
+        .collect();
+
+    let file =
+        fs::File::open(archive).with_context(|| format!("Failed to open {}", archive.display()))?;
+    let decoder = flate2::read::GzDecoder::new(BufReader::new(file));
+
+    // Exported project dir -> local project dir, resolved once per project
+    let mut project_dirs: HashMap<String, PathBuf> = HashMap::new();
+    let mut imported = 0;
+    let mut overwritten = 0;
+    let mut skipped = 0;
+    let mut not_jsonl = 0;
+
+    read_tar(decoder, |name, data| {
+        let Some(relative) = name.strip_prefix(&format!("{ARCHIVE_ROOT}/")) else {
+            return Ok(());
+        };
+        let Some((exported_dir, rest)) = relative.split_once('/') else {
+            // index.json and other files at the archive root
+            return Ok(());
+        };
+        if !rest.ends_with(".jsonl") {
+            not_jsonl += 1;
+            return Ok(());
+        }
+        if !is_safe_archive_path(relative) {
+            log::warn!("Skipping unsafe archive path: {}", name);
+            return Ok(());
+        }
+
+        let key = Path::new(rest)
+            .file_stem()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        let dest = match existing.get(key) {
+            Some(_) if !overwrite => {
+                skipped += 1;
+                return Ok(());
+            }
+            Some(local) => {
+                overwritten += 1;
+                local.clone()
+            }
+            None => {
+                let project_dir =
+                    project_dirs
+                        .entry(exported_dir.to_string())
+                        .or_insert_with(|| {
+                            let same_path = claude_dir.join(exported_dir);
+                            if same_path.is_dir() {
+                                return same_path;
+                            }
+                            let cwd = session_cwd(&data);
+                            let name = project_name(cwd.as_deref(), exported_dir);
+                            find_local_project_by_name(&claude_dir, &name).unwrap_or(same_path)
+                        });
+                imported += 1;
+                project_dir.join(rest)
+            }
+        };
+
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        fs::write(&dest, &data).with_context(|| format!("Failed to write {}", dest.display()))
+    })
+    .with_context(|| format!("Failed to read {}", archive.display()))?;
+
+    println!(
+        "{} Imported {} sessions into {}",
+        icon("✓").green(),
+        imported,
+        claude_dir.display()
+    );
+    if overwritten > 0 {
+        println!(
+            "  {} Replaced {} existing sessions",
+            icon("•").yellow(),
+            overwritten
+        );
+    }
+    if skipped > 0 {
+        println!(
+            "  {} Skipped {} sessions that already exist (use --overwrite to replace them)",
+            icon("•").dimmed(),
+            skipped
+        );
+    }
+    if not_jsonl > 0 {
+        println!(
+            "  {} Ignored {} non-.jsonl files (Markdown exports cannot be imported)",
+            icon("⚠").yellow(),
+            not_jsonl
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::handlers::export::TarWriter;
+
+    #[test]
+    fn test_session_cwd_and_project_name() {
+        let data = concat!(
+            r#"{"type":"summary","summary":"x"}"#,
+            "\n",
+            r#"{"type":"user","sessionId":"abc","cwd":"/Users/me/my-app"}"#,
+            "\n",
+        );
+        let cwd = session_cwd(data.as_bytes());
+        assert_eq!(project_name(cwd.as_deref(), "-Users-me-my-app"), "my-app");
+
+        let cwd = session_cwd(b"{}");
+        assert_eq!(cwd, None);
+        assert_eq!(project_name(cwd.as_deref(), "-tmp-p"), "p");
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_import_matches_sessions_by_file_name() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let claude = temp.path().join("claude");
+        std::env::set_var(crate::sync::discovery::CLAUDE_CONFIG_DIR_ENV, &claude);
+        let project = claude_projects_dir().unwrap().join("-tmp-p");
+        fs::create_dir_all(&project).unwrap();
+        let parent = r#"{"type":"user","sessionId":"parent","cwd":"/tmp/p"}"#;
+        fs::write(project.join("parent.jsonl"), parent).unwrap();
+
+        // A resumed session keeps its parent's sessionId in a file of its own
+        let resumed = r#"{"type":"user","sessionId":"parent","cwd":"/tmp/p","n":2}"#;
+        let archive = temp.path().join("export.tar.gz");
+        let encoder = flate2::write::GzEncoder::new(
+            fs::File::create(&archive).unwrap(),
+            flate2::Compression::default(),
+        );
+        let mut tar = TarWriter::new(encoder);
+        tar.append(
+            &format!("{ARCHIVE_ROOT}/-tmp-p/resumed.jsonl"),
+            resumed.as_bytes(),
+            0,
+        )
+        .unwrap();
+        tar.finish().unwrap().finish().unwrap();
+
+        handle_import(&archive, true).unwrap();
+        std::env::remove_var(crate::sync::discovery::CLAUDE_CONFIG_DIR_ENV);
+
+        assert_eq!(
+            fs::read_to_string(project.join("parent.jsonl")).unwrap(),
+            parent
+        );
+        assert_eq!(
+            fs::read_to_string(project.join("resumed.jsonl")).unwrap(),
+            resumed
+        );
+    }
+
+    #[test]
+    fn test_is_safe_archive_path() {
+        assert!(is_safe_archive_path("-tmp-p/abc.jsonl"));
+        assert!(is_safe_archive_path("-tmp-p/abc/subagents/agent-1.jsonl"));
+
+        for unsafe_path in [
+            "-tmp-p/../../escape.jsonl",
+            "-tmp-p/./abc.jsonl",
+            "-tmp-p//abc.jsonl",
+            "/etc/abc.jsonl",
+            "-tmp-p/..\\..\\escape.jsonl",
+            "-tmp-p/C:\\escape.jsonl",
+            "-tmp-p/abc\\x.jsonl",
+        ] {
+            assert!(!is_safe_archive_path(unsafe_path), "{}", unsafe_path);
+        }
+    }
+}
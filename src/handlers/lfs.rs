@@ -0,0 +1,190 @@
+//! Git LFS migration of existing history
+//!
+//! Handles `ccs lfs migrate`. Enabling LFS only affects files committed from
+//! then on; sessions pushed earlier stay in the history as regular blobs. The
+//! migration rewrites the sync branch with `git lfs migrate import` so those
+//! files move to LFS too, then force-pushes the result. Because every device
+//! has to re-clone afterwards, the command does nothing without `--force`
+//! and asks for confirmation before rewriting. The whole rewrite runs under
+//! the sync lock, so a hook push cannot commit in the middle of it.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+
+use crate::filter::FilterConfig;
+use crate::interactive_conflict;
+use crate::output::icon;
+use crate::scm::{self, lfs};
+use crate::sync::{open_sync_repo, SyncLock, SyncState};
+
+/// Commit message for `.gitattributes` changes made around the migration
+const GITATTRIBUTES_COMMIT_MESSAGE: &str = "Configure Git LFS patterns";
+
+/// Print what the migration does to the sync repo and to other devices
+fn print_rewrite_warning(patterns: &[String], branch: &str, has_remote: bool) {
+    println!(
+        "{} {}",
+        icon("⚠").yellow(),
+        "This rewrites the entire history of the sync repository."
+            .yellow()
+            .bold()
+    );
+    println!(
+        "  Files matching {} on branch {} will be moved to Git LFS.",
+        patterns.join(", ").cyan(),
+        branch.cyan()
+    );
+    if has_remote {
+        println!("  The rewritten branch is force-pushed to origin.");
+        println!(
+            "  Other devices must re-clone the sync repository (or reset to origin/{}) afterwards.",
+            branch
+        );
+    }
+}
+
+/// Handle `ccs lfs migrate`
+///
+/// Without `force` only the warning is shown. Otherwise, holding the sync
+/// lock, the branch is brought up to date with the remote, LFS is set up,
+/// history is rewritten and the result is force-pushed.
+pub fn handle_lfs_migrate(force: bool) -> Result<()> {
+    let state = SyncState::load()?;
+    let filter = FilterConfig::load()?;
+    let repo_path = &state.sync_repo_path;
+
+    if scm::detect_backend(repo_path) != Some(scm::Backend::Git) {
+        bail!("LFS migration needs a git sync repository");
+    }
+    if filter.lfs_patterns.is_empty() {
+        bail!("No LFS patterns configured; set them with `ccs config --lfs-patterns`");
+    }
+
+    let repo = open_sync_repo(repo_path, &filter)?;
+    let branch = repo.current_branch()?;
+    print_rewrite_warning(&filter.lfs_patterns, &branch, state.has_remote);
+
+    if !force {
+        println!();
+        println!("Re-run with {} to rewrite the history.", "--force".cyan());
+        return Ok(());
+    }
+
+    let _lock = SyncLock::acquire("lfs-migrate")?;
+    if repo.has_changes()? {
+        bail!(
+            "The sync repository has uncommitted changes; run `ccs push` first:\n  {}",
+            repo_path.display()
+        );
+    }
+    lfs::ensure_installed()?;
+
+    let assume_yes = interactive_conflict::assume_yes();
+    if !interactive_conflict::is_interactive() && !assume_yes {
+        bail!("Not a terminal: add --yes to confirm the history rewrite");
+    }
+    println!();
+    if !interactive_conflict::confirm("Rewrite the sync repository history?", false, assume_yes)? {
+        println!("{}", "Cancelled".yellow());
+        return Ok(());
+    }
+
+    // Include commits only the remote has, so the force push loses nothing
+    if state.has_remote {
+        println!("  Pulling latest changes...");
+        repo.pull("origin", &branch)
+            .context("Could not bring the sync branch up to date; run `ccs sync` first")?;
+    }
+    let previous_head = repo.current_commit_hash()?;
+
+    lfs::setup(repo_path, &filter.lfs_patterns).context("Failed to set up Git LFS")?;
+    if repo.has_changes()? {
+        repo.commit(GITATTRIBUTES_COMMIT_MESSAGE)?;
+    }
+
+    println!("  Rewriting history...");
+    lfs::migrate_import(repo_path, &branch, &filter.lfs_patterns)?;
+
+    // The rewrite may append its own lines; fold them back into the block
+    lfs::setup(repo_path, &filter.lfs_patterns)?;
+    if repo.has_changes()? {
+        repo.commit(GITATTRIBUTES_COMMIT_MESSAGE)?;
+    }
+
+    if state.has_remote {
+        println!("  Force-pushing to origin/{}...", branch);
+        repo.force_push("origin", &branch)?;
+    }
+
+    println!("{} Migrated existing history to Git LFS", icon("✓").green());
+    println!(
+        "  Previous history: {} (restore with `git reset --hard {}` in the sync repository)",
+        &previous_head[..previous_head.len().min(8)],
+        previous_head
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    /// Git sync repo with one commit and LFS patterns configured, using a
+    /// temporary config directory
+    fn setup(temp: &tempfile::TempDir) -> std::path::PathBuf {
+        let config_dir = temp.path().join("config");
+        fs::create_dir_all(&config_dir).unwrap();
+        std::env::set_var(crate::config::CONFIG_DIR_ENV, &config_dir);
+
+        let repo_path = temp.path().join("repo");
+        let repo = scm::init(&repo_path).unwrap();
+        fs::write(repo_path.join("s1.jsonl"), "{}\n").unwrap();
+        repo.stage_all().unwrap();
+        repo.commit("Initial").unwrap();
+
+        let state = serde_json::json!({ "sync_repo_path": repo_path, "has_remote": false });
+        fs::write(config_dir.join("state.json"), state.to_string()).unwrap();
+        let filter = FilterConfig {
+            lfs_patterns: vec!["*.jsonl".to_string()],
+            ..FilterConfig::default()
+        };
+        filter.save().unwrap();
+        repo_path
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_migrate_without_force_changes_nothing() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let repo_path = setup(&temp);
+        let repo = scm::open(&repo_path).unwrap();
+        let head = repo.current_commit_hash().unwrap();
+
+        handle_lfs_migrate(false).unwrap();
+
+        assert_eq!(repo.current_commit_hash().unwrap(), head);
+        assert!(!repo.has_changes().unwrap());
+        assert!(!repo_path.join(".gitattributes").exists());
+        std::env::remove_var(crate::config::CONFIG_DIR_ENV);
+    }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_migrate_refuses_dirty_repo() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let repo_path = setup(&temp);
+        fs::write(repo_path.join("s2.jsonl"), "{}\n").unwrap();
+
+        let err = handle_lfs_migrate(true).unwrap_err().to_string();
+
+        assert!(err.contains("uncommitted changes"), "{}", err);
+        let repo = scm::open(&repo_path).unwrap();
+        assert_eq!(repo.commit_summary("HEAD").unwrap(), "Initial");
+        // The lock is released on the error path too
+        assert!(!crate::config::ConfigManager::sync_lock_path()
+            .unwrap()
+            .exists());
+        std::env::remove_var(crate::config::CONFIG_DIR_ENV);
+    }
+}
@@ -3,45 +3,78 @@
 //! This module contains all command handler functions extracted from main.rs,
 //! organized by functionality area.
 
+pub mod archive;
 pub mod automate;
+pub mod claude_md;
 pub mod cleanup;
 pub mod config;
 pub mod config_sync;
+pub mod daemon;
+pub mod doctor;
+pub mod export;
 pub mod history;
+pub mod hook_paths;
 pub mod hooks;
+pub mod import;
+pub mod lfs;
 pub mod onboarding;
 pub mod platform_filter;
+pub mod repo;
 pub mod session;
+pub mod session_repair;
+pub mod session_watch;
 pub mod setup;
 pub mod undo;
 pub mod uninstall;
 pub mod unlock_delete;
 pub mod update;
+pub mod validate;
+pub mod verify;
 pub mod wrapper;
 
 // Re-export all public handler functions for convenient use
+pub use archive::{handle_session_archive, handle_session_unarchive};
 pub use automate::{handle_automate_setup, handle_automate_status, handle_automate_uninstall};
+pub use claude_md::{handle_claude_md_analyze, handle_claude_md_init};
 pub use cleanup::handle_cleanup_snapshots;
-pub use config::{handle_config_interactive, handle_config_wizard, handle_repo_selector};
+pub use config::{
+    handle_config_export, handle_config_import, handle_config_interactive, handle_config_wizard,
+    handle_repo_selector,
+};
 pub use config_sync::{
-    handle_config_apply, handle_config_list, handle_config_push, handle_config_status,
+    handle_config_apply, handle_config_list, handle_config_prune_devices, handle_config_push,
+    handle_config_rename_device, handle_config_status,
 };
+pub use daemon::{handle_daemon_start, handle_daemon_status, handle_daemon_stop};
+pub use doctor::handle_doctor;
+pub use export::handle_export_all;
 pub use history::{
     handle_history_clear, handle_history_last, handle_history_list, handle_history_review,
+    HistoryFilter,
 };
 pub use hooks::{
-    handle_hooks_install, handle_hooks_show, handle_hooks_uninstall, handle_new_project_check,
-    handle_session_start, handle_stop,
+    handle_hook_test, handle_hooks_install, handle_hooks_show, handle_hooks_uninstall,
+    handle_new_project_check, handle_session_start, handle_stop, handle_stop_trailing, HookEvent,
 };
+pub use import::handle_import;
+pub use lfs::handle_lfs_migrate;
 pub use onboarding::{is_initialized, run_init_from_config, try_init_from_config};
+pub use repo::{handle_repo_add, handle_repo_list, handle_repo_use};
 pub use session::{
-    handle_session_delete, handle_session_interactive, handle_session_list,
-    handle_session_overview, handle_session_projects, handle_session_rename,
-    handle_session_restore, handle_session_search, handle_session_show,
+    handle_open, handle_session_delete, handle_session_disk_usage, handle_session_interactive,
+    handle_session_last, handle_session_list, handle_session_overview, handle_session_projects,
+    handle_session_rename, handle_session_rename_undo, handle_session_restore,
+    handle_session_search, handle_session_show,
 };
+pub use session_repair::handle_session_repair;
+pub use session_watch::handle_session_watch;
 pub use setup::handle_setup;
-pub use undo::{handle_undo_pull, handle_undo_push};
+pub use undo::{
+    handle_undo, handle_undo_list, handle_undo_pull, handle_undo_push, handle_undo_session,
+};
 pub use uninstall::handle_uninstall;
 pub use unlock_delete::handle_unlock_delete;
 pub use update::{check_for_update_silent, handle_update, print_update_notification};
+pub use validate::handle_validate_history;
+pub use verify::handle_verify;
 pub use wrapper::{handle_wrapper_install, handle_wrapper_show, handle_wrapper_uninstall};
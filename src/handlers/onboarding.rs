@@ -105,6 +105,69 @@ pub fn try_init_from_config() -> Result<bool> {
     }
 }
 
+/// Move a corrupted state.json aside and try to rebuild it.
+///
+/// A state file that parses as neither v1 nor v2 (manual edit mistake, or a
+/// partial write by an older version) is renamed to
+/// `state.json.corrupt.<timestamp>`, then the same scan as
+/// [`try_recover_existing_repo`] is used to recreate it. If no repository is
+/// found the state is left missing so the normal onboarding flow runs.
+///
+/// Recovery runs under the sync lock, so a push or pull in progress finishes
+/// its own state write first and the file is checked again afterwards.
+///
+/// Returns Ok(true) if a corrupted file was found and moved aside.
+pub fn recover_corrupt_state() -> Result<bool> {
+    let state_path = config::ConfigManager::state_file_path()?;
+    if corrupt_state_error(&state_path).is_none() {
+        return Ok(false);
+    }
+
+    let _lock = sync::SyncLock::acquire("recover-state")?;
+    let Some(err) = corrupt_state_error(&state_path) else {
+        return Ok(false);
+    };
+
+    let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+    let backup_path = state_path.with_file_name(format!("state.json.corrupt.{}", timestamp));
+    std::fs::rename(&state_path, &backup_path).with_context(|| {
+        format!(
+            "Failed to move corrupted state file to {}",
+            backup_path.display()
+        )
+    })?;
+    log::info!(
+        "Corrupted state.json moved to {}: {}",
+        backup_path.display(),
+        err
+    );
+
+    println!(
+        "{} {} is corrupted ({}).",
+        "!".yellow(),
+        state_path.display(),
+        err
+    );
+    println!("  Backed up to: {}", backup_path.display());
+
+    if try_recover_existing_repo()? {
+        return Ok(true);
+    }
+
+    println!(
+        "  No existing sync repository found to rebuild it from; run '{}' to configure sync again.",
+        format!("{} setup", crate::BINARY_NAME).cyan()
+    );
+    println!();
+    Ok(true)
+}
+
+/// Parse error of the state file at `path`; `None` when it is valid or missing
+fn corrupt_state_error(path: &Path) -> Option<serde_json::Error> {
+    let content = std::fs::read_to_string(path).ok()?;
+    sync::state_parse_error(&content)
+}
+
 /// Try to recover an existing repository when state.json is missing.
 ///
 /// This scans common locations where users might have a sync repository:
@@ -204,6 +267,9 @@ pub fn try_recover_existing_repo() -> Result<bool> {
             is_cloned_repo: has_remote, // Assume cloned if has remote
             remote_url,
             description: Some("Recovered from existing repository".to_string()),
+            last_synced_commit: None,
+            pushed_hashes: Default::default(),
+            last_push_at: None,
         };
 
         let mut repos = HashMap::new();
@@ -12,6 +12,10 @@
 //! <!-- platform:windows -->
 //! Windows specific content here
 //! <!-- end-platform -->
+//!
+//! <!-- platform:other -->
+//! Content for every platform without its own block above
+//! <!-- end-platform -->
 //! ```
 
 use regex::Regex;
@@ -46,6 +50,15 @@ impl Platform {
         }
     }
 
+    /// All tag names (including aliases) that select this platform
+    fn tag_aliases(&self) -> &'static [&'static str] {
+        match self {
+            Platform::MacOS => &["macos", "mac", "darwin"],
+            Platform::Windows => &["windows", "win"],
+            Platform::Linux => &["linux"],
+        }
+    }
+
     /// Parse platform from tag name
 #[allow(dead_code)]
     pub fn from_tag_name(name: &str) -> Option<Self> {
@@ -64,26 +77,41 @@ impl std::fmt::Display for Platform {
     }
 }
 
+/// Tag of the fallback block, kept only when no block targets the platform
+const OTHER_TAG: &str = "other";
+
 /// Regex pattern for matching platform blocks
 /// Matches: <!-- platform:PLATFORM --> ... <!-- end-platform -->
 static PLATFORM_BLOCK_REGEX: LazyLock<Regex> = LazyLock::new(|| {
     Regex::new(
-        r"(?s)<!--\s*platform:\s*(macos|mac|darwin|windows|win|linux)\s*-->(.*?)<!--\s*end-platform\s*-->"
+        r"(?s)<!--\s*platform:\s*(macos|mac|darwin|windows|win|linux|other)\s*-->(.*?)<!--\s*end-platform\s*-->"
     ).expect("Invalid regex pattern")
 });
 
+/// Any `platform:` opening tag, known or not (for reporting typos)
+static ANY_PLATFORM_TAG_REGEX: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"<!--\s*platform:\s*([^\s>]*)\s*-->").expect("Invalid regex pattern")
+});
+
+/// Whether any block in `content` is tagged for `platform` specifically
+fn has_block_for(content: &str, platform: Platform) -> bool {
+    PLATFORM_BLOCK_REGEX.captures_iter(content).any(|caps| {
+        let name = caps[1].to_lowercase();
+        platform.tag_aliases().contains(&name.as_str())
+    })
+}
+
 /// Filter CLAUDE.md content for target platform
 ///
 /// - Removes content blocks for other platforms
 /// - Keeps content blocks for the target platform (without the tags)
+/// - Keeps `platform:other` blocks only when no block targets the platform
 /// - Keeps all content outside platform blocks
 #[allow(dead_code)]
 pub fn filter_for_platform(content: &str, target: Platform) -> String {
-    let target_names: Vec<&str> = match target {
-        Platform::MacOS => vec!["macos", "mac", "darwin"],
-        Platform::Windows => vec!["windows", "win"],
-        Platform::Linux => vec!["linux"],
-    };
+    let target_names = target.tag_aliases();
+    // First pass: the `other` block is the fallback for unlisted platforms
+    let keep_other = !has_block_for(content, target);
 
     let result = PLATFORM_BLOCK_REGEX.replace_all(content, |caps: &regex::Captures| {
         let platform_name = caps
@@ -92,7 +120,9 @@ pub fn filter_for_platform(content: &str, target: Platform) -> String {
             .unwrap_or_default();
         let block_content = caps.get(2).map(|m| m.as_str()).unwrap_or("");
 
-        if target_names.contains(&platform_name.as_str()) {
+        if target_names.contains(&platform_name.as_str())
+            || (keep_other && platform_name == OTHER_TAG)
+        {
             // Keep this block's content (strip the tags)
             block_content.to_string()
         } else {
@@ -118,8 +148,38 @@ pub fn has_platform_blocks(content: &str) -> bool {
     PLATFORM_BLOCK_REGEX.is_match(content)
 }
 
+/// Content shared by all platforms (every platform block removed)
+pub fn common_content(content: &str) -> String {
+    cleanup_blank_lines(&PLATFORM_BLOCK_REGEX.replace_all(content, ""))
+}
+
+/// Contents of the `platform:other` fallback blocks
+pub fn extract_other_blocks(content: &str) -> Vec<String> {
+    PLATFORM_BLOCK_REGEX
+        .captures_iter(content)
+        .filter(|caps| caps[1].eq_ignore_ascii_case(OTHER_TAG))
+        .map(|caps| caps[2].to_string())
+        .collect()
+}
+
+/// `platform:` tags that are not recognized, as (1-based line, tag name).
+/// Blocks with these tags are kept verbatim on every platform.
+pub fn unknown_platform_tags(content: &str) -> Vec<(usize, String)> {
+    ANY_PLATFORM_TAG_REGEX
+        .captures_iter(content)
+        .filter_map(|caps| {
+            let tag = caps.get(1)?;
+            let name = tag.as_str();
+            if name.eq_ignore_ascii_case(OTHER_TAG) || Platform::from_tag_name(name).is_some() {
+                return None;
+            }
+            let line = content[..tag.start()].matches('\n').count() + 1;
+            Some((line, name.to_string()))
+        })
+        .collect()
+}
+
 /// Extract all platform blocks from content (for analysis)
-#[allow(dead_code)]
 pub fn extract_platform_blocks(content: &str) -> Vec<(Platform, String)> {
     PLATFORM_BLOCK_REGEX
         .captures_iter(content)
@@ -133,12 +193,11 @@ pub fn extract_platform_blocks(content: &str) -> Vec<(Platform, String)> {
 }
 
 /// Extract platform block with tags preserved (for merging)
+///
+/// Falls back to the `platform:other` block when no block targets `platform`.
 pub fn extract_current_platform_block(content: &str, platform: Platform) -> Option<String> {
-    let target_names: Vec<&str> = match platform {
-        Platform::MacOS => vec!["macos", "mac", "darwin"],
-        Platform::Windows => vec!["windows", "win"],
-        Platform::Linux => vec!["linux"],
-    };
+    let target_names = platform.tag_aliases();
+    let mut other_block = None;
 
     for caps in PLATFORM_BLOCK_REGEX.captures_iter(content) {
         let platform_name = caps
@@ -149,8 +208,11 @@ pub fn extract_current_platform_block(content: &str, platform: Platform) -> Opti
             // Return the full match including tags
             return Some(caps.get(0)?.as_str().to_string());
         }
+        if platform_name == OTHER_TAG && other_block.is_none() {
+            other_block = Some(caps.get(0)?.as_str().to_string());
+        }
     }
-    None
+    other_block
 }
 
 /// Merge CLAUDE.md from source to target, preserving target's current platform block
@@ -161,8 +223,7 @@ pub fn extract_current_platform_block(content: &str, platform: Platform) -> Opti
 /// 3. Merge: filtered source + target's platform block at the end
 pub fn merge_claude_md(source_content: &str, target_content: &str, current: Platform) -> String {
     // Step 1: Filter source - remove all platform blocks (keep only common content)
-    let source_common = PLATFORM_BLOCK_REGEX.replace_all(source_content, "");
-    let source_common = cleanup_blank_lines(&source_common);
+    let source_common = common_content(source_content);
 
     // Step 2: Extract target's current platform block (preserved with tags)
     let target_platform_block = extract_current_platform_block(target_content, current);
@@ -239,6 +300,60 @@ Windows content
         assert!(filtered.contains("Common"));
     }
 
+    #[test]
+    fn test_filter_other_block_is_fallback() {
+        let content = r#"# Common
+
+<!-- platform:macos -->
+macOS content
+<!-- end-platform -->
+
+<!-- platform:other -->
+Other content
+<!-- end-platform -->
+"#;
+
+        let mac = filter_for_platform(content, Platform::MacOS);
+        assert!(mac.contains("macOS content"));
+        assert!(!mac.contains("Other content"));
+
+        let windows = filter_for_platform(content, Platform::Windows);
+        assert!(!windows.contains("macOS content"));
+        assert!(windows.contains("Other content"));
+        assert!(windows.contains("Common"));
+    }
+
+    #[test]
+    fn test_extract_current_platform_block_falls_back_to_other() {
+        let content = "<!-- platform:macos -->\nmac\n<!-- end-platform -->\n\
+                       <!-- platform:other -->\nother\n<!-- end-platform -->";
+
+        let mac = extract_current_platform_block(content, Platform::MacOS).unwrap();
+        assert!(mac.contains("platform:macos"));
+        let linux = extract_current_platform_block(content, Platform::Linux).unwrap();
+        assert!(linux.contains("platform:other"));
+    }
+
+    #[test]
+    fn test_unknown_platform_tags_and_common_content() {
+        let content = "common\n<!-- platform:macos -->\nmac\n<!-- end-platform -->\n\
+                       <!-- platform:freebsd -->\nbsd\n<!-- end-platform -->\n\
+                       <!-- platform:other -->\nrest\n<!-- end-platform -->\n";
+
+        assert_eq!(
+            unknown_platform_tags(content),
+            vec![(5, "freebsd".to_string())]
+        );
+        assert_eq!(extract_other_blocks(content), vec!["\nrest\n".to_string()]);
+
+        let common = common_content(content);
+        assert!(common.contains("common"));
+        assert!(!common.contains("mac\n"));
+        assert!(!common.contains("rest"));
+        // Unknown tags are not platform blocks, so their content stays common
+        assert!(common.contains("bsd"));
+    }
+
     #[test]
     fn test_filter_preserves_content_without_tags() {
         let content = "# No platform tags\n\nJust regular content.";
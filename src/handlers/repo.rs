@@ -0,0 +1,226 @@
+//! Repository management handlers
+//!
+//! Handles `ccs repo add/list/use` for managing multiple named sync
+//! repositories stored in the v2 (multi-repo) state file.
+
+use anyhow::{anyhow, Context, Result};
+use colored::Colorize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::config::ConfigManager;
+use crate::output::icon;
+use crate::scm;
+use crate::sync::{MultiRepoState, RepoConfig};
+use crate::BINARY_NAME;
+
+/// Whether a repo location looks like a remote URL rather than a local path
+fn is_remote_location(location: &str) -> bool {
+    location.contains("://") || (location.starts_with("git@") && location.contains(':'))
+}
+
+/// Validate a repository name (used as a map key and a directory name)
+fn validate_repo_name(name: &str) -> Result<()> {
+    if name.is_empty()
+        || name
+            .chars()
+            .any(|c| c.is_whitespace() || c == '/' || c == '\\')
+        || name == "."
+        || name == ".."
+    {
+        return Err(anyhow!(
+            "Invalid repository name '{}': use letters, digits, '-' or '_'",
+            name
+        ));
+    }
+    Ok(())
+}
+
+/// Load the multi-repo state, or start an empty one if sync isn't initialized yet
+fn load_or_empty_state(first_repo: &str) -> Result<MultiRepoState> {
+    let state_path = ConfigManager::state_file_path()?;
+    if state_path.exists() {
+        return MultiRepoState::load();
+    }
+    Ok(MultiRepoState {
+        version: 2,
+        active_repo: first_repo.to_string(),
+        repos: HashMap::new(),
+    })
+}
+
+/// Add a named sync repository from a local path or remote URL
+///
+/// Remote URLs are cloned into `<config_dir>/repos/<name>`. Local paths are
+/// opened if they are already a repository, otherwise a new Git repository
+/// is initialized there. The new repo only becomes active when it is the
+/// first one configured.
+pub fn handle_repo_add(name: &str, location: &str, description: Option<&str>) -> Result<()> {
+    validate_repo_name(name)?;
+
+    let mut state = load_or_empty_state(name)?;
+    if state.has_repo(name) {
+        return Err(anyhow!("Repository '{}' already exists", name));
+    }
+
+    let (repo_path, remote_url, is_cloned_repo) = if is_remote_location(location) {
+        let path = ConfigManager::config_dir()?.join("repos").join(name);
+        if path.exists() && scm::is_repo(&path) {
+            println!("  {} existing clone at {}", "Using".green(), path.display());
+        } else {
+            println!(
+                "{}",
+                format!("Cloning from {} to {}...", location, path.display()).cyan()
+            );
+            scm::clone(location, &path)?;
+        }
+        (path, Some(location.to_string()), true)
+    } else {
+        let path = PathBuf::from(location);
+        let path = if path.is_absolute() {
+            path
+        } else {
+            std::env::current_dir()
+                .context("Failed to get current directory")?
+                .join(path)
+        };
+        let repo = if path.exists() && scm::is_repo(&path) {
+            scm::open(&path)?
+        } else {
+            println!(
+                "  {} new repository at {}",
+                "Creating".green(),
+                path.display()
+            );
+            scm::init(&path)?
+        };
+        let remote_url = repo.get_remote_url("origin").ok();
+        (path, remote_url, false)
+    };
+
+    let is_first = state.repos.is_empty();
+    state.add_repo(RepoConfig {
+        name: name.to_string(),
+        sync_repo_path: repo_path.clone(),
+        has_remote: remote_url.is_some(),
+        is_cloned_repo,
+        remote_url,
+        description: description.map(String::from),
+        last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
+    })?;
+    if is_first {
+        state.active_repo = name.to_string();
+    }
+    ConfigManager::ensure_config_dir()?;
+    state.save()?;
+
+    println!(
+        "{} Added repository '{}' -> {}",
+        icon("✓").green().bold(),
+        name.cyan(),
+        repo_path.display()
+    );
+    if is_first {
+        println!("  Set as active repository");
+    } else {
+        println!(
+            "  Run '{}' to make it active",
+            format!("{} repo use {}", BINARY_NAME, name).cyan()
+        );
+    }
+
+    Ok(())
+}
+
+/// List all configured sync repositories
+pub fn handle_repo_list() -> Result<()> {
+    let state = MultiRepoState::load()?;
+
+    if state.repos.is_empty() {
+        println!("{}", "No repositories configured.".yellow());
+        return Ok(());
+    }
+
+    println!("{}", "Sync Repositories".cyan().bold());
+    println!("{}", "=".repeat(60).cyan());
+
+    for name in state.repo_names() {
+        let repo = &state.repos[name];
+        if *name == state.active_repo {
+            println!(
+                "{} {} {}",
+                "*".green().bold(),
+                name.green().bold(),
+                "[ACTIVE]".green()
+            );
+        } else {
+            println!("  {}", name.bold());
+        }
+        println!("    Path: {}", repo.sync_repo_path.display());
+        if let Some(ref url) = repo.remote_url {
+            println!("    Remote: {}", url.dimmed());
+        } else if !repo.has_remote {
+            println!("    Remote: {}", "none (local only)".dimmed());
+        }
+        if let Some(ref desc) = repo.description {
+            println!("    {}", desc.dimmed());
+        }
+    }
+
+    Ok(())
+}
+
+/// Switch the active sync repository
+pub fn handle_repo_use(name: &str) -> Result<()> {
+    let mut state = MultiRepoState::load()?;
+
+    if state.active_repo == name {
+        println!(
+            "{} '{}' is already the active repository.",
+            icon("ℹ").blue(),
+            name.cyan()
+        );
+        return Ok(());
+    }
+
+    state.switch_active(name)?;
+    state.save()?;
+
+    println!(
+        "{} Switched to repository '{}'",
+        icon("✓").green().bold(),
+        name.cyan()
+    );
+    if let Some(repo) = state.active() {
+        println!("  Path: {}", repo.sync_repo_path.display());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_remote_location() {
+        assert!(is_remote_location("https://github.com/user/repo.git"));
+        assert!(is_remote_location("ssh://git@host/repo.git"));
+        assert!(is_remote_location("git@github.com:user/repo.git"));
+        assert!(!is_remote_location("/home/user/sync-repo"));
+        assert!(!is_remote_location("relative/path"));
+        assert!(!is_remote_location("C:\\Users\\me\\repo"));
+    }
+
+    #[test]
+    fn test_validate_repo_name() {
+        assert!(validate_repo_name("work").is_ok());
+        assert!(validate_repo_name("my-repo_2").is_ok());
+        assert!(validate_repo_name("").is_err());
+        assert!(validate_repo_name("a/b").is_err());
+        assert!(validate_repo_name("has space").is_err());
+        assert!(validate_repo_name("..").is_err());
+    }
+}
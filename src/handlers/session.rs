@@ -6,18 +6,30 @@
 
 use anyhow::{Context, Result};
 use colored::Colorize;
-use inquire::{Confirm, Select, Text};
+use inquire::{Select, Text};
+use rayon::prelude::*;
 use serde_json::json;
 use std::fs;
+use std::io::Write;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::codex::{
     codex_history_path, codex_sessions_dir, load_codex_history_titles, CodexSession,
 };
 use crate::omp::{omp_sessions_dir, OmpSession};
 use crate::config::ConfigManager;
-use crate::filter::{ConfigSyncSettings, FilterConfig};
-use crate::parser::ConversationSession;
+use crate::conflict::{Conflict, ConflictResolution};
+use crate::error::SyncError;
+use crate::interactive_conflict::{self, prompt_keep_resolution, ResolutionAction};
+use crate::output::icon;
+use crate::report::{save_conflict_report, ConflictReport};
+use crate::filter::{CleanupSettings, ConfigSyncSettings, FilterConfig, TimeFormat};
+use crate::history::{
+    ConversationSummary, OperationHistory, OperationRecord, OperationType, SyncOperation,
+};
+use crate::parser::{estimate_tokens, ConversationSession};
 use crate::scm;
 use crate::session_cache::{mtime_secs, SessionIndexCache};
 use crate::sync::discovery::{
@@ -25,6 +37,7 @@ use crate::sync::discovery::{
 };
 use crate::sync::tombstone::{DeleteReason, DeletionRecord, TombstoneRegistry};
 use crate::sync::SyncState;
+use crate::undo::Snapshot;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SessionSourceFilter {
@@ -48,6 +61,24 @@ impl SessionSourceFilter {
     }
 }
 
+/// Which message roles `session search` matches against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchScope {
+    User,
+    Assistant,
+    Both,
+}
+
+impl SearchScope {
+    fn includes(self, role: &str) -> bool {
+        match self {
+            Self::User => role == "user",
+            Self::Assistant => role == "assistant",
+            Self::Both => true,
+        }
+    }
+}
+
 fn source_label(source: &str) -> &str {
     match source {
         "claude" => "CC",
@@ -88,7 +119,7 @@ pub struct ProjectSummary {
 }
 
 /// Session summary for listing and operations
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, serde::Serialize)]
 pub struct SessionSummary {
     pub source: String,
     pub session_id: String,
@@ -102,6 +133,8 @@ pub struct SessionSummary {
     pub first_timestamp: Option<String>,
     pub last_activity: Option<String>,
     pub file_size: u64,
+    /// Approximate token count of the conversation text
+    pub estimated_tokens: usize,
 }
 
 impl SessionSummary {
@@ -155,27 +188,31 @@ impl SessionSummary {
             first_timestamp: session.first_timestamp(),
             last_activity: session.latest_timestamp(),
             file_size,
+            estimated_tokens: session.estimated_tokens(),
         }
     }
 
     /// Get a truncated title for display (Unicode-safe)
+    ///
+    /// Lengths count grapheme clusters, so emoji sequences and combining
+    /// marks are never split.
     pub fn display_title(&self, max_chars: usize) -> String {
         let title = self.title.replace('\n', " ");
-        let chars: Vec<char> = title.chars().collect();
+        let graphemes: Vec<&str> = title.graphemes(true).collect();
 
-        if chars.len() > max_chars {
-            let truncated: String = chars[..max_chars - 3].iter().collect();
+        if graphemes.len() > max_chars {
+            let truncated = graphemes[..max_chars - 3].concat();
             format!("{}...", truncated)
         } else {
             title
         }
     }
 
-    /// Format relative time for display
-    pub fn relative_time(&self) -> String {
+    /// Format the last activity time for display
+    pub fn display_time(&self) -> String {
         self.last_activity
             .as_ref()
-            .map(|ts| format_relative_time(ts))
+            .map(|ts| format_time(ts))
             .unwrap_or_else(|| "Unknown".to_string())
     }
 }
@@ -213,6 +250,13 @@ impl SessionSummary {
             first_timestamp: session.first_timestamp(),
             last_activity: session.latest_timestamp(),
             file_size,
+            estimated_tokens: estimate_tokens(
+                session
+                    .display_messages(true)
+                    .iter()
+                    .map(|m| m.content.chars().count())
+                    .sum(),
+            ),
         }
     }
 
@@ -250,16 +294,123 @@ impl SessionSummary {
             first_timestamp: session.first_timestamp(),
             last_activity: session.latest_timestamp(),
             file_size,
+            estimated_tokens: estimate_tokens(
+                messages.iter().map(|m| m.content.chars().count()).sum(),
+            ),
         }
     }
 }
 
+/// Time format for this run, set by `ccs session --absolute` or read from
+/// config.toml on first use
+static TIME_FORMAT: OnceLock<TimeFormat> = OnceLock::new();
+
+/// Use `format` for session times in this run, overriding config.toml
+pub fn set_time_format(format: TimeFormat) {
+    let _ = TIME_FORMAT.set(format);
+}
+
+fn time_format() -> TimeFormat {
+    *TIME_FORMAT.get_or_init(|| {
+        FilterConfig::load()
+            .map(|config| config.time_format)
+            .unwrap_or_default()
+    })
+}
+
+/// Format a timestamp for listings, relative or absolute per the time format
+fn format_time(timestamp: &str) -> String {
+    match time_format() {
+        TimeFormat::Relative => format_relative_time(timestamp),
+        TimeFormat::Absolute => format_absolute_time(timestamp),
+    }
+}
+
+/// Line width assumed when stdout is not a terminal
+const DEFAULT_DISPLAY_WIDTH: usize = 80;
+
+/// Title column bounds: narrow terminals still get a readable title, and very
+/// wide ones do not push the columns after it out of sight
+const MIN_TITLE_CHARS: usize = 20;
+const MAX_TITLE_CHARS: usize = 120;
+
+/// Width of the time column, sized for absolute times ("2025-01-15 14:30")
+const TIME_COLUMN_CHARS: usize = 16;
+
+/// Listing width for this run, set by `ccs session --width` or taken from
+/// the terminal on first use
+static DISPLAY_WIDTH: OnceLock<usize> = OnceLock::new();
+
+/// Use `width` columns for session listings in this run instead of the
+/// terminal width
+pub fn set_display_width(width: usize) {
+    let _ = DISPLAY_WIDTH.set(width);
+}
+
+fn display_width() -> usize {
+    *DISPLAY_WIDTH.get_or_init(|| {
+        terminal_size::terminal_size()
+            .map(|(terminal_size::Width(width), _)| width as usize)
+            .unwrap_or(DEFAULT_DISPLAY_WIDTH)
+    })
+}
+
+/// Title length for a listing line whose other columns take `reserved` chars
+fn title_width(reserved: usize) -> usize {
+    title_width_for(display_width(), reserved)
+}
+
+fn title_width_for(display_width: usize, reserved: usize) -> usize {
+    display_width
+        .saturating_sub(reserved)
+        .clamp(MIN_TITLE_CHARS, MAX_TITLE_CHARS)
+}
+
+/// Format a timestamp as local date and time (e.g., "2025-01-15 14:30")
+///
+/// Converted with the local time zone's rules at that instant, so times on
+/// either side of a DST change are both correct.
+fn format_absolute_time(timestamp: &str) -> String {
+    match chrono::DateTime::parse_from_rfc3339(timestamp) {
+        Ok(dt) => dt
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string(),
+        Err(_) => "Unknown".to_string(),
+    }
+}
+
 /// Format a timestamp as relative time (e.g., "Today", "Yesterday", "3 days ago")
 fn format_relative_time(timestamp: &str) -> String {
+    format_relative_time_at(timestamp, chrono::Utc::now())
+}
+
+/// Whole calendar months from `from` to `to`, e.g. 1 from Jan 15 to Feb 15
+/// but 0 to Feb 14. Month ends are clamped, so Jan 31 + 1 month is Feb 28/29.
+fn calendar_months_between(
+    from: chrono::DateTime<chrono::Utc>,
+    to: chrono::DateTime<chrono::Utc>,
+) -> u32 {
+    use chrono::{Datelike, Months};
+
+    let months = (to.year() - from.year()) * 12 + to.month() as i32 - from.month() as i32;
+    let Ok(months) = u32::try_from(months) else {
+        return 0;
+    };
+    match from.checked_add_months(Months::new(months)) {
+        Some(reached) if reached <= to => months,
+        _ => months.saturating_sub(1),
+    }
+}
+
+/// [`format_relative_time`] relative to `now`
+///
+/// Weeks and days come from the elapsed duration; months and years are
+/// calendar months, so "1 month ago" means the same day of the previous month.
+fn format_relative_time_at(timestamp: &str, now: chrono::DateTime<chrono::Utc>) -> String {
     use chrono::{DateTime, Utc};
 
     if let Ok(dt) = DateTime::parse_from_rfc3339(timestamp) {
-        let now = Utc::now();
         let dt_utc = dt.with_timezone(&Utc);
         let duration = now.signed_duration_since(dt_utc);
 
@@ -283,19 +434,28 @@ fn format_relative_time(timestamp: &str) -> String {
             "Yesterday".to_string()
         } else if days < 7 {
             format!("{} days ago", days)
-        } else if days < 30 {
-            let weeks = days / 7;
-            if weeks == 1 {
-                "1 week ago".to_string()
-            } else {
-                format!("{} weeks ago", weeks)
-            }
         } else {
-            let months = days / 30;
-            if months == 1 {
-                "1 month ago".to_string()
+            let months = calendar_months_between(dt_utc, now);
+            if months == 0 {
+                let weeks = days / 7;
+                if weeks == 1 {
+                    "1 week ago".to_string()
+                } else {
+                    format!("{} weeks ago", weeks)
+                }
+            } else if months < 12 {
+                if months == 1 {
+                    "1 month ago".to_string()
+                } else {
+                    format!("{} months ago", months)
+                }
             } else {
-                format!("{} months ago", months)
+                let years = months / 12;
+                if years == 1 {
+                    "1 year ago".to_string()
+                } else {
+                    format!("{} years ago", years)
+                }
             }
         }
     } else {
@@ -324,6 +484,7 @@ enum ActionChoice {
     OpenInEditor,
     ViewDetails,
     Rename,
+    ResolveConflict,
     Delete,
     Back,
 }
@@ -340,86 +501,104 @@ pub fn scan_all_projects() -> Result<Vec<ProjectSummary>> {
         return Ok(Vec::new());
     }
 
-    let mut projects = Vec::new();
     // Use a filter with no file size limit for session listing
     let filter = FilterConfig::no_size_limit();
 
+    let mut dirs = Vec::new();
     for entry in fs::read_dir(&claude_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-
-        if !path.is_dir() {
-            continue;
-        }
-
-        let dir_name = path
+        let path = entry?.path();
+        let hidden = path
             .file_name()
             .and_then(|n| n.to_str())
-            .unwrap_or_default();
-
+            .is_none_or(|n| n.starts_with('.'));
         // Skip hidden directories
-        if dir_name.starts_with('.') {
-            continue;
+        if path.is_dir() && !hidden {
+            dirs.push(path);
         }
+    }
 
-        // Scan sessions in this project
-        let sessions = discover_sessions(&path, &filter).unwrap_or_default();
+    // Projects are independent, so scan them in parallel; `collect` keeps
+    // the directory order, which the stable sort below relies on for ties
+    let mut projects: Vec<ProjectSummary> = dirs
+        .into_par_iter()
+        .filter_map(|path| summarize_project(path, &filter))
+        .collect();
 
-        if sessions.is_empty() {
-            continue;
-        }
+    // Sort by last activity (most recent first)
+    projects.sort_by(|a, b| b.last_activity.cmp(&a.last_activity));
 
-        // Get project name from session's cwd field (more accurate than directory name)
-        // Fall back to extract_project_name if no cwd is available, unless it's a
-        // non-ASCII encoded dir ending in '-'
-        let project_name = sessions
-            .iter()
-            .find_map(|s| s.project_name().map(|n| n.to_string()))
-            .unwrap_or_else(|| {
-                if dir_name.ends_with('-') {
-                    dir_name.to_string()
-                } else {
-                    extract_project_name(dir_name).to_string()
-                }
-            });
+    Ok(projects)
+}
 
-        // Count only valid sessions (with messages and real titles)
-        let valid_session_count = sessions.iter().filter(|s| is_valid_session(s)).count();
+/// Summarize one project directory, or `None` if it has no valid sessions
+fn summarize_project(path: PathBuf, filter: &FilterConfig) -> Option<ProjectSummary> {
+    let dir_name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
 
-        // Skip projects with no valid sessions
-        if valid_session_count == 0 {
-            continue;
-        }
+    // Scan sessions in this project
+    let sessions = discover_sessions(&path, filter).unwrap_or_default();
 
-        // Find latest activity from valid sessions only
-        let last_activity = sessions
-            .iter()
-            .filter(|s| s.message_count() > 0)
-            .filter_map(|s| s.latest_timestamp())
-            .max();
+    if sessions.is_empty() {
+        return None;
+    }
 
-        projects.push(ProjectSummary {
-            name: project_name,
-            dir_path: path,
-            session_count: valid_session_count,
-            last_activity,
+    // Get project name from session's cwd field (more accurate than directory name)
+    // Fall back to extract_project_name if no cwd is available, unless it's a
+    // non-ASCII encoded dir ending in '-'
+    let project_name = sessions
+        .iter()
+        .find_map(|s| s.project_name().map(|n| n.to_string()))
+        .unwrap_or_else(|| {
+            if dir_name.ends_with('-') {
+                dir_name.to_string()
+            } else {
+                extract_project_name(dir_name).to_string()
+            }
         });
+
+    // Count only valid sessions (with messages and real titles)
+    let valid_session_count = sessions.iter().filter(|s| is_valid_session(s)).count();
+
+    // Skip projects with no valid sessions
+    if valid_session_count == 0 {
+        return None;
     }
 
-    // Sort by last activity (most recent first)
-    projects.sort_by(|a, b| b.last_activity.cmp(&a.last_activity));
+    // Find latest activity from valid sessions only
+    let last_activity = sessions
+        .iter()
+        .filter(|s| s.message_count() > 0)
+        .filter_map(|s| s.latest_timestamp())
+        .max();
+
+    Some(ProjectSummary {
+        name: project_name,
+        dir_path: path,
+        session_count: valid_session_count,
+        last_activity,
+    })
+}
 
-    Ok(projects)
+/// Cleanup rules from config.toml (`[cleanup]`), loaded once per process
+fn cleanup_rules() -> &'static CleanupSettings {
+    static RULES: OnceLock<CleanupSettings> = OnceLock::new();
+    RULES.get_or_init(|| {
+        FilterConfig::load()
+            .map(|config| config.cleanup)
+            .unwrap_or_default()
+    })
 }
 
-/// Check if a ConversationSession is valid (has messages and a real title)
+/// Check if a ConversationSession is valid (not noise per the cleanup rules)
 fn is_valid_session(session: &ConversationSession) -> bool {
-    session.message_count() > 0 && session.title().is_some()
+    cleanup_rules().keeps(session.message_count(), session.title().is_some())
 }
 
-/// Check if a SessionSummary is valid (has messages and a real title)
+/// Check if a SessionSummary is valid (not noise per the cleanup rules)
 fn is_valid_session_summary(summary: &SessionSummary) -> bool {
-    summary.message_count > 0 && summary.title != "(No title)"
+    cleanup_rules().keeps(summary.message_count, summary.title != "(No title)")
 }
 
 /// Scan sessions for a specific project, returns (valid_sessions, filtered_count)
@@ -480,10 +659,18 @@ fn scan_all_session_summaries(
     cache.retain_existing(&seen_paths);
     cache.save(&config_dir);
 
-    summaries.sort_by(|a, b| b.last_activity.cmp(&a.last_activity));
+    summaries.sort_by(newest_first);
     Ok(summaries)
 }
 
+/// Most recent activity first; the session ID breaks ties so the order (and
+/// `session list` paging) is the same on every run
+fn newest_first(a: &SessionSummary, b: &SessionSummary) -> std::cmp::Ordering {
+    b.last_activity
+        .cmp(&a.last_activity)
+        .then_with(|| a.session_id.cmp(&b.session_id))
+}
+
 /// Scan Claude Code sessions with index cache.
 ///
 /// For each JSONL file: stat() for size+mtime → cache lookup.
@@ -846,6 +1033,71 @@ pub fn rename_session(file_path: &Path, session_id: &str, new_title: &str) -> Re
     Ok(())
 }
 
+/// Marker field on `custom-title` entries written by a rename undo
+const RENAME_UNDO_MARKER: &str = "ccsRenameUndo";
+
+/// Find the title a session had before its latest (not yet undone) rename.
+///
+/// Titles are append-only `custom-title` entries. Renames push onto a title
+/// stack and entries written by [`undo_rename_session`] pop it, so repeated
+/// undos walk back through the history. An empty string means the title was
+/// derived from the first user message. Returns `None` when there is no
+/// rename left to undo.
+pub fn previous_custom_title(file_path: &Path) -> Result<Option<String>> {
+    let content = fs::read_to_string(file_path)
+        .with_context(|| format!("Failed to read file: {}", file_path.display()))?;
+
+    let mut titles: Vec<String> = Vec::new();
+    for entry in content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter(|entry| entry.get("type").and_then(|t| t.as_str()) == Some("custom-title"))
+    {
+        if entry.get(RENAME_UNDO_MARKER).is_some() {
+            titles.pop();
+        } else {
+            let title = entry
+                .get("customTitle")
+                .and_then(|t| t.as_str())
+                .unwrap_or_default();
+            titles.push(title.to_string());
+        }
+    }
+
+    Ok(match titles.len() {
+        0 => None,
+        1 => Some(String::new()),
+        n => Some(titles[n - 2].clone()),
+    })
+}
+
+/// Revert the latest rename by appending a `custom-title` entry with the
+/// previous title. Returns `false` when there is no rename to undo.
+pub fn undo_rename_session(file_path: &Path, session_id: &str) -> Result<bool> {
+    use std::io::Write;
+
+    let Some(previous) = previous_custom_title(file_path)? else {
+        return Ok(false);
+    };
+
+    let entry = json!({
+        "type": "custom-title",
+        "customTitle": previous,
+        "sessionId": session_id,
+        RENAME_UNDO_MARKER: true,
+    });
+
+    let mut file = fs::OpenOptions::new()
+        .append(true)
+        .open(file_path)
+        .with_context(|| format!("Failed to open file: {}", file_path.display()))?;
+
+    writeln!(file, "{}", serde_json::to_string(&entry)?)
+        .with_context(|| format!("Failed to write to file: {}", file_path.display()))?;
+
+    Ok(true)
+}
+
 /// Delete a session file from the local filesystem only.
 ///
 /// This is the low-level primitive: it removes the `.jsonl` file from
@@ -862,7 +1114,7 @@ pub fn delete_session(file_path: &Path) -> Result<()> {
 }
 
 /// Compute the path of a session file relative to the sync repo's `projects/`
-/// directory, mirroring [`crate::sync::push`]'s `compute_relative_path` logic
+/// directory, mirroring [`crate::sync::discovery::session_repo_path`]
 /// but operating on a [`SessionSummary`] instead of a [`ConversationSession`].
 ///
 /// Returns `None` when the session is not under `~/.claude/projects/`
@@ -906,6 +1158,46 @@ fn build_deletion_record(
     }
 }
 
+/// Snapshot a session file before it is deleted, so the deletion can be
+/// reverted with `ccs undo session`.
+fn snapshot_session_file(session: &SessionSummary) -> Result<PathBuf> {
+    Snapshot::create(OperationType::SessionDelete, [&session.file_path], None)
+        .and_then(|snapshot| snapshot.save_to_disk(None))
+        .context("Failed to snapshot session before deletion")
+}
+
+/// Record a session delete/rename in the operation history, so it shows up
+/// in `ccs history` and can be reverted with `ccs undo session`.
+///
+/// Failures are only logged: the operation itself has already succeeded.
+fn record_session_operation(
+    session: &SessionSummary,
+    operation_type: OperationType,
+    snapshot_path: Option<PathBuf>,
+) {
+    let result = ConversationSummary::new(
+        session.session_id.clone(),
+        session.file_path.to_string_lossy().to_string(),
+        session.last_activity.clone(),
+        session.message_count,
+        SyncOperation::Modified,
+    )
+    .and_then(|summary| {
+        let mut record = OperationRecord::new(operation_type, None, vec![summary]);
+        record.snapshot_path = snapshot_path;
+        OperationHistory::load()?.add_operation(record)
+    });
+
+    if let Err(e) = result {
+        log::warn!(
+            "Failed to record {} of session {} in history: {}",
+            operation_type.as_str(),
+            session.session_id,
+            e
+        );
+    }
+}
+
 /// Delete a single session: remove the local file, and for Claude sessions
 /// also remove the sync-repo copy, register a tombstone, and commit.
 ///
@@ -918,8 +1210,14 @@ fn build_deletion_record(
 ///
 /// `reason` drives both the tombstone entry and the commit message prefix.
 pub fn delete_session_with_commit(session: &SessionSummary, reason: DeleteReason) -> Result<()> {
-    // 1. Always remove the local file first.
-    delete_session(&session.file_path)?;
+    // 1. Always remove the local file first, keeping a snapshot so the
+    //    deletion can be undone.
+    let snapshot_path = snapshot_session_file(session)?;
+    if let Err(e) = delete_session(&session.file_path) {
+        let _ = fs::remove_file(&snapshot_path);
+        return Err(e);
+    }
+    record_session_operation(session, OperationType::SessionDelete, Some(snapshot_path));
 
     // 2. Codex sessions have no sync-repo representation; nothing more to do.
     let filter = FilterConfig::load()?;
@@ -933,7 +1231,7 @@ pub fn delete_session_with_commit(session: &SessionSummary, reason: DeleteReason
     };
 
     let state = SyncState::load()?;
-    let projects_dir = state.sync_repo_path.join(&filter.sync_subdirectory);
+    let projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
     let repo_file = projects_dir.join(&repo_rel);
 
     // 3. Remove the sync-repo copy if present. Missing is fine (e.g. never
@@ -970,6 +1268,32 @@ pub fn delete_session_with_commit(session: &SessionSummary, reason: DeleteReason
     Ok(())
 }
 
+/// Revert the sync-repo side of [`delete_session_with_commit`]: drop the
+/// session's tombstone and commit, so the restored local file is pushed
+/// again and other devices stop treating it as deleted.
+pub fn forget_session_deletion(session_id: &str) -> Result<()> {
+    // Nothing to revert when sync was never configured.
+    let Ok(state) = SyncState::load() else {
+        return Ok(());
+    };
+
+    let mut registry = TombstoneRegistry::load(&state.sync_repo_path)?;
+    if !registry.remove(session_id) {
+        return Ok(());
+    }
+    registry.save(&state.sync_repo_path)?;
+
+    let repo = scm::open(&state.sync_repo_path)?;
+    repo.stage_all()?;
+    if repo.has_changes()? {
+        let message = format!("restore(session): {}", session_id);
+        repo.commit(&message)?;
+        log::info!("Committed session restore: {}", message);
+    }
+
+    Ok(())
+}
+
 /// Remove a session's local file and sync-repo copy, returning a tombstone
 /// record for the caller to batch-persist.
 ///
@@ -994,7 +1318,7 @@ fn remove_session_for_batch(
         return Ok(None);
     };
 
-    let projects_dir = state.sync_repo_path.join(&filter.sync_subdirectory);
+    let projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
     let repo_file = projects_dir.join(&repo_rel);
     if repo_file.exists() {
         if let Err(e) = fs::remove_file(&repo_file) {
@@ -1050,7 +1374,7 @@ fn show_project_menu(projects: &[ProjectSummary]) -> Result<ProjectMenuChoice> {
             let time = p
                 .last_activity
                 .as_ref()
-                .map(|t| format_relative_time(t))
+                .map(|t| format_time(t))
                 .unwrap_or_else(|| "Unknown".to_string());
             format!("{:<30} {:>3} sessions  {}", p.name, p.session_count, time)
         })
@@ -1112,24 +1436,27 @@ fn show_session_menu(
     let mut options: Vec<String> = Vec::with_capacity(sessions.len() + 4);
     options.push(search_option.clone());
 
+    // "> [ 1] " + title + " 123 msgs  " + time, plus "CC " for mixed sources
     let has_mixed_sources = sessions.iter().any(|s| s.source != sessions[0].source);
+    let reserved = 18 + TIME_COLUMN_CHARS + if has_mixed_sources { 3 } else { 0 };
+    let width = title_width(reserved);
     for (i, s) in sessions.iter().enumerate() {
         if has_mixed_sources {
             options.push(format!(
-                "[{:>2}] {} {:<37} {:>3} msgs  {}",
+                "[{:>2}] {} {:<width$} {:>3} msgs  {}",
                 i + 1,
                 source_label(&s.source),
-                s.display_title(37),
+                s.display_title(width),
                 s.message_count,
-                s.relative_time()
+                s.display_time()
             ));
         } else {
             options.push(format!(
-                "[{:>2}] {:<40} {:>3} msgs  {}",
+                "[{:>2}] {:<width$} {:>3} msgs  {}",
                 i + 1,
-                s.display_title(40),
+                s.display_title(width),
                 s.message_count,
-                s.relative_time()
+                s.display_time()
             ));
         }
     }
@@ -1175,7 +1502,7 @@ fn search_sessions(
 ) -> Vec<(SessionSummary, Vec<String>)> {
     // Split input into multiple keywords for AND matching
     let keywords: Vec<&str> = keyword.split_whitespace().collect();
-    search_sessions_full(sessions, &keywords, 60, true)
+    search_sessions_full(sessions, &keywords, 60, SearchScope::User)
         .into_iter()
         .map(|r| {
             let snippets = r.matches.into_iter().map(|m| m.snippet).collect();
@@ -1250,7 +1577,7 @@ fn show_search_results(
             format!("[{:>2}]", i + 1).cyan(),
             session.display_title(50).bold(),
             session.message_count,
-            session.relative_time()
+            session.display_time()
         );
         // Show first 2 matched snippets
         for snippet in snippets.iter().take(2) {
@@ -1303,6 +1630,13 @@ fn show_action_menu(session: &SessionSummary) -> Result<ActionChoice> {
         "Selected:".cyan().bold(),
         session.display_title(60).bold()
     );
+    let has_remote_divergence = find_remote_divergence(session).is_some();
+    if has_remote_divergence {
+        println!(
+            "  {} Local and sync repo versions of this session differ",
+            icon("⚠").yellow()
+        );
+    }
     println!();
 
     let is_codex = session.source == "codex";
@@ -1319,6 +1653,9 @@ fn show_action_menu(session: &SessionSummary) -> Result<ActionChoice> {
     if !is_codex {
         options.push("Rename session");
     }
+    if has_remote_divergence {
+        options.push("Resolve local/remote difference");
+    }
     options.push("Delete session");
     options.push("Back to session list");
 
@@ -1331,6 +1668,7 @@ fn show_action_menu(session: &SessionSummary) -> Result<ActionChoice> {
             s if s == open_label => Ok(ActionChoice::OpenInEditor),
             "View details" => Ok(ActionChoice::ViewDetails),
             "Rename session" => Ok(ActionChoice::Rename),
+            "Resolve local/remote difference" => Ok(ActionChoice::ResolveConflict),
             "Delete session" => Ok(ActionChoice::Delete),
             _ => Ok(ActionChoice::Back),
         },
@@ -1362,19 +1700,16 @@ fn show_session_details(session: &SessionSummary) -> Result<()> {
         session
             .first_timestamp
             .as_ref()
-            .map(|t| format_relative_time(t))
+            .map(|t| format_time(t))
             .unwrap_or_else(|| "Unknown".to_string())
     );
-    println!(
-        "{:<15} {}",
-        "Last Activity:".bold(),
-        session.relative_time()
-    );
+    println!("{:<15} {}", "Last Activity:".bold(), session.display_time());
     println!(
         "{:<15} {:.2} KB",
         "File Size:".bold(),
         session.file_size as f64 / 1024.0
     );
+    println!("{:<15} ~{}", "Tokens:".bold(), session.estimated_tokens);
     println!(
         "{:<15} {}",
         "File Path:".bold(),
@@ -1399,7 +1734,7 @@ fn show_session_details(session: &SessionSummary) -> Result<()> {
             let time_str = m
                 .timestamp
                 .as_ref()
-                .map(|t| format_relative_time(t))
+                .map(|t| format_time(t))
                 .unwrap_or_default();
 
             let role_label = match m.role.as_str() {
@@ -1458,31 +1793,7 @@ fn save_user_data(data: &UserData) -> Result<()> {
 /// Open session in editor by executing `claude --resume {session_id}` or `omp --resume {session_id}`
 /// based on the session source. Returns: Ok(true) = executed command, Ok(false) = cancelled
 fn open_in_editor(session: &SessionSummary) -> Result<bool> {
-    // Get project path from session's cwd field
-    let project_path = if let Ok(conv) = ConversationSession::from_file(&session.file_path) {
-        conv.cwd().map(|s| s.to_string())
-    } else {
-        None
-    };
-
-    // Build default command based on session source
-    let default_cmd = match session.source.as_str() {
-        "omp" => format!("omp --resume {}", session.session_id),
-        _ => format!("claude --resume {}", session.session_id),
-    };
-
-    // Try to load saved command template
-    let mut initial_cmd = default_cmd.clone();
-    if let Ok(data) = load_user_data() {
-        if let Some(template) = &data.command_template {
-            // Replace placeholders with actual values
-            let mut saved_cmd = template.replace("{session_id}", &session.session_id);
-            if let Some(ref path) = project_path {
-                saved_cmd = saved_cmd.replace("{path}", path);
-            }
-            initial_cmd = saved_cmd;
-        }
-    }
+    let (initial_cmd, default_cmd, project_path) = resume_command(session);
 
     println!();
     let cmd = Text::new("Command to execute:")
@@ -1541,78 +1852,7 @@ fn open_in_editor(session: &SessionSummary) -> Result<bool> {
                 }
             }
 
-            println!();
-            println!("{} {}", "Executing:".cyan().bold(), cmd);
-            println!();
-
-            // Execute the command using the user's preferred shell in interactive mode
-            // This ensures that aliases, functions (like claude-auto), and customized PATH
-            // environments are properly loaded before execution.
-            let status = if cfg!(target_os = "windows") {
-                // PowerShell profile scripts define user aliases/functions (e.g. a custom
-                // `cc-auto` wrapper), so we invoke `powershell -Command` instead of `cmd /C` —
-                // cmd.exe has no knowledge of the user's PowerShell profile and fails with
-                // "not recognized" for anything defined only as a PowerShell alias/function.
-                // We use raw_arg() so std::process::Command doesn't add its own quotes around
-                // the command string, which would otherwise break paths/`&&` chains.
-                #[cfg(target_os = "windows")]
-                use std::os::windows::process::CommandExt;
-                
-                #[cfg(target_os = "windows")]
-                let mut command = std::process::Command::new("powershell");
-                
-                #[cfg(target_os = "windows")]
-                {
-                    command
-                        .arg("-NoLogo")
-                        .arg("-NonInteractive")
-                        .arg("-Command")
-                        .raw_arg(&cmd);
-                    if let Some(path) = &project_path {
-                        command.current_dir(path);
-                    }
-                    command
-                        .status()
-                        .with_context(|| format!("Failed to execute command: {}", cmd))?
-                }
-                
-                #[cfg(not(target_os = "windows"))]
-                {
-                    // This branch should be unreachable when cfg!(target_os = "windows") is true, 
-                    // but we need it to compile on non-Windows platforms.
-                    let mut command = std::process::Command::new("powershell");
-                    command
-                        .arg("-NoLogo")
-                        .arg("-NonInteractive")
-                        .arg("-Command")
-                        .arg(&cmd);
-                    if let Some(path) = &project_path {
-                        command.current_dir(path);
-                    }
-                    command
-                        .status()
-                        .with_context(|| format!("Failed to execute command: {}", cmd))?
-                }
-            } else {
-                let shell = std::env::var("SHELL").unwrap_or_else(|_| "sh".to_string());
-                let mut command = std::process::Command::new(shell);
-                command.arg("-ic").arg(&cmd);
-                if let Some(ref path) = project_path {
-                    command.current_dir(path);
-                }
-                command
-                    .status()
-                    .with_context(|| format!("Failed to execute command: {}", cmd))?
-            };
-
-            if !status.success() {
-                println!(
-                    "{} Command exited with code: {}",
-                    "WARNING:".yellow().bold(),
-                    status.code().unwrap_or(-1)
-                );
-            }
-
+            run_resume_command(&cmd, project_path.as_deref())?;
             Ok(true)
         }
         Err(_) => {
@@ -1622,6 +1862,118 @@ fn open_in_editor(session: &SessionSummary) -> Result<bool> {
     }
 }
 
+/// Build the resume command for a session.
+///
+/// Returns (command, default command, project path). The command is the saved
+/// command template with placeholders filled in, or the default
+/// `claude --resume` / `omp --resume` command when no template is saved.
+fn resume_command(session: &SessionSummary) -> (String, String, Option<String>) {
+    // Get project path from session's cwd field
+    let project_path = if let Ok(conv) = ConversationSession::from_file(&session.file_path) {
+        conv.cwd().map(|s| s.to_string())
+    } else {
+        None
+    };
+
+    // Build default command based on session source
+    let default_cmd = match session.source.as_str() {
+        "omp" => format!("omp --resume {}", session.session_id),
+        _ => format!("claude --resume {}", session.session_id),
+    };
+
+    // Try to load saved command template
+    let mut cmd = default_cmd.clone();
+    if let Ok(data) = load_user_data() {
+        if let Some(template) = &data.command_template {
+            // Replace placeholders with actual values
+            let mut saved_cmd = template.replace("{session_id}", &session.session_id);
+            if let Some(ref path) = project_path {
+                saved_cmd = saved_cmd.replace("{path}", path);
+            }
+            cmd = saved_cmd;
+        }
+    }
+
+    (cmd, default_cmd, project_path)
+}
+
+/// Execute a resume command in the user's shell, from the project directory
+fn run_resume_command(cmd: &str, project_path: Option<&str>) -> Result<()> {
+    println!();
+    println!("{} {}", "Executing:".cyan().bold(), cmd);
+    println!();
+
+    // Execute the command using the user's preferred shell in interactive mode
+    // This ensures that aliases, functions (like claude-auto), and customized PATH
+    // environments are properly loaded before execution.
+    let status = if cfg!(target_os = "windows") {
+        // PowerShell profile scripts define user aliases/functions (e.g. a custom
+        // `cc-auto` wrapper), so we invoke `powershell -Command` instead of `cmd /C` —
+        // cmd.exe has no knowledge of the user's PowerShell profile and fails with
+        // "not recognized" for anything defined only as a PowerShell alias/function.
+        // We use raw_arg() so std::process::Command doesn't add its own quotes around
+        // the command string, which would otherwise break paths/`&&` chains.
+        #[cfg(target_os = "windows")]
+        use std::os::windows::process::CommandExt;
+        
+        #[cfg(target_os = "windows")]
+        let mut command = std::process::Command::new("powershell");
+        
+        #[cfg(target_os = "windows")]
+        {
+            command
+                .arg("-NoLogo")
+                .arg("-NonInteractive")
+                .arg("-Command")
+                .raw_arg(cmd);
+            if let Some(path) = project_path {
+                command.current_dir(path);
+            }
+            command
+                .status()
+                .with_context(|| format!("Failed to execute command: {}", cmd))?
+        }
+        
+        #[cfg(not(target_os = "windows"))]
+        {
+            // This branch should be unreachable when cfg!(target_os = "windows") is true, 
+            // but we need it to compile on non-Windows platforms.
+            let mut command = std::process::Command::new("powershell");
+            command
+                .arg("-NoLogo")
+                .arg("-NonInteractive")
+                .arg("-Command")
+                .arg(cmd);
+            if let Some(path) = project_path {
+                command.current_dir(path);
+            }
+            command
+                .status()
+                .with_context(|| format!("Failed to execute command: {}", cmd))?
+        }
+    } else {
+        let shell = std::env::var("SHELL").unwrap_or_else(|_| "sh".to_string());
+        let mut command = std::process::Command::new(shell);
+        command.arg("-ic").arg(cmd);
+        if let Some(path) = project_path {
+            command.current_dir(path);
+        }
+        command
+            .status()
+            .with_context(|| format!("Failed to execute command: {}", cmd))?
+    };
+
+    if !status.success() {
+        println!(
+            "{} Command exited with code: {}",
+            "WARNING:".yellow().bold(),
+            status.code().unwrap_or(-1)
+        );
+    }
+
+    Ok(())
+}
+
 /// Interactive rename session
 fn rename_session_interactive(session: &mut SessionSummary) -> Result<bool> {
     println!();
@@ -1647,6 +1999,7 @@ fn rename_session_interactive(session: &mut SessionSummary) -> Result<bool> {
             }
 
             rename_session(&session.file_path, &session.session_id, &title)?;
+            record_session_operation(session, OperationType::SessionRename, None);
             session.title = title.clone();
 
             println!();
@@ -1662,24 +2015,137 @@ fn rename_session_interactive(session: &mut SessionSummary) -> Result<bool> {
 }
 
 /// Interactive delete session
-fn delete_session_interactive(session: &SessionSummary) -> Result<bool> {
-    println!();
-    println!(
-        "{} {}",
-        "WARNING:".red().bold(),
-        "You are about to delete this session:".red()
+/// Locate the sync-repo copy of a Claude session and return both versions
+/// `(local, remote)` when their content differs.
+fn find_remote_divergence(
+    session: &SessionSummary,
+) -> Option<(ConversationSession, ConversationSession)> {
+    let filter = FilterConfig::load().ok()?;
+    let repo_rel = repo_relative_path(session, &filter)?;
+    let state = SyncState::load().ok()?;
+    let remote_file = filter
+        .repo_projects_dir(&state.sync_repo_path)
+        .join(repo_rel);
+    if !remote_file.is_file() {
+        return None;
+    }
+
+    let local = ConversationSession::from_file(&session.file_path).ok()?;
+    let remote = ConversationSession::from_file(&remote_file).ok()?;
+    (local.content_hash() != remote.content_hash()).then_some((local, remote))
+}
+
+/// Write the version chosen for a local/remote difference
+///
+/// - `KeepLocal`: local content overwrites the sync-repo copy
+/// - `KeepRemote`: sync-repo content overwrites the local file
+/// - `KeepBoth`: the remote version is saved next to the local file with a
+///   `-conflict-<timestamp>` suffix; returns that path
+fn apply_session_resolution(
+    action: &ResolutionAction,
+    local: &ConversationSession,
+    remote: &ConversationSession,
+) -> Result<Option<PathBuf>> {
+    match action {
+        ResolutionAction::KeepLocal => {
+            local.write_to_file(&remote.file_path)?;
+            Ok(None)
+        }
+        ResolutionAction::KeepRemote => {
+            remote.write_to_file(&local.file_path)?;
+            Ok(None)
+        }
+        ResolutionAction::KeepBoth => {
+            let local_path = Path::new(&local.file_path);
+            let stem = local_path
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .unwrap_or(&local.session_id);
+            let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+            let renamed = local_path.with_file_name(format!("{stem}-conflict-{timestamp}.jsonl"));
+            remote.write_to_file(&renamed)?;
+            Ok(Some(renamed))
+        }
+        ResolutionAction::SmartMerge | ResolutionAction::ViewDetails => Ok(None),
+    }
+}
+
+/// Resolve a difference between the local and sync-repo copy of a session
+///
+/// Returns `true` if files were changed (session list should refresh).
+fn resolve_session_conflict_interactive(session: &SessionSummary) -> Result<bool> {
+    let Some((local, remote)) = find_remote_divergence(session) else {
+        println!(
+            "{}",
+            "Local and sync repo versions are identical.".green()
+        );
+        return Ok(false);
+    };
+
+    let mut conflict = Conflict::new(&local, &remote);
+    let action = match prompt_keep_resolution(&conflict) {
+        Ok(action) => action,
+        Err(_) => {
+            println!("{}", "Resolution cancelled.".yellow());
+            return Ok(false);
+        }
+    };
+
+    let renamed = apply_session_resolution(&action, &local, &remote)?;
+    conflict.resolution = match (&action, renamed) {
+        (ResolutionAction::KeepRemote, _) => ConflictResolution::KeepRemote,
+        (ResolutionAction::KeepBoth, Some(path)) => {
+            println!(
+                "  {} Saved remote version as: {}",
+                icon("✓").cyan(),
+                path.display()
+            );
+            ConflictResolution::KeepBoth {
+                renamed_remote_file: path,
+            }
+        }
+        _ => ConflictResolution::KeepLocal,
+    };
+
+    if let Err(e) = save_conflict_report(&ConflictReport::from_conflicts(&[conflict])) {
+        log::warn!("Failed to save conflict report: {}", e);
+    }
+
+    println!(
+        "{} Resolved: {}",
+        "SUCCESS:".green().bold(),
+        action
+    );
+    println!(
+        "  Run '{}' to sync the result.",
+        format!("{} push", crate::BINARY_NAME).cyan()
+    );
+    Ok(true)
+}
+
+fn delete_session_interactive(session: &SessionSummary) -> Result<bool> {
+    println!();
+    println!(
+        "{} {}",
+        "WARNING:".red().bold(),
+        "You are about to delete this session:".red()
     );
     println!();
     println!("  Title: {}", session.display_title(50));
     println!("  Messages: {}", session.message_count);
     println!("  File: {}", session.file_path.display());
     println!();
-    println!("{}", "This action cannot be undone!".red().bold());
+    println!(
+        "{}",
+        format!("Run '{} undo session' to restore it.", crate::BINARY_NAME).dimmed()
+    );
     println!();
 
-    let confirm = Confirm::new("Are you sure you want to delete this session?")
-        .with_default(false)
-        .prompt();
+    let confirm = interactive_conflict::confirm(
+        "Are you sure you want to delete this session?",
+        false,
+        interactive_conflict::assume_yes(),
+    );
 
     match confirm {
         Ok(true) => {
@@ -1716,9 +2182,10 @@ fn cleanup_sessions_interactive(project: &ProjectSummary) -> Result<usize> {
 
     println!();
     println!(
-        "{} Found {} filtered sessions (empty or no title):",
+        "{} Found {} filtered sessions ({}):",
         "Cleanup:".cyan().bold(),
-        filtered_sessions.len()
+        filtered_sessions.len(),
+        cleanup_rules().describe()
     );
     println!();
 
@@ -1744,12 +2211,11 @@ fn cleanup_sessions_interactive(project: &ProjectSummary) -> Result<usize> {
     println!("{}", "This action cannot be undone!".red().bold());
     println!();
 
-    let confirm = Confirm::new(&format!(
-        "Delete all {} filtered sessions?",
-        filtered_sessions.len()
-    ))
-    .with_default(false)
-    .prompt();
+    let confirm = interactive_conflict::confirm(
+        &format!("Delete all {} filtered sessions?", filtered_sessions.len()),
+        false,
+        interactive_conflict::assume_yes(),
+    );
 
     match confirm {
         Ok(true) => {
@@ -1832,11 +2298,145 @@ fn cleanup_sessions_interactive(project: &ProjectSummary) -> Result<usize> {
 // Main Entry Point
 // ============================================================================
 
+/// Scan results shared across menu transitions of the interactive manager
+///
+/// Scanning re-parses every session file, so the interactive loop keeps the
+/// last scan here and only calls [`SessionIndex::refresh`] after an action
+/// that changed sessions on disk (rename, delete, conflict resolution,
+/// cleanup).
+struct SessionIndex {
+    source: SessionSourceFilter,
+    sessions: Vec<SessionSummary>,
+    projects: Vec<ProjectSummary>,
+    /// Claude project directories, scanned on first use
+    claude_projects: Option<Vec<ProjectSummary>>,
+    /// Filtered (cleanup candidate) session counts per project name
+    filtered_counts: std::collections::HashMap<String, usize>,
+}
+
+impl SessionIndex {
+    fn load(source: SessionSourceFilter) -> Result<Self> {
+        let sessions = scan_all_session_summaries(None, source)?;
+        let projects = build_projects_from_sessions(&sessions);
+        Ok(Self {
+            source,
+            sessions,
+            projects,
+            claude_projects: None,
+            filtered_counts: std::collections::HashMap::new(),
+        })
+    }
+
+    /// Re-scan after sessions changed on disk
+    fn refresh(&mut self) -> Result<()> {
+        *self = Self::load(self.source)?;
+        Ok(())
+    }
+
+    /// Sessions belonging to a project
+    fn project_sessions(&self, project_name: &str) -> Vec<SessionSummary> {
+        self.sessions
+            .iter()
+            .filter(|s| s.project_name == project_name)
+            .cloned()
+            .collect()
+    }
+
+    /// The Claude project directory for a project name, if there is one
+    fn claude_project(&mut self, project_name: &str) -> Result<Option<ProjectSummary>> {
+        if self.claude_projects.is_none() {
+            self.claude_projects = Some(scan_all_projects()?);
+        }
+        Ok(self
+            .claude_projects
+            .iter()
+            .flatten()
+            .find(|p| p.name == project_name)
+            .cloned())
+    }
+
+    /// Number of sessions the cleanup action would offer to delete
+    fn filtered_count(&mut self, project_name: &str) -> Result<usize> {
+        if let Some(&count) = self.filtered_counts.get(project_name) {
+            return Ok(count);
+        }
+        let count = match self.claude_project(project_name)? {
+            Some(project) => get_filtered_sessions(&project)
+                .map(|f| f.len())
+                .unwrap_or(0),
+            None => 0,
+        };
+        self.filtered_counts.insert(project_name.to_string(), count);
+        Ok(count)
+    }
+}
+
 /// Main interactive session management handler
 pub fn handle_session_interactive(
     project_filter: Option<&str>,
     source: SessionSourceFilter,
 ) -> Result<()> {
+    let Some(index) = load_interactive_index(source)? else {
+        return Ok(());
+    };
+
+    // Try to detect current project or use filter
+    let initial_project = if let Some(name) = project_filter {
+        index.projects.iter().find(|p| p.name == name).cloned()
+    } else {
+        detect_current_project()?
+    };
+
+    if let Some(ref proj) = initial_project {
+        println!();
+        println!(
+            "{} Detected current project: {}",
+            "INFO:".cyan(),
+            proj.name.bold()
+        );
+    }
+
+    run_session_manager(index, initial_project, source)
+}
+
+/// Open the session menu of a project directly (`ccs open`)
+///
+/// The project is matched by exact name, then through its local Claude
+/// project directory ([`find_local_project_by_name`]), then by a unique
+/// case-insensitive name. When nothing matches, similar project names are
+/// suggested.
+pub fn handle_open(project: &str) -> Result<()> {
+    let source = SessionSourceFilter::All;
+    let Some(index) = load_interactive_index(source)? else {
+        return Ok(());
+    };
+
+    let claude_dir = claude_projects_dir().ok();
+    let Some(found) = resolve_project(&index, claude_dir.as_deref(), project) else {
+        let names: Vec<&str> = index.projects.iter().map(|p| p.name.as_str()).collect();
+        let suggestions = similar_project_names(&names, project);
+        if !suggestions.is_empty() {
+            println!();
+            println!("{}", "Did you mean:".yellow());
+            for name in suggestions {
+                println!("  {}", name);
+            }
+        }
+        return Err(SyncError::NotFound {
+            kind: "Project",
+            name: project.to_string(),
+        }
+        .into());
+    };
+
+    println!();
+    println!("{} Opening project: {}", "INFO:".cyan(), found.name.bold());
+    run_session_manager(index, Some(found), source)
+}
+
+/// Check for a terminal, print the session manager header and load the
+/// session index; `None` when there are no sessions at all
+fn load_interactive_index(source: SessionSourceFilter) -> Result<Option<SessionIndex>> {
     // Check if running in interactive terminal
     if !atty::is(atty::Stream::Stdout) {
         anyhow::bail!(
@@ -1849,53 +2449,108 @@ pub fn handle_session_interactive(
     println!("{}", "=".repeat(40).cyan());
 
     // Load all sessions (Claude + Codex) and group into projects
-    let mut all_sessions = scan_all_session_summaries(None, source)?;
-    let mut projects = build_projects_from_sessions(&all_sessions);
+    let index = SessionIndex::load(source)?;
 
-    if projects.is_empty() {
+    if index.projects.is_empty() {
         println!("{}", "No sessions found.".yellow());
         println!(
             "{}",
             "Run Claude Code or Codex in a project directory first.".dimmed()
         );
-        return Ok(());
+        return Ok(None);
     }
+    Ok(Some(index))
+}
 
-    // Try to detect current project or use filter
-    let initial_project = if let Some(name) = project_filter {
-        projects.iter().find(|p| p.name == name).cloned()
-    } else {
-        detect_current_project()?
-    };
+/// Project for `ccs open`, see [`handle_open`]
+fn resolve_project(
+    index: &SessionIndex,
+    claude_dir: Option<&Path>,
+    name: &str,
+) -> Option<ProjectSummary> {
+    let by_name = |name: &str| index.projects.iter().find(|p| p.name == name).cloned();
+    if let Some(project) = by_name(name) {
+        return Some(project);
+    }
 
-    // Start with detected project or project list
-    let mut current_project = initial_project.clone();
+    // A project can span several directories, so go through its sessions
+    if let Some(dir) = claude_dir.and_then(|dir| find_local_project_by_name(dir, name)) {
+        let session = index.sessions.iter().find(|s| s.project_dir == dir);
+        if let Some(project) = session.and_then(|s| by_name(&s.project_name)) {
+            return Some(project);
+        }
+    }
 
-    if let Some(ref proj) = current_project {
-        println!();
-        println!(
-            "{} Detected current project: {}",
-            "INFO:".cyan(),
-            proj.name.bold()
-        );
+    let lower = name.to_lowercase();
+    let mut matches = index
+        .projects
+        .iter()
+        .filter(|p| p.name.to_lowercase() == lower);
+    match (matches.next(), matches.next()) {
+        (Some(project), None) => Some(project.clone()),
+        _ => None,
+    }
+}
+
+/// Project names close to `query`: those containing it (ignoring case) or
+/// within a small edit distance, closest first
+fn similar_project_names<'a>(names: &[&'a str], query: &str) -> Vec<&'a str> {
+    const MAX_SUGGESTIONS: usize = 5;
+    let query = query.to_lowercase();
+    let max_distance = (query.chars().count() / 3).max(1);
+
+    let mut scored: Vec<(usize, &str)> = names
+        .iter()
+        .filter_map(|&name| {
+            let lower = name.to_lowercase();
+            let distance = edit_distance(&lower, &query);
+            let contains = lower.contains(&query) || query.contains(&lower);
+            (contains || distance <= max_distance).then_some((distance, name))
+        })
+        .collect();
+    scored.sort();
+    scored.dedup_by(|a, b| a.1 == b.1);
+    scored
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, name)| name)
+        .collect()
+}
+
+/// Levenshtein distance between two strings, by characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = diagonal + usize::from(ca != cb);
+            diagonal = row[j + 1];
+            row[j + 1] = substitution.min(row[j] + 1).min(diagonal + 1);
+        }
     }
+    row[b.len()]
+}
+
+/// The interactive session manager loop, starting in `initial_project`'s
+/// session menu or, without one, in the project list
+fn run_session_manager(
+    mut index: SessionIndex,
+    initial_project: Option<ProjectSummary>,
+    source: SessionSourceFilter,
+) -> Result<()> {
+    // Start with detected project or project list
+    let mut current_project = initial_project;
 
     loop {
         if let Some(ref project) = current_project {
             // Filter sessions for this project from the pre-loaded list
-            let sessions: Vec<SessionSummary> = all_sessions
-                .iter()
-                .filter(|s| s.project_name == project.name)
-                .cloned()
-                .collect();
+            let sessions = index.project_sessions(&project.name);
 
             // Cleanup count is only meaningful for Claude projects
             let filtered_count = if source.includes_claude() {
-                scan_all_projects()?
-                    .iter()
-                    .find(|p| p.name == project.name)
-                    .map(|p| get_filtered_sessions(p).map(|f| f.len()).unwrap_or(0))
-                    .unwrap_or(0)
+                index.filtered_count(&project.name)?
             } else {
                 0
             };
@@ -1919,6 +2574,11 @@ pub fn handle_session_interactive(
                                     list_needs_refresh = true;
                                 }
                             }
+                            ActionChoice::ResolveConflict => {
+                                if resolve_session_conflict_interactive(&session)? {
+                                    list_needs_refresh = true;
+                                }
+                            }
                             ActionChoice::Delete => {
                                 if delete_session_interactive(&session)? {
                                     list_needs_refresh = true;
@@ -1931,7 +2591,7 @@ pub fn handle_session_interactive(
                         }
                     }
                     if list_needs_refresh {
-                        all_sessions = scan_all_session_summaries(None, source)?;
+                        index.refresh()?;
                     }
                 }
                 SessionMenuChoice::Search => {
@@ -1960,6 +2620,11 @@ pub fn handle_session_interactive(
                                                 list_needs_refresh = true;
                                             }
                                         }
+                                        ActionChoice::ResolveConflict => {
+                                            if resolve_session_conflict_interactive(&session)? {
+                                                list_needs_refresh = true;
+                                            }
+                                        }
                                         ActionChoice::Delete => {
                                             if delete_session_interactive(&session)? {
                                                 list_needs_refresh = true;
@@ -1972,24 +2637,23 @@ pub fn handle_session_interactive(
                                     }
                                 }
                                 if list_needs_refresh {
-                                    all_sessions = scan_all_session_summaries(None, source)?;
+                                    index.refresh()?;
                                 }
                             }
                         }
                     }
                 }
                 SessionMenuChoice::Cleanup => {
-                    if let Some(claude_project) =
-                        scan_all_projects()?.iter().find(|p| p.name == project.name)
-                    {
-                        cleanup_sessions_interactive(claude_project)?;
+                    if let Some(claude_project) = index.claude_project(&project.name)? {
+                        if cleanup_sessions_interactive(&claude_project)? > 0 {
+                            index.refresh()?;
+                        }
                     } else {
                         println!(
                             "{}",
                             "Cleanup is only available for Claude sessions.".yellow()
                         );
                     }
-                    all_sessions = scan_all_session_summaries(None, source)?;
                 }
                 SessionMenuChoice::SwitchProject => {
                     current_project = None;
@@ -1999,11 +2663,7 @@ pub fn handle_session_interactive(
                 }
             }
         } else {
-            // Refresh sessions and projects
-            all_sessions = scan_all_session_summaries(None, source)?;
-            projects = build_projects_from_sessions(&all_sessions);
-
-            match show_project_menu(&projects)? {
+            match show_project_menu(&index.projects)? {
                 ProjectMenuChoice::Select(project) => {
                     current_project = Some(project);
                 }
@@ -2023,72 +2683,267 @@ pub fn handle_session_interactive(
 // Non-Interactive Handlers
 // ============================================================================
 
+/// Skip `offset` items and keep at most `limit`, returning the page and the
+/// number of items left after it
+fn paginate<T>(items: Vec<T>, offset: usize, limit: Option<usize>) -> (Vec<T>, usize) {
+    let mut page: Vec<T> = items.into_iter().skip(offset).collect();
+    let remaining = match limit {
+        Some(limit) if page.len() > limit => page.split_off(limit).len(),
+        _ => 0,
+    };
+    (page, remaining)
+}
+
+/// Delimited table formats for `session list`, for spreadsheets
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableFormat {
+    /// Tab-separated values
+    Tsv,
+    /// Comma-separated values
+    Csv,
+}
+
+/// Write sessions as a delimited table with a header row
+///
+/// Fields containing the delimiter, quotes or line breaks are quoted, with
+/// embedded quotes doubled.
+fn write_session_table(
+    sessions: &[&SessionSummary],
+    format: TableFormat,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let delimiter = match format {
+        TableFormat::Tsv => b'\t',
+        TableFormat::Csv => b',',
+    };
+    let mut writer = csv::WriterBuilder::new()
+        .delimiter(delimiter)
+        .from_writer(out);
+    writer.write_record([
+        "project",
+        "session_id",
+        "title",
+        "message_count",
+        "last_activity",
+        "file_size",
+    ])?;
+    for session in sessions {
+        writer.write_record([
+            session.project_name.as_str(),
+            session.session_id.as_str(),
+            session.title.as_str(),
+            &session.message_count.to_string(),
+            session.last_activity.as_deref().unwrap_or(""),
+            &session.file_size.to_string(),
+        ])?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
 /// List sessions (non-interactive)
+///
+/// `limit`/`offset` page through the sessions, newest first, either over the
+/// whole list or within each project when `per_project` is set. `json` and
+/// `table` print the page as one newest-first list instead of by project.
+#[allow(clippy::too_many_arguments)]
 pub fn handle_session_list(
     project_filter: Option<&str>,
     show_ids: bool,
     source: SessionSourceFilter,
+    json: bool,
+    table: Option<TableFormat>,
+    limit: Option<usize>,
+    offset: usize,
+    per_project: bool,
+    out: &mut dyn Write,
 ) -> Result<()> {
     let sessions = scan_all_session_summaries(project_filter, source)?;
+    let total = sessions.len();
 
-    if sessions.is_empty() {
-        if project_filter.is_some() {
-            println!("{}", "No matching project found.".yellow());
-        } else {
-            println!("{}", "No sessions found.".yellow());
-        }
-        return Ok(());
-    }
+    // Global paging slices the newest-first list before grouping
+    let (sessions, more) = if per_project {
+        (sessions, 0)
+    } else {
+        paginate(sessions, offset, limit)
+    };
 
-    let mut groups: Vec<(String, Vec<SessionSummary>)> = Vec::new();
+    let mut groups: Vec<(String, Vec<SessionSummary>, usize)> = Vec::new();
     for session in sessions {
-        if let Some((_, existing)) = groups
+        if let Some((_, existing, _)) = groups
             .iter_mut()
-            .find(|(name, _)| name == &session.project_name)
+            .find(|(name, _, _)| name == &session.project_name)
         {
             existing.push(session);
         } else {
-            groups.push((session.project_name.clone(), vec![session]));
+            groups.push((session.project_name.clone(), vec![session], 0));
         }
     }
 
-    for (project_name, sessions) in &groups {
-        println!();
-        println!(
+    if per_project {
+        for (_, sessions, more) in &mut groups {
+            let (page, rest) = paginate(std::mem::take(sessions), offset, limit);
+            *sessions = page;
+            *more = rest;
+        }
+        groups.retain(|(_, sessions, _)| !sessions.is_empty());
+    }
+
+    if json || table.is_some() {
+        let mut page: Vec<&SessionSummary> = groups
+            .iter()
+            .flat_map(|(_, sessions, _)| sessions)
+            .collect();
+        page.sort_by(|a, b| newest_first(a, b));
+        if let Some(format) = table {
+            return write_session_table(&page, format, out);
+        }
+        let output =
+            serde_json::to_string_pretty(&page).context("Failed to serialize session list")?;
+        writeln!(out, "{}", output)?;
+        return Ok(());
+    }
+
+    if total == 0 {
+        if project_filter.is_some() {
+            writeln!(out, "{}", "No matching project found.".yellow())?;
+        } else {
+            writeln!(out, "{}", "No sessions found.".yellow())?;
+        }
+        return Ok(());
+    }
+
+    if groups.is_empty() {
+        writeln!(
+            out,
+            "{}",
+            format!("No sessions at offset {} ({} in total).", offset, total).yellow()
+        )?;
+        return Ok(());
+    }
+
+    // "[ 1] [CC] " + title + " | 123 msgs | " + time, plus "<id> | " with IDs
+    let reserved = 24 + TIME_COLUMN_CHARS + if show_ids { 39 } else { 0 };
+    let width = title_width(reserved);
+
+    for (project_name, sessions, project_more) in &groups {
+        writeln!(out)?;
+        writeln!(
+            out,
             "{} {} ({} sessions)",
             "Project:".cyan().bold(),
             project_name.bold(),
             sessions.len()
-        );
-        println!("{}", "-".repeat(60));
+        )?;
+        writeln!(out, "{}", "-".repeat(60))?;
 
         for (i, session) in sessions.iter().enumerate() {
             if show_ids {
-                println!(
+                writeln!(
+                    out,
                     "[{:>2}] [{}] {} | {} | {} msgs | {}",
                     i + 1,
                     source_label(&session.source),
                     session.session_id.dimmed(),
-                    session.display_title(40),
+                    session.display_title(width),
                     session.message_count,
-                    session.relative_time()
-                );
+                    session.display_time()
+                )?;
             } else {
-                println!(
+                writeln!(
+                    out,
                     "[{:>2}] [{}] {} | {} msgs | {}",
                     i + 1,
                     source_label(&session.source),
-                    session.display_title(50),
+                    session.display_title(width),
                     session.message_count,
-                    session.relative_time()
-                );
+                    session.display_time()
+                )?;
             }
         }
+
+        if *project_more > 0 {
+            writeln!(out, "{}", format!("... and {} more", project_more).dimmed())?;
+        }
+    }
+
+    if more > 0 {
+        writeln!(out)?;
+        writeln!(
+            out,
+            "{}",
+            format!(
+                "... and {} more (next page: --offset {})",
+                more,
+                offset + limit.unwrap_or(0)
+            )
+            .dimmed()
+        )?;
     }
 
     Ok(())
 }
 
+/// Find the Claude Code session with the newest activity, optionally within one project
+fn find_last_session(project_filter: Option<&str>) -> Result<Option<SessionSummary>> {
+    let mut last: Option<SessionSummary> = None;
+
+    // Projects are sorted by last activity, so once a project is not newer than
+    // the best session found so far, no later project can be either.
+    for project in scan_all_projects()? {
+        if project_filter.is_some_and(|name| project.name != name) {
+            continue;
+        }
+        if last
+            .as_ref()
+            .is_some_and(|s| project.last_activity <= s.last_activity)
+        {
+            break;
+        }
+        if let Some(session) = scan_project_sessions(&project)?.into_iter().next() {
+            if last
+                .as_ref()
+                .is_none_or(|s| session.last_activity > s.last_activity)
+            {
+                last = Some(session);
+            }
+        }
+    }
+
+    Ok(last)
+}
+
+/// Resume the most recently active session without any menus
+///
+/// With `print`, only the resume command is written to stdout (prefixed with a
+/// `cd` into the project directory when known) so it can be used from scripts.
+pub fn handle_session_last(project_filter: Option<&str>, print: bool) -> Result<()> {
+    let Some(session) = find_last_session(project_filter)? else {
+        match project_filter {
+            Some(name) => anyhow::bail!("No sessions found for project '{}'", name),
+            None => anyhow::bail!("No sessions found"),
+        }
+    };
+
+    let (cmd, _, project_path) = resume_command(&session);
+
+    if print {
+        match project_path {
+            Some(path) => println!("cd \"{}\" && {}", path, cmd),
+            None => println!("{}", cmd),
+        }
+        return Ok(());
+    }
+
+    println!(
+        "{} {} {}",
+        "Resuming:".cyan().bold(),
+        session.display_title(50).bold(),
+        format!("({}, {})", session.project_name, session.display_time()).dimmed()
+    );
+    run_resume_command(&cmd, project_path.as_deref())
+}
+
 /// List all projects (non-interactive)
 pub fn handle_session_projects(source: SessionSourceFilter) -> Result<()> {
     let sessions = scan_all_session_summaries(None, source)?;
@@ -2124,7 +2979,7 @@ pub fn handle_session_projects(source: SessionSourceFilter) -> Result<()> {
         let time_str = project
             .last_activity
             .as_ref()
-            .map(|t| format_relative_time(t))
+            .map(|t| format_time(t))
             .unwrap_or_else(|| "Unknown".to_string());
 
         println!(
@@ -2139,6 +2994,70 @@ pub fn handle_session_projects(source: SessionSourceFilter) -> Result<()> {
     Ok(())
 }
 
+/// Per-project disk usage: (project name, session count, total bytes),
+/// largest first
+fn disk_usage_by_project(sessions: &[SessionSummary]) -> Vec<(String, usize, u64)> {
+    let mut usage: Vec<(String, usize, u64)> = Vec::new();
+    for session in sessions {
+        if let Some(entry) = usage
+            .iter_mut()
+            .find(|(name, _, _)| name == &session.project_name)
+        {
+            entry.1 += 1;
+            entry.2 += session.file_size;
+        } else {
+            usage.push((session.project_name.clone(), 1, session.file_size));
+        }
+    }
+    usage.sort_by(|a, b| b.2.cmp(&a.2).then_with(|| a.0.cmp(&b.0)));
+    usage
+}
+
+/// Show disk usage per project, largest first (non-interactive)
+pub fn handle_session_disk_usage(top: Option<usize>, source: SessionSourceFilter) -> Result<()> {
+    let sessions = scan_all_session_summaries(None, source)?;
+
+    if sessions.is_empty() {
+        println!("{}", "No projects found.".yellow());
+        return Ok(());
+    }
+
+    let usage = disk_usage_by_project(&sessions);
+    let total_bytes: u64 = usage.iter().map(|(_, _, bytes)| bytes).sum();
+    let mb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+    let shown = top.unwrap_or(usage.len()).min(usage.len());
+
+    println!("{} ({} projects)", "Disk usage".cyan().bold(), usage.len());
+    println!("{}", "-".repeat(60));
+
+    for (i, (name, count, bytes)) in usage.iter().take(shown).enumerate() {
+        println!(
+            "[{:>2}] {} | {} sessions | {:.1} MB",
+            i + 1,
+            name.bold(),
+            count,
+            mb(*bytes)
+        );
+    }
+
+    if shown < usage.len() {
+        println!(
+            "{}",
+            format!("... and {} more projects", usage.len() - shown).dimmed()
+        );
+    }
+
+    println!("{}", "-".repeat(60));
+    println!(
+        "{} {} sessions | {:.1} MB",
+        "Total:".bold(),
+        sessions.len(),
+        mb(total_bytes)
+    );
+
+    Ok(())
+}
+
 // ============================================================================
 // Overview
 // ============================================================================
@@ -2474,7 +3393,7 @@ pub fn handle_session_overview(
             let time_str = proj
                 .last_activity
                 .as_ref()
-                .map(|t| format_relative_time(t))
+                .map(|t| format_time(t))
                 .unwrap_or_else(|| "Unknown".to_string());
 
             if let Some(desc) = &proj.description {
@@ -2501,7 +3420,7 @@ pub fn handle_session_overview(
                 let sess_time = sess
                     .last_activity
                     .as_ref()
-                    .map(|t| format_relative_time(t))
+                    .map(|t| format_time(t))
                     .unwrap_or_else(|| "?".to_string());
 
                 println!(
@@ -2535,117 +3454,104 @@ pub fn handle_session_overview(
     Ok(())
 }
 
-/// Show session details (non-interactive), with optional drill-down flags
-#[allow(clippy::too_many_arguments)]
-pub fn handle_session_show(
-    session_id: &str,
-    tail: Option<usize>,
-    head: Option<usize>,
-    around: Option<&str>,
-    num: usize,
-    json: bool,
-    full: bool,
-    source: SessionSourceFilter,
-) -> Result<()> {
-    let sessions = scan_all_session_summaries(None, source)?;
-
-    if let Some(session) = sessions.iter().find(|s| s.session_id == session_id) {
-        // If no drill-down flags and not json, use interactive view
-        if (session.source == "claude" || session.source == "omp")
-            && tail.is_none()
-            && head.is_none()
-            && around.is_none()
-            && !json
-        {
-            show_session_details(session)?;
-            return Ok(());
-        }
-
-        // Drill-down mode: parse and filter messages
-        // JSON or --full uses full content (no truncation); terminal uses simplified.
-        // --around always uses full content so its keyword matching stays consistent with
-        // `search` (which indexes full content); otherwise simplification (code-block removal
-        // + 500-char truncation) would drop keywords that search matched, misplacing the anchor.
-        let messages =
-            collect_display_messages_for_summary(session, json || full || around.is_some());
+/// Find a session by its full ID or a unique ID prefix
+///
+/// An exact match wins over prefix matches. A prefix shared by several
+/// sessions is an error that lists them.
+fn find_session_by_id<'a>(sessions: &'a [SessionSummary], id: &str) -> Result<&'a SessionSummary> {
+    if let Some(session) = sessions.iter().find(|s| s.session_id == id) {
+        return Ok(session);
+    }
 
-        if messages.is_empty() {
-            if json {
-                println!(
-                    "{}",
-                    serde_json::to_string(&serde_json::json!({
-                        "source": session.source,
-                        "session_id": session.session_id,
-                        "project": session.project_name,
-                        "title": session.title,
-                        "message_count": 0,
-                        "messages": []
-                    }))?
-                );
-            } else {
-                println!("(No messages found)");
-            }
-            return Ok(());
+    let mut matches: Vec<&SessionSummary> = if id.is_empty() {
+        Vec::new()
+    } else {
+        sessions
+            .iter()
+            .filter(|s| s.session_id.starts_with(id))
+            .collect()
+    };
+    // The same session can be scanned from more than one place
+    matches.sort_by(|a, b| a.session_id.cmp(&b.session_id));
+    matches.dedup_by(|a, b| a.session_id == b.session_id);
+
+    match matches.as_slice() {
+        [] => Err(SyncError::session_not_found(id).into()),
+        [session] => Ok(session),
+        candidates => {
+            let list: Vec<String> = candidates
+                .iter()
+                .map(|s| {
+                    format!(
+                        "  {}  {} ({})",
+                        s.session_id,
+                        s.display_title(50),
+                        s.project_name
+                    )
+                })
+                .collect();
+            anyhow::bail!(
+                "Session ID prefix '{}' is ambiguous, it matches {} sessions:\n{}",
+                id,
+                candidates.len(),
+                list.join("\n")
+            )
         }
+    }
+}
 
-        // Determine slice range
-        let total = messages.len();
+/// Every session of every local project, for lookups by ID
+fn scan_all_project_sessions() -> Result<Vec<SessionSummary>> {
+    let mut sessions = Vec::new();
+    for project in &scan_all_projects()? {
+        sessions.extend(scan_project_sessions(project)?);
+    }
+    Ok(sessions)
+}
 
-        // --around: locate the keyword up-front. If it is not present anywhere, tell the user
-        // explicitly instead of silently falling back to the start of the session.
-        let around_range = if let Some(keyword) = around {
-            match find_around_range(&messages, keyword, num) {
-                Some(range) => Some(range),
-                None => {
-                    if json {
-                        println!(
-                            "{}",
-                            serde_json::to_string(&serde_json::json!({
-                                "source": session.source,
-                                "session_id": session.session_id,
-                                "project": session.project_name,
-                                "title": session.title,
-                                "message_count": session.message_count,
-                                "showing": format!("around:\"{}\":{}:not-found", keyword, num),
-                                "messages": [],
-                            }))?
-                        );
-                    } else {
-                        println!("未在会话中找到关键词: {}", keyword);
-                    }
-                    return Ok(());
-                }
-            }
-        } else {
-            None
-        };
+/// Show session details (non-interactive), with optional drill-down flags
+#[allow(clippy::too_many_arguments)]
+pub fn handle_session_show(
+    session_id: &str,
+    tail: Option<usize>,
+    head: Option<usize>,
+    around: Option<&str>,
+    num: usize,
+    json: bool,
+    full: bool,
+    source: SessionSourceFilter,
+) -> Result<()> {
+    let sessions = scan_all_session_summaries(None, source)?;
 
-        let (start, end, showing) = if let (Some(keyword), Some((s, e))) = (around, around_range) {
-            (s, e, format!("around:\"{}\":{}", keyword, num))
-        } else if let Some(n) = tail {
-            let s = total.saturating_sub(n);
-            (s, total, format!("tail:{}", n))
-        } else if let Some(n) = head {
-            (0, n.min(total), format!("head:{}", n))
-        } else {
-            (0, total, "all".to_string())
-        };
+    let session = find_session_by_id(&sessions, session_id)?;
+    // If no drill-down flags and not json, use interactive view
+    if (session.source == "claude" || session.source == "omp")
+        && tail.is_none()
+        && head.is_none()
+        && around.is_none()
+        && !json
+        && !full
+    {
+        show_session_details(session)?;
+        return Ok(());
+    }
 
-        let slice = &messages[start..end];
+    // Drill-down mode: parse and filter messages
+    // JSON or --full uses full content (no truncation); terminal uses simplified.
+    // --around always uses full content so its keyword matching stays consistent with
+    // `search` (which indexes full content); otherwise simplification (code-block removal
+    // + 500-char truncation) would drop keywords that search matched, misplacing the anchor.
+    // Claude sessions with --full also include tool calls and their results.
+    let messages = if full && session.source == "claude" {
+        ConversationSession::from_file(&session.file_path)
+            .map(|conv| collect_transcript_messages(&conv))
+            .unwrap_or_default()
+    } else {
+        collect_display_messages_for_summary(session, json || full || around.is_some())
+    };
 
+    if messages.is_empty() {
         if json {
-            let json_msgs: Vec<serde_json::Value> = slice
-                .iter()
-                .map(|m| {
-                    serde_json::json!({
-                    "index": m.index,
-                    "role": m.role,
-                    "timestamp": m.timestamp,
-                        "content": m.content,
-                    })
-                })
-                .collect();
-
             println!(
                 "{}",
                 serde_json::to_string(&serde_json::json!({
@@ -2653,55 +3559,131 @@ pub fn handle_session_show(
                     "session_id": session.session_id,
                     "project": session.project_name,
                     "title": session.title,
-                    "message_count": session.message_count,
-                    "showing": showing,
-                    "messages": json_msgs,
+                    "message_count": 0,
+                    "messages": []
                 }))?
             );
         } else {
-            let is_tty = atty::is(atty::Stream::Stdout);
-            println!(
-                "--- [{}] {} | {} | {} | {} msgs | showing {} ---",
-                source_label(&session.source),
-                session.session_id,
-                session.project_name,
-                session.display_title(40),
-                session.message_count,
-                showing,
-            );
-            println!();
-            for m in slice {
-                let role_tag = if m.role == "user" { "U" } else { "A" };
-                let time_str = m
-                    .timestamp
-                    .as_ref()
-                    .map(|t| format_compact_relative_time(t))
-                    .unwrap_or_default();
-                if is_tty {
+            println!("(No messages found)");
+        }
+        return Ok(());
+    }
+
+    // Determine slice range
+    let total = messages.len();
+
+    // --around: locate the keyword up-front. If it is not present anywhere, tell the user
+    // explicitly instead of silently falling back to the start of the session.
+    let around_range = if let Some(keyword) = around {
+        match find_around_range(&messages, keyword, num) {
+            Some(range) => Some(range),
+            None => {
+                if json {
                     println!(
-                        "[{}] [{}] {}",
-                        format!("{}", m.index).cyan(),
-                        if m.role == "user" {
-                            role_tag.green().bold().to_string()
-                        } else {
-                            role_tag.blue().bold().to_string()
-                        },
-                        time_str.dimmed()
+                        "{}",
+                        serde_json::to_string(&serde_json::json!({
+                            "source": session.source,
+                            "session_id": session.session_id,
+                            "project": session.project_name,
+                            "title": session.title,
+                            "message_count": session.message_count,
+                            "showing": format!("around:\"{}\":{}:not-found", keyword, num),
+                            "messages": [],
+                        }))?
                     );
                 } else {
-                    println!("[{}] [{}] {}", m.index, role_tag, time_str);
+                    println!("未在会话中找到关键词: {}", keyword);
                 }
-                for line in m.content.lines() {
-                    println!("  {}", line);
-                }
-                println!();
+                return Ok(());
             }
         }
+    } else {
+        None
+    };
 
-        return Ok(());
+    let (start, end, showing) = if let (Some(keyword), Some((s, e))) = (around, around_range) {
+        (s, e, format!("around:\"{}\":{}", keyword, num))
+    } else if let Some(n) = tail {
+        let s = total.saturating_sub(n);
+        (s, total, format!("tail:{}", n))
+    } else if let Some(n) = head {
+        (0, n.min(total), format!("head:{}", n))
+    } else {
+        (0, total, "all".to_string())
+    };
+
+    let slice = &messages[start..end];
+
+    if json {
+        let json_msgs: Vec<serde_json::Value> = slice
+            .iter()
+            .map(|m| {
+                serde_json::json!({
+                "index": m.index,
+                "role": m.role,
+                "timestamp": m.timestamp,
+                    "content": m.content,
+                })
+            })
+            .collect();
+
+        println!(
+            "{}",
+            serde_json::to_string(&serde_json::json!({
+                "source": session.source,
+                "session_id": session.session_id,
+                "project": session.project_name,
+                "title": session.title,
+                "message_count": session.message_count,
+                "showing": showing,
+                "messages": json_msgs,
+            }))?
+        );
+    } else {
+        let is_tty = atty::is(atty::Stream::Stdout);
+        println!(
+            "--- [{}] {} | {} | {} | {} msgs | showing {} ---",
+            source_label(&session.source),
+            session.session_id,
+            session.project_name,
+            session.display_title(40),
+            session.message_count,
+            showing,
+        );
+        println!();
+        for m in slice {
+            let role_tag = match m.role.as_str() {
+                "user" => "U",
+                "tool" => "T",
+                _ => "A",
+            };
+            let time_str = m
+                .timestamp
+                .as_ref()
+                .map(|t| format_compact_time(t))
+                .unwrap_or_default();
+            if is_tty {
+                println!(
+                    "[{}] [{}] {}",
+                    format!("{}", m.index).cyan(),
+                    match m.role.as_str() {
+                        "user" => role_tag.green().bold().to_string(),
+                        "tool" => role_tag.yellow().bold().to_string(),
+                        _ => role_tag.blue().bold().to_string(),
+                    },
+                    time_str.dimmed()
+                );
+            } else {
+                println!("[{}] [{}] {}", m.index, role_tag, time_str);
+            }
+            for line in m.content.lines() {
+                println!("  {}", line);
+            }
+            println!();
+        }
     }
 
-    anyhow::bail!("Session not found: {}", session_id)
+    Ok(())
 }
 
 // ============================================================================
@@ -2864,6 +3846,67 @@ fn collect_display_messages(conv: &ConversationSession, full_content: bool) -> V
     messages
 }
 
+/// Collect every user, assistant and tool-result entry of a Claude session
+/// as its own message, with tool calls and results included
+/// (`session show --full`)
+fn collect_transcript_messages(conv: &ConversationSession) -> Vec<DisplayMessage> {
+    let mut messages = Vec::new();
+    for entry in &conv.entries {
+        let is_user = match entry.entry_type.as_str() {
+            "user" => true,
+            "assistant" => false,
+            _ => continue,
+        };
+        let Some(content) = entry
+            .message
+            .as_ref()
+            .and_then(|msg| ConversationSession::extract_transcript_content(msg, is_user))
+        else {
+            continue;
+        };
+        let role = if ConversationSession::is_tool_result_entry(entry) {
+            "tool"
+        } else {
+            entry.entry_type.as_str()
+        };
+        messages.push(DisplayMessage {
+            index: messages.len() + 1,
+            role: role.to_string(),
+            timestamp: entry.timestamp.clone(),
+            content,
+        });
+    }
+    messages
+}
+
+/// Render a Claude session as a Markdown document with its full message text
+/// (`ccs export-all --format markdown`)
+pub(crate) fn session_markdown(conv: &ConversationSession) -> String {
+    let mut out = format!(
+        "# {}\n\n",
+        conv.title().unwrap_or_else(|| conv.session_id.clone())
+    );
+    out.push_str(&format!("- Session: `{}`\n", conv.session_id));
+    if let Some(cwd) = conv.cwd() {
+        out.push_str(&format!("- Project: `{}`\n", cwd));
+    }
+    if let Some(ts) = conv.first_timestamp() {
+        out.push_str(&format!("- Started: {}\n", ts));
+    }
+    out.push_str(&format!("- Messages: {}\n", conv.message_count()));
+
+    for msg in collect_display_messages(conv, true) {
+        let role = if msg.role == "user" { "User" } else { "Assistant" };
+        match &msg.timestamp {
+            Some(ts) => out.push_str(&format!("\n## {} ({})\n\n", role, ts)),
+            None => out.push_str(&format!("\n## {}\n\n", role)),
+        }
+        out.push_str(msg.content.trim_end());
+        out.push('\n');
+    }
+    out
+}
+
 fn collect_display_messages_for_summary(
     session: &SessionSummary,
     full_content: bool,
@@ -3015,7 +4058,7 @@ fn format_tool_summary(tools: &[(String, Option<String>)]) -> String {
 }
 
 /// Parse a duration string (e.g., "1d", "3h", "1w") into a cutoff DateTime
-fn parse_duration_filter(since: &str) -> Result<chrono::DateTime<chrono::Utc>> {
+pub(crate) fn parse_duration_filter(since: &str) -> Result<chrono::DateTime<chrono::Utc>> {
     use chrono::Utc;
 
     let since = since.trim().to_lowercase();
@@ -3063,6 +4106,14 @@ fn calculate_recency_score(last_activity: Option<&str>) -> f64 {
     (-hours_ago / 168.0 * 0.693).exp()
 }
 
+/// Compact time for search output, relative or absolute per the time format
+fn format_compact_time(timestamp: &str) -> String {
+    match time_format() {
+        TimeFormat::Relative => format_compact_relative_time(timestamp),
+        TimeFormat::Absolute => format_absolute_time(timestamp),
+    }
+}
+
 /// Compact relative time for search output
 fn format_compact_relative_time(timestamp: &str) -> String {
     use chrono::{DateTime, Utc};
@@ -3205,14 +4256,14 @@ fn memory_search_roots_from_sessions(sessions: &[SessionSummary]) -> Vec<MemoryS
     roots
 }
 
-/// Search sessions across projects (both user and assistant messages).
+/// Search sessions across projects, limited to the roles in `scope`.
 /// With multiple keywords, collects AND matches (all keywords present)
 /// and OR matches (any keyword present), sorted with AND results first.
 fn search_sessions_full(
     sessions: &[SessionSummary],
     keywords: &[&str],
     context_chars: usize,
-    user_only: bool,
+    scope: SearchScope,
 ) -> Vec<SessionSearchResult> {
     let keywords_lower: Vec<String> = keywords.iter().map(|k| k.to_lowercase()).collect();
     let multi_keyword = keywords_lower.len() > 1;
@@ -3228,7 +4279,7 @@ fn search_sessions_full(
                 break;
             }
 
-            if user_only && message.role != "user" {
+            if !scope.includes(&message.role) {
                 continue;
             }
 
@@ -3303,7 +4354,7 @@ pub fn handle_session_search(
     since: Option<&str>,
     context_chars: usize,
     limit: usize,
-    user_only: bool,
+    scope: SearchScope,
     json_output: bool,
     source: SessionSourceFilter,
 ) -> Result<()> {
@@ -3352,7 +4403,7 @@ pub fn handle_session_search(
     }
 
     // 5. Search sessions
-    let session_results = search_sessions_full(&all_sessions, keywords, context_chars, user_only);
+    let session_results = search_sessions_full(&all_sessions, keywords, context_chars, scope);
 
     // 6. Count totals
     let memory_match_count: usize = memory_results.iter().map(|r| r.matches.len()).sum();
@@ -3499,7 +4550,7 @@ pub fn handle_session_search(
                 .summary
                 .last_activity
                 .as_ref()
-                .map(|t| format_compact_relative_time(t))
+                .map(|t| format_compact_time(t))
                 .unwrap_or_else(|| "?".to_string());
 
             let header = format!(
@@ -3545,62 +4596,73 @@ pub fn handle_session_search(
 
 /// Rename session (non-interactive)
 pub fn handle_session_rename(session_id: &str, new_title: &str) -> Result<()> {
-    let projects = scan_all_projects()?;
+    let sessions = scan_all_project_sessions()?;
+    let session = find_session_by_id(&sessions, session_id)?;
 
-    for project in &projects {
-        let sessions = scan_project_sessions(project)?;
+    rename_session(&session.file_path, &session.session_id, new_title)?;
+    record_session_operation(session, OperationType::SessionRename, None);
+    println!(
+        "{} Session renamed successfully!",
+        "SUCCESS:".green().bold()
+    );
+    Ok(())
+}
 
-        if let Some(session) = sessions.iter().find(|s| s.session_id == session_id) {
-            rename_session(&session.file_path, session_id, new_title)?;
-            println!(
-                "{} Session renamed successfully!",
-                "SUCCESS:".green().bold()
-            );
-            return Ok(());
-        }
+/// Revert the latest rename of a session (non-interactive)
+pub fn handle_session_rename_undo(session_id: &str) -> Result<()> {
+    let sessions = scan_all_project_sessions()?;
+    let session = find_session_by_id(&sessions, session_id)?;
+
+    if !undo_rename_session(&session.file_path, &session.session_id)? {
+        anyhow::bail!("Session {} has no rename to undo", session.session_id);
     }
 
-    anyhow::bail!("Session not found: {}", session_id)
+    let restored = ConversationSession::from_file(&session.file_path)
+        .ok()
+        .and_then(|s| s.title())
+        .unwrap_or_else(|| "(No title)".to_string());
+    println!(
+        "{} Title restored: {} -> {}",
+        "SUCCESS:".green().bold(),
+        session.title.dimmed(),
+        restored
+    );
+    Ok(())
 }
 
 /// Delete session (non-interactive)
 pub fn handle_session_delete(session_id: &str, force: bool) -> Result<()> {
-    let projects = scan_all_projects()?;
-
-    for project in &projects {
-        let sessions = scan_project_sessions(project)?;
+    let sessions = scan_all_project_sessions()?;
+    let session = find_session_by_id(&sessions, session_id)?;
 
-        if let Some(session) = sessions.iter().find(|s| s.session_id == session_id) {
-            if !force {
-                println!(
-                    "{} {}",
-                    "WARNING:".red().bold(),
-                    "About to delete session:".red()
-                );
-                println!("  Title: {}", session.display_title(50));
-                println!("  File: {}", session.file_path.display());
-                println!();
-
-                let confirm = Confirm::new("Proceed with deletion?")
-                    .with_default(false)
-                    .prompt();
+    if !force {
+        println!(
+            "{} {}",
+            "WARNING:".red().bold(),
+            "About to delete session:".red()
+        );
+        println!("  Title: {}", session.display_title(50));
+        println!("  File: {}", session.file_path.display());
+        println!();
 
-                if !matches!(confirm, Ok(true)) {
-                    println!("{}", "Delete cancelled.".yellow());
-                    return Ok(());
-                }
-            }
+        let confirm = interactive_conflict::confirm(
+            "Proceed with deletion?",
+            false,
+            interactive_conflict::assume_yes(),
+        );
 
-            delete_session_with_commit(session, DeleteReason::Explicit)?;
-            println!(
-                "{} Session deleted successfully!",
-                "SUCCESS:".green().bold()
-            );
+        if !matches!(confirm, Ok(true)) {
+            println!("{}", "Delete cancelled.".yellow());
             return Ok(());
         }
     }
 
-    anyhow::bail!("Session not found: {}", session_id)
+    delete_session_with_commit(session, DeleteReason::Explicit)?;
+    println!(
+        "{} Session deleted successfully!",
+        "SUCCESS:".green().bold()
+    );
+    Ok(())
 }
 
 /// Restore a session that exists in the sync repo but is missing locally
@@ -3608,7 +4670,7 @@ pub fn handle_session_restore(session_id: Option<&str>) -> Result<()> {
     let state = SyncState::load().context("Failed to load sync state (is sync configured?)")?;
     let filter = FilterConfig::load()?;
     let claude_dir = claude_projects_dir()?;
-    let remote_projects_dir = state.sync_repo_path.join(&filter.sync_subdirectory);
+    let remote_projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
 
     if !remote_projects_dir.exists() {
         println!(
@@ -3697,7 +4759,7 @@ pub fn handle_session_restore(session_id: Option<&str>) -> Result<()> {
         let time = summary
             .last_activity
             .as_ref()
-            .map(|t| format_relative_time(t))
+            .map(|t| format_time(t))
             .unwrap_or_else(|| "unknown".to_string());
 
         options.push(format!(
@@ -3827,6 +4889,53 @@ mod tests {
         assert!(result.contains("ago") || result == "Just now");
     }
 
+    #[test]
+    fn test_format_relative_time_calendar_boundaries() {
+        let at = |now: &str, days: i64| {
+            let now = chrono::DateTime::parse_from_rfc3339(now)
+                .unwrap()
+                .with_timezone(&chrono::Utc);
+            let then = (now - chrono::Duration::days(days)).to_rfc3339();
+            format_relative_time_at(&then, now)
+        };
+
+        assert_eq!(at("2025-03-15T12:00:00Z", 6), "6 days ago");
+        assert_eq!(at("2025-03-15T12:00:00Z", 7), "1 week ago");
+        // 30 days is a month only once the calendar month has passed
+        assert_eq!(at("2025-03-15T12:00:00Z", 30), "1 month ago");
+        assert_eq!(at("2025-01-31T12:00:00Z", 30), "4 weeks ago");
+        // Jan 30 + 1 month is clamped to Feb 28
+        assert_eq!(at("2025-03-01T12:00:00Z", 30), "1 month ago");
+        assert_eq!(at("2025-03-15T12:00:00Z", 58), "1 month ago");
+        assert_eq!(at("2025-03-15T12:00:00Z", 60), "2 months ago");
+        // 365 days is a year, except across a leap day
+        assert_eq!(at("2025-06-01T12:00:00Z", 365), "1 year ago");
+        assert_eq!(at("2024-12-31T12:00:00Z", 365), "11 months ago");
+        assert_eq!(at("2025-06-01T12:00:00Z", 366 + 365), "2 years ago");
+    }
+
+    #[test]
+    fn test_title_width_follows_display_width() {
+        assert_eq!(title_width_for(80, 34), 46);
+        assert_eq!(title_width_for(120, 34), 86);
+        assert_eq!(title_width_for(300, 34), MAX_TITLE_CHARS);
+        // Narrow terminals keep a minimum title, even past the line width
+        assert_eq!(title_width_for(40, 34), MIN_TITLE_CHARS);
+        assert_eq!(title_width_for(0, 79), MIN_TITLE_CHARS);
+    }
+
+    #[test]
+    fn test_format_absolute_time_uses_local_zone() {
+        let timestamp = "2025-01-15T14:30:00Z";
+        let expected = chrono::DateTime::parse_from_rfc3339(timestamp)
+            .unwrap()
+            .with_timezone(&chrono::Local)
+            .format("%Y-%m-%d %H:%M")
+            .to_string();
+        assert_eq!(format_absolute_time(timestamp), expected);
+        assert_eq!(format_absolute_time("not a time"), "Unknown");
+    }
+
     #[test]
     fn test_display_title_truncation() {
         let session = SessionSummary {
@@ -3842,6 +4951,7 @@ mod tests {
             first_timestamp: None,
             last_activity: None,
             file_size: 0,
+            estimated_tokens: 0,
         };
 
         let short = session.display_title(20);
@@ -3849,6 +4959,33 @@ mod tests {
         assert!(short.ends_with("..."));
     }
 
+    #[test]
+    fn test_session_summary_serializes_to_json() {
+        let session = SessionSummary {
+            source: "claude".to_string(),
+            session_id: "abc-123".to_string(),
+            title: "Fix login".to_string(),
+            project_name: "my-app".to_string(),
+            project_dir: PathBuf::from("/tmp/my-app"),
+            file_path: PathBuf::from("/tmp/my-app/abc-123.jsonl"),
+            message_count: 4,
+            user_message_count: 2,
+            assistant_message_count: 2,
+            first_timestamp: Some("2026-01-01T00:00:00Z".to_string()),
+            last_activity: None,
+            file_size: 1024,
+            estimated_tokens: 1200,
+        };
+
+        let value = serde_json::to_value(vec![session]).unwrap();
+        assert_eq!(value[0]["session_id"], "abc-123");
+        assert_eq!(value[0]["project_name"], "my-app");
+        assert_eq!(value[0]["file_path"], "/tmp/my-app/abc-123.jsonl");
+        assert_eq!(value[0]["message_count"], 4);
+        assert_eq!(value[0]["estimated_tokens"], 1200);
+        assert!(value[0]["last_activity"].is_null());
+    }
+
     #[test]
     fn test_display_title_unicode() {
         let session = SessionSummary {
@@ -3864,6 +5001,7 @@ mod tests {
             first_timestamp: None,
             last_activity: None,
             file_size: 0,
+            estimated_tokens: 0,
         };
 
         let short = session.display_title(10);
@@ -3871,6 +5009,39 @@ mod tests {
         assert!(short.ends_with("..."));
     }
 
+    #[test]
+    fn test_display_title_keeps_grapheme_clusters() {
+        let family = "\u{1F468}\u{200D}\u{1F469}\u{200D}\u{1F467}\u{200D}\u{1F466}";
+        let flag = "\u{1F1EF}\u{1F1F5}";
+        let mut session = SessionSummary {
+            source: "claude".to_string(),
+            session_id: "test".to_string(),
+            title: format!("ab{family}{flag}cde\u{301}fghij"),
+            project_name: "test".to_string(),
+            project_dir: PathBuf::new(),
+            file_path: PathBuf::new(),
+            message_count: 0,
+            user_message_count: 0,
+            assistant_message_count: 0,
+            first_timestamp: None,
+            last_activity: None,
+            file_size: 0,
+            estimated_tokens: 0,
+        };
+
+        // 12 graphemes: a b <family> <flag> c d e\u{301} f g h i j
+        assert_eq!(session.display_title(12), session.title);
+        assert_eq!(session.display_title(6), format!("ab{family}..."));
+        assert_eq!(session.display_title(7), format!("ab{family}{flag}..."));
+        assert_eq!(
+            session.display_title(10),
+            format!("ab{family}{flag}cde\u{301}...")
+        );
+
+        session.title = format!("{flag}{flag}{flag}{flag}{flag}");
+        assert_eq!(session.display_title(4), format!("{flag}..."));
+    }
+
     #[test]
     fn test_codex_session_uses_cwd_as_project_dir() {
         let session = CodexSession {
@@ -3890,6 +5061,16 @@ mod tests {
         assert_eq!(memory_dir_name_for_source("codex"), ".memory");
     }
 
+    #[test]
+    fn test_search_scope_includes() {
+        assert!(SearchScope::User.includes("user"));
+        assert!(!SearchScope::User.includes("assistant"));
+        assert!(SearchScope::Assistant.includes("assistant"));
+        assert!(!SearchScope::Assistant.includes("user"));
+        assert!(SearchScope::Both.includes("user"));
+        assert!(SearchScope::Both.includes("assistant"));
+    }
+
     #[test]
     fn test_parse_duration_filter_days() {
         let cutoff = parse_duration_filter("7d").unwrap();
@@ -3961,4 +5142,250 @@ mod tests {
         let ts = (chrono::Utc::now() - chrono::Duration::days(5)).to_rfc3339();
         assert_eq!(format_compact_relative_time(&ts), "5d ago");
     }
+
+    #[test]
+    fn test_previous_custom_title() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("sess.jsonl");
+        let line = json!({
+            "type": "user",
+            "uuid": "u1",
+            "sessionId": "sess",
+            "message": {"role": "user", "content": "original"},
+        });
+        fs::write(&path, format!("{line}\n")).unwrap();
+        assert_eq!(previous_custom_title(&path).unwrap(), None);
+
+        rename_session(&path, "sess", "first").unwrap();
+        assert_eq!(previous_custom_title(&path).unwrap(), Some(String::new()));
+
+        rename_session(&path, "sess", "second").unwrap();
+        assert_eq!(
+            previous_custom_title(&path).unwrap(),
+            Some("first".to_string())
+        );
+
+        // Undos walk back through the history instead of toggling
+        assert!(undo_rename_session(&path, "sess").unwrap());
+        let session = ConversationSession::from_file(&path).unwrap();
+        assert_eq!(session.title(), Some("first".to_string()));
+
+        assert!(undo_rename_session(&path, "sess").unwrap());
+        let session = ConversationSession::from_file(&path).unwrap();
+        assert_eq!(session.title(), Some("original".to_string()));
+
+        assert!(!undo_rename_session(&path, "sess").unwrap());
+    }
+
+    #[test]
+    fn test_paginate() {
+        let items: Vec<u32> = (1..=10).collect();
+        assert_eq!(paginate(items.clone(), 0, None), (items.clone(), 0));
+        assert_eq!(paginate(items.clone(), 0, Some(3)), (vec![1, 2, 3], 7));
+        assert_eq!(paginate(items.clone(), 8, Some(3)), (vec![9, 10], 0));
+        assert_eq!(paginate(items, 12, Some(3)), (vec![], 0));
+    }
+
+    #[test]
+    fn test_find_session_by_id_prefix() {
+        let session = |id: &str| SessionSummary {
+            source: "claude".to_string(),
+            session_id: id.to_string(),
+            title: "title".to_string(),
+            project_name: "p".to_string(),
+            project_dir: PathBuf::new(),
+            file_path: PathBuf::new(),
+            message_count: 1,
+            user_message_count: 1,
+            assistant_message_count: 0,
+            first_timestamp: None,
+            last_activity: None,
+            file_size: 0,
+            estimated_tokens: 0,
+        };
+        let sessions = vec![session("abc"), session("abcdef"), session("abd123")];
+
+        // Exact match wins over the longer ID sharing the prefix
+        assert_eq!(
+            find_session_by_id(&sessions, "abc").unwrap().session_id,
+            "abc"
+        );
+        assert_eq!(
+            find_session_by_id(&sessions, "abcd").unwrap().session_id,
+            "abcdef"
+        );
+        assert_eq!(
+            find_session_by_id(&sessions, "abd").unwrap().session_id,
+            "abd123"
+        );
+
+        let err = find_session_by_id(&sessions, "ab").unwrap_err();
+        assert!(err.to_string().contains("ambiguous"));
+        assert!(err.to_string().contains("abd123"));
+        assert!(matches!(
+            SyncError::find(&find_session_by_id(&sessions, "x").unwrap_err()),
+            Some(SyncError::NotFound { .. })
+        ));
+        assert!(find_session_by_id(&sessions, "").is_err());
+    }
+
+    #[test]
+    fn test_write_session_table_escapes_fields() {
+        let session = SessionSummary {
+            source: "claude".to_string(),
+            session_id: "abc".to_string(),
+            title: "Fix \"login\", then\tdeploy".to_string(),
+            project_name: "my-app".to_string(),
+            project_dir: PathBuf::new(),
+            file_path: PathBuf::new(),
+            message_count: 12,
+            user_message_count: 6,
+            assistant_message_count: 6,
+            first_timestamp: None,
+            last_activity: Some("2025-01-15T14:30:00Z".to_string()),
+            file_size: 2048,
+            estimated_tokens: 0,
+        };
+
+        let mut csv = Vec::new();
+        write_session_table(&[&session], TableFormat::Csv, &mut csv).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "project,session_id,title,message_count,last_activity,file_size\n\
+             my-app,abc,\"Fix \"\"login\"\", then\tdeploy\",12,2025-01-15T14:30:00Z,2048\n"
+        );
+
+        let mut tsv = Vec::new();
+        write_session_table(&[&session], TableFormat::Tsv, &mut tsv).unwrap();
+        assert_eq!(
+            String::from_utf8(tsv).unwrap().lines().nth(1).unwrap(),
+            "my-app\tabc\t\"Fix \"\"login\"\", then\tdeploy\"\t12\t2025-01-15T14:30:00Z\t2048"
+        );
+    }
+
+    #[test]
+    fn test_resolve_project_and_suggestions() {
+        let root = tempfile::TempDir::new().unwrap();
+        let app_dir = root.path().join("-Users-me-my-app");
+        fs::create_dir_all(&app_dir).unwrap();
+        let session = |project: &str, dir: &Path| SessionSummary {
+            source: "claude".to_string(),
+            session_id: project.to_string(),
+            title: "title".to_string(),
+            project_name: project.to_string(),
+            project_dir: dir.to_path_buf(),
+            file_path: PathBuf::new(),
+            message_count: 1,
+            user_message_count: 1,
+            assistant_message_count: 0,
+            first_timestamp: None,
+            last_activity: None,
+            file_size: 0,
+            estimated_tokens: 0,
+        };
+        let sessions = vec![
+            session("My-App", &app_dir),
+            session("backend", &root.path().join("-srv-backend")),
+        ];
+        let index = SessionIndex {
+            source: SessionSourceFilter::All,
+            projects: build_projects_from_sessions(&sessions),
+            sessions,
+            claude_projects: None,
+            filtered_counts: std::collections::HashMap::new(),
+        };
+        let resolve = |name: &str| resolve_project(&index, Some(root.path()), name).map(|p| p.name);
+
+        assert_eq!(resolve("backend").as_deref(), Some("backend"));
+        // Through the local directory name
+        assert_eq!(resolve("app").as_deref(), Some("My-App"));
+        assert_eq!(resolve("my-app").as_deref(), Some("My-App"));
+        assert_eq!(resolve("frontend"), None);
+
+        let names = ["backend", "backend-api", "frontend", "docs"];
+        assert_eq!(similar_project_names(&names, "backnd"), vec!["backend"]);
+        assert_eq!(
+            similar_project_names(&names, "back"),
+            vec!["backend", "backend-api"]
+        );
+        assert_eq!(similar_project_names(&names, "front"), vec!["frontend"]);
+        assert!(similar_project_names(&names, "zzz").is_empty());
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+    }
+
+    #[test]
+    fn test_disk_usage_by_project() {
+        let session = |project: &str, file_size: u64| SessionSummary {
+            source: "claude".to_string(),
+            session_id: "test".to_string(),
+            title: "title".to_string(),
+            project_name: project.to_string(),
+            project_dir: PathBuf::new(),
+            file_path: PathBuf::new(),
+            message_count: 1,
+            user_message_count: 1,
+            assistant_message_count: 0,
+            first_timestamp: None,
+            last_activity: None,
+            file_size,
+            estimated_tokens: 0,
+        };
+        let sessions = vec![session("a", 100), session("b", 500), session("a", 300)];
+
+        let usage = disk_usage_by_project(&sessions);
+        assert_eq!(
+            usage,
+            vec![("b".to_string(), 1, 500), ("a".to_string(), 2, 400)]
+        );
+    }
+
+    #[test]
+    fn test_apply_session_resolution() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let write_session = |path: &Path, text: &str| {
+            let line = json!({
+                "type": "user",
+                "uuid": text,
+                "sessionId": "sess",
+                "message": {"role": "user", "content": text},
+            });
+            fs::write(path, format!("{line}\n")).unwrap();
+            ConversationSession::from_file(path).unwrap()
+        };
+        let local_path = dir.path().join("local").join("sess.jsonl");
+        let remote_path = dir.path().join("remote").join("sess.jsonl");
+        fs::create_dir_all(local_path.parent().unwrap()).unwrap();
+        fs::create_dir_all(remote_path.parent().unwrap()).unwrap();
+
+        // Keep remote: local file takes the remote content
+        let local = write_session(&local_path, "local");
+        let remote = write_session(&remote_path, "remote");
+        apply_session_resolution(&ResolutionAction::KeepRemote, &local, &remote).unwrap();
+        let reread = ConversationSession::from_file(&local_path).unwrap();
+        assert_eq!(reread.content_hash(), remote.content_hash());
+
+        // Keep local: sync-repo copy takes the local content
+        let local = write_session(&local_path, "local");
+        apply_session_resolution(&ResolutionAction::KeepLocal, &local, &remote).unwrap();
+        let reread = ConversationSession::from_file(&remote_path).unwrap();
+        assert_eq!(reread.content_hash(), local.content_hash());
+
+        // Keep both: remote saved next to the local file, local untouched
+        let remote = write_session(&remote_path, "remote");
+        let renamed = apply_session_resolution(&ResolutionAction::KeepBoth, &local, &remote)
+            .unwrap()
+            .unwrap();
+        assert_eq!(renamed.parent(), local_path.parent());
+        assert!(renamed
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("sess-conflict-"));
+        assert_eq!(
+            ConversationSession::from_file(&local_path)
+                .unwrap()
+                .content_hash(),
+            local.content_hash()
+        );
+    }
 }
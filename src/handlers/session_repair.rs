@@ -0,0 +1,306 @@
+//! Session ID consistency checks
+//!
+//! Handles `ccs session repair`. Session discovery assumes every entry in a
+//! `.jsonl` file carries the same `sessionId`, and that it matches the file
+//! name (Claude Code resumes sessions by file name). Files copied or merged
+//! by other tools sometimes break this, which makes deduplication in
+//! `discover_sessions` drop or merge the wrong files. This module reports such
+//! files and can rewrite them to a single ID.
+
+use anyhow::{bail, Context, Result};
+use colored::Colorize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::output::icon;
+use crate::sync::discovery::claude_projects_dir;
+
+/// Agent subprocess transcripts are named `agent-<id>.jsonl` and carry the
+/// session ID of their parent conversation, so their name never matches
+const AGENT_FILE_PREFIX: &str = "agent-";
+
+/// Session IDs found in one file
+#[derive(Debug, PartialEq, Eq)]
+pub struct SessionIdReport {
+    pub path: PathBuf,
+    /// Distinct `sessionId` values with their entry counts, most common first
+    pub ids: Vec<(String, usize)>,
+    /// User/assistant entries without a `sessionId`
+    pub missing: usize,
+    /// The ID the file should use
+    pub expected: Option<String>,
+}
+
+impl SessionIdReport {
+    /// Human-readable problems, empty when the file is consistent
+    pub fn problems(&self) -> Vec<String> {
+        let mut problems = Vec::new();
+        if self.ids.len() > 1 {
+            let ids: Vec<String> = self
+                .ids
+                .iter()
+                .map(|(id, count)| format!("{} ({})", id, count))
+                .collect();
+            problems.push(format!("entries disagree on sessionId: {}", ids.join(", ")));
+        }
+        if self.missing > 0 && !self.ids.is_empty() {
+            problems.push(format!("{} message(s) without sessionId", self.missing));
+        }
+        if let (Some(expected), [(id, _)]) = (&self.expected, self.ids.as_slice()) {
+            if id != expected {
+                problems.push(format!("file name is {} but sessionId is {}", expected, id));
+            }
+        }
+        problems
+    }
+}
+
+fn is_agent_file(path: &Path) -> bool {
+    path.file_stem()
+        .and_then(|s| s.to_str())
+        .is_some_and(|s| s.starts_with(AGENT_FILE_PREFIX))
+}
+
+fn is_message(entry: &serde_json::Map<String, Value>) -> bool {
+    matches!(
+        entry.get("type").and_then(|t| t.as_str()),
+        Some("user" | "assistant")
+    )
+}
+
+/// Analyze the session IDs of one file
+///
+/// The expected ID is the file name, except for agent files where it is the
+/// most common ID in the file.
+pub fn analyze_session_ids(path: &Path) -> Result<SessionIdReport> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut ids: Vec<(String, usize)> = Vec::new();
+    let mut missing = 0;
+    for entry in content
+        .lines()
+        .filter_map(|line| serde_json::from_str::<serde_json::Map<String, Value>>(line).ok())
+    {
+        match entry.get("sessionId").and_then(|s| s.as_str()) {
+            Some(id) => match ids.iter_mut().find(|(known, _)| known == id) {
+                Some((_, count)) => *count += 1,
+                None => ids.push((id.to_string(), 1)),
+            },
+            None if is_message(&entry) => missing += 1,
+            None => {}
+        }
+    }
+    // Stable sort keeps first-seen order among equally common IDs
+    ids.sort_by_key(|(_, count)| std::cmp::Reverse(*count));
+
+    let expected = if is_agent_file(path) {
+        ids.first().map(|(id, _)| id.clone())
+    } else {
+        path.file_stem()
+            .and_then(|s| s.to_str())
+            .map(str::to_string)
+    };
+
+    Ok(SessionIdReport {
+        path: path.to_path_buf(),
+        ids,
+        missing,
+        expected,
+    })
+}
+
+/// Rewrite every entry that has a `sessionId`, and every message without one,
+/// to use `session_id`. Unparseable lines are kept as they are. The original
+/// file is kept next to it as `<name>.jsonl.bak`.
+pub fn normalize_session_ids(path: &Path, session_id: &str) -> Result<PathBuf> {
+    let content =
+        fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+    let mut output = String::with_capacity(content.len());
+    for line in content.lines() {
+        match serde_json::from_str::<Value>(line) {
+            Ok(Value::Object(mut entry))
+                if entry.contains_key("sessionId") || is_message(&entry) =>
+            {
+                entry.insert("sessionId".to_string(), Value::from(session_id));
+                output.push_str(&serde_json::to_string(&entry)?);
+            }
+            _ => output.push_str(line),
+        }
+        output.push('\n');
+    }
+
+    let mut backup = path.as_os_str().to_owned();
+    backup.push(".bak");
+    let backup = PathBuf::from(backup);
+    fs::copy(path, &backup).with_context(|| format!("Failed to back up {}", path.display()))?;
+    fs::write(path, output).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(backup)
+}
+
+/// Print a report and, with `fix`, normalize the file. Returns whether the
+/// file had problems.
+fn repair_file(path: &Path, fix: bool, quiet_if_ok: bool) -> Result<bool> {
+    let report = analyze_session_ids(path)?;
+    let problems = report.problems();
+    if problems.is_empty() {
+        if !quiet_if_ok {
+            println!("{} {} is consistent", icon("✓").green(), path.display());
+        }
+        return Ok(false);
+    }
+
+    println!("{} {}", icon("⚠").yellow(), path.display());
+    for problem in &problems {
+        println!("    {}", problem);
+    }
+
+    if fix {
+        match &report.expected {
+            Some(id) => {
+                let backup = normalize_session_ids(path, id)?;
+                println!(
+                    "    {} Rewrote to sessionId {} (backup: {})",
+                    icon("✓").green(),
+                    id.cyan(),
+                    backup.display().to_string().dimmed()
+                );
+            }
+            None => println!(
+                "    {}",
+                "Cannot tell which sessionId to use; skipped".yellow()
+            ),
+        }
+    }
+    Ok(true)
+}
+
+/// Handle `ccs session repair`
+///
+/// Checks one file, or every session file with `check_all`. With `fix`,
+/// inconsistent files are rewritten to the expected session ID.
+pub fn handle_session_repair(file: Option<&Path>, check_all: bool, fix: bool) -> Result<()> {
+    if let Some(path) = file {
+        if !path.is_file() {
+            bail!("File not found: {}", path.display());
+        }
+        let had_problems = repair_file(path, fix, false)?;
+        if had_problems && !fix {
+            println!("  Run again with --fix to normalize it");
+        }
+        return Ok(());
+    }
+
+    if !check_all {
+        bail!("Pass a session file, or --check to scan all sessions");
+    }
+
+    let projects_dir = claude_projects_dir()?;
+    let mut checked = 0;
+    let mut inconsistent = 0;
+    for entry in WalkDir::new(&projects_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter(|e| e.path().extension().and_then(|s| s.to_str()) == Some("jsonl"))
+    {
+        checked += 1;
+        match repair_file(entry.path(), fix, true) {
+            Ok(true) => inconsistent += 1,
+            Ok(false) => {}
+            Err(e) => log::warn!("Skipping {}: {}", entry.path().display(), e),
+        }
+    }
+
+    println!();
+    if inconsistent == 0 {
+        println!(
+            "{} All {} session files are consistent",
+            icon("✓").green(),
+            checked
+        );
+    } else if fix {
+        println!(
+            "{} Checked {} session files, {} had inconsistent session IDs",
+            icon("✓").green(),
+            checked,
+            inconsistent
+        );
+    } else {
+        println!(
+            "{} {} of {} session files have inconsistent session IDs (use --fix to normalize)",
+            icon("⚠").yellow(),
+            inconsistent,
+            checked
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const MIXED: &str = concat!(
+        r#"{"type":"user","sessionId":"abc","message":{"role":"user","content":"hi"}}"#,
+        "\n",
+        r#"{"type":"assistant","sessionId":"other","message":{"role":"assistant","content":"yo"}}"#,
+        "\n",
+        r#"{"type":"assistant","message":{"role":"assistant","content":"no id"}}"#,
+        "\n",
+        r#"{"type":"file-history-snapshot","snapshot":{}}"#,
+        "\n",
+        "not json\n",
+    );
+
+    #[test]
+    fn test_analyze_reports_mixed_and_missing_ids() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("abc.jsonl");
+        fs::write(&path, MIXED).unwrap();
+
+        let report = analyze_session_ids(&path).unwrap();
+        assert_eq!(
+            report.ids,
+            vec![("abc".to_string(), 1), ("other".to_string(), 1)]
+        );
+        assert_eq!(report.missing, 1);
+        assert_eq!(report.expected.as_deref(), Some("abc"));
+        assert_eq!(report.problems().len(), 2);
+    }
+
+    #[test]
+    fn test_filename_mismatch_except_agent_files() {
+        let temp = TempDir::new().unwrap();
+        let line = r#"{"type":"user","sessionId":"parent","message":{}}"#;
+        let main = temp.path().join("abc.jsonl");
+        let agent = temp.path().join("agent-1.jsonl");
+        fs::write(&main, line).unwrap();
+        fs::write(&agent, line).unwrap();
+
+        assert_eq!(analyze_session_ids(&main).unwrap().problems().len(), 1);
+        assert!(analyze_session_ids(&agent).unwrap().problems().is_empty());
+    }
+
+    #[test]
+    fn test_normalize_rewrites_ids_and_keeps_backup() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("abc.jsonl");
+        fs::write(&path, MIXED).unwrap();
+
+        let backup = normalize_session_ids(&path, "abc").unwrap();
+        assert_eq!(fs::read_to_string(&backup).unwrap(), MIXED);
+
+        let report = analyze_session_ids(&path).unwrap();
+        assert_eq!(report.ids, vec![("abc".to_string(), 3)]);
+        assert!(report.problems().is_empty());
+
+        let content = fs::read_to_string(&path).unwrap();
+        assert!(content.contains("not json"));
+        assert!(content.contains(r#"{"type":"file-history-snapshot","snapshot":{}}"#));
+    }
+}
@@ -0,0 +1,195 @@
+//! Live session activity
+//!
+//! Handles `ccs session watch`, a `tail -f` for conversation history. The
+//! initial state comes from `discover_sessions`; afterwards a filesystem
+//! watcher on `~/.claude/projects` reports changed `.jsonl` files, which are
+//! re-parsed and compared with the last known message count. A line is
+//! printed for every new session and every session that gained messages,
+//! which makes it easy to follow background agents.
+
+use anyhow::{Context, Result};
+use chrono::Local;
+use colored::Colorize;
+use notify::{EventKind, RecursiveMode, Watcher};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use crate::filter::FilterConfig;
+use crate::handlers::session::SessionSummary;
+use crate::output::icon;
+use crate::parser::ConversationSession;
+use crate::sync::discovery::{claude_projects_dir, discover_sessions, extract_project_name};
+
+/// Quiet period after the last event before changed files are re-read;
+/// Claude Code appends several entries per turn in quick succession
+const SETTLE_DELAY: Duration = Duration::from_millis(500);
+
+/// Maximum title length shown per line
+const TITLE_MAX_CHARS: usize = 60;
+
+/// What happened to a session since it was last seen
+#[derive(Debug, PartialEq, Eq)]
+enum SessionChange {
+    /// First time the session has messages
+    New,
+    /// The session gained this many messages
+    Grew(usize),
+}
+
+/// Record the current message count of `path`, returning the change since
+/// the previous count
+///
+/// Empty files are not recorded, so a session is reported as new once its
+/// first message is written rather than when the file is created.
+fn record_count(
+    counts: &mut HashMap<PathBuf, usize>,
+    path: &Path,
+    message_count: usize,
+) -> Option<SessionChange> {
+    if message_count == 0 && !counts.contains_key(path) {
+        return None;
+    }
+    match counts.insert(path.to_path_buf(), message_count) {
+        None => Some(SessionChange::New),
+        Some(previous) if message_count > previous => {
+            Some(SessionChange::Grew(message_count - previous))
+        }
+        Some(_) => None,
+    }
+}
+
+/// Summary of a session file for display, named like `ccs session list`
+fn summarize(claude_dir: &Path, session: &ConversationSession) -> SessionSummary {
+    let path = Path::new(&session.file_path);
+    let project_dir = path.parent().unwrap_or(claude_dir);
+    let project_name = session
+        .project_name()
+        .map(str::to_string)
+        .unwrap_or_else(|| {
+            let dir_name = project_dir
+                .file_name()
+                .and_then(|n| n.to_str())
+                .unwrap_or_default();
+            extract_project_name(dir_name).to_string()
+        });
+    SessionSummary::from_session(session, &project_name, project_dir)
+}
+
+/// Print one activity line
+fn print_change(summary: &SessionSummary, change: &SessionChange) {
+    let time = Local::now().format("%H:%M:%S").to_string();
+    let (marker, detail) = match change {
+        SessionChange::New => (
+            icon("•").green(),
+            format!("new session, {} messages", summary.message_count),
+        ),
+        SessionChange::Grew(added) => (
+            icon("↻").cyan(),
+            format!("+{} ({} messages)", added, summary.message_count),
+        ),
+    };
+    println!(
+        "{} {} {} {} {}",
+        time.dimmed(),
+        marker,
+        summary.project_name.cyan(),
+        summary.display_title(TITLE_MAX_CHARS),
+        format!("[{}]", detail).dimmed()
+    );
+}
+
+/// Handle `ccs session watch`
+pub fn handle_session_watch() -> Result<()> {
+    let claude_dir = claude_projects_dir()?;
+    let filter = FilterConfig::no_size_limit();
+
+    let mut counts: HashMap<PathBuf, usize> = HashMap::new();
+    for session in discover_sessions(&claude_dir, &filter)? {
+        let summary = summarize(&claude_dir, &session);
+        record_count(&mut counts, &summary.file_path, summary.message_count);
+    }
+
+    let (tx, rx) = mpsc::channel();
+    let mut watcher =
+        notify::recommended_watcher(tx).context("Failed to create file system watcher")?;
+    watcher
+        .watch(&claude_dir, RecursiveMode::Recursive)
+        .with_context(|| format!("Failed to watch {}", claude_dir.display()))?;
+
+    println!(
+        "Watching {} sessions in {} (Ctrl+C to stop)",
+        counts.len(),
+        claude_dir.display()
+    );
+
+    loop {
+        // Block for the first event, then collect the burst that follows
+        let mut changed = BTreeSet::new();
+        let Ok(first) = rx.recv() else {
+            return Ok(());
+        };
+        let mut next = Some(first);
+        while let Some(result) = next {
+            match result {
+                Ok(event) => {
+                    let jsonl = event
+                        .paths
+                        .into_iter()
+                        .filter(|p| p.extension().and_then(|e| e.to_str()) == Some("jsonl"));
+                    match event.kind {
+                        EventKind::Create(_) | EventKind::Modify(_) => changed.extend(jsonl),
+                        EventKind::Remove(_) => {
+                            for path in jsonl {
+                                counts.remove(&path);
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+                Err(e) => log::warn!("Watch error: {}", e),
+            }
+            next = rx.recv_timeout(SETTLE_DELAY).ok();
+        }
+
+        for path in changed {
+            if !path.is_file() || filter.exclusion_reason(&path).is_some() {
+                continue;
+            }
+            let session = match ConversationSession::from_file(&path) {
+                Ok(session) => session,
+                Err(e) => {
+                    log::debug!("Failed to parse {}: {:#}", path.display(), e);
+                    continue;
+                }
+            };
+            let summary = summarize(&claude_dir, &session);
+            if let Some(change) = record_count(&mut counts, &path, summary.message_count) {
+                print_change(&summary, &change);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_count_reports_new_and_growing_sessions() {
+        let mut counts = HashMap::new();
+        let path = Path::new("/p/s1.jsonl");
+
+        assert_eq!(record_count(&mut counts, path, 0), None);
+        assert_eq!(record_count(&mut counts, path, 2), Some(SessionChange::New));
+        assert_eq!(
+            record_count(&mut counts, path, 5),
+            Some(SessionChange::Grew(3))
+        );
+        // Metadata-only writes (titles, summaries) do not add messages
+        assert_eq!(record_count(&mut counts, path, 5), None);
+        assert_eq!(record_count(&mut counts, path, 4), None);
+        assert_eq!(counts[path], 4);
+    }
+}
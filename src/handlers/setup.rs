@@ -10,6 +10,7 @@ use std::process::Command;
 
 use crate::config::ConfigManager;
 use crate::filter::FilterConfig;
+use crate::lang::t;
 use crate::onboarding::{expand_tilde, is_valid_git_url};
 use crate::scm;
 use crate::sync;
@@ -25,8 +26,8 @@ enum SyncMode {
 impl std::fmt::Display for SyncMode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            SyncMode::MultiDevice => write!(f, "多设备同步 (推荐) - 支持不同电脑同步同一项目"),
-            SyncMode::SingleDevice => write!(f, "单设备备份 - 仅本机备份，使用完整路径"),
+            SyncMode::MultiDevice => write!(f, "{}", t!("setup.mode_multi_option")),
+            SyncMode::SingleDevice => write!(f, "{}", t!("setup.mode_single_option")),
         }
     }
 }
@@ -41,8 +42,8 @@ enum RepoSource {
 impl std::fmt::Display for RepoSource {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            RepoSource::Existing => write!(f, "使用已有仓库 - 输入仓库地址"),
-            RepoSource::CreateNew => write!(f, "创建新仓库 - 自动在 GitHub 创建"),
+            RepoSource::Existing => write!(f, "{}", t!("setup.source_existing_option")),
+            RepoSource::CreateNew => write!(f, "{}", t!("setup.source_create_option")),
         }
     }
 }
@@ -82,12 +83,12 @@ fn get_os() -> &'static str {
 fn install_gh_cli() -> Result<()> {
     let os = get_os();
 
-    println!("{}", "📦 正在安装 GitHub CLI (gh)...".cyan());
+    println!("{}", t!("setup.installing_gh").cyan());
     println!();
 
     let (cmd, args): (&str, Vec<&str>) = match os {
         "macos" => {
-            println!("{}", "   使用 Homebrew 安装...".cyan());
+            println!("{}", t!("setup.install_via_brew").cyan());
             // Check if brew is installed
             if !Command::new("brew")
                 .arg("--version")
@@ -95,9 +96,7 @@ fn install_gh_cli() -> Result<()> {
                 .map(|o| o.status.success())
                 .unwrap_or(false)
             {
-                return Err(anyhow::anyhow!(
-                    "未安装 Homebrew。请先安装: /bin/bash -c \"$(curl -fsSL https://raw.githubusercontent.com/Homebrew/install/HEAD/install.sh)\""
-                ));
+                return Err(anyhow::anyhow!(t!("setup.no_homebrew")));
             }
             ("brew", vec!["install", "gh"])
         }
@@ -109,16 +108,16 @@ fn install_gh_cli() -> Result<()> {
                 .map(|o| o.status.success())
                 .unwrap_or(false)
             {
-                println!("{}", "   使用 apt 安装...".cyan());
+                println!("{}", t!("setup.install_via_apt").cyan());
                 // Need to add GitHub's apt repository first
-                println!("{}", "   添加 GitHub APT 源...".cyan());
+                println!("{}", t!("setup.adding_apt_source").cyan());
 
                 let add_key = Command::new("sh")
                     .args(["-c", "curl -fsSL https://cli.github.com/packages/githubcli-archive-keyring.gpg | sudo dd of=/usr/share/keyrings/githubcli-archive-keyring.gpg"])
                     .status();
 
                 if add_key.is_err() {
-                    return Err(anyhow::anyhow!("添加 GitHub GPG key 失败"));
+                    return Err(anyhow::anyhow!(t!("setup.gpg_key_failed")));
                 }
 
                 let add_repo = Command::new("sh")
@@ -126,7 +125,7 @@ fn install_gh_cli() -> Result<()> {
                     .status();
 
                 if add_repo.is_err() {
-                    return Err(anyhow::anyhow!("添加 GitHub APT 源失败"));
+                    return Err(anyhow::anyhow!(t!("setup.apt_source_failed")));
                 }
 
                 // Update and install
@@ -138,7 +137,7 @@ fn install_gh_cli() -> Result<()> {
                 .map(|o| o.status.success())
                 .unwrap_or(false)
             {
-                println!("{}", "   使用 dnf 安装...".cyan());
+                println!("{}", t!("setup.install_via_dnf").cyan());
                 ("sudo", vec!["dnf", "install", "-y", "gh"])
             } else if Command::new("pacman")
                 .arg("--version")
@@ -146,12 +145,10 @@ fn install_gh_cli() -> Result<()> {
                 .map(|o| o.status.success())
                 .unwrap_or(false)
             {
-                println!("{}", "   使用 pacman 安装...".cyan());
+                println!("{}", t!("setup.install_via_pacman").cyan());
                 ("sudo", vec!["pacman", "-S", "--noconfirm", "github-cli"])
             } else {
-                return Err(anyhow::anyhow!(
-                    "未检测到支持的包管理器。请手动安装 gh: https://github.com/cli/cli#installation"
-                ));
+                return Err(anyhow::anyhow!(t!("setup.no_package_manager")));
             }
         }
         "windows" => {
@@ -162,7 +159,7 @@ fn install_gh_cli() -> Result<()> {
                 .map(|o| o.status.success())
                 .unwrap_or(false)
             {
-                println!("{}", "   使用 winget 安装...".cyan());
+                println!("{}", t!("setup.install_via_winget").cyan());
                 ("winget", vec!["install", "--id", "GitHub.cli", "-e"])
             } else if Command::new("scoop")
                 .arg("--version")
@@ -170,18 +167,14 @@ fn install_gh_cli() -> Result<()> {
                 .map(|o| o.status.success())
                 .unwrap_or(false)
             {
-                println!("{}", "   使用 scoop 安装...".cyan());
+                println!("{}", t!("setup.install_via_scoop").cyan());
                 ("scoop", vec!["install", "gh"])
             } else {
-                return Err(anyhow::anyhow!(
-                    "未检测到 winget 或 scoop。请手动安装 gh: https://github.com/cli/cli#installation"
-                ));
+                return Err(anyhow::anyhow!(t!("setup.no_winget_scoop")));
             }
         }
         _ => {
-            return Err(anyhow::anyhow!(
-                "不支持的操作系统。请手动安装 gh: https://github.com/cli/cli#installation"
-            ));
+            return Err(anyhow::anyhow!(t!("setup.unsupported_os")));
         }
     };
 
@@ -191,21 +184,18 @@ fn install_gh_cli() -> Result<()> {
         .context("执行安装命令失败")?;
 
     if !status.success() {
-        return Err(anyhow::anyhow!("gh CLI 安装失败"));
+        return Err(anyhow::anyhow!(t!("setup.gh_install_failed")));
     }
 
-    println!("{}", "✓ GitHub CLI 安装成功".green());
+    println!("{}", t!("setup.gh_installed").green());
     Ok(())
 }
 
 /// Authenticate with GitHub using web browser
 fn authenticate_gh() -> Result<()> {
     println!();
-    println!("{}", "🔐 需要登录 GitHub 账号".cyan().bold());
-    println!(
-        "{}",
-        "   将打开浏览器进行认证，请在浏览器中完成登录。".cyan()
-    );
+    println!("{}", t!("setup.gh_login_required").cyan().bold());
+    println!("{}", t!("setup.gh_login_browser").cyan());
     println!();
 
     let status = Command::new("gh")
@@ -214,17 +204,17 @@ fn authenticate_gh() -> Result<()> {
         .context("启动 gh auth login 失败")?;
 
     if !status.success() {
-        return Err(anyhow::anyhow!("GitHub 认证失败"));
+        return Err(anyhow::anyhow!(t!("setup.gh_auth_failed")));
     }
 
-    println!("{}", "✓ GitHub 认证成功".green());
+    println!("{}", t!("setup.gh_authenticated").green());
     Ok(())
 }
 
 /// Create a new GitHub repository
 fn create_github_repo(repo_name: &str, private: bool) -> Result<String> {
     println!();
-    println!("{}", format!("📦 正在创建仓库 {}...", repo_name).cyan());
+    println!("{}", t!("setup.creating_repo", name = repo_name).cyan());
 
     let output = Command::new("gh")
         .args([
@@ -262,7 +252,7 @@ fn create_github_repo(repo_name: &str, private: bool) -> Result<String> {
         return Ok(format!("https://github.com/{}/{}.git", username, repo_name));
     }
 
-    println!("{}", "✓ 仓库创建成功".green());
+    println!("{}", t!("setup.repo_created").green());
     Ok(format!("{}.git", url))
 }
 
@@ -271,20 +261,18 @@ fn ensure_gh_ready() -> Result<()> {
     // Check if gh is installed
     if !is_gh_installed() {
         println!();
-        println!("{}", "⚠️  未检测到 GitHub CLI (gh)".yellow());
+        println!("{}", t!("setup.gh_missing").yellow());
 
-        let install = Confirm::new("是否自动安装 GitHub CLI?")
+        let install = Confirm::new(t!("setup.gh_install_confirm"))
             .with_default(true)
-            .with_help_message("需要 gh CLI 来创建仓库和进行认证")
+            .with_help_message(t!("setup.gh_install_help"))
             .prompt()
             .unwrap_or(false);
 
         if install {
             install_gh_cli()?;
         } else {
-            return Err(anyhow::anyhow!(
-                "需要 GitHub CLI。请手动安装: https://github.com/cli/cli#installation"
-            ));
+            return Err(anyhow::anyhow!(t!("setup.gh_required")));
         }
     }
 
@@ -292,7 +280,7 @@ fn ensure_gh_ready() -> Result<()> {
     if !is_gh_authenticated() {
         authenticate_gh()?;
     } else {
-        println!("{}", "✓ GitHub CLI 已认证".green());
+        println!("{}", t!("setup.gh_already_authenticated").green());
     }
 
     Ok(())
@@ -312,11 +300,11 @@ fn confirm_overwrite_and_clone(
 
     if overwrite {
         std::fs::remove_dir_all(local_path).context("删除已有目录失败")?;
-        println!("{}", "📥 正在克隆仓库...".cyan());
+        println!("{}", t!("setup.cloning").cyan());
         clone_with_retry(remote_url, local_path)?;
         Ok(true)
     } else {
-        println!("{}", "已取消。请手动清理目录后重试。".yellow());
+        println!("{}", t!("setup.overwrite_cancelled").yellow());
         Ok(false)
     }
 }
@@ -340,19 +328,19 @@ fn clone_with_retry(remote_url: &str, local_path: &std::path::Path) -> Result<()
             Ok(()) => {
                 // Retry clone after authentication
                 println!();
-                println!("{}", "📥 重新尝试克隆...".cyan());
+                println!("{}", t!("setup.retrying_clone").cyan());
                 scm::clone(remote_url, local_path).context("重试克隆仍然失败")?;
             }
             Err(ref retry_err) if retry_err.to_string() == "REPO_NOT_FOUND_CREATE_NEW" => {
                 // User wants to create new repo
                 ensure_gh_ready()?;
 
-                let repo_name = Text::new("新仓库名称:")
+                let repo_name = Text::new(t!("setup.new_repo_name"))
                     .with_default("claude-code-history")
                     .prompt()
                     .context("取消输入仓库名称")?;
 
-                let private = Confirm::new("设为私有仓库?")
+                let private = Confirm::new(t!("setup.private_repo"))
                     .with_default(true)
                     .prompt()
                     .unwrap_or(true);
@@ -360,7 +348,7 @@ fn clone_with_retry(remote_url: &str, local_path: &std::path::Path) -> Result<()
                 let new_url = create_github_repo(&repo_name, private)?;
 
                 println!();
-                println!("{}", "📥 克隆新仓库...".cyan());
+                println!("{}", t!("setup.cloning_new").cyan());
                 scm::clone(&new_url, local_path).context("克隆新仓库失败")?;
             }
             Err(e) => return Err(e),
@@ -374,7 +362,7 @@ fn handle_clone_failure(error: &anyhow::Error, remote_url: &str) -> Result<()> {
     let error_msg = error.to_string().to_lowercase();
 
     println!();
-    println!("{}", "❌ 克隆仓库失败".red().bold());
+    println!("{}", t!("setup.clone_failed").red().bold());
     println!();
 
     if error_msg.contains("no such file or directory")
@@ -382,7 +370,7 @@ fn handle_clone_failure(error: &anyhow::Error, remote_url: &str) -> Result<()> {
         || error_msg.contains("command not found")
     {
         // Git not installed (shouldn't happen if pre-flight check passes, but just in case)
-        println!("{}", "💡 未找到 git 命令。请先安装 Git:".yellow());
+        println!("{}", t!("setup.git_not_found").yellow());
         print_git_install_instructions();
     } else if error_msg.contains("authentication")
         || error_msg.contains("auth")
@@ -391,18 +379,26 @@ fn handle_clone_failure(error: &anyhow::Error, remote_url: &str) -> Result<()> {
         || error_msg.contains("401")
     {
         // Authentication error
-        println!("{}", "💡 这可能是认证问题。解决方案:".yellow());
+        println!("{}", t!("setup.auth_problem").yellow());
         println!();
-        println!("   {} 使用 GitHub CLI 网页认证 (推荐)", "方式一:".cyan());
-        println!("      运行: gh auth login --web");
+        println!(
+            "   {} {}",
+            t!("setup.auth_option_one").cyan(),
+            t!("setup.auth_option_gh")
+        );
+        println!("{}", t!("setup.auth_run_gh"));
         println!();
-        println!("   {} 使用 Personal Access Token", "方式二:".cyan());
-        println!("      1. 访问 https://github.com/settings/tokens");
-        println!("      2. 创建 token (需要 repo 权限)");
-        println!("      3. 使用格式: https://<token>@github.com/user/repo.git");
+        println!(
+            "   {} {}",
+            t!("setup.auth_option_two").cyan(),
+            t!("setup.auth_option_token")
+        );
+        println!("{}", t!("setup.auth_token_step1"));
+        println!("{}", t!("setup.auth_token_step2"));
+        println!("{}", t!("setup.auth_token_step3"));
         println!();
 
-        let retry_auth = Confirm::new("是否使用 GitHub CLI 进行网页认证?")
+        let retry_auth = Confirm::new(t!("setup.auth_gh_confirm"))
             .with_default(true)
             .prompt()
             .unwrap_or(false);
@@ -417,44 +413,40 @@ fn handle_clone_failure(error: &anyhow::Error, remote_url: &str) -> Result<()> {
     {
         // Repository not found — could be genuinely missing OR a private repo without access
         // (GitHub returns "not found" for unauthorized access to private repos)
-        println!("{}", "💡 仓库不存在或无访问权限。".yellow());
+        println!("{}", t!("setup.not_found").yellow());
         println!();
-        println!("   可能的原因:");
-        println!("   1. 仓库地址不正确");
-        println!("   2. 仓库尚未创建");
-        println!("   3. {}", "这是一个私有仓库，需要先登录 GitHub".cyan());
+        println!("{}", t!("setup.possible_causes_indented"));
+        println!("{}", t!("setup.cause_wrong_url_numbered"));
+        println!("{}", t!("setup.cause_not_created"));
+        println!("   3. {}", t!("setup.cause_private").cyan());
         println!();
-        println!("   当前地址: {}", remote_url.cyan());
+        println!("   {}", t!("setup.current_url", url = remote_url.cyan()));
         println!();
 
-        let action = Select::new(
-            "请选择:",
-            vec!["先登录 GitHub 再重试 (私有仓库推荐)", "创建新仓库", "取消"],
-        )
-        .prompt()
-        .unwrap_or("取消");
+        let login = t!("setup.not_found_login_retry");
+        let create = t!("setup.not_found_create");
+        let cancel = t!("common.cancel");
+        let action = Select::new(t!("setup.choose"), vec![login, create, cancel])
+            .prompt()
+            .unwrap_or(cancel);
 
-        match action {
-            "先登录 GitHub 再重试 (私有仓库推荐)" => {
-                ensure_gh_ready()?;
-                return Ok(()); // Signal to retry clone
-            }
-            "创建新仓库" => {
-                return Err(anyhow::anyhow!("REPO_NOT_FOUND_CREATE_NEW"));
-            }
-            _ => {}
+        if action == login {
+            ensure_gh_ready()?;
+            return Ok(()); // Signal to retry clone
+        } else if action == create {
+            return Err(anyhow::anyhow!("REPO_NOT_FOUND_CREATE_NEW"));
         }
     } else {
         // Generic error
-        println!("   错误信息: {}", error);
+        println!("   {}", t!("setup.error_message", error = error));
         println!();
-        println!("{}", "💡 可能的原因:".yellow());
-        println!("   - 网络连接问题");
-        println!("   - 仓库地址不正确");
-        println!("   - 没有访问权限");
+        println!("{}", t!("setup.possible_causes").yellow());
+        println!("{}", t!("setup.cause_network"));
+        println!("{}", t!("setup.cause_wrong_url"));
+        println!("{}", t!("setup.cause_no_access"));
     }
 
-    Err(anyhow::anyhow!("克隆失败，请解决上述问题后重试"))
+    Err(anyhow::anyhow!(t!("setup.clone_failed_retry")))
 }
 
 /// Print git installation instructions for each platform.
@@ -467,25 +459,25 @@ fn print_git_install_instructions() {
 /// Run the interactive setup wizard
 pub fn handle_setup(skip_sync: bool) -> Result<()> {
     println!();
-    println!("{}", "🔧 Claude Code Sync 配置向导".cyan().bold());
+    println!("{}", t!("setup.title").cyan().bold());
     println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".cyan());
     println!();
 
     // Pre-flight: ensure git is installed
     if !scm::Backend::Git.is_available() {
-        println!("{}", "❌ 未检测到 Git".red().bold());
+        println!("{}", t!("setup.git_missing").red().bold());
         println!();
-        println!("{}", "💡 请先安装 Git:".yellow());
+        println!("{}", t!("setup.install_git_first").yellow());
         print_git_install_instructions();
-        return Err(anyhow::anyhow!("需要安装 Git 才能使用 Claude Code Sync"));
+        return Err(anyhow::anyhow!(t!("setup.git_required")));
     }
 
     // Step 1: Select sync mode
     let sync_mode = Select::new(
-        "选择同步模式:",
+        t!("setup.select_mode"),
         vec![SyncMode::MultiDevice, SyncMode::SingleDevice],
     )
-    .with_help_message("多设备模式允许在不同电脑间同步相同项目名的对话")
+    .with_help_message(t!("setup.select_mode_help"))
     .prompt()
     .context("取消选择同步模式")?;
 
@@ -495,44 +487,41 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
     if let Ok(existing_config) = crate::filter::FilterConfig::load() {
         if existing_config.use_project_name_only != use_project_name_only {
             println!();
-            println!("{}", "⚠️  检测到同步模式变更".yellow().bold());
+            println!("{}", t!("setup.mode_changed").yellow().bold());
             println!("{}", "─".repeat(50).dimmed());
 
             let old_mode = if existing_config.use_project_name_only {
-                "多设备同步"
+                t!("setup.mode_multi")
             } else {
-                "单设备备份"
+                t!("setup.mode_single")
             };
             let new_mode = if use_project_name_only {
-                "多设备同步"
+                t!("setup.mode_multi")
             } else {
-                "单设备备份"
+                t!("setup.mode_single")
             };
 
-            println!(
-                "当前配置: {} → 新选择: {}",
-                old_mode.cyan(),
-                new_mode.green()
-            );
-            println!();
             println!(
                 "{}",
-                "切换模式可能导致同步仓库中出现混合目录格式。".yellow()
-            );
-            println!(
-                "{}",
-                "建议在切换后手动清理旧格式的目录以避免数据重复。".yellow()
+                t!(
+                    "setup.mode_change",
+                    old = old_mode.cyan(),
+                    new = new_mode.green()
+                )
             );
+            println!();
+            println!("{}", t!("setup.mode_change_mixed").yellow());
+            println!("{}", t!("setup.mode_change_cleanup").yellow());
             println!("{}", "─".repeat(50).dimmed());
             println!();
 
-            let confirm = Confirm::new("确认切换模式？")
+            let confirm = Confirm::new(t!("setup.mode_change_confirm"))
                 .with_default(true)
                 .prompt()
                 .context("取消确认")?;
 
             if !confirm {
-                return Err(anyhow::anyhow!("用户取消配置"));
+                return Err(anyhow::anyhow!(t!("setup.user_cancelled")));
             }
         }
     }
@@ -541,10 +530,10 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
 
     // Step 2: Select repository source
     let repo_source = Select::new(
-        "仓库来源:",
+        t!("setup.repo_source"),
         vec![RepoSource::Existing, RepoSource::CreateNew],
     )
-    .with_help_message("选择使用已有仓库还是创建新仓库")
+    .with_help_message(t!("setup.repo_source_help"))
     .prompt()
     .context("取消选择仓库来源")?;
 
@@ -555,15 +544,15 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
 
             println!();
 
-            let repo_name = Text::new("新仓库名称:")
+            let repo_name = Text::new(t!("setup.new_repo_name"))
                 .with_default("claude-code-history")
-                .with_help_message("将在你的 GitHub 账号下创建此仓库")
+                .with_help_message(t!("setup.new_repo_help"))
                 .prompt()
                 .context("取消输入仓库名称")?;
 
-            let private = Confirm::new("设为私有仓库?")
+            let private = Confirm::new(t!("setup.private_repo"))
                 .with_default(true)
-                .with_help_message("私有仓库只有你能访问，推荐用于存储对话历史")
+                .with_help_message(t!("setup.private_repo_help"))
                 .prompt()
                 .unwrap_or(true);
 
@@ -572,9 +561,9 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
         RepoSource::Existing => {
             println!();
 
-            Text::new("远程仓库地址:")
+            Text::new(t!("setup.remote_url"))
                 .with_placeholder("https://github.com/username/claude-code-history.git")
-                .with_help_message("Git 仓库地址，用于备份和同步对话历史")
+                .with_help_message(t!("setup.remote_url_help"))
                 .prompt()
                 .context("取消输入远程仓库地址")?
         }
@@ -582,9 +571,7 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
 
     // Validate URL
     if !is_valid_git_url(&remote_url) {
-        return Err(anyhow::anyhow!(
-            "无效的 Git URL。必须以 'https://', 'http://', 'git@' 或 'ssh://' 开头"
-        ));
+        return Err(anyhow::anyhow!(t!("setup.invalid_url")));
     }
 
     println!();
@@ -594,9 +581,9 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
         .map(|p| p.display().to_string())
         .unwrap_or_else(|_| "~/claude-history-backup".to_string());
 
-    let local_path_str = Text::new("本地备份目录:")
+    let local_path_str = Text::new(t!("setup.local_dir"))
         .with_default(&default_path)
-        .with_help_message("对话历史将同步到此目录")
+        .with_help_message(t!("setup.local_dir_help"))
         .prompt()
         .context("取消输入本地目录")?;
 
@@ -605,28 +592,32 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
     println!();
 
     // Show configuration summary
-    println!("{}", "📋 配置摘要".cyan().bold());
+    println!("{}", t!("setup.summary").cyan().bold());
     println!(
         "   {} {}",
-        "模式:".cyan(),
+        t!("setup.summary_mode").cyan(),
         if use_project_name_only {
-            "多设备同步"
+            t!("setup.mode_multi")
         } else {
-            "单设备备份"
+            t!("setup.mode_single")
         }
     );
-    println!("   {} {}", "远程:".cyan(), remote_url);
-    println!("   {} {}", "本地:".cyan(), local_path.display());
+    println!("   {} {}", t!("setup.summary_remote").cyan(), remote_url);
+    println!(
+        "   {} {}",
+        t!("setup.summary_local").cyan(),
+        local_path.display()
+    );
     println!();
 
     // Confirm
-    let confirm = Confirm::new("确认以上配置?")
+    let confirm = Confirm::new(t!("setup.confirm_config"))
         .with_default(true)
         .prompt()
         .context("取消确认")?;
 
     if !confirm {
-        println!("{}", "已取消配置。".yellow());
+        println!("{}", t!("setup.config_cancelled").yellow());
         return Ok(());
     }
 
@@ -644,21 +635,21 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
                 normalize_git_url(&existing_remote) == normalize_git_url(&remote_url);
 
             if remote_matches {
-                println!("{}", "📦 检测到已有仓库，正在拉取最新变更...".cyan());
+                println!("{}", t!("setup.existing_repo_pulling").cyan());
                 let branch = existing_scm
                     .current_branch()
                     .unwrap_or_else(|_| "main".to_string());
                 existing_scm.pull("origin", &branch).ok(); // best-effort pull
             } else {
-                println!("{}", "⚠️  目标目录已存在一个不同的仓库".yellow().bold());
-                println!("   已有远程: {}", existing_remote);
-                println!("   新的远程: {}", remote_url);
+                println!("{}", t!("setup.different_repo").yellow().bold());
+                println!("   {}", t!("setup.existing_remote", url = existing_remote));
+                println!("   {}", t!("setup.new_remote", url = &remote_url));
                 println!();
 
                 if !confirm_overwrite_and_clone(
                     &local_path,
                     &remote_url,
-                    "是否删除已有仓库并重新克隆?",
+                    t!("setup.overwrite_repo_confirm"),
                 )? {
                     return Ok(());
                 }
@@ -672,48 +663,48 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
             if is_empty {
                 // Empty directory — remove it so clone can proceed
                 std::fs::remove_dir(&local_path).ok();
-                println!("{}", "📥 正在克隆仓库...".cyan());
+                println!("{}", t!("setup.cloning").cyan());
                 clone_with_retry(&remote_url, &local_path)?;
             } else {
-                println!("{}", "⚠️  目标目录已存在且不是 Git 仓库".yellow().bold());
-                println!("   路径: {}", local_path.display());
+                println!("{}", t!("setup.not_a_repo").yellow().bold());
+                println!("   {}", t!("setup.path", path = local_path.display()));
                 println!();
 
                 if !confirm_overwrite_and_clone(
                     &local_path,
                     &remote_url,
-                    "是否删除该目录并重新克隆?",
+                    t!("setup.overwrite_dir_confirm"),
                 )? {
                     return Ok(());
                 }
             }
         }
     } else {
-        println!("{}", "📥 正在克隆仓库...".cyan());
+        println!("{}", t!("setup.cloning").cyan());
         clone_with_retry(&remote_url, &local_path)?;
     }
 
-    println!("{}", "✓ 仓库克隆成功".green());
+    println!("{}", t!("setup.cloned").green());
 
     // Step 5: Initialize sync state
     sync::init_from_onboarding(&local_path, Some(&remote_url), true)
         .context("初始化同步状态失败")?;
 
     // Step 6: Filter preferences
-    let exclude_attachments = Confirm::new("是否排除文件附件 (图片、PDF 等)?")
+    let exclude_attachments = Confirm::new(t!("setup.exclude_attachments"))
         .with_default(true)
-        .with_help_message("仅同步 .jsonl 对话文件，排除附件可减少存储空间")
+        .with_help_message(t!("setup.exclude_attachments_help"))
         .prompt()
         .unwrap_or(true);
 
-    let exclude_old = Confirm::new("是否排除旧对话?")
+    let exclude_old = Confirm::new(t!("setup.exclude_old"))
         .with_default(false)
-        .with_help_message("仅同步近期修改的对话")
+        .with_help_message(t!("setup.exclude_old_help"))
         .prompt()
         .unwrap_or(false);
 
     let exclude_older_than_days = if exclude_old {
-        let days_str = Text::new("排除多少天前的对话:")
+        let days_str = Text::new(t!("setup.exclude_old_days"))
             .with_default("30")
             .prompt()
             .unwrap_or_else(|_| "30".to_string());
@@ -734,32 +725,41 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
 
     // Step 7: Optional initial sync
     if !skip_sync {
-        let do_sync = Confirm::new("是否立即同步?")
+        let do_sync = Confirm::new(t!("setup.sync_now"))
             .with_default(true)
-            .with_help_message("将本地对话历史推送到远程仓库")
+            .with_help_message(t!("setup.sync_now_help"))
             .prompt()
             .unwrap_or(false);
 
         if do_sync {
             println!();
-            println!("{}", "🔄 正在同步...".cyan());
+            println!("{}", t!("setup.syncing").cyan());
 
             match sync::sync_bidirectional(
                 None,
                 None,
                 false,
                 false,
+                crate::conflict::Prefer::Interactive,
+                false,
+                &[],
+                &[],
+                None,
                 false,
                 crate::VerbosityLevel::Normal,
             ) {
                 Ok(()) => {
-                    println!("{}", "✓ 同步完成".green());
+                    println!("{}", t!("setup.sync_done").green());
                 }
                 Err(e) => {
-                    println!("{} {}", "⚠️  同步时出现问题:".yellow(), e);
+                    println!("{} {}", t!("setup.sync_problem").yellow(), e);
                     println!(
                         "{}",
-                        format!("   可以稍后使用 '{} sync' 重试", BINARY_NAME).yellow()
+                        t!(
+                            "setup.sync_retry_hint",
+                            command = format!("{} sync", BINARY_NAME)
+                        )
+                        .yellow()
                     );
                 }
             }
@@ -768,21 +768,21 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
 
     // Step 8: Configure auto-sync (hooks + wrapper)
     println!();
-    let setup_auto_sync = Confirm::new("是否配置自动同步？")
+    let setup_auto_sync = Confirm::new(t!("setup.auto_sync"))
         .with_default(true)
-        .with_help_message("启动时自动拉取，退出时自动推送，无需手动执行命令")
+        .with_help_message(t!("setup.auto_sync_help"))
         .prompt()
         .unwrap_or(false);
 
     if setup_auto_sync {
         println!();
-        println!("{}", "🔧 正在配置自动同步...".cyan());
+        println!("{}", t!("setup.configuring_auto_sync").cyan());
 
         // Install hooks
         match crate::handlers::hooks::handle_hooks_install() {
             Ok(()) => {}
             Err(e) => {
-                println!("{} {}", "⚠️  Hooks 安装失败:".yellow(), e);
+                println!("{} {}", t!("setup.hooks_install_failed").yellow(), e);
             }
         }
 
@@ -790,26 +790,29 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
         match crate::handlers::wrapper::handle_wrapper_install(false) {
             Ok(wrapper_path) => {
                 println!();
-                println!("{}", "✓ 自动同步已配置".green());
+                println!("{}", t!("setup.auto_sync_configured").green());
                 println!();
-                println!("{}", "使用方式:".cyan());
+                println!("{}", t!("setup.usage").cyan());
+                println!(
+                    "   {}",
+                    t!("setup.wrapper_usage", command = "claude-sync".bold())
+                );
                 println!(
-                    "   使用 {} 启动 Claude Code（替代 claude 命令）",
-                    "claude-sync".bold()
+                    "   {}",
+                    t!("setup.wrapper_alias", path = wrapper_path.display())
                 );
-                println!("   或添加别名: alias claude='{}'", wrapper_path.display());
             }
             Err(e) => {
-                println!("{} {}", "⚠️  Wrapper 安装失败:".yellow(), e);
+                println!("{} {}", t!("setup.wrapper_install_failed").yellow(), e);
             }
         }
     }
 
     // Step 9: Configure config sync (settings.json, CLAUDE.md, etc.)
     println!();
-    let sync_config = Confirm::new("是否同步配置文件？")
+    let sync_config = Confirm::new(t!("setup.sync_config"))
         .with_default(true)
-        .with_help_message("同步 settings.json、CLAUDE.md 等配置到远程仓库")
+        .with_help_message(t!("setup.sync_config_help"))
         .prompt()
         .unwrap_or(true);
 
@@ -819,62 +822,83 @@ pub fn handle_setup(skip_sync: bool) -> Result<()> {
     if sync_config {
         // Let user choose what to sync
         println!();
-        println!("{}", "选择需要同步的配置项:".cyan());
+        println!("{}", t!("setup.select_config_items").cyan());
 
-        filter_config.config_sync.sync_settings =
-            Confirm::new("  同步 settings.json (权限、模型配置)?")
-                .with_default(true)
-                .prompt()
-                .unwrap_or(true);
+        filter_config.config_sync.sync_settings = Confirm::new(t!("setup.sync_settings"))
+            .with_default(true)
+            .prompt()
+            .unwrap_or(true);
 
-        filter_config.config_sync.sync_claude_md = Confirm::new("  同步 CLAUDE.md (用户指令)?")
+        filter_config.config_sync.sync_claude_md = Confirm::new(t!("setup.sync_claude_md"))
             .with_default(true)
             .prompt()
             .unwrap_or(true);
 
-        filter_config.config_sync.sync_hooks = Confirm::new("  同步 hooks (钩子脚本)?")
+        filter_config.config_sync.sync_hooks = Confirm::new(t!("setup.sync_hooks"))
             .with_default(false)
-            .with_help_message("注意: hooks 路径可能不跨平台兼容")
+            .with_help_message(t!("setup.sync_hooks_help"))
             .prompt()
             .unwrap_or(false);
 
-        filter_config.config_sync.sync_skills_list = Confirm::new("  同步 skills/plugins 列表?")
+        filter_config.config_sync.sync_skills_list = Confirm::new(t!("setup.sync_skills"))
             .with_default(true)
-            .with_help_message("仅同步列表，需要在每台设备手动安装")
+            .with_help_message(t!("setup.sync_skills_help"))
             .prompt()
             .unwrap_or(true);
     }
 
     filter_config.save().context("保存配置失败")?;
-    println!("{}", "✓ 配置已保存".green());
+    println!("{}", t!("setup.config_saved").green());
 
     println!();
     println!("{}", "━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━".green());
-    println!("{}", "🎉 配置完成！".green().bold());
+    println!("{}", t!("setup.done").green().bold());
     println!();
 
     if setup_auto_sync {
-        println!("{}", "自动同步已启用，使用 claude-sync 启动即可。".cyan());
+        println!("{}", t!("setup.auto_sync_enabled").cyan());
         println!();
-        println!("{}", "管理命令:".cyan());
+        println!("{}", t!("setup.management_commands").cyan());
         println!(
-            "   {} - 查看自动同步状态",
-            format!("{} automate --status", BINARY_NAME).bold()
+            "   {} - {}",
+            format!("{} automate --status", BINARY_NAME).bold(),
+            t!("setup.cmd_automate_status")
         );
         println!(
-            "   {} - 卸载自动同步",
-            format!("{} automate --uninstall", BINARY_NAME).bold()
+            "   {} - {}",
+            format!("{} automate --uninstall", BINARY_NAME).bold(),
+            t!("setup.cmd_automate_uninstall")
         );
     } else {
-        println!("{}", "常用命令:".cyan());
-        println!("   {} - 双向同步", format!("{} sync", BINARY_NAME).bold());
-        println!("   {} - 推送到远程", format!("{} push", BINARY_NAME).bold());
-        println!("   {} - 拉取到本地", format!("{} pull", BINARY_NAME).bold());
-        println!("   {} - 查看状态", format!("{} status", BINARY_NAME).bold());
+        println!("{}", t!("setup.common_commands").cyan());
+        println!(
+            "   {} - {}",
+            format!("{} sync", BINARY_NAME).bold(),
+            t!("setup.cmd_sync")
+        );
+        println!(
+            "   {} - {}",
+            format!("{} push", BINARY_NAME).bold(),
+            t!("setup.cmd_push")
+        );
+        println!(
+            "   {} - {}",
+            format!("{} pull", BINARY_NAME).bold(),
+            t!("setup.cmd_pull")
+        );
+        println!(
+            "   {} - {}",
+            format!("{} status", BINARY_NAME).bold(),
+            t!("setup.cmd_status")
+        );
         println!();
         println!(
             "{}",
-            format!("提示: 运行 '{} automate' 可配置自动同步", BINARY_NAME).dimmed()
+            t!(
+                "setup.automate_hint",
+                command = format!("{} automate", BINARY_NAME)
+            )
+            .dimmed()
         );
     }
     println!();
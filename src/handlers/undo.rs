@@ -1,13 +1,19 @@
 //! Undo command handlers
 //!
-//! Handles the undo pull and undo push commands, including preview
-//! and confirmation dialogs when running interactively.
+//! Handles the undo pull, undo push and undo session commands, including
+//! preview and confirmation dialogs when running interactively, plus
+//! `ccs undo list` and `ccs undo <ID>` for undoing a specific recorded
+//! operation instead of the latest one.
 
-use anyhow::{Context, Result};
+use anyhow::{bail, Context, Result};
 use colored::Colorize;
-use inquire::Confirm;
+use std::path::Path;
 
+use crate::filter::FilterConfig;
+use crate::handlers::session::{forget_session_deletion, undo_rename_session};
+use crate::history::{OperationHistory, OperationRecord, OperationType};
 use crate::interactive_conflict;
+use crate::scm::Scm;
 use crate::sync;
 use crate::undo;
 use crate::BINARY_NAME;
@@ -46,11 +52,12 @@ pub fn handle_undo_pull(preview_only: bool, verbosity: crate::VerbosityLevel) ->
 
     if is_interactive {
         // Ask for confirmation
-        let confirm = Confirm::new("Do you want to proceed with this undo operation?")
-            .with_default(false)
-            .with_help_message("This will restore files to their pre-pull state")
-            .prompt()
-            .context("Failed to get confirmation")?;
+        let confirm = interactive_conflict::confirm_with_help(
+            "Do you want to proceed with this undo operation?",
+            "This will restore files to their pre-pull state",
+            false,
+            interactive_conflict::assume_yes(),
+        )?;
 
         if !confirm {
             println!("\n{}", "Undo operation cancelled.".yellow());
@@ -103,6 +110,13 @@ pub fn handle_undo_push(preview_only: bool, verbosity: crate::VerbosityLevel) ->
     let preview = undo::preview_undo_push(None).context("Failed to preview undo operation")?;
     preview.display(undo_verbosity);
 
+    let repo = open_repo_for_undo(&state.sync_repo_path)?;
+    if let Some(target) = &preview.commit_hash {
+        if verbosity != crate::VerbosityLevel::Quiet {
+            show_reset_target(repo.as_ref(), target);
+        }
+    }
+
     // If preview-only mode, exit now
     if preview_only {
         if verbosity != crate::VerbosityLevel::Quiet {
@@ -111,16 +125,163 @@ pub fn handle_undo_push(preview_only: bool, verbosity: crate::VerbosityLevel) ->
         return Ok(());
     }
 
-    // Check if we're in an interactive terminal
-    let is_interactive = interactive_conflict::is_interactive();
+    let _lock = sync::SyncLock::acquire("undo-push")?;
+    if !ensure_clean_sync_repo(repo.as_ref())? || !confirm_push_reset()? {
+        println!("\n{}", "Undo operation cancelled.".yellow());
+        return Ok(());
+    }
 
-    if is_interactive {
-        // Ask for confirmation
-        let confirm = Confirm::new("Do you want to proceed with this undo operation?")
-            .with_default(false)
-            .with_help_message("This will reset the git repository to the previous commit")
-            .prompt()
-            .context("Failed to get confirmation")?;
+    if verbosity != crate::VerbosityLevel::Quiet {
+        println!("\n{}", "Undoing last push operation...".cyan());
+    }
+
+    // Call undo_push with repository path and default history path
+    let summary =
+        undo::undo_push(&state.sync_repo_path, None).context("Failed to undo push operation")?;
+
+    if verbosity == crate::VerbosityLevel::Quiet {
+        println!("Push undone successfully");
+    } else {
+        println!("\n{}", "SUCCESS".green().bold());
+        println!("{summary}");
+    }
+
+    Ok(())
+}
+
+/// Open the sync repo to reset it, with the configured SSH identity applied
+/// like push and pull do
+fn open_repo_for_undo(repo_path: &Path) -> Result<Box<dyn Scm>> {
+    sync::open_sync_repo(repo_path, &FilterConfig::load()?)
+        .with_context(|| format!("Failed to open repository at {}", repo_path.display()))
+}
+
+/// Show the commit a push undo resets to and what the reset takes back
+fn show_reset_target(repo: &dyn Scm, target: &str) {
+    let short_hash = &target[..target.len().min(8)];
+    let summary = repo.commit_summary(target).unwrap_or_default();
+    println!(
+        "\n{} {} {}",
+        "Reset to:".bold(),
+        short_hash.yellow(),
+        summary
+    );
+
+    match repo.diff_stat(target, "HEAD") {
+        Ok(stat) if stat.is_empty() => {
+            println!("{}", "No file changes since that commit.".dimmed())
+        }
+        Ok(stat) => {
+            println!("{}", "Changes that will be reverted:".bold());
+            for line in stat.lines() {
+                println!("  {}", line.trim_start());
+            }
+        }
+        Err(e) => log::warn!("Could not summarize changes since {}: {}", short_hash, e),
+    }
+}
+
+/// Make sure the sync repo has no uncommitted changes before a reset
+///
+/// Offers to stash them when running interactively. Returns `false` if the
+/// user declines.
+fn ensure_clean_sync_repo(repo: &dyn Scm) -> Result<bool> {
+    if !repo.has_changes()? {
+        return Ok(true);
+    }
+
+    println!(
+        "\n{}",
+        "The sync repository has uncommitted changes.".yellow()
+    );
+    if !interactive_conflict::is_interactive() {
+        bail!("Commit or stash the changes in the sync repository before undoing a push");
+    }
+
+    // Never stash on --yes alone; the changes are not ours
+    let stash = interactive_conflict::confirm_with_help(
+        "Stash them and continue?",
+        "Restore them later with 'git stash pop' in the sync repository",
+        false,
+        false,
+    )?;
+    if stash {
+        repo.stash("ccs undo push")?;
+        println!("{} Changes stashed", crate::output::icon("✓").green());
+    }
+    Ok(stash)
+}
+
+/// Confirm resetting the sync repo. Without a terminal only `--yes` proceeds.
+fn confirm_push_reset() -> Result<bool> {
+    if !interactive_conflict::is_interactive() && !interactive_conflict::assume_yes() {
+        bail!(
+            "Undoing a push resets the sync repository; run with --yes to confirm without a terminal"
+        );
+    }
+    interactive_conflict::confirm_with_help(
+        "Do you want to proceed with this undo operation?",
+        "This will reset the sync repository to the commit shown above",
+        false,
+        interactive_conflict::assume_yes(),
+    )
+}
+
+/// Handle undo session command
+///
+/// Reverts the most recent `ccs session delete` or `ccs session rename`
+/// (including those made from the interactive session manager).
+///
+/// # Arguments
+/// * `preview_only` - If true, only show what would be undone
+/// * `verbosity` - Output verbosity level
+pub fn handle_undo_session(preview_only: bool, verbosity: crate::VerbosityLevel) -> Result<()> {
+    let mut history = OperationHistory::load().context("Failed to load operation history")?;
+    let Some(record) = history
+        .list_operations()
+        .iter()
+        .find(|op| op.operation_type.is_session_operation())
+        .cloned()
+    else {
+        bail!("No session delete or rename found in history to undo");
+    };
+    let Some(session) = record.affected_conversations.first() else {
+        bail!("Last session operation has no session recorded. Cannot undo.");
+    };
+
+    if verbosity != crate::VerbosityLevel::Quiet {
+        let op_type = match record.operation_type {
+            OperationType::SessionDelete => "SESSION DELETE".red(),
+            _ => "SESSION RENAME".yellow(),
+        };
+        println!("{} {}", "Operation:".bold(), op_type);
+        println!(
+            "{} {}",
+            "Performed:".bold(),
+            record.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        println!("{} {}", "Session:".bold(), session.session_id);
+        println!("{} {}", "File:".bold(), session.project_path);
+    }
+
+    if preview_only {
+        if verbosity != crate::VerbosityLevel::Quiet {
+            println!("\n{}", "Preview only - no changes made.".yellow());
+        }
+        return Ok(());
+    }
+
+    if interactive_conflict::is_interactive() {
+        let help = match record.operation_type {
+            OperationType::SessionDelete => "This will restore the deleted session file",
+            _ => "This will restore the previous session title",
+        };
+        let confirm = interactive_conflict::confirm_with_help(
+            "Do you want to proceed with this undo operation?",
+            help,
+            false,
+            interactive_conflict::assume_yes(),
+        )?;
 
         if !confirm {
             println!("\n{}", "Undo operation cancelled.".yellow());
@@ -128,16 +289,247 @@ pub fn handle_undo_push(preview_only: bool, verbosity: crate::VerbosityLevel) ->
         }
     }
 
+    let summary = match record.operation_type {
+        OperationType::SessionDelete => {
+            let summary =
+                undo::undo_session_delete(None, None).context("Failed to undo session delete")?;
+            forget_session_deletion(&session.session_id)
+                .context("Session restored, but failed to clear its deletion record")?;
+            summary
+        }
+        _ => {
+            let file_path = Path::new(&session.project_path);
+            if !undo_rename_session(file_path, &session.session_id)? {
+                bail!("Session {} has no rename to undo", session.session_id);
+            }
+            history.remove_last_operation_by_type(OperationType::SessionRename, None)?;
+            format!("Restored previous title of session {}", session.session_id)
+        }
+    };
+
+    if verbosity == crate::VerbosityLevel::Quiet {
+        println!("Session operation undone successfully");
+    } else {
+        println!("\n{}", "SUCCESS".green().bold());
+        println!("{summary}");
+    }
+
+    Ok(())
+}
+
+/// Colored label for an operation type
+fn operation_label(op_type: OperationType) -> colored::ColoredString {
+    match op_type {
+        OperationType::Pull => "PULL".green(),
+        OperationType::Push => "PUSH".blue(),
+        OperationType::SessionDelete => "SESSION DELETE".red(),
+        OperationType::SessionRename => "SESSION RENAME".yellow(),
+    }
+}
+
+/// What undoing a record relies on, for `ccs undo list`
+fn undo_source(record: &OperationRecord) -> String {
+    match (&record.commit_hash, &record.snapshot_path) {
+        (Some(hash), _) => format!("commit {}", &hash[..hash.len().min(8)]),
+        (None, Some(path)) if path.exists() => "snapshot".to_string(),
+        (None, Some(_)) => "snapshot missing".to_string(),
+        (None, None) if record.operation_type == OperationType::SessionRename => {
+            "title history".to_string()
+        }
+        (None, None) => "unavailable".to_string(),
+    }
+}
+
+/// Handle `ccs undo list`
+///
+/// Lists the recorded operations, most recent first, numbered for
+/// `ccs undo <ID>`.
+pub fn handle_undo_list() -> Result<()> {
+    let history = OperationHistory::load().context("Failed to load operation history")?;
+    if history.is_empty() {
+        println!("{}", "No operations in history.".yellow());
+        return Ok(());
+    }
+
+    println!("{}", "Recorded operations (most recent first)".cyan().bold());
+    println!(
+        "  {:<4} {:<16} {:<21} {:>13}  {}",
+        "ID".bold(),
+        "Type".bold(),
+        "Time".bold(),
+        "Conversations".bold(),
+        "Undo via".bold()
+    );
+    for (idx, op) in history.list_operations().iter().enumerate() {
+        println!(
+            "  {:<4} {:<16} {:<21} {:>13}  {}",
+            idx + 1,
+            operation_label(op.operation_type),
+            op.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            op.affected_conversations.len(),
+            undo_source(op).dimmed()
+        );
+    }
+    println!();
+    println!(
+        "Undo one with '{}' (add --preview to check first)",
+        format!("{} undo <ID>", BINARY_NAME).cyan()
+    );
+    Ok(())
+}
+
+/// Handle `ccs undo <ID>`
+///
+/// Undoes the operation numbered `id` in `ccs undo list` (1 = most recent).
+/// Undoing an older push resets the sync repo past every later push as well;
+/// undoing an older pull restores its snapshot without reverting later
+/// operations, so both cases are called out before confirming.
+pub fn handle_undo(id: usize, preview_only: bool, verbosity: crate::VerbosityLevel) -> Result<()> {
+    let history = OperationHistory::load().context("Failed to load operation history")?;
+    let operations = history.list_operations();
+    let Some(index) = id.checked_sub(1).filter(|&i| i < operations.len()) else {
+        bail!(
+            "No operation #{} in history ({} recorded). See '{} undo list'.",
+            id,
+            operations.len(),
+            BINARY_NAME
+        );
+    };
+    let record = &operations[index];
+
+    // The most recent session operation goes through `undo session`, which
+    // also clears deletion records and handles renames
+    let latest_session_op = operations
+        .iter()
+        .position(|op| op.operation_type.is_session_operation());
+    if record.operation_type.is_session_operation() && latest_session_op == Some(index) {
+        return handle_undo_session(preview_only, verbosity);
+    }
+    if record.operation_type == OperationType::SessionRename {
+        bail!(
+            "Only the most recent session rename can be undone (use '{} undo session')",
+            BINARY_NAME
+        );
+    }
+
     if verbosity != crate::VerbosityLevel::Quiet {
-        println!("\n{}", "Undoing last push operation...".cyan());
+        println!(
+            "{} {}",
+            "Operation:".bold(),
+            operation_label(record.operation_type)
+        );
+        println!(
+            "{} {}",
+            "Performed:".bold(),
+            record.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
+        );
+        if let Some(branch) = &record.branch {
+            println!("{} {}", "Branch:".bold(), branch);
+        }
+        println!(
+            "{} {}",
+            "Conversations:".bold(),
+            record.affected_conversations.len()
+        );
+        println!("{} {}", "Undo via:".bold(), undo_source(record));
+
+        let later = &operations[..index];
+        match record.operation_type {
+            OperationType::Push => {
+                let later_pushes = later
+                    .iter()
+                    .filter(|op| op.operation_type == OperationType::Push)
+                    .count();
+                if later_pushes > 0 {
+                    println!(
+                        "{}",
+                        format!(
+                            "Resetting to before this push also reverts the {} push(es) made after it.",
+                            later_pushes
+                        )
+                        .yellow()
+                    );
+                }
+            }
+            _ if !later.is_empty() => println!(
+                "{}",
+                format!(
+                    "{} later operation(s) are not reverted; files they changed may be overwritten.",
+                    later.len()
+                )
+                .yellow()
+            ),
+            _ => {}
+        }
     }
 
-    // Call undo_push with repository path and default history path
-    let summary =
-        undo::undo_push(&state.sync_repo_path, None).context("Failed to undo push operation")?;
+    // Pushes are undone by resetting the sync repo, which gets its own checks
+    let sync_repo = if record.operation_type == OperationType::Push {
+        let state = sync::SyncState::load().context(format!(
+            "Sync not initialized. Run '{} init' first.",
+            BINARY_NAME
+        ))?;
+        let repo = open_repo_for_undo(&state.sync_repo_path)?;
+        if verbosity != crate::VerbosityLevel::Quiet {
+            show_reset_target(repo.as_ref(), &undo::push_target_commit(record)?);
+        }
+        Some((state.sync_repo_path, repo))
+    } else {
+        None
+    };
+
+    if preview_only {
+        if verbosity != crate::VerbosityLevel::Quiet {
+            println!("\n{}", "Preview only - no changes made.".yellow());
+        }
+        return Ok(());
+    }
+
+    // Resetting the sync repo must not race a push or pull
+    let _lock = match &sync_repo {
+        Some(_) => Some(sync::SyncLock::acquire("undo-push")?),
+        None => None,
+    };
+    let confirmed = match &sync_repo {
+        Some((_, repo)) => ensure_clean_sync_repo(repo.as_ref())? && confirm_push_reset()?,
+        None if interactive_conflict::is_interactive() => interactive_conflict::confirm_with_help(
+            "Do you want to proceed with this undo operation?",
+            "Files are restored to their state before this operation",
+            false,
+            interactive_conflict::assume_yes(),
+        )?,
+        None => true,
+    };
+    if !confirmed {
+        println!("\n{}", "Undo operation cancelled.".yellow());
+        return Ok(());
+    }
+
+    let summary = match record.operation_type {
+        OperationType::Pull => {
+            undo::undo_pull_at(index, None, None).context("Failed to undo pull operation")?
+        }
+        OperationType::Push => {
+            let (repo_path, _) = sync_repo.as_ref().expect("opened for push operations");
+            undo::undo_push_at(repo_path, index, None).context("Failed to undo push operation")?
+        }
+        _ => {
+            let session_id = record
+                .affected_conversations
+                .first()
+                .map(|s| s.session_id.clone());
+            let summary = undo::undo_session_delete_at(index, None, None)
+                .context("Failed to undo session delete")?;
+            if let Some(session_id) = session_id {
+                forget_session_deletion(&session_id)
+                    .context("Session restored, but failed to clear its deletion record")?;
+            }
+            summary
+        }
+    };
 
     if verbosity == crate::VerbosityLevel::Quiet {
-        println!("Push undone successfully");
+        println!("Operation #{} undone successfully", id);
     } else {
         println!("\n{}", "SUCCESS".green().bold());
         println!("{summary}");
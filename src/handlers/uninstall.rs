@@ -7,6 +7,7 @@ use colored::Colorize;
 use inquire::Confirm;
 
 use crate::config::ConfigManager;
+use crate::output::icon;
 use crate::sync::MultiRepoState;
 
 /// Run the uninstall flow
@@ -71,7 +72,7 @@ pub fn handle_uninstall(force: bool) -> Result<()> {
         if dir.exists() {
             std::fs::remove_dir_all(dir)
                 .with_context(|| format!("删除配置目录失败: {}", dir.display()))?;
-            println!("   {} {}", "✓".green(), dir.display());
+            println!("   {} {}", icon("✓").green(), dir.display());
         } else {
             println!("   {} 配置目录不存在，跳过", "!".yellow());
         }
@@ -96,7 +97,7 @@ pub fn handle_uninstall(force: bool) -> Result<()> {
             if delete_repo {
                 std::fs::remove_dir_all(repo)
                     .with_context(|| format!("删除同步仓库失败: {}", repo.display()))?;
-                println!("   {} 同步仓库已删除", "✓".green());
+                println!("   {} 同步仓库已删除", icon("✓").green());
             } else {
                 println!("   {} 保留同步仓库", "!".yellow());
             }
@@ -129,7 +130,7 @@ pub fn handle_uninstall(force: bool) -> Result<()> {
 
             std::fs::remove_file(exe)
                 .with_context(|| format!("删除二进制失败: {}", exe.display()))?;
-            println!("   {} ccs 二进制已删除", "✓".green());
+            println!("   {} ccs 二进制已删除", icon("✓").green());
         } else {
             println!("   {} 保留 ccs 二进制", "!".yellow());
         }
@@ -1,5 +1,6 @@
 //! CLI handler for `ccs unlock-delete`.
 
+use crate::output::icon;
 use crate::sync::delete_unlock;
 use anyhow::Result;
 use colored::Colorize;
@@ -11,7 +12,7 @@ pub fn handle_unlock_delete(minutes: u64, off: bool, status: bool) -> Result<()>
         delete_unlock::disable()?;
         println!(
             "{} 删除放行窗口已关闭，恢复保护模式。",
-            "✓".green()
+            icon("✓").green()
         );
         return Ok(());
     }
@@ -20,7 +21,7 @@ pub fn handle_unlock_delete(minutes: u64, off: bool, status: bool) -> Result<()>
         match delete_unlock::status()? {
             Some(secs) => println!(
                 "{} 删除放行窗口生效中，剩余约 {} 分钟。",
-                "🔓".yellow(),
+                icon("🔓").yellow(),
                 secs / 60
             ),
             None => println!(
@@ -42,13 +43,13 @@ pub fn handle_unlock_delete(minutes: u64, off: bool, status: bool) -> Result<()>
 
     println!(
         "{} 已开启删除放行窗口 {} 分钟（到期 {}）。",
-        "🔓".yellow(),
+        icon("🔓").yellow(),
         minutes,
         expire_local
     );
     println!(
         "  {} 窗口期内 push（含自动同步）会把本地已删除的 session 同步删除到云端，请谨慎。",
-        "⚠".yellow()
+        icon("⚠").yellow()
     );
     Ok(())
 }
@@ -0,0 +1,189 @@
+//! Line-level validation of session files
+//!
+//! Handles `ccs validate-history`. Session parsing skips malformed lines with
+//! only a debug log, and drops a whole file when a line is not valid UTF-8,
+//! so corrupt conversations can quietly go missing from syncs. This command
+//! checks every line of every `.jsonl` file under `~/.claude/projects/` and
+//! reports which ones fail to parse and why.
+
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::error::SyncError;
+use crate::output::icon;
+use crate::parser::ConversationSession;
+use crate::sync::discovery::{
+    claude_projects_dir, extract_project_name, get_project_name_from_dir,
+};
+
+/// Invalid lines listed per file before the rest are summarized
+const MAX_LINES_PER_FILE: usize = 10;
+
+/// Whether the project directory `dir` is the project `name`: its directory
+/// name, the name taken from its sessions' `cwd`, or the name decoded from
+/// the directory name
+fn project_matches(dir: &Path, name: &str) -> bool {
+    let Some(dir_name) = dir.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    dir_name == name
+        || get_project_name_from_dir(dir).is_some_and(|project| project == name)
+        || extract_project_name(dir_name) == name
+}
+
+/// Project directories to check, all of them without a filter
+fn project_dirs(projects_dir: &Path, project: Option<&str>) -> Result<Vec<PathBuf>> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(projects_dir)
+        .map(|entries| {
+            entries
+                .filter_map(|e| e.ok())
+                .map(|e| e.path())
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default();
+    if let Some(name) = project {
+        dirs.retain(|dir| project_matches(dir, name));
+        if dirs.is_empty() {
+            return Err(SyncError::NotFound {
+                kind: "Project",
+                name: name.to_string(),
+            }
+            .into());
+        }
+    }
+    dirs.sort();
+    Ok(dirs)
+}
+
+/// Handle `ccs validate-history`
+///
+/// Exits non-zero when any line fails to parse.
+pub fn handle_validate_history(project: Option<&str>) -> Result<()> {
+    let projects_dir = claude_projects_dir()?;
+    let mut files_checked = 0;
+    let mut lines_checked = 0;
+    let mut bad_files = 0;
+    let mut bad_lines = 0;
+
+    for dir in project_dirs(&projects_dir, project)? {
+        let mut files: Vec<PathBuf> = WalkDir::new(&dir)
+            .into_iter()
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+            .map(|e| e.into_path())
+            .filter(|p| p.extension().and_then(|s| s.to_str()) == Some("jsonl"))
+            .collect();
+        files.sort();
+
+        for path in files {
+            let validation = match ConversationSession::validate_file(&path) {
+                Ok(validation) => validation,
+                Err(e) => {
+                    log::warn!("Skipping {}: {:#}", path.display(), e);
+                    continue;
+                }
+            };
+            files_checked += 1;
+            lines_checked += validation.lines;
+            if validation.invalid.is_empty() {
+                continue;
+            }
+
+            bad_files += 1;
+            bad_lines += validation.invalid.len();
+            let relative = path.strip_prefix(&projects_dir).unwrap_or(&path);
+            println!(
+                "{} {} ({} of {} lines invalid)",
+                icon("✗").red(),
+                relative.display(),
+                validation.invalid.len(),
+                validation.lines
+            );
+            for line in validation.invalid.iter().take(MAX_LINES_PER_FILE) {
+                let recovered = if line.recovered > 0 {
+                    format!(" (recovers {} entries)", line.recovered)
+                        .dimmed()
+                        .to_string()
+                } else {
+                    String::new()
+                };
+                println!("    line {}: {}{}", line.line, line.reason, recovered);
+            }
+            if validation.invalid.len() > MAX_LINES_PER_FILE {
+                println!(
+                    "    ... and {} more",
+                    validation.invalid.len() - MAX_LINES_PER_FILE
+                );
+            }
+            if validation.unreadable() {
+                println!(
+                    "    {} {}",
+                    icon("⚠").yellow(),
+                    "This file is skipped entirely by sync".yellow()
+                );
+            } else if validation.no_entries() {
+                println!(
+                    "    {} {}",
+                    icon("⚠").yellow(),
+                    "No line parses; this session syncs as empty".yellow()
+                );
+            }
+        }
+    }
+
+    if bad_files == 0 {
+        println!(
+            "{} All {} lines in {} session files parse",
+            icon("✓").green(),
+            lines_checked,
+            files_checked
+        );
+        return Ok(());
+    }
+
+    println!();
+    bail!(
+        "{} invalid lines in {} of {} session files",
+        bad_lines,
+        bad_files,
+        files_checked
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_project_dirs_filters_by_name() {
+        let root = tempfile::TempDir::new().unwrap();
+        let app = root.path().join("-Users-me-app");
+        fs::create_dir_all(&app).unwrap();
+        fs::write(
+            app.join("s.jsonl"),
+            r#"{"type":"user","cwd":"/Users/me/app"}"#,
+        )
+        .unwrap();
+        fs::create_dir_all(root.path().join("-Users-me-other")).unwrap();
+
+        assert_eq!(project_dirs(root.path(), None).unwrap().len(), 2);
+        assert_eq!(
+            project_dirs(root.path(), Some("app")).unwrap(),
+            vec![app.clone()]
+        );
+        assert_eq!(
+            project_dirs(root.path(), Some("-Users-me-app")).unwrap(),
+            vec![app]
+        );
+
+        let err = project_dirs(root.path(), Some("missing")).unwrap_err();
+        assert!(matches!(
+            SyncError::find(&err),
+            Some(SyncError::NotFound { .. })
+        ));
+    }
+}
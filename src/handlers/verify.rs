@@ -0,0 +1,293 @@
+//! Sync repo integrity check
+//!
+//! Handles `ccs verify`, which compares every local session with its copy in
+//! the sync repo, located the same way push writes it, and reports sessions
+//! that are missing from the repo, whose content differs, and repo files with
+//! no local counterpart. Nothing is written; the command exits non-zero when
+//! any discrepancy is found so it can be used in scripts.
+
+use anyhow::{bail, Result};
+use colored::Colorize;
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+use crate::filter::{CollisionStrategy, FilterConfig};
+use crate::handlers::archive::archived_session_ids;
+use crate::output::icon;
+use crate::parser::ConversationSession;
+use crate::sync::discovery::{
+    claude_projects_dir, colliding_dir_names, discover_sessions, find_colliding_projects,
+    project_dir_name, session_repo_path,
+};
+use crate::sync::SyncState;
+use crate::BINARY_NAME;
+
+/// Entries listed per category before the rest are summarized
+const MAX_LISTED: usize = 20;
+
+/// Differences between local sessions and the sync repo, as repo-relative paths
+#[derive(Debug, Default)]
+struct VerifyReport {
+    /// Local sessions whose repo copy matches
+    matching: usize,
+    /// Local sessions with no file in the repo
+    missing: Vec<PathBuf>,
+    /// Local sessions whose repo file has different content or cannot be parsed
+    differing: Vec<PathBuf>,
+    /// Repo session files with no local session of the same name
+    extra: Vec<PathBuf>,
+    /// Local sessions push would not write (no `cwd`, or skipped collisions)
+    not_synced: usize,
+}
+
+impl VerifyReport {
+    fn discrepancies(&self) -> usize {
+        self.missing.len() + self.differing.len() + self.extra.len()
+    }
+}
+
+/// Compare `sessions` under `claude_dir` with the repo files under
+/// `repo_projects_dir`
+///
+/// Repo files are "extra" only when no `.jsonl` of the same name exists
+/// anywhere locally, so sessions hidden by the sync filters and archived
+/// sessions are not reported.
+fn compare(
+    claude_dir: &Path,
+    repo_projects_dir: &Path,
+    sessions: &[ConversationSession],
+    filter: &FilterConfig,
+    colliding_dirs: &HashSet<String>,
+    archived: &HashSet<String>,
+) -> VerifyReport {
+    let mut report = VerifyReport::default();
+    let mut expected: HashSet<PathBuf> = HashSet::new();
+
+    for session in sessions {
+        let skipped_collision = filter.collision_strategy == CollisionStrategy::Skip
+            && project_dir_name(claude_dir, Path::new(&session.file_path))
+                .is_some_and(|dir| colliding_dirs.contains(&dir));
+        let relative = match session_repo_path(claude_dir, session, filter, colliding_dirs) {
+            Some(relative) if !skipped_collision => relative,
+            _ => {
+                report.not_synced += 1;
+                continue;
+            }
+        };
+        expected.insert(relative.clone());
+
+        let repo_file = repo_projects_dir.join(&relative);
+        if !repo_file.is_file() {
+            report.missing.push(relative);
+            continue;
+        }
+
+        // Push stores the redacted session, so compare against that
+        let local_hash = if filter.redact_on_push {
+            session.without_attachments().content_hash()
+        } else {
+            session.content_hash()
+        };
+        match ConversationSession::from_file(&repo_file) {
+            Ok(repo_session) if repo_session.content_hash() == local_hash => report.matching += 1,
+            Ok(_) => report.differing.push(relative),
+            Err(e) => {
+                log::debug!("Failed to parse {}: {:#}", repo_file.display(), e);
+                report.differing.push(relative);
+            }
+        }
+    }
+
+    let local_names: HashSet<String> = WalkDir::new(claude_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| e.file_name().to_str().map(str::to_string))
+        .collect();
+
+    for entry in WalkDir::new(repo_projects_dir)
+        .into_iter()
+        .filter_map(|e| e.ok())
+        .filter(|e| e.file_type().is_file())
+    {
+        let path = entry.path();
+        if path.extension().and_then(|e| e.to_str()) != Some("jsonl") {
+            continue;
+        }
+        let Ok(relative) = path.strip_prefix(repo_projects_dir) else {
+            continue;
+        };
+        let name = entry.file_name().to_string_lossy();
+        let stem = name.trim_end_matches(".jsonl");
+        if expected.contains(relative)
+            || local_names.contains(name.as_ref())
+            || archived.contains(stem)
+        {
+            continue;
+        }
+        report.extra.push(relative.to_path_buf());
+    }
+
+    report.missing.sort();
+    report.differing.sort();
+    report.extra.sort();
+    report
+}
+
+/// Print one category of discrepancies, capped at [`MAX_LISTED`] entries
+fn print_category(title: &str, paths: &[PathBuf], marker: colored::ColoredString) {
+    if paths.is_empty() {
+        return;
+    }
+    println!();
+    println!("{} ({}):", title.bold(), paths.len());
+    for path in paths.iter().take(MAX_LISTED) {
+        println!("  {} {}", marker, path.display());
+    }
+    if paths.len() > MAX_LISTED {
+        println!("  ... and {} more", paths.len() - MAX_LISTED);
+    }
+}
+
+/// Handle `ccs verify`
+pub fn handle_verify() -> Result<()> {
+    let state = SyncState::load()?;
+    let filter = FilterConfig::load()?;
+    let claude_dir = claude_projects_dir()?;
+    let repo_projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
+
+    let sessions = discover_sessions(&claude_dir, &filter)?;
+    let colliding_dirs = if filter.use_project_name_only {
+        colliding_dir_names(&find_colliding_projects(&claude_dir))
+    } else {
+        HashSet::new()
+    };
+
+    println!(
+        "Verifying {} local sessions against {}",
+        sessions.len(),
+        repo_projects_dir.display()
+    );
+
+    let report = compare(
+        &claude_dir,
+        &repo_projects_dir,
+        &sessions,
+        &filter,
+        &colliding_dirs,
+        &archived_session_ids(),
+    );
+
+    print_category("Missing from sync repo", &report.missing, icon("✗").red());
+    print_category("Content differs", &report.differing, icon("✎").yellow());
+    print_category("Only in sync repo", &report.extra, icon("•").cyan());
+
+    println!();
+    println!("{}", "═".repeat(60).dimmed());
+    println!(
+        "{} match, {} missing, {} differ, {} only in repo",
+        report.matching.to_string().green(),
+        report.missing.len().to_string().red(),
+        report.differing.len().to_string().yellow(),
+        report.extra.len().to_string().cyan()
+    );
+    if report.not_synced > 0 {
+        println!(
+            "{} {} local sessions are not pushed (no working directory or skipped name collision)",
+            icon("ℹ").dimmed(),
+            report.not_synced
+        );
+    }
+
+    if report.discrepancies() == 0 {
+        println!("{} Sync repo matches local history", icon("✓").green());
+        return Ok(());
+    }
+
+    if !report.missing.is_empty() || !report.differing.is_empty() {
+        println!(
+            "Run '{}' to update the sync repo",
+            format!("{} push", BINARY_NAME).cyan()
+        );
+    }
+    if !report.extra.is_empty() {
+        println!(
+            "Run '{}' to restore sessions that exist only in the sync repo",
+            format!("{} pull", BINARY_NAME).cyan()
+        );
+    }
+    bail!(
+        "Sync repo does not match local history ({} discrepancies)",
+        report.discrepancies()
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_session(dir: &Path, id: &str, text: &str) -> PathBuf {
+        fs::create_dir_all(dir).unwrap();
+        let path = dir.join(format!("{id}.jsonl"));
+        let line = serde_json::json!({
+            "type": "user",
+            "sessionId": id,
+            "cwd": "/tmp/app",
+            "timestamp": "2026-01-01T00:00:00Z",
+            "message": {"role": "user", "content": text},
+        });
+        fs::write(&path, format!("{line}\n")).unwrap();
+        path
+    }
+
+    #[test]
+    fn test_compare_reports_missing_differing_and_extra() {
+        let root = tempfile::TempDir::new().unwrap();
+        let claude_dir = root.path().join("claude");
+        let repo_dir = root.path().join("repo");
+        let local_project = claude_dir.join("-tmp-app");
+        let filter = FilterConfig::default();
+        assert!(filter.use_project_name_only);
+
+        let sessions: Vec<ConversationSession> = [
+            write_session(&local_project, "same", "hi"),
+            write_session(&local_project, "changed", "new text"),
+            write_session(&local_project, "unpushed", "hi"),
+        ]
+        .iter()
+        .map(|path| ConversationSession::from_file(path).unwrap())
+        .collect();
+
+        let repo_project = repo_dir.join("app");
+        write_session(&repo_project, "same", "hi");
+        write_session(&repo_project, "changed", "old text");
+        write_session(&repo_project, "other-device", "hi");
+        write_session(&repo_project, "archived", "hi");
+
+        let archived = HashSet::from(["archived".to_string()]);
+        let report = compare(
+            &claude_dir,
+            &repo_dir,
+            &sessions,
+            &filter,
+            &HashSet::new(),
+            &archived,
+        );
+        assert_eq!(report.matching, 1);
+        assert_eq!(
+            report.missing,
+            vec![Path::new("app").join("unpushed.jsonl")]
+        );
+        assert_eq!(
+            report.differing,
+            vec![Path::new("app").join("changed.jsonl")]
+        );
+        assert_eq!(
+            report.extra,
+            vec![Path::new("app").join("other-device.jsonl")]
+        );
+        assert_eq!(report.discrepancies(), 3);
+    }
+}
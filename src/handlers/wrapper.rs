@@ -7,6 +7,8 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use std::path::PathBuf;
 
+use crate::interactive_conflict;
+use crate::output::icon;
 #[cfg(unix)]
 use std::os::unix::fs::PermissionsExt;
 
@@ -20,10 +22,12 @@ const UNIX_WRAPPER_SCRIPT: &str = r#"#!/bin/bash
 
 SCRIPT_DIR="$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)"
 
-# Pull latest history before starting Claude (silent, non-blocking on error)
-"$SCRIPT_DIR/ccs" pull --quiet 2>/dev/null || true
+# Pull latest history before starting Claude (silent, non-blocking on error).
+# stdin is detached so input piped to the wrapper reaches Claude untouched.
+"$SCRIPT_DIR/ccs" pull --quiet </dev/null >/dev/null 2>&1 || true
 
-# Start Claude Code with all arguments
+# Replace this shell with Claude Code: arguments are passed through verbatim
+# and Claude's exit status becomes the wrapper's
 exec claude "$@"
 "#;
 
@@ -39,10 +43,11 @@ REM Use this instead of 'claude' to ensure you have the latest history.
 set SCRIPT_DIR=%~dp0
 
 REM Pull latest history before starting Claude (silent, non-blocking on error)
-"%SCRIPT_DIR%ccs.exe" pull --quiet 2>nul
+"%SCRIPT_DIR%ccs.exe" pull --quiet <nul >nul 2>nul
 
-REM Start Claude Code with all arguments
+REM Start Claude Code with all arguments and return its exit code
 claude %*
+exit /b %ERRORLEVEL%
 "#;
 
 /// Windows PowerShell wrapper script content
@@ -57,13 +62,14 @@ $scriptDir = Split-Path -Parent $MyInvocation.MyCommand.Path
 
 # Pull latest history before starting Claude (silent, non-blocking on error)
 try {
-    & "$scriptDir\ccs.exe" pull --quiet 2>$null
+    & "$scriptDir\ccs.exe" pull --quiet *> $null
 } catch {
     # Ignore errors
 }
 
-# Start Claude Code with all arguments
+# Start Claude Code with all arguments and return its exit code
 & claude @args
+exit $LASTEXITCODE
 "#;
 
 /// Get the directory where ccs is installed
@@ -91,6 +97,115 @@ fn get_windows_ps1_wrapper_path() -> Result<PathBuf> {
     Ok(get_install_dir()?.join("claude-sync.ps1"))
 }
 
+/// Wrapper scripts for this platform with the content `wrapper install`
+/// writes for each
+fn expected_wrappers() -> Result<Vec<(PathBuf, &'static str)>> {
+    #[cfg(unix)]
+    {
+        Ok(vec![(get_unix_wrapper_path()?, UNIX_WRAPPER_SCRIPT)])
+    }
+
+    #[cfg(windows)]
+    {
+        Ok(vec![
+            (get_windows_bat_wrapper_path()?, WINDOWS_BAT_WRAPPER_SCRIPT),
+            (get_windows_ps1_wrapper_path()?, WINDOWS_PS1_WRAPPER_SCRIPT),
+        ])
+    }
+}
+
+/// Line diff from `installed` to `expected`, as `-`/`+` prefixed lines
+///
+/// Unchanged lines are left out; an empty result means the contents match
+/// (line endings are ignored, so a CRLF copy of a script is not drift).
+fn line_diff(installed: &str, expected: &str) -> Vec<String> {
+    let old: Vec<&str> = installed.lines().collect();
+    let new: Vec<&str> = expected.lines().collect();
+
+    // Longest common subsequence table, filled from the end
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    diff
+}
+
+/// Compare installed wrappers with what this binary would install, and
+/// offer to reinstall stale ones
+fn show_wrapper_drift() -> Result<()> {
+    let mut stale = false;
+    for (path, expected) in expected_wrappers()? {
+        if !path.exists() {
+            continue;
+        }
+        let installed = std::fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let diff = line_diff(&installed, expected);
+        if diff.is_empty() {
+            println!("  {} {} is up to date", icon("✓").green(), path.display());
+            continue;
+        }
+
+        stale = true;
+        println!(
+            "  {} {} differs from the current version:",
+            icon("⚠").yellow(),
+            path.display()
+        );
+        for line in &diff {
+            if line.starts_with('+') {
+                println!("    {}", line.green());
+            } else {
+                println!("    {}", line.red());
+            }
+        }
+    }
+
+    if !stale {
+        return Ok(());
+    }
+
+    println!();
+    if interactive_conflict::is_interactive() || interactive_conflict::assume_yes() {
+        let reinstall = interactive_conflict::confirm_with_help(
+            "Reinstall the wrapper with the current version?",
+            "Overwrites the installed wrapper script(s)",
+            true,
+            interactive_conflict::assume_yes(),
+        )?;
+        if reinstall {
+            handle_wrapper_install(true)?;
+        }
+    } else {
+        println!(
+            "Run '{}' to update the wrapper.",
+            "ccs wrapper install --force".cyan()
+        );
+    }
+    Ok(())
+}
+
 /// Install wrapper script(s)
 pub fn handle_wrapper_install(force: bool) -> Result<PathBuf> {
     println!("{}", "Creating wrapper script(s)...".cyan().bold());
@@ -133,7 +248,11 @@ fn install_unix_wrapper(wrapper_path: &PathBuf, force: bool) -> Result<()> {
     perms.set_mode(0o755);
     std::fs::set_permissions(wrapper_path, perms)?;
 
-    println!("  {} Created: {}", "✓".green(), wrapper_path.display());
+    println!(
+        "  {} Created: {}",
+        icon("✓").green(),
+        wrapper_path.display()
+    );
 
     Ok(())
 }
@@ -149,7 +268,7 @@ fn install_windows_wrappers(bat_path: &PathBuf, ps1_path: &PathBuf, force: bool)
         );
     } else {
         std::fs::write(bat_path, WINDOWS_BAT_WRAPPER_SCRIPT)?;
-        println!("  {} Created: {}", "✓".green(), bat_path.display());
+        println!("  {} Created: {}", icon("✓").green(), bat_path.display());
     }
 
     // Install .ps1 wrapper
@@ -161,7 +280,7 @@ fn install_windows_wrappers(bat_path: &PathBuf, ps1_path: &PathBuf, force: bool)
         );
     } else {
         std::fs::write(ps1_path, WINDOWS_PS1_WRAPPER_SCRIPT)?;
-        println!("  {} Created: {}", "✓".green(), ps1_path.display());
+        println!("  {} Created: {}", icon("✓").green(), ps1_path.display());
     }
 
     if !force && (bat_path.exists() || ps1_path.exists()) {
@@ -182,7 +301,11 @@ pub fn handle_wrapper_uninstall() -> Result<()> {
         let wrapper_path = get_unix_wrapper_path()?;
         if wrapper_path.exists() {
             std::fs::remove_file(&wrapper_path)?;
-            println!("  {} Removed: {}", "✓".green(), wrapper_path.display());
+            println!(
+                "  {} Removed: {}",
+                icon("✓").green(),
+                wrapper_path.display()
+            );
             removed = true;
         }
     }
@@ -194,13 +317,13 @@ pub fn handle_wrapper_uninstall() -> Result<()> {
 
         if bat_path.exists() {
             std::fs::remove_file(&bat_path)?;
-            println!("  {} Removed: {}", "✓".green(), bat_path.display());
+            println!("  {} Removed: {}", icon("✓").green(), bat_path.display());
             removed = true;
         }
 
         if ps1_path.exists() {
             std::fs::remove_file(&ps1_path)?;
-            println!("  {} Removed: {}", "✓".green(), ps1_path.display());
+            println!("  {} Removed: {}", icon("✓").green(), ps1_path.display());
             removed = true;
         }
     }
@@ -213,7 +336,10 @@ pub fn handle_wrapper_uninstall() -> Result<()> {
 }
 
 /// Show wrapper script status
-pub fn handle_wrapper_show() -> Result<()> {
+///
+/// With `diff`, installed wrappers are also compared against the content
+/// this version would install, and stale ones can be reinstalled.
+pub fn handle_wrapper_show(diff: bool) -> Result<()> {
     println!("{}", "Wrapper Script Status".cyan().bold());
 
     let install_dir = get_install_dir()?;
@@ -276,6 +402,12 @@ pub fn handle_wrapper_show() -> Result<()> {
         }
     }
 
+    if diff && is_wrapper_installed()? {
+        println!();
+        println!("{}", "Compared with the current version:".bold());
+        show_wrapper_drift()?;
+    }
+
     Ok(())
 }
 
@@ -304,3 +436,70 @@ pub fn get_wrapper_path() -> Result<PathBuf> {
         get_windows_bat_wrapper_path()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Run the generated Unix wrapper with a fake `ccs` that fails and a fake
+    /// `claude` that echoes its arguments and stdin, then exits with 3
+    #[cfg(unix)]
+    #[test]
+    fn test_unix_wrapper_forwards_args_stdin_and_exit_code() {
+        use std::io::Write;
+        use std::process::{Command, Stdio};
+
+        let temp = tempfile::TempDir::new().unwrap();
+        let write_script = |name: &str, content: &str| {
+            let path = temp.path().join(name);
+            std::fs::write(&path, content).unwrap();
+            std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+            path
+        };
+        let wrapper = write_script("claude-sync", UNIX_WRAPPER_SCRIPT);
+        write_script(
+            "ccs",
+            "#!/bin/sh\ncat >/dev/null\necho ccs-output\nexit 1\n",
+        );
+        write_script(
+            "claude",
+            "#!/bin/sh\nfor arg in \"$@\"; do printf '[%s]\\n' \"$arg\"; done\ncat\nexit 3\n",
+        );
+
+        let path = format!(
+            "{}:{}",
+            temp.path().display(),
+            std::env::var("PATH").unwrap_or_default()
+        );
+        let mut child = Command::new(&wrapper)
+            .args(["-p", "two words", "", "--flag=$HOME", "*"])
+            .env("PATH", path)
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .unwrap();
+        child
+            .stdin
+            .take()
+            .unwrap()
+            .write_all(b"piped input\n")
+            .unwrap();
+        let output = child.wait_with_output().unwrap();
+
+        assert_eq!(output.status.code(), Some(3));
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout),
+            "[-p]\n[two words]\n[]\n[--flag=$HOME]\n[*]\npiped input\n"
+        );
+    }
+
+    #[test]
+    fn test_line_diff() {
+        assert!(line_diff("a\nb\n", "a\r\nb").is_empty());
+        assert_eq!(
+            line_diff("a\nold\nc\n", "a\nnew\nc\nd\n"),
+            vec!["- old", "+ new", "+ d"]
+        );
+        assert_eq!(line_diff("a\nb\n", "b\n"), vec!["- a"]);
+    }
+}
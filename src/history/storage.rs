@@ -194,6 +194,21 @@ impl OperationHistory {
             Ok(false)
         }
     }
+
+    /// Remove the operation at `index` (0 = most recent)
+    ///
+    /// Used when undoing an operation picked from `ccs undo list`.
+    ///
+    /// # Arguments
+    /// * `index` - Position of the operation in [`Self::list_operations`]
+    /// * `path` - Optional custom path to save to (for testing)
+    pub fn remove_operation(&mut self, index: usize, path: Option<PathBuf>) -> Result<()> {
+        if index >= self.operations.len() {
+            anyhow::bail!("No operation #{} in history", index + 1);
+        }
+        self.operations.remove(index);
+        self.save_to(path)
+    }
 }
 
 impl Default for OperationHistory {
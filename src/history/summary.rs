@@ -10,6 +10,7 @@ pub struct ConversationSummary {
     pub session_id: String,
 
     /// Relative path from claude projects directory
+    /// (absolute session file path for session delete/rename operations)
     pub project_path: String,
 
     /// Timestamp of the conversation (if available)
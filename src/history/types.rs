@@ -8,6 +8,12 @@ pub enum OperationType {
     Pull,
     /// Push operation: syncing from local to remote
     Push,
+    /// A session file was deleted via `ccs session delete` or the session manager
+    #[serde(rename = "session-delete")]
+    SessionDelete,
+    /// A session was renamed via `ccs session rename` or the session manager
+    #[serde(rename = "session-rename")]
+    SessionRename,
 }
 
 impl OperationType {
@@ -16,8 +22,19 @@ impl OperationType {
         match self {
             OperationType::Pull => "pull",
             OperationType::Push => "push",
+            OperationType::SessionDelete => "session-delete",
+            OperationType::SessionRename => "session-rename",
         }
     }
+
+    /// Whether this is a session management operation (delete/rename)
+    /// rather than a sync operation
+    pub fn is_session_operation(&self) -> bool {
+        matches!(
+            self,
+            OperationType::SessionDelete | OperationType::SessionRename
+        )
+    }
 }
 
 /// Type of operation performed on a specific conversation during sync
@@ -54,6 +71,8 @@ mod tests {
     fn test_operation_type_as_str() {
         assert_eq!(OperationType::Pull.as_str(), "pull");
         assert_eq!(OperationType::Push.as_str(), "push");
+        assert_eq!(OperationType::SessionDelete.as_str(), "session-delete");
+        assert_eq!(OperationType::SessionRename.as_str(), "session-rename");
     }
 
     #[test]
@@ -68,6 +87,12 @@ mod tests {
 
         let deserialized: OperationType = serde_json::from_str(r#""pull""#).unwrap();
         assert_eq!(deserialized, OperationType::Pull);
+
+        let serialized = serde_json::to_string(&OperationType::SessionDelete).unwrap();
+        assert_eq!(serialized, r#""session-delete""#);
+
+        let deserialized: OperationType = serde_json::from_str(r#""session-rename""#).unwrap();
+        assert_eq!(deserialized, OperationType::SessionRename);
     }
 
     #[test]
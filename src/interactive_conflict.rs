@@ -2,8 +2,10 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use inquire::{Confirm, Select};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use crate::conflict::{Conflict, ConflictResolution};
+use crate::output::icon;
 use crate::parser::ConversationSession;
 
 /// Resolution action chosen by the user
@@ -82,6 +84,53 @@ pub fn is_interactive() -> bool {
     atty::is(atty::Stream::Stdin) && atty::is(atty::Stream::Stdout)
 }
 
+/// Set by the global `--yes` flag
+static ASSUME_YES: AtomicBool = AtomicBool::new(false);
+
+/// Auto-accept confirmation prompts (global `--yes`)
+pub fn set_assume_yes(yes: bool) {
+    ASSUME_YES.store(yes, Ordering::Relaxed);
+}
+
+/// Whether confirmation prompts should be auto-accepted
+pub fn assume_yes() -> bool {
+    ASSUME_YES.load(Ordering::Relaxed)
+}
+
+/// Ask a yes/no question, or answer yes without prompting when `assume_yes`
+/// is set (usually [`assume_yes()`], optionally combined with a `--force`).
+pub fn confirm(message: &str, default: bool, assume_yes: bool) -> Result<bool> {
+    confirm_prompt(
+        message,
+        Confirm::new(message).with_default(default),
+        assume_yes,
+    )
+}
+
+/// Like [`confirm`], with a help line shown under the prompt
+pub fn confirm_with_help(
+    message: &str,
+    help: &str,
+    default: bool,
+    assume_yes: bool,
+) -> Result<bool> {
+    confirm_prompt(
+        message,
+        Confirm::new(message)
+            .with_default(default)
+            .with_help_message(help),
+        assume_yes,
+    )
+}
+
+fn confirm_prompt(message: &str, prompt: Confirm, assume_yes: bool) -> Result<bool> {
+    if assume_yes {
+        println!("{} {}", message, "yes (--yes)".dimmed());
+        return Ok(true);
+    }
+    prompt.prompt().context("Failed to get confirmation")
+}
+
 /// Display detailed conflict information
 fn display_conflict_details(conflict: &Conflict) {
     println!("\n{}", "=".repeat(80).cyan());
@@ -123,19 +172,19 @@ fn display_conflict_details(conflict: &Conflict) {
     if msg_diff > 0 {
         println!(
             "\n{} Remote has {} more messages",
-            "→".yellow(),
+            icon("→").yellow(),
             msg_diff.to_string().yellow().bold()
         );
     } else if msg_diff < 0 {
         println!(
             "\n{} Local has {} more messages",
-            "→".green(),
+            icon("→").green(),
             (-msg_diff).to_string().green().bold()
         );
     } else {
         println!(
             "\n{} Both have the same number of messages, but content differs",
-            "→".cyan()
+            icon("→").cyan()
         );
     }
 
@@ -144,22 +193,50 @@ fn display_conflict_details(conflict: &Conflict) {
 
 /// Interactively resolve a single conflict
 fn resolve_conflict_interactive(conflict: &Conflict) -> Result<ResolutionAction> {
-    loop {
-        println!("\n{}", "Conflict Detected!".yellow().bold());
-        println!("  {}", conflict.description().dimmed());
-
-        let options = vec![
+    prompt_resolution(
+        conflict,
+        &[
             ResolutionAction::SmartMerge,
             ResolutionAction::KeepLocal,
             ResolutionAction::KeepRemote,
             ResolutionAction::KeepBoth,
             ResolutionAction::ViewDetails,
-        ];
+        ],
+    )
+}
 
-        let action = Select::new("How would you like to resolve this conflict?", options)
-            .with_help_message("Use arrow keys to navigate, Enter to select")
-            .prompt()
-            .context("Failed to get resolution action")?;
+/// Ask how to resolve a single conflict without offering smart merge
+///
+/// Used by the session manager, where the user picks one version explicitly.
+/// Never returns `ViewDetails` (details are shown and the prompt repeats).
+pub fn prompt_keep_resolution(conflict: &Conflict) -> Result<ResolutionAction> {
+    prompt_resolution(
+        conflict,
+        &[
+            ResolutionAction::KeepLocal,
+            ResolutionAction::KeepRemote,
+            ResolutionAction::KeepBoth,
+            ResolutionAction::ViewDetails,
+        ],
+    )
+}
+
+/// Prompt with the given options until a resolving action is chosen
+fn prompt_resolution(
+    conflict: &Conflict,
+    options: &[ResolutionAction],
+) -> Result<ResolutionAction> {
+    loop {
+        println!("\n{}", "Conflict Detected!".yellow().bold());
+        println!("  {}", conflict.description().dimmed());
+
+        let action = Select::new(
+            "How would you like to resolve this conflict?",
+            options.to_vec(),
+        )
+        .with_help_message("Use arrow keys to navigate, Enter to select")
+        .prompt()
+        .context("Failed to get resolution action")?;
 
         match action {
             ResolutionAction::ViewDetails => {
@@ -230,7 +307,7 @@ pub fn resolve_conflicts_interactive_with_sessions(
                                 {
                                     println!(
                                         "  {} Smart merged ({} local + {} remote = {} total, {} branches)",
-                                        "✓".green(),
+                                        icon("✓").green(),
                                         stats.local_messages,
                                         stats.remote_messages,
                                         stats.merged_messages,
@@ -240,32 +317,32 @@ pub fn resolve_conflicts_interactive_with_sessions(
                                 result.smart_merge.push(conflict.clone());
                             }
                             Err(e) => {
-                                eprintln!("  {} Smart merge failed: {}", "✗".red(), e);
+                                eprintln!("  {} Smart merge failed: {}", icon("✗").red(), e);
                                 eprintln!("  Please choose another resolution method...");
                                 // Don't add to result, user will be prompted again
                                 continue;
                             }
                         }
                     } else {
-                        eprintln!("  {} Cannot find local or remote session", "✗".red());
+                        eprintln!("  {} Cannot find local or remote session", icon("✗").red());
                         eprintln!("  Please choose another resolution method...");
                         continue;
                     }
                 } else {
-                    eprintln!("  {} Session maps not provided", "✗".red());
+                    eprintln!("  {} Session maps not provided", icon("✗").red());
                     eprintln!("  Please choose another resolution method...");
                     continue;
                 }
             }
             ResolutionAction::KeepLocal => {
-                println!("  {} Keeping local version", "✓".green());
+                println!("  {} Keeping local version", icon("✓").green());
                 conflict.resolution = ConflictResolution::KeepLocal;
                 result.keep_local.push(conflict.clone());
             }
             ResolutionAction::KeepRemote => {
                 println!(
                     "  {} Keeping remote version (will overwrite local)",
-                    "✓".yellow()
+                    icon("✓").yellow()
                 );
                 conflict.resolution = ConflictResolution::KeepRemote;
                 result.keep_remote.push(conflict.clone());
@@ -273,7 +350,7 @@ pub fn resolve_conflicts_interactive_with_sessions(
             ResolutionAction::KeepBoth => {
                 println!(
                     "  {} Keeping both versions (remote will be saved with conflict suffix)",
-                    "✓".cyan()
+                    icon("✓").cyan()
                 );
                 // Keep both is handled later with proper renaming
                 result.keep_both.push(conflict.clone());
@@ -306,10 +383,7 @@ pub fn resolve_conflicts_interactive_with_sessions(
     println!("{}", "=".repeat(80).green());
 
     // Final confirmation
-    let confirm = Confirm::new("Apply these resolutions?")
-        .with_default(true)
-        .prompt()
-        .context("Failed to get confirmation")?;
+    let confirm = confirm("Apply these resolutions?", true, assume_yes())?;
 
     if !confirm {
         return Err(anyhow::anyhow!(
@@ -371,7 +445,7 @@ pub fn apply_resolutions(
 
             println!(
                 "  {} Wrote smart merged conversation: {}",
-                "✓".cyan(),
+                icon("✓").cyan(),
                 conflict.local_file.display()
             );
         }
@@ -396,7 +470,7 @@ pub fn apply_resolutions(
 
             println!(
                 "  {} Overwrote local with remote: {}",
-                "✓".yellow(),
+                icon("✓").yellow(),
                 conflict.local_file.display()
             );
         }
@@ -431,7 +505,7 @@ pub fn apply_resolutions(
                 .unwrap_or(&renamed_path);
             println!(
                 "  {} Saved remote as: {}",
-                "✓".cyan(),
+                icon("✓").cyan(),
                 relative_renamed.display()
             );
 
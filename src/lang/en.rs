@@ -0,0 +1,302 @@
+//! English message table.
+
+
+pub(super) static MESSAGES: &[(&str, &str)] = &[
+    ("common.cancelled", "Cancelled"),
+    ("common.enabled", "enabled"),
+    ("common.disabled", "disabled"),
+    ("common.yes", "yes"),
+    ("common.no", "no"),
+    ("common.and_more", "... and {count} more"),
+    ("common.note", "Note:"),
+    ("common.project", "Project:"),
+    ("common.cancel", "Cancel"),
+
+    ("config_sync.pushed", "✓ Config pushed"),
+    ("config_sync.no_changes", "No config changes"),
+    ("config_sync.nothing_to_sync", "⚠️  No config files found to sync"),
+    ("config_sync.prune_none", "No device configs older than {days} days"),
+    ("config_sync.prune_header", "Devices that have not synced config for more than {days} days:"),
+    ("config_sync.prune_last_sync", "last sync: {time} ({days} days ago)"),
+    ("config_sync.prune_dry_run", "(dry-run) No device configs were deleted"),
+    ("config_sync.prune_confirm", "Delete these {count} device config directories?"),
+    ("config_sync.pruned", "✓ Deleted {count} device configs"),
+    ("config_sync.no_configs_dir", "No config sync directory found"),
+    ("config_sync.run_push_hint", "Run {command} to push this device's config"),
+    ("config_sync.available_devices", "Available device configs:"),
+    ("config_sync.current_device", "(this device)"),
+    ("config_sync.platform", "Platform: {platform}"),
+    ("config_sync.last_sync", "Last sync: {time}"),
+    ("config_sync.files", "Files: {files}"),
+    ("config_sync.no_device_configs", "No device configs found"),
+    ("config_sync.device_not_found", "Device config not found: {device}\nRun `{command}` to see available configs"),
+    ("config_sync.applying", "Applying config from {device}..."),
+    ("config_sync.settings_backed_up", "Backed up to settings.json.backup"),
+    ("config_sync.with_hooks", "with hooks"),
+    ("config_sync.keep_local_hooks", "local hooks kept"),
+    ("config_sync.claude_md_merged", "Merged CLAUDE.md (kept local {platform} platform content)"),
+    ("config_sync.hooks_path_check", "Checking hook paths on this device:"),
+    ("config_sync.hooks_path_ok", "OK"),
+    ("config_sync.hooks_path_missing", "MISSING"),
+    ("config_sync.hooks_paths_ok", "✓ All hook paths resolve on this device"),
+    ("config_sync.hooks_paths_missing", "⚠️  {count} hook(s) reference paths missing on this device; fix them before restarting Claude Code"),
+    ("config_sync.skills_install", "Skills install commands:"),
+    ("config_sync.plugins_install", "Plugins install commands:"),
+    ("config_sync.applied", "✓ Config applied"),
+    ("config_sync.restart_hint", "Restart Claude Code for the config to take effect"),
+    ("config_sync.nothing_applied", "No config was applied"),
+    ("config_sync.status_title", "Config sync status"),
+    ("config_sync.device_name", "Device name: {name}"),
+    ("config_sync.local_files", "Local config files:"),
+    ("config_sync.skills_count", "skills: {count}"),
+    ("config_sync.settings_header", "Sync settings:"),
+    ("config_sync.setting_enabled", "Config sync: {value}"),
+    ("config_sync.setting_sync_settings", "Sync settings.json: {value}"),
+    ("config_sync.setting_sync_claude_md", "Sync CLAUDE.md: {value}"),
+    ("config_sync.setting_sync_hooks", "Sync hooks: {value}"),
+    ("config_sync.setting_sync_skills_list", "Sync skills list: {value}"),
+    ("config_sync.settings_conflict", "settings.json conflict:"),
+    ("config_sync.local_value", "local"),
+    ("config_sync.remote_value", "remote"),
+    ("config_sync.settings_conflict_prompt", "Which value do you want to keep?"),
+    ("config_sync.keep_local_value", "Keep local"),
+    ("config_sync.take_remote_value", "Take remote"),
+    ("config_sync.rename_target_exists", "Device config already exists: {device}"),
+    ("config_sync.rename_invalid_name", "Device name must contain letters or digits"),
+    ("config_sync.rename_same", "Device is already named {device}"),
+    ("config_sync.device_name_pinned", "Set device_name = \"{device}\" in config.toml"),
+    ("config_sync.renamed_device", "✓ Renamed device config {old} → {new}"),
+    ("config_sync.no_other_device", "No config from another device found\nRun `{command}` on the other device first"),
+    ("config_sync.latest_device", "Most recently synced device: {device} (last sync: {time})"),
+    ("config_sync.latest_device_confirm", "Apply config from {device}?"),
+    ("config_sync.latest_device_hint", "Not a terminal: run `{command}` to apply it, or add --yes"),
+    ("config_sync.claude_md_applied", "✓ Applied CLAUDE.md from {device}"),
+    ("config_sync.claude_md_backed_up", "Backed up to CLAUDE.md.backup"),
+    ("config_sync.claude_md_unchanged", "CLAUDE.md already matches {device}"),
+    ("config_sync.claude_md_local_newer", "This device's config is newer than {device}'s; CLAUDE.md was not changed\nUse --force to apply it anyway"),
+    ("config_sync.claude_md_missing", "{device} has no synced CLAUDE.md"),
+    ("config_sync.install_script_written", "Install script written to {path}"),
+    ("config_sync.install_none", "No skills or plugins to install"),
+    ("config_sync.install_confirm", "Run {count} install commands now?"),
+    ("config_sync.install_failed", "{count} install commands failed"),
+    ("config_sync.install_done", "All install commands finished"),
+    ("config_sync.device_latest_tag", "(latest)"),
+    ("config_sync.device_stale_tag", "(stale)"),
+    ("config_sync.rename_invalid_old", "Not a device name: \"{device}\""),
+
+    ("push.header", "Pushing Claude Code history..."),
+    ("push.rebased_on_attempt", "Rebased and pushed on attempt {attempt}"),
+    ("push.lfs_git_only", "LFS is only supported for git repositories, skipped"),
+    ("push.configuring_lfs", "Configuring Git LFS..."),
+    ("push.structure_warning", "Directory structure inconsistency warning"),
+    ("push.structure_confirm", "Continue pushing?"),
+    ("push.structure_confirm_help", "Cleaning up the directory structure first is recommended"),
+    ("push.cancelled", "Push cancelled."),
+    ("push.structure_switch_hint", "Tip: use '{command}' to switch the sync mode"),
+    ("push.structure_interactive_hint", "Use --interactive to choose whether to continue when the structure is inconsistent"),
+    ("push.discovering", "Discovering conversation sessions..."),
+    ("push.found", "Found {count} sessions"),
+    ("push.collision_warning", "Warning: Multiple projects map to the same name:"),
+    ("push.collision_locations", "{count} locations:"),
+    ("push.collision_merge", "Sessions from colliding projects will be merged into the same directory."),
+    ("push.copying", "Copying sessions to sync repository..."),
+    ("push.summary_title", "Push Summary:"),
+    ("push.summary_added", "Added: {count}"),
+    ("push.summary_modified", "Modified: {count}"),
+    ("push.summary_unchanged", "Unchanged: {count}"),
+    ("push.summary_skipped_no_cwd", "Skipped (no cwd): {count}"),
+    ("push.summary_with_cwd", "Sessions (with project context): {count}"),
+    ("push.files_to_push", "Files to be pushed:"),
+    ("push.status_new", "new"),
+    ("push.status_modified", "modified"),
+    ("push.status_unchanged", "unchanged"),
+    ("push.confirm", "Do you want to proceed with pushing these changes?"),
+    ("push.confirm_help", "This will commit and push to the sync repository"),
+    ("push.syncing_config", "Syncing device configuration..."),
+    ("push.config_synced", "Device configuration synced:"),
+    ("push.no_config_files", "No configuration files to sync"),
+    ("push.config_sync_failed", "Failed to sync device configuration: {error}"),
+    ("push.pruned_unlocked", "Delete-unlock window active, deleted {count} session(s) from sync repo ({minutes} min remaining)"),
+    ("push.pruned", "Pruned {count} missing sessions from sync repo"),
+    ("push.missing_protected", "Detected {count} session(s) missing locally but present in sync repo — protected from deletion."),
+    ("push.missing_protected_hint", "Use '{restore}' to recover them, or '{prune}' to force-delete."),
+    ("push.syncing_memory", "Syncing auto memory directories..."),
+    ("push.memory_synced", "Synced {count} memory directories"),
+    ("push.no_memory_dirs", "No memory directories found"),
+    ("push.memory_removed", "Removed {count} deleted memory files from sync repo"),
+    ("push.recorded_commit", "Recorded commit {hash} for undo"),
+    ("push.first_push", "First push - no previous commit to undo to"),
+    ("push.committing", "Committing changes..."),
+    ("push.committed", "Committed: {message}"),
+    ("push.pushing_remote", "Pushing to remote..."),
+    ("push.pushed_to", "Pushed to origin/{branch}"),
+    ("push.degraded", "Push degraded; kept {count} conflict file(s)"),
+    ("push.push_failed", "Failed to push: {error}"),
+    ("push.no_changes", "No changes to commit"),
+    ("push.summary_heading", "Push Summary"),
+    ("push.stats", "{added} Added    {modified} Modified    {unchanged} Unchanged"),
+    ("push.stats_with_deleted", "{added} Added    {modified} Modified    {deleted} Deleted    {unchanged} Unchanged"),
+    ("push.pushed_conversations", "Pushed Conversations:"),
+    ("push.more_conversations", "... and {count} more conversations"),
+    ("push.complete", "Push complete!"),
+    ("push.timings", "Timings:"),
+    ("push.retrying", "Push attempt {attempt}/{max} failed, retrying in {secs}s..."),
+    ("push.remote_behind", "Changes are committed locally but the remote is behind; run the push again once the network is back"),
+    ("push.secrets_found", "Possible secrets found in {count} session(s):"),
+    ("push.secrets_blocked", "Push blocked by --strict: {count} session(s) contain possible secrets"),
+    ("push.excluded_projects", "Excluded {count} sessions from {projects} for this run"),
+    ("push.included_projects", "Only pushing {projects}: skipped {count} other sessions"),
+    ("push.oversized_session", "Skipped {path} ({size} MB): larger than the {limit} MB limit"),
+    ("push.oversized_hint", "To include them, run `{command}` or raise max_file_size_bytes in the config"),
+    ("push.summary_filtered", "Filtered out: {count} (size: {size}, age: {age}, pattern: {pattern})"),
+    ("push.collision_suffix", "Each colliding project is stored in its own folder, named <project>-<path hash>."),
+    ("push.collision_skip", "Sessions from colliding projects are skipped and not pushed."),
+    ("push.collision_strategy_hint", "To keep them apart, run `{command} suffix` (or `skip`)."),
+    ("push.since_last_skipped", "Skipped {count} sessions unchanged since the last push ({time})"),
+    ("push.since_last_first", "No previous push recorded; checking all sessions"),
+    ("push.no_delete_notice", "Orphan removal disabled (--no-delete): sessions missing locally are kept in the sync repo"),
+
+    ("setup.installing_gh", "📦 Installing GitHub CLI (gh)..."),
+    ("setup.install_via_brew", "   Installing with Homebrew..."),
+    ("setup.no_homebrew", "Homebrew is not installed. Install it first: /bin/bash -c \"$(curl -fsSL https://raw.githubusercontent.com/Homebrew/install/HEAD/install.sh)\""),
+    ("setup.install_via_apt", "   Installing with apt..."),
+    ("setup.adding_apt_source", "   Adding the GitHub APT source..."),
+    ("setup.gpg_key_failed", "Failed to add the GitHub GPG key"),
+    ("setup.apt_source_failed", "Failed to add the GitHub APT source"),
+    ("setup.install_via_dnf", "   Installing with dnf..."),
+    ("setup.install_via_pacman", "   Installing with pacman..."),
+    ("setup.no_package_manager", "No supported package manager found. Install gh manually: https://github.com/cli/cli#installation"),
+    ("setup.install_via_winget", "   Installing with winget..."),
+    ("setup.install_via_scoop", "   Installing with scoop..."),
+    ("setup.no_winget_scoop", "Neither winget nor scoop found. Install gh manually: https://github.com/cli/cli#installation"),
+    ("setup.unsupported_os", "Unsupported operating system. Install gh manually: https://github.com/cli/cli#installation"),
+    ("setup.gh_install_failed", "Failed to install gh CLI"),
+    ("setup.gh_installed", "✓ GitHub CLI installed"),
+    ("setup.gh_login_required", "🔐 GitHub login required"),
+    ("setup.gh_login_browser", "   A browser will open for authentication; complete the login there."),
+    ("setup.gh_auth_failed", "GitHub authentication failed"),
+    ("setup.gh_authenticated", "✓ GitHub authentication succeeded"),
+    ("setup.repo_created", "✓ Repository created"),
+    ("setup.gh_missing", "⚠️  GitHub CLI (gh) not found"),
+    ("setup.gh_install_confirm", "Install GitHub CLI automatically?"),
+    ("setup.gh_install_help", "gh CLI is needed to create repositories and authenticate"),
+    ("setup.gh_required", "GitHub CLI is required. Install it manually: https://github.com/cli/cli#installation"),
+    ("setup.gh_already_authenticated", "✓ GitHub CLI is authenticated"),
+    ("setup.cloning", "📥 Cloning repository..."),
+    ("setup.overwrite_cancelled", "Cancelled. Clean up the directory manually and try again."),
+    ("setup.retrying_clone", "📥 Retrying clone..."),
+    ("setup.new_repo_name", "New repository name:"),
+    ("setup.private_repo", "Make the repository private?"),
+    ("setup.cloning_new", "📥 Cloning the new repository..."),
+    ("setup.clone_failed", "❌ Failed to clone repository"),
+    ("setup.git_not_found", "💡 git command not found. Install Git first:"),
+    ("setup.auth_problem", "💡 This looks like an authentication problem. Solutions:"),
+    ("setup.auth_run_gh", "      Run: gh auth login --web"),
+    ("setup.auth_token_step1", "      1. Visit https://github.com/settings/tokens"),
+    ("setup.auth_token_step2", "      2. Create a token (repo scope required)"),
+    ("setup.auth_token_step3", "      3. Use the format: https://<token>@github.com/user/repo.git"),
+    ("setup.auth_gh_confirm", "Authenticate in the browser with GitHub CLI?"),
+    ("setup.not_found", "💡 The repository does not exist or you have no access."),
+    ("setup.possible_causes_indented", "   Possible causes:"),
+    ("setup.cause_wrong_url_numbered", "   1. The repository URL is wrong"),
+    ("setup.cause_not_created", "   2. The repository has not been created yet"),
+    ("setup.cause_private", "This is a private repository; log in to GitHub first"),
+    ("setup.possible_causes", "💡 Possible causes:"),
+    ("setup.cause_network", "   - Network connectivity problems"),
+    ("setup.cause_wrong_url", "   - The repository URL is wrong"),
+    ("setup.cause_no_access", "   - No access permission"),
+    ("setup.clone_failed_retry", "Clone failed; resolve the problems above and try again"),
+    ("setup.title", "🔧 Claude Code Sync setup wizard"),
+    ("setup.git_missing", "❌ Git not found"),
+    ("setup.install_git_first", "💡 Install Git first:"),
+    ("setup.git_required", "Git is required to use Claude Code Sync"),
+    ("setup.select_mode", "Select sync mode:"),
+    ("setup.select_mode_help", "Multi-device mode syncs conversations of same-named projects across computers"),
+    ("setup.mode_changed", "⚠️  Sync mode change detected"),
+    ("setup.mode_multi", "Multi-device sync"),
+    ("setup.mode_single", "Single-device backup"),
+    ("setup.mode_change_mixed", "Switching modes may leave mixed directory formats in the sync repo."),
+    ("setup.mode_change_cleanup", "Clean up directories in the old format after switching to avoid duplicate data."),
+    ("setup.mode_change_confirm", "Switch the mode?"),
+    ("setup.user_cancelled", "Setup cancelled by user"),
+    ("setup.repo_source", "Repository source:"),
+    ("setup.repo_source_help", "Use an existing repository or create a new one"),
+    ("setup.new_repo_help", "The repository will be created under your GitHub account"),
+    ("setup.private_repo_help", "Only you can access a private repository; recommended for conversation history"),
+    ("setup.remote_url", "Remote repository URL:"),
+    ("setup.remote_url_help", "Git repository URL used to back up and sync conversation history"),
+    ("setup.invalid_url", "Invalid Git URL. It must start with 'https://', 'http://', 'git@' or 'ssh://'"),
+    ("setup.local_dir", "Local backup directory:"),
+    ("setup.local_dir_help", "Conversation history will be synced to this directory"),
+    ("setup.summary", "📋 Configuration summary"),
+    ("setup.summary_mode", "Mode:"),
+    ("setup.summary_remote", "Remote:"),
+    ("setup.summary_local", "Local:"),
+    ("setup.confirm_config", "Confirm the configuration above?"),
+    ("setup.config_cancelled", "Setup cancelled."),
+    ("setup.existing_repo_pulling", "📦 Existing repository found, pulling latest changes..."),
+    ("setup.different_repo", "⚠️  The target directory already contains a different repository"),
+    ("setup.overwrite_repo_confirm", "Delete the existing repository and clone again?"),
+    ("setup.not_a_repo", "⚠️  The target directory exists and is not a Git repository"),
+    ("setup.overwrite_dir_confirm", "Delete the directory and clone again?"),
+    ("setup.cloned", "✓ Repository cloned"),
+    ("setup.exclude_attachments", "Exclude file attachments (images, PDFs, etc.)?"),
+    ("setup.exclude_attachments_help", "Only .jsonl conversation files are synced; excluding attachments saves space"),
+    ("setup.exclude_old", "Exclude old conversations?"),
+    ("setup.exclude_old_help", "Only sync recently modified conversations"),
+    ("setup.exclude_old_days", "Exclude conversations older than (days):"),
+    ("setup.sync_now", "Sync now?"),
+    ("setup.sync_now_help", "Push local conversation history to the remote repository"),
+    ("setup.syncing", "🔄 Syncing..."),
+    ("setup.sync_done", "✓ Sync complete"),
+    ("setup.sync_problem", "⚠️  Sync ran into a problem:"),
+    ("setup.auto_sync", "Set up automatic sync?"),
+    ("setup.auto_sync_help", "Pull on startup and push on exit, no manual commands needed"),
+    ("setup.configuring_auto_sync", "🔧 Configuring automatic sync..."),
+    ("setup.hooks_install_failed", "⚠️  Failed to install hooks:"),
+    ("setup.auto_sync_configured", "✓ Automatic sync configured"),
+    ("setup.usage", "Usage:"),
+    ("setup.wrapper_install_failed", "⚠️  Failed to install wrapper:"),
+    ("setup.sync_config", "Sync configuration files?"),
+    ("setup.sync_config_help", "Sync settings.json, CLAUDE.md and similar files to the remote repository"),
+    ("setup.select_config_items", "Choose which configuration to sync:"),
+    ("setup.sync_settings", "  Sync settings.json (permissions, model config)?"),
+    ("setup.sync_claude_md", "  Sync CLAUDE.md (user instructions)?"),
+    ("setup.sync_hooks", "  Sync hooks (hook scripts)?"),
+    ("setup.sync_hooks_help", "Note: hook paths may not be portable across platforms"),
+    ("setup.sync_skills", "  Sync the skills/plugins list?"),
+    ("setup.sync_skills_help", "Only the list is synced; install them manually on each device"),
+    ("setup.config_saved", "✓ Configuration saved"),
+    ("setup.done", "🎉 Setup complete!"),
+    ("setup.auto_sync_enabled", "Automatic sync is enabled; start with claude-sync."),
+    ("setup.management_commands", "Management commands:"),
+    ("setup.common_commands", "Common commands:"),
+    ("setup.auth_option_one", "Option 1:"),
+    ("setup.auth_option_two", "Option 2:"),
+    ("setup.auth_option_gh", "Authenticate via GitHub CLI in the browser (recommended)"),
+    ("setup.auth_option_token", "Use a Personal Access Token"),
+    ("setup.mode_multi_option", "Multi-device sync (recommended) - sync the same project across computers"),
+    ("setup.mode_single_option", "Single-device backup - local backup only, uses full paths"),
+    ("setup.source_existing_option", "Use an existing repository - enter its URL"),
+    ("setup.source_create_option", "Create a new repository - created on GitHub automatically"),
+    ("setup.creating_repo", "📦 Creating repository {name}..."),
+    ("setup.current_url", "Current URL: {url}"),
+    ("setup.not_found_login_retry", "Log in to GitHub and retry (recommended for private repos)"),
+    ("setup.not_found_create", "Create a new repository"),
+    ("setup.choose", "Choose:"),
+    ("setup.error_message", "Error: {error}"),
+    ("setup.mode_change", "Current: {old} → New: {new}"),
+    ("setup.existing_remote", "Existing remote: {url}"),
+    ("setup.new_remote", "New remote: {url}"),
+    ("setup.path", "Path: {path}"),
+    ("setup.sync_retry_hint", "   Retry later with '{command}'"),
+    ("setup.wrapper_usage", "Start Claude Code with {command} (instead of claude)"),
+    ("setup.wrapper_alias", "Or add an alias: alias claude='{path}'"),
+    ("setup.cmd_automate_status", "show automatic sync status"),
+    ("setup.cmd_automate_uninstall", "uninstall automatic sync"),
+    ("setup.cmd_sync", "bidirectional sync"),
+    ("setup.cmd_push", "push to remote"),
+    ("setup.cmd_pull", "pull to local"),
+    ("setup.cmd_status", "show status"),
+    ("setup.automate_hint", "Tip: run '{command}' to set up automatic sync"),
+];
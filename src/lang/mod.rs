@@ -0,0 +1,194 @@
+//! Minimal i18n layer for user-facing messages.
+//!
+//! Messages are looked up by key with the [`t!`] macro. The locale is chosen
+//! once per run: `--lang` wins, otherwise `LC_ALL` / `LC_MESSAGES` / `LANG`
+//! (any `zh*` locale selects Chinese), otherwise English.
+//!
+//! Placeholders use `{name}` syntax and are filled from named arguments:
+//!
+//! ```ignore
+//! println!("{}", t!("push.committed", message = msg));
+//! ```
+//!
+//! Missing keys fall back to the English table, then to the key itself, so
+//! a forgotten translation never hides a message.
+
+mod en;
+mod zh_cn;
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+/// Supported UI languages
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lang {
+    En,
+    ZhCn,
+}
+
+impl Lang {
+    /// Parse a `--lang` value or locale string (`en`, `zh-CN`, `zh_CN.UTF-8`, ...)
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim().to_ascii_lowercase();
+        if value.starts_with("zh") {
+            Some(Lang::ZhCn)
+        } else if value.starts_with("en") || value == "c" || value == "posix" {
+            Some(Lang::En)
+        } else {
+            None
+        }
+    }
+
+    /// Locale from the standard environment variables, in POSIX priority order
+    fn from_env() -> Self {
+        ["LC_ALL", "LC_MESSAGES", "LANG"]
+            .iter()
+            .filter_map(|var| std::env::var(var).ok())
+            .find(|v| !v.is_empty())
+            .and_then(|v| Self::parse(&v))
+            .unwrap_or(Lang::En)
+    }
+}
+
+/// 0 = not chosen yet, otherwise `Lang as u8 + 1`
+static CURRENT: AtomicU8 = AtomicU8::new(0);
+
+/// Force the UI language (from `--lang`)
+pub fn set_lang(lang: Lang) {
+    CURRENT.store(lang as u8 + 1, Ordering::Relaxed);
+}
+
+/// The UI language for this run
+pub fn current() -> Lang {
+    match CURRENT.load(Ordering::Relaxed) {
+        0 => {
+            let lang = Lang::from_env();
+            set_lang(lang);
+            lang
+        }
+        1 => Lang::En,
+        _ => Lang::ZhCn,
+    }
+}
+
+fn table(lang: Lang) -> &'static HashMap<&'static str, &'static str> {
+    static EN: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+    static ZH_CN: OnceLock<HashMap<&str, &str>> = OnceLock::new();
+    match lang {
+        Lang::En => EN.get_or_init(|| en::MESSAGES.iter().copied().collect()),
+        Lang::ZhCn => ZH_CN.get_or_init(|| zh_cn::MESSAGES.iter().copied().collect()),
+    }
+}
+
+/// Look up a message in a specific language, falling back to English
+fn lookup(lang: Lang, key: &'static str) -> &'static str {
+    table(lang)
+        .get(key)
+        .or_else(|| table(Lang::En).get(key))
+        .copied()
+        .unwrap_or(key)
+}
+
+/// Translate a message without placeholders
+pub fn tr(key: &'static str) -> &'static str {
+    lookup(current(), key)
+}
+
+/// Translate a message and fill its `{name}` placeholders
+pub fn tr_args(key: &'static str, args: &[(&str, &dyn Display)]) -> String {
+    fill(tr(key), args)
+}
+
+fn fill(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut message = template.to_string();
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    message
+}
+
+/// Translate a message key, optionally filling `{name}` placeholders.
+///
+/// `t!("key")` returns `&'static str`; `t!("key", name = value, ...)` returns
+/// a `String`.
+macro_rules! t {
+    ($key:expr) => {
+        $crate::lang::tr($key)
+    };
+    ($key:expr, $($name:ident = $value:expr),+ $(,)?) => {
+        $crate::lang::tr_args(
+            $key,
+            &[$((stringify!($name), &$value as &dyn std::fmt::Display)),+],
+        )
+    };
+}
+pub(crate) use t;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn placeholders(message: &str) -> BTreeSet<String> {
+        let mut names = BTreeSet::new();
+        let mut rest = message;
+        while let Some(start) = rest.find('{') {
+            rest = &rest[start + 1..];
+            if let Some(end) = rest.find('}') {
+                names.insert(rest[..end].to_string());
+                rest = &rest[end + 1..];
+            }
+        }
+        names
+    }
+
+    #[test]
+    fn test_parse_lang() {
+        assert_eq!(Lang::parse("zh-CN"), Some(Lang::ZhCn));
+        assert_eq!(Lang::parse("zh_CN.UTF-8"), Some(Lang::ZhCn));
+        assert_eq!(Lang::parse("en_US.UTF-8"), Some(Lang::En));
+        assert_eq!(Lang::parse("C"), Some(Lang::En));
+        assert_eq!(Lang::parse("fr_FR"), None);
+    }
+
+    #[test]
+    fn test_tables_have_same_keys_and_placeholders() {
+        let en = table(Lang::En);
+        let zh = table(Lang::ZhCn);
+        assert_eq!(en.len(), en::MESSAGES.len(), "duplicate key in en table");
+        assert_eq!(
+            zh.len(),
+            zh_cn::MESSAGES.len(),
+            "duplicate key in zh-CN table"
+        );
+
+        for (key, message) in en {
+            let translated = zh
+                .get(key)
+                .unwrap_or_else(|| panic!("missing zh-CN translation for {}", key));
+            assert_eq!(
+                placeholders(message),
+                placeholders(translated),
+                "placeholder mismatch for {}",
+                key
+            );
+        }
+        for key in zh.keys() {
+            assert!(en.contains_key(key), "zh-CN key {} missing in en", key);
+        }
+    }
+
+    #[test]
+    fn test_lookup_and_fill() {
+        assert_eq!(lookup(Lang::En, "no.such.key"), "no.such.key");
+        assert_eq!(
+            fill(
+                "Pushed to {remote}/{branch}",
+                &[("remote", &"origin"), ("branch", &"main")]
+            ),
+            "Pushed to origin/main"
+        );
+    }
+}
@@ -0,0 +1,302 @@
+//! Simplified Chinese message table.
+
+
+pub(super) static MESSAGES: &[(&str, &str)] = &[
+    ("common.cancelled", "已取消"),
+    ("common.enabled", "启用"),
+    ("common.disabled", "禁用"),
+    ("common.yes", "是"),
+    ("common.no", "否"),
+    ("common.and_more", "... 以及另外 {count} 项"),
+    ("common.note", "注意："),
+    ("common.project", "项目："),
+    ("common.cancel", "取消"),
+
+    ("config_sync.pushed", "✓ 配置已推送"),
+    ("config_sync.no_changes", "配置无变化"),
+    ("config_sync.nothing_to_sync", "⚠️  没有找到可同步的配置文件"),
+    ("config_sync.prune_none", "没有超过 {days} 天未同步的设备配置"),
+    ("config_sync.prune_header", "以下设备超过 {days} 天未同步配置:"),
+    ("config_sync.prune_last_sync", "最后同步: {time} ({days} 天前)"),
+    ("config_sync.prune_dry_run", "(dry-run) 未删除任何设备配置"),
+    ("config_sync.prune_confirm", "删除这 {count} 个设备配置目录?"),
+    ("config_sync.pruned", "✓ 已删除 {count} 个设备配置"),
+    ("config_sync.no_configs_dir", "没有找到配置同步目录"),
+    ("config_sync.run_push_hint", "运行 {command} 推送当前设备配置"),
+    ("config_sync.available_devices", "可用的设备配置:"),
+    ("config_sync.current_device", "(当前设备)"),
+    ("config_sync.platform", "平台: {platform}"),
+    ("config_sync.last_sync", "最后同步: {time}"),
+    ("config_sync.files", "文件: {files}"),
+    ("config_sync.no_device_configs", "没有找到设备配置"),
+    ("config_sync.device_not_found", "设备配置不存在: {device}\n运行 `{command}` 查看可用配置"),
+    ("config_sync.applying", "从 {device} 应用配置..."),
+    ("config_sync.settings_backed_up", "已备份到 settings.json.backup"),
+    ("config_sync.with_hooks", "含 hooks"),
+    ("config_sync.keep_local_hooks", "保留本地 hooks"),
+    ("config_sync.claude_md_merged", "已合并 CLAUDE.md（保留本地 {platform} 平台内容）"),
+    ("config_sync.hooks_path_check", "检查 hooks 引用的路径在本机是否存在："),
+    ("config_sync.hooks_path_ok", "正常"),
+    ("config_sync.hooks_path_missing", "缺失"),
+    ("config_sync.hooks_paths_ok", "✓ 所有 hooks 路径在本机均存在"),
+    ("config_sync.hooks_paths_missing", "⚠️  {count} 个 hook 引用了本机不存在的路径，请在重启 Claude Code 前修正"),
+    ("config_sync.skills_install", "Skills 安装命令:"),
+    ("config_sync.plugins_install", "Plugins 安装命令:"),
+    ("config_sync.applied", "✓ 配置已应用"),
+    ("config_sync.restart_hint", "请重启 Claude Code 使配置生效"),
+    ("config_sync.nothing_applied", "没有应用任何配置"),
+    ("config_sync.status_title", "配置同步状态"),
+    ("config_sync.device_name", "设备名称: {name}"),
+    ("config_sync.local_files", "本地配置文件:"),
+    ("config_sync.skills_count", "skills: {count} 个"),
+    ("config_sync.settings_header", "同步设置:"),
+    ("config_sync.setting_enabled", "配置同步: {value}"),
+    ("config_sync.setting_sync_settings", "同步 settings.json: {value}"),
+    ("config_sync.setting_sync_claude_md", "同步 CLAUDE.md: {value}"),
+    ("config_sync.setting_sync_hooks", "同步 hooks: {value}"),
+    ("config_sync.setting_sync_skills_list", "同步 skills 列表: {value}"),
+    ("config_sync.settings_conflict", "settings.json 冲突："),
+    ("config_sync.local_value", "本地"),
+    ("config_sync.remote_value", "远程"),
+    ("config_sync.settings_conflict_prompt", "保留哪个值？"),
+    ("config_sync.keep_local_value", "保留本地"),
+    ("config_sync.take_remote_value", "使用远程"),
+    ("config_sync.rename_target_exists", "设备配置已存在：{device}"),
+    ("config_sync.rename_invalid_name", "设备名必须包含字母或数字"),
+    ("config_sync.rename_same", "设备名已经是 {device}"),
+    ("config_sync.device_name_pinned", "已在 config.toml 中设置 device_name = \"{device}\""),
+    ("config_sync.renamed_device", "✓ 已将设备配置 {old} 重命名为 {new}"),
+    ("config_sync.no_other_device", "未找到其他设备的配置\n请先在其他设备上运行 `{command}`"),
+    ("config_sync.latest_device", "最近同步的设备：{device}（最后同步：{time}）"),
+    ("config_sync.latest_device_confirm", "应用 {device} 的配置？"),
+    ("config_sync.latest_device_hint", "当前不是终端：运行 `{command}` 应用该配置，或加上 --yes"),
+    ("config_sync.claude_md_applied", "✓ 已应用 {device} 的 CLAUDE.md"),
+    ("config_sync.claude_md_backed_up", "已备份到 CLAUDE.md.backup"),
+    ("config_sync.claude_md_unchanged", "CLAUDE.md 已与 {device} 一致"),
+    ("config_sync.claude_md_local_newer", "本设备的配置比 {device} 更新，未修改 CLAUDE.md\n如需强制应用，请加 --force"),
+    ("config_sync.claude_md_missing", "{device} 没有同步 CLAUDE.md"),
+    ("config_sync.install_script_written", "安装脚本已写入 {path}"),
+    ("config_sync.install_none", "没有需要安装的 Skills 或 Plugins"),
+    ("config_sync.install_confirm", "现在执行这 {count} 条安装命令?"),
+    ("config_sync.install_failed", "{count} 条安装命令执行失败"),
+    ("config_sync.install_done", "安装命令已全部执行完成"),
+    ("config_sync.device_latest_tag", "(最新)"),
+    ("config_sync.device_stale_tag", "(已过期)"),
+    ("config_sync.rename_invalid_old", "不是有效的设备名：\"{device}\""),
+
+    ("push.header", "正在推送 Claude Code 历史记录..."),
+    ("push.rebased_on_attempt", "已在第 {attempt} 次尝试时变基并推送"),
+    ("push.lfs_git_only", "LFS 仅支持 git 仓库，已跳过"),
+    ("push.configuring_lfs", "正在配置 Git LFS..."),
+    ("push.structure_warning", "目录结构不一致警告"),
+    ("push.structure_confirm", "是否继续推送？"),
+    ("push.structure_confirm_help", "建议先清理目录结构再继续"),
+    ("push.cancelled", "推送已取消。"),
+    ("push.structure_switch_hint", "提示：使用 '{command}' 可以切换同步模式"),
+    ("push.structure_interactive_hint", "使用 --interactive 选项可以在不一致时选择是否继续"),
+    ("push.discovering", "正在查找对话会话..."),
+    ("push.found", "找到 {count} 个会话"),
+    ("push.collision_warning", "警告：多个项目映射到了同一个名称："),
+    ("push.collision_locations", "{count} 个位置："),
+    ("push.collision_merge", "同名项目的会话将被合并到同一目录。"),
+    ("push.copying", "正在复制会话到同步仓库..."),
+    ("push.summary_title", "推送摘要："),
+    ("push.summary_added", "新增：{count}"),
+    ("push.summary_modified", "修改：{count}"),
+    ("push.summary_unchanged", "未变：{count}"),
+    ("push.summary_skipped_no_cwd", "跳过（无 cwd）：{count}"),
+    ("push.summary_with_cwd", "会话（含项目上下文）：{count}"),
+    ("push.files_to_push", "待推送文件："),
+    ("push.status_new", "新增"),
+    ("push.status_modified", "修改"),
+    ("push.status_unchanged", "未变"),
+    ("push.confirm", "是否继续推送这些更改？"),
+    ("push.confirm_help", "这将提交并推送到同步仓库"),
+    ("push.syncing_config", "正在同步设备配置..."),
+    ("push.config_synced", "设备配置已同步："),
+    ("push.no_config_files", "没有需要同步的配置文件"),
+    ("push.config_sync_failed", "同步设备配置失败：{error}"),
+    ("push.pruned_unlocked", "删除放行窗口生效中，已同步删除 {count} 个 session（剩余 {minutes} 分钟）"),
+    ("push.pruned", "已从同步仓库清除 {count} 个本地缺失的会话"),
+    ("push.missing_protected", "检测到 {count} 个会话本地缺失但同步仓库中存在 — 已保护，不会删除。"),
+    ("push.missing_protected_hint", "使用 '{restore}' 恢复，或使用 '{prune}' 强制删除。"),
+    ("push.syncing_memory", "正在同步 auto memory 目录..."),
+    ("push.memory_synced", "已同步 {count} 个 memory 目录"),
+    ("push.no_memory_dirs", "未找到 memory 目录"),
+    ("push.memory_removed", "已从同步仓库移除 {count} 个已删除的 memory 文件"),
+    ("push.recorded_commit", "已记录提交 {hash} 用于撤销"),
+    ("push.first_push", "首次推送 - 没有可撤销到的先前提交"),
+    ("push.committing", "正在提交更改..."),
+    ("push.committed", "已提交：{message}"),
+    ("push.pushing_remote", "正在推送到远程..."),
+    ("push.pushed_to", "已推送到 origin/{branch}"),
+    ("push.degraded", "推送降级；保留了 {count} 个冲突文件"),
+    ("push.push_failed", "推送失败：{error}"),
+    ("push.no_changes", "没有需要提交的更改"),
+    ("push.summary_heading", "推送摘要"),
+    ("push.stats", "{added} 新增    {modified} 修改    {unchanged} 未变"),
+    ("push.stats_with_deleted", "{added} 新增    {modified} 修改    {deleted} 删除    {unchanged} 未变"),
+    ("push.pushed_conversations", "已推送的对话："),
+    ("push.more_conversations", "... 以及另外 {count} 个对话"),
+    ("push.complete", "推送完成！"),
+    ("push.timings", "各阶段耗时："),
+    ("push.retrying", "第 {attempt}/{max} 次推送失败，{secs} 秒后重试..."),
+    ("push.remote_behind", "更改已提交到本地仓库，但远程尚未更新；网络恢复后请重新推送"),
+    ("push.secrets_found", "在 {count} 个会话中发现疑似密钥："),
+    ("push.secrets_blocked", "--strict 已阻止推送：{count} 个会话包含疑似密钥"),
+    ("push.excluded_projects", "本次排除了 {projects} 的 {count} 个会话"),
+    ("push.included_projects", "本次仅推送 {projects}，跳过其余 {count} 个会话"),
+    ("push.oversized_session", "已跳过 {path}（{size} MB）：超过 {limit} MB 的大小限制"),
+    ("push.oversized_hint", "如需同步这些会话，请运行 `{command}` 或在配置中调大 max_file_size_bytes"),
+    ("push.summary_filtered", "已过滤：{count}（大小：{size}，时间：{age}，规则：{pattern}）"),
+    ("push.collision_suffix", "名称冲突的项目将分别存放在各自的目录中，目录名为 <项目名>-<路径哈希>。"),
+    ("push.collision_skip", "名称冲突项目的会话将被跳过，不会推送。"),
+    ("push.collision_strategy_hint", "如需分开存放，请运行 `{command} suffix`（或 `skip`）。"),
+    ("push.since_last_skipped", "已跳过 {count} 个自上次推送（{time}）以来未变化的会话"),
+    ("push.since_last_first", "没有上次推送的记录，检查全部会话"),
+    ("push.no_delete_notice", "已禁用孤立会话删除（--no-delete）：本地缺失的会话保留在同步仓库中"),
+
+    ("setup.installing_gh", "📦 正在安装 GitHub CLI (gh)..."),
+    ("setup.install_via_brew", "   使用 Homebrew 安装..."),
+    ("setup.no_homebrew", "未安装 Homebrew。请先安装: /bin/bash -c \"$(curl -fsSL https://raw.githubusercontent.com/Homebrew/install/HEAD/install.sh)\""),
+    ("setup.install_via_apt", "   使用 apt 安装..."),
+    ("setup.adding_apt_source", "   添加 GitHub APT 源..."),
+    ("setup.gpg_key_failed", "添加 GitHub GPG key 失败"),
+    ("setup.apt_source_failed", "添加 GitHub APT 源失败"),
+    ("setup.install_via_dnf", "   使用 dnf 安装..."),
+    ("setup.install_via_pacman", "   使用 pacman 安装..."),
+    ("setup.no_package_manager", "未检测到支持的包管理器。请手动安装 gh: https://github.com/cli/cli#installation"),
+    ("setup.install_via_winget", "   使用 winget 安装..."),
+    ("setup.install_via_scoop", "   使用 scoop 安装..."),
+    ("setup.no_winget_scoop", "未检测到 winget 或 scoop。请手动安装 gh: https://github.com/cli/cli#installation"),
+    ("setup.unsupported_os", "不支持的操作系统。请手动安装 gh: https://github.com/cli/cli#installation"),
+    ("setup.gh_install_failed", "gh CLI 安装失败"),
+    ("setup.gh_installed", "✓ GitHub CLI 安装成功"),
+    ("setup.gh_login_required", "🔐 需要登录 GitHub 账号"),
+    ("setup.gh_login_browser", "   将打开浏览器进行认证，请在浏览器中完成登录。"),
+    ("setup.gh_auth_failed", "GitHub 认证失败"),
+    ("setup.gh_authenticated", "✓ GitHub 认证成功"),
+    ("setup.repo_created", "✓ 仓库创建成功"),
+    ("setup.gh_missing", "⚠️  未检测到 GitHub CLI (gh)"),
+    ("setup.gh_install_confirm", "是否自动安装 GitHub CLI?"),
+    ("setup.gh_install_help", "需要 gh CLI 来创建仓库和进行认证"),
+    ("setup.gh_required", "需要 GitHub CLI。请手动安装: https://github.com/cli/cli#installation"),
+    ("setup.gh_already_authenticated", "✓ GitHub CLI 已认证"),
+    ("setup.cloning", "📥 正在克隆仓库..."),
+    ("setup.overwrite_cancelled", "已取消。请手动清理目录后重试。"),
+    ("setup.retrying_clone", "📥 重新尝试克隆..."),
+    ("setup.new_repo_name", "新仓库名称:"),
+    ("setup.private_repo", "设为私有仓库?"),
+    ("setup.cloning_new", "📥 克隆新仓库..."),
+    ("setup.clone_failed", "❌ 克隆仓库失败"),
+    ("setup.git_not_found", "💡 未找到 git 命令。请先安装 Git:"),
+    ("setup.auth_problem", "💡 这可能是认证问题。解决方案:"),
+    ("setup.auth_run_gh", "      运行: gh auth login --web"),
+    ("setup.auth_token_step1", "      1. 访问 https://github.com/settings/tokens"),
+    ("setup.auth_token_step2", "      2. 创建 token (需要 repo 权限)"),
+    ("setup.auth_token_step3", "      3. 使用格式: https://<token>@github.com/user/repo.git"),
+    ("setup.auth_gh_confirm", "是否使用 GitHub CLI 进行网页认证?"),
+    ("setup.not_found", "💡 仓库不存在或无访问权限。"),
+    ("setup.possible_causes_indented", "   可能的原因:"),
+    ("setup.cause_wrong_url_numbered", "   1. 仓库地址不正确"),
+    ("setup.cause_not_created", "   2. 仓库尚未创建"),
+    ("setup.cause_private", "这是一个私有仓库，需要先登录 GitHub"),
+    ("setup.possible_causes", "💡 可能的原因:"),
+    ("setup.cause_network", "   - 网络连接问题"),
+    ("setup.cause_wrong_url", "   - 仓库地址不正确"),
+    ("setup.cause_no_access", "   - 没有访问权限"),
+    ("setup.clone_failed_retry", "克隆失败，请解决上述问题后重试"),
+    ("setup.title", "🔧 Claude Code Sync 配置向导"),
+    ("setup.git_missing", "❌ 未检测到 Git"),
+    ("setup.install_git_first", "💡 请先安装 Git:"),
+    ("setup.git_required", "需要安装 Git 才能使用 Claude Code Sync"),
+    ("setup.select_mode", "选择同步模式:"),
+    ("setup.select_mode_help", "多设备模式允许在不同电脑间同步相同项目名的对话"),
+    ("setup.mode_changed", "⚠️  检测到同步模式变更"),
+    ("setup.mode_multi", "多设备同步"),
+    ("setup.mode_single", "单设备备份"),
+    ("setup.mode_change_mixed", "切换模式可能导致同步仓库中出现混合目录格式。"),
+    ("setup.mode_change_cleanup", "建议在切换后手动清理旧格式的目录以避免数据重复。"),
+    ("setup.mode_change_confirm", "确认切换模式？"),
+    ("setup.user_cancelled", "用户取消配置"),
+    ("setup.repo_source", "仓库来源:"),
+    ("setup.repo_source_help", "选择使用已有仓库还是创建新仓库"),
+    ("setup.new_repo_help", "将在你的 GitHub 账号下创建此仓库"),
+    ("setup.private_repo_help", "私有仓库只有你能访问，推荐用于存储对话历史"),
+    ("setup.remote_url", "远程仓库地址:"),
+    ("setup.remote_url_help", "Git 仓库地址，用于备份和同步对话历史"),
+    ("setup.invalid_url", "无效的 Git URL。必须以 'https://', 'http://', 'git@' 或 'ssh://' 开头"),
+    ("setup.local_dir", "本地备份目录:"),
+    ("setup.local_dir_help", "对话历史将同步到此目录"),
+    ("setup.summary", "📋 配置摘要"),
+    ("setup.summary_mode", "模式:"),
+    ("setup.summary_remote", "远程:"),
+    ("setup.summary_local", "本地:"),
+    ("setup.confirm_config", "确认以上配置?"),
+    ("setup.config_cancelled", "已取消配置。"),
+    ("setup.existing_repo_pulling", "📦 检测到已有仓库，正在拉取最新变更..."),
+    ("setup.different_repo", "⚠️  目标目录已存在一个不同的仓库"),
+    ("setup.overwrite_repo_confirm", "是否删除已有仓库并重新克隆?"),
+    ("setup.not_a_repo", "⚠️  目标目录已存在且不是 Git 仓库"),
+    ("setup.overwrite_dir_confirm", "是否删除该目录并重新克隆?"),
+    ("setup.cloned", "✓ 仓库克隆成功"),
+    ("setup.exclude_attachments", "是否排除文件附件 (图片、PDF 等)?"),
+    ("setup.exclude_attachments_help", "仅同步 .jsonl 对话文件，排除附件可减少存储空间"),
+    ("setup.exclude_old", "是否排除旧对话?"),
+    ("setup.exclude_old_help", "仅同步近期修改的对话"),
+    ("setup.exclude_old_days", "排除多少天前的对话:"),
+    ("setup.sync_now", "是否立即同步?"),
+    ("setup.sync_now_help", "将本地对话历史推送到远程仓库"),
+    ("setup.syncing", "🔄 正在同步..."),
+    ("setup.sync_done", "✓ 同步完成"),
+    ("setup.sync_problem", "⚠️  同步时出现问题:"),
+    ("setup.auto_sync", "是否配置自动同步？"),
+    ("setup.auto_sync_help", "启动时自动拉取，退出时自动推送，无需手动执行命令"),
+    ("setup.configuring_auto_sync", "🔧 正在配置自动同步..."),
+    ("setup.hooks_install_failed", "⚠️  Hooks 安装失败:"),
+    ("setup.auto_sync_configured", "✓ 自动同步已配置"),
+    ("setup.usage", "使用方式:"),
+    ("setup.wrapper_install_failed", "⚠️  Wrapper 安装失败:"),
+    ("setup.sync_config", "是否同步配置文件？"),
+    ("setup.sync_config_help", "同步 settings.json、CLAUDE.md 等配置到远程仓库"),
+    ("setup.select_config_items", "选择需要同步的配置项:"),
+    ("setup.sync_settings", "  同步 settings.json (权限、模型配置)?"),
+    ("setup.sync_claude_md", "  同步 CLAUDE.md (用户指令)?"),
+    ("setup.sync_hooks", "  同步 hooks (钩子脚本)?"),
+    ("setup.sync_hooks_help", "注意: hooks 路径可能不跨平台兼容"),
+    ("setup.sync_skills", "  同步 skills/plugins 列表?"),
+    ("setup.sync_skills_help", "仅同步列表，需要在每台设备手动安装"),
+    ("setup.config_saved", "✓ 配置已保存"),
+    ("setup.done", "🎉 配置完成！"),
+    ("setup.auto_sync_enabled", "自动同步已启用，使用 claude-sync 启动即可。"),
+    ("setup.management_commands", "管理命令:"),
+    ("setup.common_commands", "常用命令:"),
+    ("setup.auth_option_one", "方式一:"),
+    ("setup.auth_option_two", "方式二:"),
+    ("setup.auth_option_gh", "使用 GitHub CLI 网页认证 (推荐)"),
+    ("setup.auth_option_token", "使用 Personal Access Token"),
+    ("setup.mode_multi_option", "多设备同步 (推荐) - 支持不同电脑同步同一项目"),
+    ("setup.mode_single_option", "单设备备份 - 仅本机备份，使用完整路径"),
+    ("setup.source_existing_option", "使用已有仓库 - 输入仓库地址"),
+    ("setup.source_create_option", "创建新仓库 - 自动在 GitHub 创建"),
+    ("setup.creating_repo", "📦 正在创建仓库 {name}..."),
+    ("setup.current_url", "当前地址: {url}"),
+    ("setup.not_found_login_retry", "先登录 GitHub 再重试 (私有仓库推荐)"),
+    ("setup.not_found_create", "创建新仓库"),
+    ("setup.choose", "请选择:"),
+    ("setup.error_message", "错误信息: {error}"),
+    ("setup.mode_change", "当前配置: {old} → 新选择: {new}"),
+    ("setup.existing_remote", "已有远程: {url}"),
+    ("setup.new_remote", "新的远程: {url}"),
+    ("setup.path", "路径: {path}"),
+    ("setup.sync_retry_hint", "   可以稍后使用 '{command}' 重试"),
+    ("setup.wrapper_usage", "使用 {command} 启动 Claude Code（替代 claude 命令）"),
+    ("setup.wrapper_alias", "或添加别名: alias claude='{path}'"),
+    ("setup.cmd_automate_status", "查看自动同步状态"),
+    ("setup.cmd_automate_uninstall", "卸载自动同步"),
+    ("setup.cmd_sync", "双向同步"),
+    ("setup.cmd_push", "推送到远程"),
+    ("setup.cmd_pull", "拉取到本地"),
+    ("setup.cmd_status", "查看状态"),
+    ("setup.automate_hint", "提示: 运行 '{command}' 可配置自动同步"),
+];
@@ -59,6 +59,10 @@ pub mod omp;
 /// keeping both versions (with automatic renaming), keeping local, or keeping remote.
 pub mod conflict;
 
+/// Typed errors for failures callers handle differently, such as an
+/// uninitialized sync setup or an unreachable remote, and their exit codes.
+pub mod error;
+
 /// Interactive terminal-based conflict resolution interface.
 ///
 /// Provides a user-friendly TUI for resolving sync conflicts interactively. Users can
@@ -116,6 +120,12 @@ pub mod onboarding;
 /// file snapshots, etc.) with metadata like timestamps, UUIDs, and session IDs.
 pub mod parser;
 
+/// Minimal i18n layer (`t!` message lookup, en / zh-CN tables).
+pub mod lang;
+
+/// Output destination (stdout or `--output` file) for listing and status commands.
+pub mod output;
+
 /// Conflict report generation and formatting.
 ///
 /// Generates detailed reports of sync conflicts in multiple formats (JSON, Markdown, console).
@@ -123,6 +133,13 @@ pub mod parser;
 /// and resolution strategies applied during the last sync operation.
 pub mod report;
 
+/// Pre-push detection of credentials pasted into conversations.
+///
+/// Scans new and modified sessions for strings that look like API keys or
+/// access tokens (built-in detectors plus configurable regexes) so a push can
+/// warn about them, or refuse to continue with `--strict`.
+pub mod secrets;
+
 /// Core synchronization logic for pushing and pulling conversation history.
 ///
 /// Implements the main sync operations:
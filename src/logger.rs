@@ -1,7 +1,8 @@
 use anyhow::{Context, Result};
-use log::LevelFilter;
+use log::{Level, LevelFilter};
 use std::fs::OpenOptions;
 use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use crate::config::ConfigManager;
 
@@ -9,7 +10,10 @@ use crate::config::ConfigManager;
 ///
 /// Sets up logging to both console and a log file in the config directory.
 ///
-/// **Console logging** can be controlled via the `RUST_LOG` environment variable:
+/// **Console logging** goes to stderr with colored levels (disabled by
+/// `--plain` or `NO_COLOR`). Debug and trace records also name the module
+/// they come from. The level is set by `level` (the global `--log-level`
+/// flag), or else by the `RUST_LOG` environment variable:
 /// - `RUST_LOG=error` - Only errors
 /// - `RUST_LOG=warn` - Warnings and errors
 /// - `RUST_LOG=info` - Info, warnings, and errors (default)
@@ -25,6 +29,7 @@ use crate::config::ConfigManager;
 ///
 /// ```bash
 /// # Show all debug messages on console
+/// ccs --log-level debug sync
 /// RUST_LOG=debug ccs sync
 ///
 /// # Only show errors on console
@@ -33,29 +38,43 @@ use crate::config::ConfigManager;
 /// # No console output (file logging continues)
 /// RUST_LOG=off ccs pull
 /// ```
-pub fn init_logger() -> Result<()> {
+pub fn init_logger(level: Option<LevelFilter>) -> Result<()> {
     // Ensure config directory exists
     ConfigManager::ensure_config_dir()?;
 
     // Determine if console logging should be enabled
-    // By default, use Info level unless RUST_LOG is set
-    let default_level = std::env::var("RUST_LOG")
-        .ok()
-        .and_then(|s| s.parse::<LevelFilter>().ok())
+    // By default, use Info level unless --log-level or RUST_LOG is set
+    let default_level = level
+        .or_else(|| {
+            std::env::var("RUST_LOG")
+                .ok()
+                .and_then(|s| s.parse::<LevelFilter>().ok())
+        })
         .unwrap_or(LevelFilter::Info);
 
+    let write_style = if crate::output::is_plain() {
+        env_logger::WriteStyle::Never
+    } else {
+        env_logger::WriteStyle::Auto
+    };
+
     // Initialize env_logger with custom format
     env_logger::Builder::from_default_env()
         .format(|buf, record| {
-            writeln!(
+            let style = buf.default_level_style(record.level());
+            write!(
                 buf,
-                "{} [{:5}] {}",
+                "{} [{style}{:5}{style:#}] ",
                 chrono::Local::now().format("%H:%M:%S"),
                 record.level(),
-                record.args()
-            )
+            )?;
+            if record.level() >= Level::Debug {
+                write!(buf, "{}: ", record.target())?;
+            }
+            writeln!(buf, "{}", record.args())
         })
         .filter_level(default_level)
+        .write_style(write_style)
         .target(env_logger::Target::Stderr)
         .try_init()
         .ok(); // Ignore error if logger is already initialized
@@ -86,30 +105,64 @@ pub fn log_to_file(message: &str) -> Result<()> {
     Ok(())
 }
 
-/// Rotate log file if it exceeds the size limit (default: 10MB)
-pub fn rotate_log_if_needed() -> Result<()> {
-    const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024; // 10MB
+/// Size limit of the hook log before it is rotated (1MB)
+const MAX_HOOK_LOG_SIZE: u64 = 1024 * 1024;
 
-    let log_path = ConfigManager::log_file_path()?;
+/// Append a line to the hook log (`hook-debug.log` in the config directory)
+///
+/// Hooks run detached from any terminal, so this file is the only record of
+/// what they did. It is rotated to `hook-debug.log.old` once it passes
+/// [`MAX_HOOK_LOG_SIZE`], keeping at most two files.
+pub fn log_hook(message: &str) -> Result<()> {
+    ConfigManager::ensure_config_dir()?;
+    let log_path = ConfigManager::hook_log_path()?;
+    rotate_if_larger(&log_path, MAX_HOOK_LOG_SIZE)?;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&log_path)
+        .with_context(|| format!("Failed to open log file: {}", log_path.display()))?;
 
+    writeln!(
+        file,
+        "[{}] {}",
+        chrono::Local::now().format("%Y-%m-%d %H:%M:%S"),
+        message
+    )?;
+
+    Ok(())
+}
+
+/// Rotate `log_path` to `<name>.old` if it is larger than `max_size`,
+/// replacing an earlier backup. Returns the backup path if it rotated.
+fn rotate_if_larger(log_path: &Path, max_size: u64) -> Result<Option<PathBuf>> {
     // Check if log file exists and its size
-    if log_path.exists() {
-        let metadata = std::fs::metadata(&log_path)?;
+    if !log_path.exists() || std::fs::metadata(log_path)?.len() <= max_size {
+        return Ok(None);
+    }
 
-        if metadata.len() > MAX_LOG_SIZE {
-            // Rotate: rename current log to .old and start fresh
-            let old_log_path = log_path.with_extension("log.old");
+    // Rotate: rename current log to .old and start fresh
+    let old_log_path = log_path.with_extension("log.old");
 
-            // Remove old backup if it exists
-            if old_log_path.exists() {
-                std::fs::remove_file(&old_log_path)?;
-            }
+    // Remove old backup if it exists
+    if old_log_path.exists() {
+        std::fs::remove_file(&old_log_path)?;
+    }
 
-            // Rename current log to .old
-            std::fs::rename(&log_path, &old_log_path)?;
+    // Rename current log to .old
+    std::fs::rename(log_path, &old_log_path)?;
 
-            log::info!("Log file rotated to {}", old_log_path.display());
-        }
+    Ok(Some(old_log_path))
+}
+
+/// Rotate log file if it exceeds the size limit (default: 10MB)
+pub fn rotate_log_if_needed() -> Result<()> {
+    const MAX_LOG_SIZE: u64 = 10 * 1024 * 1024; // 10MB
+
+    let log_path = ConfigManager::log_file_path()?;
+    if let Some(old_log_path) = rotate_if_larger(&log_path, MAX_LOG_SIZE)? {
+        log::info!("Log file rotated to {}", old_log_path.display());
     }
 
     Ok(())
@@ -126,7 +179,7 @@ mod tests {
     #[serial]
     fn test_init_logger_succeeds() {
         // Should not panic
-        let result = init_logger();
+        let result = init_logger(None);
         assert!(result.is_ok());
     }
 
@@ -187,4 +240,32 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    #[serial]
+    fn test_log_hook_rotates_at_size_limit() -> Result<()> {
+        let temp_dir = tempfile::TempDir::new()?;
+        let config_dir = temp_dir.path().join("claude-code-sync");
+        std::env::set_var(CONFIG_DIR_ENV, &config_dir);
+
+        log_hook("Stop hook executed (debounce: false)")?;
+        let log_path = ConfigManager::hook_log_path()?;
+        assert!(std::fs::read_to_string(&log_path)?.contains("Stop hook executed"));
+
+        std::fs::write(&log_path, vec![b'a'; MAX_HOOK_LOG_SIZE as usize + 1])?;
+        log_hook("SessionStart pull completed")?;
+
+        let old_log_path = log_path.with_extension("log.old");
+        assert_eq!(
+            std::fs::metadata(&old_log_path)?.len(),
+            MAX_HOOK_LOG_SIZE + 1
+        );
+        let contents = std::fs::read_to_string(&log_path)?;
+        assert_eq!(contents.lines().count(), 1);
+        assert!(contents.contains("SessionStart pull completed"));
+
+        std::env::remove_var(CONFIG_DIR_ENV);
+
+        Ok(())
+    }
 }
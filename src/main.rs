@@ -2,15 +2,19 @@ mod codex;
 mod omp;
 mod config;
 mod conflict;
+mod error;
 mod filter;
 mod handlers;
 mod history;
 mod interactive_conflict;
+mod lang;
 mod logger;
 mod merge;
 mod onboarding;
+mod output;
 mod parser;
 mod report;
+mod secrets;
 mod scm;
 mod session_cache;
 mod sync;
@@ -37,8 +41,45 @@ pub use claude_code_sync::BINARY_NAME;
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+
+    /// Write output to a file instead of stdout, without colors
+    /// (status, report, session list, config-sync list)
+    #[arg(short, long, global = true, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Plain output: no colors, ASCII instead of symbols (NO_COLOR is also honored)
+    #[arg(long, global = true)]
+    plain: bool,
+
+    /// Answer yes to confirmation prompts (for unattended scripts)
+    #[arg(short = 'y', long, global = true)]
+    yes: bool,
+
+    /// UI language: en or zh-CN (default: from LC_ALL / LC_MESSAGES / LANG)
+    #[arg(long, global = true, value_name = "LANG", value_parser = parse_lang)]
+    lang: Option<lang::Lang>,
+
+    /// Claude Code directory to use instead of ~/.claude
+    /// (overrides CLAUDE_CONFIG_DIR)
+    #[arg(long, global = true, value_name = "DIR")]
+    claude_dir: Option<PathBuf>,
+
+    /// Use this configured repository instead of the active one, without
+    /// switching (see `repo list`)
+    #[arg(long, global = true, value_name = "NAME")]
+    repo: Option<String>,
+
+    /// Diagnostic log level on stderr (overrides RUST_LOG; default: info)
+    #[arg(long, global = true, value_enum, value_name = "LEVEL")]
+    log_level: Option<LogLevelArg>,
 }
 
+fn parse_lang(value: &str) -> Result<lang::Lang, String> {
+    lang::Lang::parse(value).ok_or_else(|| format!("unsupported language '{}' (use en or zh-CN)", value))
+}
+
+// Parsed once per run; `Config` carries every settings flag
+#[allow(clippy::large_enum_variant)]
 #[derive(Subcommand)]
 enum Commands {
     /// Initialize a new sync repository
@@ -79,8 +120,14 @@ enum Commands {
         exclude_attachments: bool,
 
         /// Do not sync device configuration (settings.json, CLAUDE.md, etc.)
-        #[arg(long)]
-        no_config: bool,
+        /// for this run, whatever the config-sync settings say
+        #[arg(long, alias = "no-config")]
+        no_config_sync: bool,
+
+        /// Sync device configuration for this run even when config sync or
+        /// push_with_config is turned off
+        #[arg(long, conflicts_with = "no_config_sync")]
+        config_sync: bool,
 
         /// Force-delete sync-repo sessions that are missing locally (escape
         /// hatch for accidental-deletion protection). By default missing
@@ -88,6 +135,40 @@ enum Commands {
         #[arg(long)]
         prune: bool,
 
+        /// Never remove anything from the sync repo: sessions and memory files
+        /// missing locally are kept (overrides --prune and unlock-delete)
+        #[arg(long, conflicts_with = "prune")]
+        no_delete: bool,
+
+        /// Scan new and modified sessions for API keys/tokens and abort the
+        /// push if any are found (implies scanning even when disabled in config)
+        #[arg(long)]
+        strict: bool,
+
+        /// Override the sync repo subdirectory for this run (relative path)
+        #[arg(long, value_name = "PATH")]
+        subdir: Option<String>,
+
+        /// Only push sessions of this project for this run, ignoring the
+        /// configured include patterns (project name; repeatable)
+        #[arg(long = "include-project", value_name = "NAME")]
+        include_projects: Vec<String>,
+
+        /// Skip sessions of this project for this run (project name, as
+        /// shown by `session list`; repeatable; wins over --include-project)
+        #[arg(long = "exclude-project", value_name = "NAME")]
+        exclude_projects: Vec<String>,
+
+        /// Push sessions up to this many MB for this run, overriding the
+        /// configured max file size (larger sessions are skipped and reported)
+        #[arg(long, value_name = "MB", value_parser = clap::value_parser!(u64).range(1..))]
+        max_file_size: Option<u64>,
+
+        /// Only copy sessions changed since the last successful push (faster
+        /// for large, mostly unchanged histories)
+        #[arg(long)]
+        since_last: bool,
+
         /// Interactive mode - preview changes and confirm before pushing
         #[arg(short, long)]
         interactive: bool,
@@ -115,6 +196,14 @@ enum Commands {
         #[arg(short, long)]
         interactive: bool,
 
+        /// Override the sync repo subdirectory for this run (relative path)
+        #[arg(long, value_name = "PATH")]
+        subdir: Option<String>,
+
+        /// Only pull this project (name as stored in the sync repo)
+        #[arg(short, long)]
+        project: Option<String>,
+
         /// Show detailed verbose output
         #[arg(short, long)]
         verbose: bool,
@@ -147,6 +236,31 @@ enum Commands {
         #[arg(short, long)]
         interactive: bool,
 
+        /// How to resolve sessions changed on both sides: keep the local or
+        /// remote version, the one with the newer last message, or smart
+        /// merge and ask (default)
+        #[arg(long, value_enum, default_value_t = PreferArg::Interactive)]
+        prefer: PreferArg,
+
+        /// Only push sessions of this project in the push phase (project
+        /// name; repeatable)
+        #[arg(long = "include-project", value_name = "NAME")]
+        include_projects: Vec<String>,
+
+        /// Skip sessions of this project in the push phase (project name;
+        /// repeatable; wins over --include-project)
+        #[arg(long = "exclude-project", value_name = "NAME")]
+        exclude_projects: Vec<String>,
+
+        /// Push sessions up to this many MB in the push phase, overriding the
+        /// configured max file size
+        #[arg(long, value_name = "MB", value_parser = clap::value_parser!(u64).range(1..))]
+        max_file_size: Option<u64>,
+
+        /// Only push sessions changed since the last successful push
+        #[arg(long)]
+        since_last: bool,
+
         /// Show detailed verbose output
         #[arg(short, long)]
         verbose: bool,
@@ -156,6 +270,27 @@ enum Commands {
         quiet: bool,
     },
 
+    /// Check the installation and suggest fixes for common misconfigurations
+    Doctor,
+
+    /// Check that the sync repo matches local sessions (read-only)
+    ///
+    /// Reports sessions missing from the repo, sessions whose content
+    /// differs, and repo files with no local counterpart. Exits non-zero
+    /// when any are found.
+    Verify,
+
+    /// Report lines of local session files that fail to parse
+    ///
+    /// Sync skips such lines silently (and whole files that are not valid
+    /// UTF-8); this lists them by file and line number. Exits non-zero when
+    /// any are found.
+    ValidateHistory {
+        /// Only check this project (name or encoded directory name)
+        #[arg(long)]
+        project: Option<String>,
+    },
+
     /// Show sync status and conflicts
     Status {
         /// Show detailed conflict information
@@ -165,6 +300,19 @@ enum Commands {
         /// Show which files would be synced
         #[arg(long)]
         show_files: bool,
+
+        /// List local projects with their sessions not pushed yet
+        #[arg(long)]
+        projects: bool,
+
+        /// Output as JSON
+        #[arg(long)]
+        json: bool,
+
+        /// Output stable key=value lines for shell scripts (no color or
+        /// translation)
+        #[arg(long, conflicts_with = "json")]
+        porcelain: bool,
     },
 
     /// Configure sync settings
@@ -173,6 +321,11 @@ enum Commands {
         #[arg(long)]
         exclude_older_than: Option<u32>,
 
+        /// Measure age for --exclude-older-than by file modification time or
+        /// by the last message timestamp in the session
+        #[arg(long, value_enum, value_name = "BY")]
+        age_by: Option<AgeByArg>,
+
         /// Include only specific project paths (comma-separated patterns)
         #[arg(long)]
         include_projects: Option<String>,
@@ -185,6 +338,22 @@ enum Commands {
         #[arg(long)]
         exclude_attachments: Option<bool>,
 
+        /// Strip images/documents embedded in session files from pushed copies
+        #[arg(long)]
+        redact_on_push: Option<bool>,
+
+        /// Scan new and modified sessions for API keys/tokens before pushing
+        #[arg(long)]
+        scan_secrets: Option<bool>,
+
+        /// Extra regexes to treat as secrets (comma-separated, empty to clear)
+        #[arg(long)]
+        secret_patterns: Option<String>,
+
+        /// Follow symlinked project directories in ~/.claude/projects
+        #[arg(long)]
+        follow_symlinks: Option<bool>,
+
         /// Enable Git LFS for large files
         #[arg(long)]
         enable_lfs: Option<bool>,
@@ -205,6 +374,59 @@ enum Commands {
         #[arg(long)]
         use_project_name_only: Option<bool>,
 
+        /// What push does with local projects sharing a name in project-name-only
+        /// mode: merge them, give each a path-hash suffix, or skip them
+        #[arg(long, value_enum, value_name = "STRATEGY")]
+        collision_strategy: Option<CollisionStrategyArg>,
+
+        /// Store this device's projects under <NAME>/ in the sync repo when
+        /// using project names, for repos shared by several people (empty
+        /// string clears)
+        #[arg(long, value_name = "NAME")]
+        device_namespace: Option<String>,
+
+        /// SSH private key for push/pull (e.g. ~/.ssh/id_work; empty string clears)
+        #[arg(long)]
+        ssh_identity: Option<String>,
+
+        /// Push commit message template, e.g. "Sync {count} sessions from {device}"
+        /// (placeholders: {count} {added} {modified} {deleted} {device} {date};
+        /// empty string clears)
+        #[arg(long)]
+        commit_message_template: Option<String>,
+
+        /// Warn about conversation files at least this many MB during push (0 disables)
+        #[arg(long, value_name = "MB")]
+        large_file_warning_mb: Option<u64>,
+
+        /// Minimum seconds between Stop hook pushes; responses in between are
+        /// coalesced into the next push (0 pushes after every response)
+        #[arg(long, value_name = "SECS")]
+        stop_push_interval: Option<u64>,
+
+        /// Seconds a hook-triggered push or pull may run before it is killed
+        /// (0 disables the limit, default: 45)
+        #[arg(long, value_name = "SECS")]
+        hook_timeout: Option<u64>,
+
+        /// Attempts for pushing to the remote, retried with exponential backoff (default: 3)
+        #[arg(long, value_name = "N")]
+        push_attempts: Option<u32>,
+
+        /// Sessions with fewer messages than this are offered by session Cleanup
+        /// and hidden from session lists (default: 1)
+        #[arg(long, value_name = "N")]
+        cleanup_min_messages: Option<usize>,
+
+        /// Name of the auto memory directory inside each project (default: memory)
+        #[arg(long, value_name = "NAME")]
+        memory_dir_name: Option<String>,
+
+        /// How session listings show last activity: relative ("3 hours ago")
+        /// or absolute local time ("2025-01-15 14:30")
+        #[arg(long, value_enum, value_name = "FORMAT")]
+        time_format: Option<TimeFormatArg>,
+
         /// Show current configuration
         #[arg(long)]
         show: bool,
@@ -216,6 +438,9 @@ enum Commands {
         /// Step-by-step configuration wizard
         #[arg(short, long)]
         wizard: bool,
+
+        #[command(subcommand)]
+        action: Option<ConfigAction>,
     },
 
     /// View conflict reports
@@ -224,9 +449,44 @@ enum Commands {
         #[arg(short, long, default_value = "markdown")]
         format: String,
 
-        /// Output file (default: print to stdout)
-        #[arg(short, long)]
-        output: Option<PathBuf>,
+        /// Print JSON (same as --format json; with --list, the list as JSON)
+        #[arg(long)]
+        json: bool,
+
+        /// Show the newest archived report that had conflicts, even if later
+        /// syncs were clean
+        #[arg(long, conflicts_with = "list")]
+        latest: bool,
+
+        /// List past reports that had conflicts, newest first
+        #[arg(long)]
+        list: bool,
+    },
+
+    /// Export all sessions (after filters) into a single .tar.gz, organized by
+    /// project
+    ExportAll {
+        /// Archive to write (.tar.gz)
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+
+        /// How sessions are stored in the archive
+        #[arg(long, value_enum, default_value_t = ExportFormatArg::Jsonl)]
+        format: ExportFormatArg,
+
+        /// Also write index.json describing every exported session
+        #[arg(long)]
+        index: bool,
+    },
+
+    /// Import sessions from an export-all archive into ~/.claude/projects
+    Import {
+        /// Archive created by export-all (jsonl format)
+        archive: PathBuf,
+
+        /// Replace sessions that already exist locally
+        #[arg(long)]
+        overwrite: bool,
     },
 
     /// Manage git remote configuration
@@ -235,10 +495,25 @@ enum Commands {
         action: RemoteAction,
     },
 
-    /// Undo the last sync operation
+    /// Manage multiple named sync repositories
+    Repo {
+        #[command(subcommand)]
+        action: RepoAction,
+    },
+
+    /// Undo the last sync operation, or a specific one by ID (see `undo list`)
+    #[command(args_conflicts_with_subcommands = true)]
     Undo {
         #[command(subcommand)]
-        operation: UndoOperation,
+        operation: Option<UndoOperation>,
+
+        /// ID of the operation to undo, as shown by `undo list`
+        #[arg(value_name = "ID")]
+        id: Option<usize>,
+
+        /// Preview the undo of ID without executing it
+        #[arg(long, requires = "id")]
+        preview: bool,
 
         /// Show detailed verbose output
         #[arg(short, long, global = true)]
@@ -282,13 +557,20 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
 
-        /// Maximum number of snapshots to keep per operation type
-        #[arg(long, default_value_t = 5)]
-        max_count: usize,
-
-        /// Maximum age of snapshots to keep (in days)
-        #[arg(long, default_value_t = 7)]
-        max_age_days: i64,
+        /// Always keep the N most recent snapshots per operation type
+        /// (default without --older-than: 5)
+        #[arg(long, value_name = "N", visible_alias = "max-count")]
+        keep: Option<usize>,
+
+        /// Delete snapshots older than D days, beyond the ones --keep retains
+        /// (default without --keep: 7)
+        #[arg(
+            long,
+            value_name = "D",
+            visible_alias = "max-age-days",
+            value_parser = clap::value_parser!(i64).range(0..)
+        )]
+        older_than: Option<i64>,
 
         /// Interactive mode with detailed confirmation
         #[arg(short, long)]
@@ -315,6 +597,19 @@ enum Commands {
         action: WrapperAction,
     },
 
+    /// Git LFS maintenance for the sync repository
+    Lfs {
+        #[command(subcommand)]
+        action: LfsAction,
+    },
+
+    /// Run a background daemon that batches pushes instead of pushing after
+    /// every response
+    Daemon {
+        #[command(subcommand)]
+        action: DaemonAction,
+    },
+
     /// One-click setup for automatic synchronization
     Automate {
         /// Show automation configuration status
@@ -326,6 +621,12 @@ enum Commands {
         uninstall: bool,
     },
 
+    /// Inspect the platform blocks in ~/.claude/CLAUDE.md
+    ClaudeMd {
+        #[command(subcommand)]
+        action: ClaudeMdAction,
+    },
+
     /// Sync Claude Code configuration files across devices
     ConfigSync {
         #[command(subcommand)]
@@ -340,9 +641,24 @@ enum Commands {
     #[command(hide = true)]
     HookSessionStart,
 
-    /// Internal command for Stop hook (push after each response)
+    /// Internal command for Stop hook (push after each response, debounced)
     #[command(hide = true)]
-    HookStop,
+    HookStop {
+        /// Push immediately, ignoring stop_push_interval_secs
+        #[arg(long)]
+        now: bool,
+
+        /// Push at the end of the debounce interval (scheduled by debounced Stop hooks)
+        #[arg(long, conflicts_with = "now")]
+        trailing: bool,
+    },
+
+    /// Open a project's session menu directly, skipping the project list
+    Open {
+        /// Project name (exact, then matched via its local directory or
+        /// ignoring case)
+        project: String,
+    },
 
     /// Manage Claude Code conversation sessions
     Session {
@@ -356,6 +672,14 @@ enum Commands {
         /// Filter by session source (all, claude, codex, omp)
         #[arg(short, long, global = true, default_value = "all")]
         source: SessionSourceArg,
+
+        /// Show last activity as local date and time instead of "N hours ago"
+        #[arg(long, global = true)]
+        absolute: bool,
+
+        /// Line width for session listings (default: terminal width)
+        #[arg(long, global = true, value_name = "COLUMNS")]
+        width: Option<usize>,
     },
 
     /// Temporarily allow push to sync session deletions to the cloud
@@ -374,6 +698,31 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum RepoAction {
+    /// Add a sync repository from a local path or remote URL
+    Add {
+        /// Repository name (e.g., "work", "personal")
+        name: String,
+
+        /// Local path or remote URL (remote URLs are cloned)
+        location: String,
+
+        /// Optional description
+        #[arg(short, long)]
+        description: Option<String>,
+    },
+
+    /// List configured repositories
+    List,
+
+    /// Switch the active repository
+    Use {
+        /// Repository name
+        name: String,
+    },
+}
+
 #[derive(Subcommand)]
 enum RemoteAction {
     /// Show current remote URL
@@ -399,6 +748,9 @@ enum RemoteAction {
 
 #[derive(Subcommand)]
 enum UndoOperation {
+    /// List recorded operations with their IDs
+    List,
+
     /// Undo the last pull operation
     Pull {
         /// Preview the undo without executing it
@@ -412,6 +764,13 @@ enum UndoOperation {
         #[arg(long)]
         preview: bool,
     },
+
+    /// Undo the last session delete or rename
+    Session {
+        /// Preview the undo without executing it
+        #[arg(long)]
+        preview: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -421,6 +780,22 @@ enum HistoryAction {
         /// Number of operations to show (default: 10)
         #[arg(short, long, default_value_t = 10)]
         limit: usize,
+
+        /// Only operations of this type
+        #[arg(short = 't', long = "type", value_enum)]
+        operation_type: Option<OperationTypeArg>,
+
+        /// Only operations at or after this time (2026-10-13, RFC 3339, or a duration like "3d")
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only operations at or before this time (a date includes the whole day)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Only operations on this branch
+        #[arg(long)]
+        branch: Option<String>,
     },
 
     /// Show details of the last operation
@@ -428,6 +803,10 @@ enum HistoryAction {
         /// Filter by operation type (pull or push)
         #[arg(short = 't', long)]
         operation_type: Option<String>,
+
+        /// Print the operation record as JSON
+        #[arg(long)]
+        json: bool,
     },
 
     /// Interactively review and select operations to view details
@@ -435,6 +814,22 @@ enum HistoryAction {
         /// Number of operations to show for selection (default: 10)
         #[arg(short, long, default_value_t = 10)]
         limit: usize,
+
+        /// Only operations of this type
+        #[arg(short = 't', long = "type", value_enum)]
+        operation_type: Option<OperationTypeArg>,
+
+        /// Only operations at or after this time (2026-10-13, RFC 3339, or a duration like "3d")
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Only operations at or before this time (a date includes the whole day)
+        #[arg(long)]
+        until: Option<String>,
+
+        /// Only operations on this branch
+        #[arg(long)]
+        branch: Option<String>,
     },
 
     /// Clear all operation history
@@ -451,6 +846,67 @@ enum HooksAction {
 
     /// Show current hooks configuration status
     Show,
+
+    /// Simulate a hook event and print what the hook would do and why,
+    /// without pulling or pushing
+    Test {
+        /// Hook event to simulate
+        #[arg(value_enum)]
+        event: HookEventArg,
+
+        /// SessionStart source (startup, resume, clear or compact; default: startup)
+        #[arg(long)]
+        source: Option<String>,
+
+        /// Working directory reported in the hook input (default: current directory)
+        #[arg(long, value_name = "DIR")]
+        cwd: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum HookEventArg {
+    SessionStart,
+    Stop,
+    UserPromptSubmit,
+}
+
+impl From<HookEventArg> for HookEvent {
+    fn from(value: HookEventArg) -> Self {
+        match value {
+            HookEventArg::SessionStart => Self::SessionStart,
+            HookEventArg::Stop => Self::Stop,
+            HookEventArg::UserPromptSubmit => Self::UserPromptSubmit,
+        }
+    }
+}
+
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Write the current settings (config.toml) to a file
+    Export {
+        /// File to write
+        path: PathBuf,
+    },
+
+    /// Validate settings exported on another machine and use them here
+    /// (this machine's device name and SSH identity are kept)
+    Import {
+        /// File to read
+        path: PathBuf,
+    },
+}
+
+#[derive(Subcommand)]
+enum LfsAction {
+    /// Move already-committed files matching lfs_patterns to LFS by
+    /// rewriting the sync repo history, then force-push it
+    Migrate {
+        /// Actually rewrite the history (without it, only explains what
+        /// would happen)
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -466,29 +922,114 @@ enum WrapperAction {
     Uninstall,
 
     /// Show wrapper script path and status
-    Show,
+    Show {
+        /// Compare the installed wrapper with the current version and offer
+        /// to reinstall it when it differs
+        #[arg(long)]
+        diff: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum DaemonAction {
+    /// Start the daemon in the background
+    Start {
+        /// Minimum seconds between pushes
+        #[arg(long, default_value_t = handlers::daemon::DEFAULT_INTERVAL_SECS)]
+        interval: u64,
+
+        /// Run in the current terminal instead of detaching
+        #[arg(long)]
+        foreground: bool,
+    },
+
+    /// Stop the running daemon
+    Stop,
+
+    /// Show whether the daemon is running
+    Status,
+}
+
+#[derive(Subcommand)]
+enum ClaudeMdAction {
+    /// Show common content, platform blocks and unknown tags, and what each
+    /// platform will receive
+    Analyze,
+
+    /// Write a starter ~/.claude/CLAUDE.md with example platform blocks
+    Init {
+        /// Replace an existing CLAUDE.md (a backup is kept)
+        #[arg(long)]
+        force: bool,
+    },
 }
 
 #[derive(Subcommand)]
 enum ConfigSyncAction {
     /// Push local configuration to sync repository
-    Push,
+    Push {
+        /// Commit message (default: "Sync config from <device>")
+        #[arg(short, long)]
+        message: Option<String>,
+    },
 
     /// List available device configurations
     List,
 
     /// Apply configuration from another device
     Apply {
-        /// Device name to apply configuration from
-        device: String,
+        /// Device name to apply configuration from (default: the most
+        /// recently synced other device, after confirmation)
+        device: Option<String>,
 
         /// Also apply hooks configuration (check paths!)
-        #[arg(long)]
+        #[arg(long, conflicts_with = "claude_md")]
         with_hooks: bool,
+
+        /// Only apply CLAUDE.md, skipping it if this device's config is newer
+        #[arg(long)]
+        claude_md: bool,
+
+        /// With --claude-md, apply it even if this device's config is newer
+        #[arg(long, requires = "claude_md")]
+        force: bool,
+
+        /// Write the skills and plugins install commands to an executable
+        /// shell script
+        #[arg(long, value_name = "PATH", conflicts_with = "claude_md")]
+        emit_install_script: Option<PathBuf>,
+
+        /// Run the skills and plugins install commands after confirmation
+        #[arg(long, conflicts_with = "claude_md")]
+        run_install: bool,
     },
 
     /// Show configuration sync status
     Status,
+
+    /// Remove config folders of devices that haven't synced for a long time
+    PruneDevices {
+        /// Remove devices whose last sync is older than N days
+        #[arg(long, default_value_t = 30)]
+        older_than_days: u32,
+
+        /// Skip the confirmation prompt
+        #[arg(long)]
+        force: bool,
+
+        /// Only list devices that would be removed
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Rename a device's config folder (e.g. after renaming the machine)
+    RenameDevice {
+        /// Current device name (see `config-sync list`)
+        old: String,
+
+        /// New device name
+        new: String,
+    },
 }
 
 #[derive(Subcommand)]
@@ -506,6 +1047,27 @@ enum SessionAction {
         /// Session source to query (default: all)
         #[arg(long, value_enum, default_value_t = SessionSourceArg::All)]
         source: SessionSourceArg,
+
+        /// Output as JSON (for jq and scripts)
+        #[arg(long)]
+        json: bool,
+
+        /// Output as a delimited table (project, session_id, title,
+        /// message_count, last_activity, file_size) for spreadsheets
+        #[arg(long, value_enum, conflicts_with = "json")]
+        format: Option<TableFormatArg>,
+
+        /// Show at most N sessions (newest first)
+        #[arg(long, value_name = "N")]
+        limit: Option<usize>,
+
+        /// Skip the first N sessions (use with --limit to page)
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        offset: usize,
+
+        /// Apply --limit/--offset within each project instead of overall
+        #[arg(long)]
+        per_project: bool,
     },
 
     /// Search sessions and memory files by keyword (multiple words = AND match)
@@ -529,8 +1091,12 @@ enum SessionAction {
         #[arg(short = 'n', long, default_value_t = 10)]
         limit: usize,
 
-        /// Search only user messages (default: both user and assistant)
-        #[arg(long)]
+        /// Which messages to search (default: both user and assistant)
+        #[arg(long, value_enum, default_value_t = SearchScopeArg::Both)]
+        scope: SearchScopeArg,
+
+        /// Search only user messages (same as --scope user)
+        #[arg(long, conflicts_with = "scope")]
         user_only: bool,
 
         /// Output as JSON
@@ -544,7 +1110,7 @@ enum SessionAction {
 
     /// Show session details (supports drill-down with --tail/--head/--around)
     Show {
-        /// Session ID
+        /// Session ID, or a unique prefix of it
         session_id: String,
 
         /// Show last N messages
@@ -567,7 +1133,8 @@ enum SessionAction {
         #[arg(long)]
         json: bool,
 
-        /// Show full content without truncation (default for interactive detail view)
+        /// Show full content without truncation, including tool calls and
+        /// their results for Claude sessions
         #[arg(long)]
         full: bool,
 
@@ -576,18 +1143,34 @@ enum SessionAction {
         source: SessionSourceArg,
     },
 
+    /// Resume the most recently active session (skips all menus)
+    Last {
+        /// Only consider sessions of this project
+        #[arg(short, long)]
+        project: Option<String>,
+
+        /// Print the resume command instead of running it
+        #[arg(long)]
+        print: bool,
+    },
+
     /// Rename session (change title)
     Rename {
-        /// Session ID
+        /// Session ID, or a unique prefix of it
         session_id: String,
 
         /// New title
-        title: String,
+        #[arg(required_unless_present = "undo", conflicts_with = "undo")]
+        title: Option<String>,
+
+        /// Restore the title from before the latest rename
+        #[arg(long)]
+        undo: bool,
     },
 
     /// Delete session
     Delete {
-        /// Session ID
+        /// Session ID, or a unique prefix of it
         session_id: String,
 
         /// Skip confirmation
@@ -595,12 +1178,56 @@ enum SessionAction {
         force: bool,
     },
 
+    /// Archive sessions to ~/.claude/archive (hidden from listings, recoverable)
+    Archive {
+        /// Session ID to archive
+        #[arg(required_unless_present = "older_than", conflicts_with = "older_than")]
+        session_id: Option<String>,
+
+        /// Archive all sessions with no activity in the last N days
+        #[arg(long, value_name = "DAYS")]
+        older_than: Option<u32>,
+
+        /// Compress archived session files with gzip
+        #[arg(long)]
+        gzip: bool,
+
+        /// Skip confirmation
+        #[arg(short, long)]
+        force: bool,
+    },
+
+    /// Move an archived session back into ~/.claude/projects
+    Unarchive {
+        /// Session ID
+        session_id: String,
+    },
+
     /// Restore sessions deleted by accident (present in sync repo, missing locally)
     Restore {
         /// Specific session ID to restore (restores all if omitted)
         session_id: Option<String>,
     },
 
+    /// Print a line whenever a session is created or gains messages
+    /// (like `tail -f` for conversation history)
+    Watch,
+
+    /// Check session files for inconsistent sessionId values
+    Repair {
+        /// Session file to check
+        #[arg(required_unless_present = "check", conflicts_with = "check")]
+        file: Option<PathBuf>,
+
+        /// Scan every session file instead of a single one
+        #[arg(long)]
+        check: bool,
+
+        /// Rewrite inconsistent files to one sessionId (keeps a .bak copy)
+        #[arg(long)]
+        fix: bool,
+    },
+
     /// List all projects (non-interactive)
     Projects {
         /// Session source to query (default: all)
@@ -608,6 +1235,17 @@ enum SessionAction {
         source: SessionSourceArg,
     },
 
+    /// Show disk usage per project, largest first
+    DiskUsage {
+        /// Only show the N largest projects
+        #[arg(long, value_name = "N")]
+        top: Option<usize>,
+
+        /// Session source to query (default: all)
+        #[arg(long, value_enum, default_value_t = SessionSourceArg::All)]
+        source: SessionSourceArg,
+    },
+
     /// Overview of all projects with recent session context (for agent consumption)
     Overview {
         /// Number of recent sessions per project (default: 3)
@@ -647,10 +1285,177 @@ impl From<SessionSourceArg> for handlers::session::SessionSourceFilter {
     }
 }
 
-fn main() -> Result<()> {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum ExportFormatArg {
+    Jsonl,
+    Markdown,
+}
+
+impl From<ExportFormatArg> for handlers::export::ExportFormat {
+    fn from(value: ExportFormatArg) -> Self {
+        match value {
+            ExportFormatArg::Jsonl => Self::Jsonl,
+            ExportFormatArg::Markdown => Self::Markdown,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TableFormatArg {
+    Tsv,
+    Csv,
+}
+
+impl From<TableFormatArg> for handlers::session::TableFormat {
+    fn from(value: TableFormatArg) -> Self {
+        match value {
+            TableFormatArg::Tsv => Self::Tsv,
+            TableFormatArg::Csv => Self::Csv,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum PreferArg {
+    Local,
+    Remote,
+    Newer,
+    Interactive,
+}
+
+impl From<PreferArg> for conflict::Prefer {
+    fn from(value: PreferArg) -> Self {
+        match value {
+            PreferArg::Local => Self::Local,
+            PreferArg::Remote => Self::Remote,
+            PreferArg::Newer => Self::Newer,
+            PreferArg::Interactive => Self::Interactive,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum LogLevelArg {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevelArg> for log::LevelFilter {
+    fn from(value: LogLevelArg) -> Self {
+        match value {
+            LogLevelArg::Off => Self::Off,
+            LogLevelArg::Error => Self::Error,
+            LogLevelArg::Warn => Self::Warn,
+            LogLevelArg::Info => Self::Info,
+            LogLevelArg::Debug => Self::Debug,
+            LogLevelArg::Trace => Self::Trace,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum CollisionStrategyArg {
+    Merge,
+    Suffix,
+    Skip,
+}
+
+impl From<CollisionStrategyArg> for filter::CollisionStrategy {
+    fn from(value: CollisionStrategyArg) -> Self {
+        match value {
+            CollisionStrategyArg::Merge => Self::Merge,
+            CollisionStrategyArg::Suffix => Self::Suffix,
+            CollisionStrategyArg::Skip => Self::Skip,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum TimeFormatArg {
+    Relative,
+    Absolute,
+}
+
+impl From<TimeFormatArg> for filter::TimeFormat {
+    fn from(value: TimeFormatArg) -> Self {
+        match value {
+            TimeFormatArg::Relative => Self::Relative,
+            TimeFormatArg::Absolute => Self::Absolute,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum AgeByArg {
+    Mtime,
+    Content,
+}
+
+impl From<AgeByArg> for filter::AgeBy {
+    fn from(value: AgeByArg) -> Self {
+        match value {
+            AgeByArg::Mtime => Self::Mtime,
+            AgeByArg::Content => Self::Content,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OperationTypeArg {
+    Pull,
+    Push,
+    SessionDelete,
+    SessionRename,
+}
+
+impl From<OperationTypeArg> for history::OperationType {
+    fn from(value: OperationTypeArg) -> Self {
+        match value {
+            OperationTypeArg::Pull => Self::Pull,
+            OperationTypeArg::Push => Self::Push,
+            OperationTypeArg::SessionDelete => Self::SessionDelete,
+            OperationTypeArg::SessionRename => Self::SessionRename,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum SearchScopeArg {
+    User,
+    Assistant,
+    Both,
+}
+
+impl From<SearchScopeArg> for handlers::session::SearchScope {
+    fn from(value: SearchScopeArg) -> Self {
+        match value {
+            SearchScopeArg::User => Self::User,
+            SearchScopeArg::Assistant => Self::Assistant,
+            SearchScopeArg::Both => Self::Both,
+        }
+    }
+}
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("Error: {:?}", e);
+        std::process::exit(error::exit_code(&e));
+    }
+}
+
+fn run() -> Result<()> {
+    let cli = Cli::parse();
+    if cli.plain {
+        output::set_plain(true);
+    }
+
     // Initialize logging (rotate log if needed, then set up logger)
     logger::rotate_log_if_needed().ok(); // Ignore errors during log rotation
-    logger::init_logger().ok(); // Ignore errors during logger init
+    logger::init_logger(cli.log_level.map(Into::into)).ok(); // Ignore errors during logger init
 
     log::debug!("ccs started");
 
@@ -658,7 +1463,19 @@ fn main() -> Result<()> {
     // Only check if not running update command itself
     let update_check_handle = std::thread::spawn(check_for_update_silent);
 
-    let cli = Cli::parse();
+    if let Some(lang) = cli.lang {
+        lang::set_lang(lang);
+    }
+    if cli.yes {
+        interactive_conflict::set_assume_yes(true);
+    }
+    if let Some(dir) = &cli.claude_dir {
+        let dir = onboarding::expand_tilde(&dir.to_string_lossy())?;
+        sync::set_claude_dir_override(std::path::absolute(&dir)?);
+    }
+    if let Some(name) = &cli.repo {
+        sync::set_repo_override(name.clone());
+    }
 
     // Check if this is the update command (skip notification for update command)
     let is_update_command = matches!(cli.command, Some(Commands::Update { .. }));
@@ -667,10 +1484,16 @@ fn main() -> Result<()> {
     let is_local_command = matches!(
         cli.command,
         Some(Commands::Session { .. })
+            | Some(Commands::Open { .. })
             | Some(Commands::Config { .. })
             | Some(Commands::Status { .. })
             | Some(Commands::Report { .. })
             | Some(Commands::History { .. })
+            | Some(Commands::Repo { .. })
+            | Some(Commands::ExportAll { .. })
+            | Some(Commands::Import { .. })
+            | Some(Commands::Verify)
+            | Some(Commands::ValidateHistory { .. })
     );
 
     // Print update notification if available (and not running update/local commands)
@@ -680,6 +1503,11 @@ fn main() -> Result<()> {
         }
     }
 
+    // Self-heal a corrupted state.json before deciding whether onboarding is needed
+    if let Err(e) = handlers::onboarding::recover_corrupt_state() {
+        log::warn!("Failed to recover corrupted state: {}", e);
+    }
+
     // Check if initialization is needed (before processing any command)
     let needs_onboarding = !is_initialized()?;
 
@@ -696,6 +1524,11 @@ fn main() -> Result<()> {
                 exclude_attachments: false,
                 prune: false,
                 interactive: false,
+                prefer: PreferArg::Interactive,
+                include_projects: Vec::new(),
+                exclude_projects: Vec::new(),
+                max_file_size: None,
+                since_last: false,
                 verbose: false,
                 quiet: false,
             }
@@ -707,20 +1540,54 @@ fn main() -> Result<()> {
                 exclude_attachments: false,
                 prune: false,
                 interactive: false,
+                prefer: PreferArg::Interactive,
+                include_projects: Vec::new(),
+                exclude_projects: Vec::new(),
+                max_file_size: None,
+                since_last: false,
                 verbose: false,
                 quiet: false,
             }
         }
     };
 
+    let supports_output = matches!(
+        command,
+        Commands::Status { .. }
+            | Commands::Report { .. }
+            | Commands::ConfigSync {
+                action: ConfigSyncAction::List
+            }
+            | Commands::Session {
+                action: Some(SessionAction::List { .. }),
+                ..
+            }
+    );
+    if cli.output.is_some() && !supports_output {
+        anyhow::bail!(
+            "--output is only supported by status, report, session list and config-sync list"
+        );
+    }
+
     // Check if this is a command that should skip auto-onboarding
     let is_init_command = matches!(command, Commands::Init { .. });
     let is_config_command = matches!(command, Commands::Config { .. });
-    let is_session_command = matches!(command, Commands::Session { .. });
+    let is_session_command = matches!(
+        command,
+        Commands::Session { .. } | Commands::Open { .. }
+    );
     let is_setup_command = matches!(command, Commands::Setup { .. });
     let is_update_command = matches!(command, Commands::Update { .. });
     let is_uninstall_command = matches!(command, Commands::Uninstall { .. });
     let is_unlock_delete_command = matches!(command, Commands::UnlockDelete { .. });
+    let is_repo_command = matches!(command, Commands::Repo { .. });
+    let is_doctor_command = matches!(command, Commands::Doctor);
+    let is_validate_command = matches!(command, Commands::ValidateHistory { .. });
+    let is_claude_md_command = matches!(command, Commands::ClaudeMd { .. });
+    let is_export_command = matches!(
+        command,
+        Commands::ExportAll { .. } | Commands::Import { .. }
+    );
 
     // Run onboarding if needed (skip for commands that don't require sync repo)
     if needs_onboarding
@@ -731,6 +1598,11 @@ fn main() -> Result<()> {
         && !is_update_command
         && !is_uninstall_command
         && !is_unlock_delete_command
+        && !is_repo_command
+        && !is_doctor_command
+        && !is_validate_command
+        && !is_claude_md_command
+        && !is_export_command
     {
         log::info!("Running onboarding flow - first time setup detected");
 
@@ -743,6 +1615,11 @@ fn main() -> Result<()> {
         }
 
         if !initialized {
+            // The setup wizard needs a terminal; scripts and hooks get a
+            // typed error (and its exit code) instead
+            if !interactive_conflict::is_interactive() {
+                return Err(error::SyncError::NotInitialized.into());
+            }
             // Fall back to interactive setup wizard
             handle_setup(false)?;
         }
@@ -827,8 +1704,16 @@ fn main() -> Result<()> {
             push_remote,
             branch,
             exclude_attachments,
-            no_config,
+            no_config_sync,
+            config_sync,
             prune,
+            no_delete,
+            strict,
+            subdir,
+            include_projects,
+            exclude_projects,
+            max_file_size,
+            since_last,
             interactive,
             verbose,
             quiet,
@@ -842,16 +1727,36 @@ fn main() -> Result<()> {
                 VerbosityLevel::Normal
             };
 
-            sync::push_history(
-                message.as_deref(),
+            sync::push_history(sync::PushOptions {
+                commit_message: message.as_deref(),
                 push_remote,
-                branch.as_deref(),
+                branch: branch.as_deref(),
                 exclude_attachments,
-                !no_config, // sync_config = !no_config
+                sync_config: if no_config_sync {
+                    Some(false)
+                } else {
+                    config_sync.then_some(true)
+                },
                 interactive,
                 prune,
+                delete_missing: !no_delete,
+                strict,
+                subdir: subdir.as_deref(),
+                include_projects: &include_projects,
+                exclude_projects: &exclude_projects,
+                max_file_size_mb: max_file_size,
+                since_last,
                 verbosity,
-            )?;
+            })?;
+        }
+        Commands::Doctor => {
+            handle_doctor()?;
+        }
+        Commands::Verify => {
+            handle_verify()?;
+        }
+        Commands::ValidateHistory { project } => {
+            handle_validate_history(project.as_deref())?;
         }
         Commands::UnlockDelete {
             minutes,
@@ -864,6 +1769,8 @@ fn main() -> Result<()> {
             fetch_remote,
             branch,
             interactive,
+            subdir,
+            project,
             verbose,
             quiet,
         } => {
@@ -876,7 +1783,15 @@ fn main() -> Result<()> {
                 VerbosityLevel::Normal
             };
 
-            sync::pull_history(fetch_remote, branch.as_deref(), interactive, verbosity)?;
+            sync::pull_history(
+                fetch_remote,
+                branch.as_deref(),
+                interactive,
+                conflict::Prefer::Interactive,
+                subdir.as_deref(),
+                project.as_deref(),
+                verbosity,
+            )?;
         }
         Commands::Sync {
             message,
@@ -884,6 +1799,11 @@ fn main() -> Result<()> {
             exclude_attachments,
             prune,
             interactive,
+            prefer,
+            include_projects,
+            exclude_projects,
+            max_file_size,
+            since_last,
             verbose,
             quiet,
         } => {
@@ -901,44 +1821,99 @@ fn main() -> Result<()> {
                 branch.as_deref(),
                 exclude_attachments,
                 interactive,
+                prefer.into(),
                 prune,
+                &include_projects,
+                &exclude_projects,
+                max_file_size,
+                since_last,
                 verbosity,
             )?;
         }
         Commands::Status {
             show_conflicts,
             show_files,
+            projects,
+            json,
+            porcelain,
         } => {
-            sync::show_status(show_conflicts, show_files)?;
+            let mut out = output::open_output(cli.output.as_deref())?;
+            sync::show_status(
+                show_conflicts,
+                show_files,
+                projects,
+                json,
+                porcelain,
+                &mut out,
+            )?;
         }
         Commands::Config {
             exclude_older_than,
+            age_by,
             include_projects,
             exclude_projects,
             exclude_attachments,
+            redact_on_push,
+            scan_secrets,
+            secret_patterns,
+            follow_symlinks,
             enable_lfs,
             lfs_patterns,
             scm_backend,
             sync_subdirectory,
             use_project_name_only,
+            collision_strategy,
+            device_namespace,
+            ssh_identity,
+            commit_message_template,
+            large_file_warning_mb,
+            stop_push_interval,
+            hook_timeout,
+            push_attempts,
+            cleanup_min_messages,
+            memory_dir_name,
+            time_format,
             show,
             interactive,
             wizard,
+            action,
         } => {
             // Check if ANY flag was provided
-            let has_any_flag = exclude_older_than.is_some()
-                || include_projects.is_some()
-                || exclude_projects.is_some()
-                || exclude_attachments.is_some()
-                || enable_lfs.is_some()
-                || lfs_patterns.is_some()
-                || scm_backend.is_some()
-                || sync_subdirectory.is_some()
-                || show
-                || interactive
-                || wizard;
-
-            if !has_any_flag {
+            let update = filter::ConfigUpdate {
+                exclude_older_than,
+                age_by: age_by.map(Into::into),
+                include_projects,
+                exclude_projects,
+                exclude_attachments,
+                redact_on_push,
+                scan_secrets,
+                secret_patterns,
+                follow_symlinks,
+                enable_lfs,
+                lfs_patterns,
+                scm_backend,
+                sync_subdirectory,
+                use_project_name_only,
+                collision_strategy: collision_strategy.map(Into::into),
+                device_namespace,
+                ssh_identity,
+                commit_message_template,
+                large_file_warning_mb,
+                stop_push_interval,
+                hook_timeout,
+                push_attempts,
+                cleanup_min_messages,
+                memory_dir_name,
+                time_format: time_format.map(Into::into),
+            };
+            let has_any_flag = !update.is_empty() || show || interactive || wizard;
+
+            if let Some(action) = action {
+                match action {
+                    ConfigAction::Export { path } => handle_config_export(&path)?,
+                    ConfigAction::Import { path } => handle_config_import(&path)?,
+                }
+            } else if !has_any_flag {
                 // No args provided - show repo selector menu
                 handle_repo_selector()?;
             } else if interactive {
@@ -948,22 +1923,44 @@ fn main() -> Result<()> {
             } else if show {
                 filter::show_config()?;
             } else {
-                filter::update_config(
-                    exclude_older_than,
-                    include_projects,
-                    exclude_projects,
-                    exclude_attachments,
-                    enable_lfs,
-                    lfs_patterns,
-                    scm_backend,
-                    sync_subdirectory,
-                    use_project_name_only,
-                )?;
+                filter::update_config(update)?;
             }
         }
-        Commands::Report { format, output } => {
-            report::generate_report(&format, output.as_deref())?;
+        Commands::Report {
+            format,
+            json,
+            latest,
+            list,
+        } => {
+            report::handle_report(latest, list, json, &format, cli.output.as_deref())?;
         }
+        Commands::ExportAll {
+            file,
+            format,
+            index,
+        } => {
+            let file = onboarding::expand_tilde(&file.to_string_lossy())?;
+            handle_export_all(&file, format.into(), index)?;
+        }
+        Commands::Import { archive, overwrite } => {
+            let archive = onboarding::expand_tilde(&archive.to_string_lossy())?;
+            handle_import(&archive, overwrite)?;
+        }
+        Commands::Repo { action } => match action {
+            RepoAction::Add {
+                name,
+                location,
+                description,
+            } => {
+                handle_repo_add(&name, &location, description.as_deref())?;
+            }
+            RepoAction::List => {
+                handle_repo_list()?;
+            }
+            RepoAction::Use { name } => {
+                handle_repo_use(&name)?;
+            }
+        },
         Commands::Remote { action } => match action {
             RemoteAction::Show => {
                 sync::show_remote()?;
@@ -977,6 +1974,8 @@ fn main() -> Result<()> {
         },
         Commands::Undo {
             operation,
+            id,
+            preview,
             verbose,
             quiet,
         } => {
@@ -989,24 +1988,66 @@ fn main() -> Result<()> {
                 VerbosityLevel::Normal
             };
 
-            match operation {
-                UndoOperation::Pull { preview } => {
+            match (operation, id) {
+                (Some(UndoOperation::List), _) => {
+                    handle_undo_list()?;
+                }
+                (Some(UndoOperation::Pull { preview }), _) => {
                     handle_undo_pull(preview, verbosity)?;
                 }
-                UndoOperation::Push { preview } => {
+                (Some(UndoOperation::Push { preview }), _) => {
                     handle_undo_push(preview, verbosity)?;
                 }
+                (Some(UndoOperation::Session { preview }), _) => {
+                    handle_undo_session(preview, verbosity)?;
+                }
+                (None, Some(id)) => {
+                    handle_undo(id, preview, verbosity)?;
+                }
+                (None, None) => {
+                    anyhow::bail!(
+                        "Specify what to undo: pull, push, session, or an ID from '{} undo list'",
+                        BINARY_NAME
+                    );
+                }
             }
         }
         Commands::History { action } => match action {
-            HistoryAction::List { limit } => {
-                handle_history_list(limit)?;
+            HistoryAction::List {
+                limit,
+                operation_type,
+                since,
+                until,
+                branch,
+            } => {
+                let filter = HistoryFilter {
+                    operation_type: operation_type.map(Into::into),
+                    since,
+                    until,
+                    branch,
+                };
+                handle_history_list(limit, &filter)?;
             }
-            HistoryAction::Last { operation_type } => {
-                handle_history_last(operation_type.as_deref())?;
+            HistoryAction::Last {
+                operation_type,
+                json,
+            } => {
+                handle_history_last(operation_type.as_deref(), json)?;
             }
-            HistoryAction::Review { limit } => {
-                handle_history_review(limit)?;
+            HistoryAction::Review {
+                limit,
+                operation_type,
+                since,
+                until,
+                branch,
+            } => {
+                let filter = HistoryFilter {
+                    operation_type: operation_type.map(Into::into),
+                    since,
+                    until,
+                    branch,
+                };
+                handle_history_review(limit, &filter)?;
             }
             HistoryAction::Clear => {
                 handle_history_clear()?;
@@ -1023,8 +2064,8 @@ fn main() -> Result<()> {
         }
         Commands::CleanupSnapshots {
             dry_run,
-            max_count,
-            max_age_days,
+            keep,
+            older_than,
             interactive,
             verbose,
             quiet,
@@ -1038,7 +2079,7 @@ fn main() -> Result<()> {
                 VerbosityLevel::Normal
             };
 
-            handle_cleanup_snapshots(dry_run, max_count, max_age_days, interactive, verbosity)?;
+            handle_cleanup_snapshots(dry_run, keep, older_than, interactive, verbosity)?;
         }
         Commands::Hooks { action } => match action {
             HooksAction::Install => {
@@ -1050,6 +2091,14 @@ fn main() -> Result<()> {
             HooksAction::Show => {
                 handle_hooks_show()?;
             }
+            HooksAction::Test { event, source, cwd } => {
+                handle_hook_test(event.into(), source.as_deref(), cwd.as_deref())?;
+            }
+        },
+        Commands::Lfs { action } => match action {
+            LfsAction::Migrate { force } => {
+                handle_lfs_migrate(force)?;
+            }
         },
         Commands::Wrapper { action } => match action {
             WrapperAction::Install { force } => {
@@ -1058,8 +2107,22 @@ fn main() -> Result<()> {
             WrapperAction::Uninstall => {
                 handle_wrapper_uninstall()?;
             }
-            WrapperAction::Show => {
-                handle_wrapper_show()?;
+            WrapperAction::Show { diff } => {
+                handle_wrapper_show(diff)?;
+            }
+        },
+        Commands::Daemon { action } => match action {
+            DaemonAction::Start {
+                interval,
+                foreground,
+            } => {
+                handle_daemon_start(interval, foreground)?;
+            }
+            DaemonAction::Stop => {
+                handle_daemon_stop()?;
+            }
+            DaemonAction::Status => {
+                handle_daemon_status()?;
             }
         },
         Commands::Automate { status, uninstall } => {
@@ -1077,31 +2140,85 @@ fn main() -> Result<()> {
         Commands::HookSessionStart => {
             handle_session_start()?;
         }
-        Commands::HookStop => {
-            handle_stop()?;
+        Commands::HookStop { now, trailing } => {
+            if trailing {
+                handle_stop_trailing()?;
+            } else {
+                handle_stop(now)?;
+            }
         }
+        Commands::ClaudeMd { action } => match action {
+            ClaudeMdAction::Analyze => {
+                handle_claude_md_analyze()?;
+            }
+            ClaudeMdAction::Init { force } => {
+                handle_claude_md_init(force)?;
+            }
+        },
         Commands::ConfigSync { action } => {
             let filter_config = filter::FilterConfig::load()?;
             match action {
-                ConfigSyncAction::Push => {
-                    handle_config_push(&filter_config.config_sync)?;
+                ConfigSyncAction::Push { message } => {
+                    handle_config_push(&filter_config.config_sync, message.as_deref())?;
                 }
                 ConfigSyncAction::List => {
-                    handle_config_list()?;
+                    let mut out = output::open_output(cli.output.as_deref())?;
+                    handle_config_list(&mut out)?;
                 }
-                ConfigSyncAction::Apply { device, with_hooks } => {
-                    handle_config_apply(&device, with_hooks, &filter_config.config_sync)?;
+                ConfigSyncAction::Apply {
+                    device,
+                    with_hooks,
+                    claude_md,
+                    force,
+                    emit_install_script,
+                    run_install,
+                } => {
+                    handle_config_apply(
+                        device.as_deref(),
+                        with_hooks,
+                        claude_md,
+                        force,
+                        emit_install_script.as_deref(),
+                        run_install,
+                        &filter_config.config_sync,
+                    )?;
                 }
                 ConfigSyncAction::Status => {
                     handle_config_status(&filter_config.config_sync)?;
                 }
+                ConfigSyncAction::PruneDevices {
+                    older_than_days,
+                    force,
+                    dry_run,
+                } => {
+                    handle_config_prune_devices(
+                        older_than_days,
+                        force,
+                        dry_run,
+                        &filter_config.config_sync,
+                    )?;
+                }
+                ConfigSyncAction::RenameDevice { old, new } => {
+                    handle_config_rename_device(&old, &new, &filter_config.config_sync)?;
+                }
             }
         }
+        Commands::Open { project } => {
+            handle_open(&project)?;
+        }
         Commands::Session {
             action,
             project,
             source,
+            absolute,
+            width,
         } => {
+            if absolute {
+                handlers::session::set_time_format(filter::TimeFormat::Absolute);
+            }
+            if let Some(width) = width {
+                handlers::session::set_display_width(width);
+            }
             match action {
                 None => {
                     // Interactive mode
@@ -1111,10 +2228,26 @@ fn main() -> Result<()> {
                     project: list_project,
                     show_ids,
                     source,
+                    json,
+                    format,
+                    limit,
+                    offset,
+                    per_project,
                 }) => {
                     // Use subcommand project filter if provided, otherwise use global
                     let filter = list_project.as_deref().or(project.as_deref());
-                    handle_session_list(filter, show_ids, source.into())?;
+                    let mut out = output::open_output(cli.output.as_deref())?;
+                    handle_session_list(
+                        filter,
+                        show_ids,
+                        source.into(),
+                        json,
+                        format.map(Into::into),
+                        limit,
+                        offset,
+                        per_project,
+                        &mut out,
+                    )?;
                 }
                 Some(SessionAction::Search {
                     keyword,
@@ -1122,19 +2255,21 @@ fn main() -> Result<()> {
                     since,
                     context,
                     limit,
+                    scope,
                     user_only,
                     json,
                     source,
                 }) => {
                     let filter = search_project.as_deref().or(project.as_deref());
                     let keywords: Vec<&str> = keyword.iter().map(|s| s.as_str()).collect();
+                    let scope = if user_only { SearchScopeArg::User } else { scope };
                     handle_session_search(
                         &keywords,
                         filter,
                         since.as_deref(),
                         context,
                         limit,
-                        user_only,
+                        scope.into(),
                         json,
                         source.into(),
                     )?;
@@ -1160,18 +2295,50 @@ fn main() -> Result<()> {
                         source.into(),
                     )?;
                 }
-                Some(SessionAction::Rename { session_id, title }) => {
-                    handle_session_rename(&session_id, &title)?;
+                Some(SessionAction::Last {
+                    project: last_project,
+                    print,
+                }) => {
+                    let filter = last_project.as_deref().or(project.as_deref());
+                    handle_session_last(filter, print)?;
                 }
+                Some(SessionAction::Rename {
+                    session_id,
+                    title,
+                    undo,
+                }) => match title {
+                    Some(title) if !undo => handle_session_rename(&session_id, &title)?,
+                    _ => handle_session_rename_undo(&session_id)?,
+                },
                 Some(SessionAction::Delete { session_id, force }) => {
                     handle_session_delete(&session_id, force)?;
                 }
+                Some(SessionAction::Archive {
+                    session_id,
+                    older_than,
+                    gzip,
+                    force,
+                }) => {
+                    handle_session_archive(session_id.as_deref(), older_than, gzip, force)?;
+                }
+                Some(SessionAction::Unarchive { session_id }) => {
+                    handle_session_unarchive(&session_id)?;
+                }
                 Some(SessionAction::Restore { session_id }) => {
                     handle_session_restore(session_id.as_deref())?;
                 }
+                Some(SessionAction::Repair { file, check, fix }) => {
+                    handle_session_repair(file.as_deref(), check, fix)?;
+                }
+                Some(SessionAction::Watch) => {
+                    handle_session_watch()?;
+                }
                 Some(SessionAction::Projects { source }) => {
                     handle_session_projects(source.into())?;
                 }
+                Some(SessionAction::DiskUsage { top, source }) => {
+                    handle_session_disk_usage(top, source.into())?;
+                }
                 Some(SessionAction::Overview {
                     recent,
                     since,
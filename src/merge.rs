@@ -146,6 +146,8 @@ impl<'a> SmartMerger<'a> {
 
         // Combine UUID-based and timestamp-based entries, sorted by timestamp
         merged_entries.extend(non_uuid_merged);
+        let (mut merged_entries, uuid_duplicates) = dedup_entries_by_uuid(merged_entries);
+        self.stats.duplicates_removed += uuid_duplicates;
         merged_entries.sort_by(|a, b| {
             let a_ts = a.timestamp.as_ref();
             let b_ts = b.timestamp.as_ref();
@@ -529,6 +531,29 @@ impl<'a> SmartMerger<'a> {
     }
 }
 
+/// Removes entries whose `uuid` was already seen
+///
+/// The first occurrence of each UUID wins; entries without a UUID are always
+/// kept. The remaining entries keep their order, so a pulled file is written
+/// back as it was apart from the duplicates. Returns the entries and the
+/// number removed.
+pub fn dedup_entries_by_uuid(entries: Vec<ConversationEntry>) -> (Vec<ConversationEntry>, usize) {
+    let mut seen = HashSet::new();
+    let mut removed = 0;
+    let unique: Vec<ConversationEntry> = entries
+        .into_iter()
+        .filter(|entry| match &entry.uuid {
+            Some(uuid) if !seen.insert(uuid.clone()) => {
+                removed += 1;
+                false
+            }
+            _ => true,
+        })
+        .collect();
+
+    (unique, removed)
+}
+
 /// Attempts to perform a smart merge on two conversation sessions
 ///
 /// This is the main entry point for the smart merge feature. It will attempt
@@ -719,4 +744,64 @@ mod tests {
             Some(json!({"text": "Remote version (newer)"}))
         );
     }
+
+    #[test]
+    fn test_dedup_entries_by_uuid_across_files() {
+        use std::io::Write;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let write_jsonl = |name: &str, entries: &[ConversationEntry]| {
+            let path = dir.path().join(name);
+            let mut file = std::fs::File::create(&path).unwrap();
+            for entry in entries {
+                writeln!(file, "{}", serde_json::to_string(entry).unwrap()).unwrap();
+            }
+            ConversationSession::from_file(&path).unwrap()
+        };
+
+        // Main file: 1 -> 2 -> 3; agent subprocess file repeats 2 and 3 and adds 4
+        let main = write_jsonl(
+            "main.jsonl",
+            &[
+                create_test_entry("1", None, "2025-01-01T00:00:00Z"),
+                create_test_entry("2", Some("1"), "2025-01-01T00:01:00Z"),
+                create_test_entry("3", Some("2"), "2025-01-01T00:02:00Z"),
+            ],
+        );
+        let agent = write_jsonl(
+            "agent.jsonl",
+            &[
+                create_test_entry("3", Some("2"), "2025-01-01T00:02:00Z"),
+                create_test_entry("2", Some("1"), "2025-01-01T00:01:00Z"),
+                create_test_entry("4", Some("3"), "2025-01-01T00:03:00Z"),
+            ],
+        );
+
+        // Entries keep their order, including a summary line without a
+        // timestamp, which must not move to the front
+        let summary = ConversationEntry {
+            uuid: None,
+            timestamp: None,
+            ..create_test_entry("s", None, "2025-01-01T00:00:00Z")
+        };
+        let mut combined = agent.entries.clone();
+        combined.push(summary);
+        combined.extend(main.entries.clone());
+        let (deduped, removed) = dedup_entries_by_uuid(combined);
+        let uuids: Vec<_> = deduped
+            .iter()
+            .map(|e| e.uuid.as_deref().unwrap_or("summary"))
+            .collect();
+        assert_eq!(removed, 2);
+        assert_eq!(uuids, vec!["3", "2", "4", "summary", "1"]);
+
+        // The merge path yields the same de-duplicated, time-ordered entries
+        let result = merge_conversations(&main, &agent).unwrap();
+        let merged: Vec<_> = result
+            .merged_entries
+            .iter()
+            .filter_map(|e| e.uuid.clone())
+            .collect();
+        assert_eq!(merged, vec!["1", "2", "3", "4"]);
+    }
 }
@@ -0,0 +1,105 @@
+//! Output styling and destination helpers.
+//!
+//! Commands that support the global `--output <file>` option write through a
+//! `dyn Write` obtained from [`open_output`] instead of printing directly, so
+//! the same code renders to the terminal or to a file.
+//!
+//! Status symbols (✓, ⚠, → ...) go through [`icon`] so that `--plain` or the
+//! `NO_COLOR` environment variable can swap them for ASCII markers.
+
+use anyhow::{Context, Result};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Set by the global `--plain` flag
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+/// Enable plain output: no colors and ASCII instead of symbols/emoji
+pub fn set_plain(plain: bool) {
+    PLAIN.store(plain, Ordering::Relaxed);
+    if plain {
+        colored::control::set_override(false);
+    }
+}
+
+/// Whether plain output is active (`--plain` or a non-empty `NO_COLOR`)
+///
+/// Colors themselves are disabled by `colored`, which honors `NO_COLOR`
+/// on its own; this only decides whether symbols are replaced.
+pub fn is_plain() -> bool {
+    PLAIN.load(Ordering::Relaxed) || std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty())
+}
+
+/// ASCII replacement for a status symbol in plain mode
+fn plain_icon(symbol: &'static str) -> &'static str {
+    match symbol {
+        "✓" => "[ok]",
+        "✗" => "[x]",
+        "⚠" | "⚠️" => "[!]",
+        "ℹ" => "[i]",
+        "→" => "->",
+        "←" => "<-",
+        "•" => "-",
+        "↻" => "[~]",
+        "🔓" => "[unlocked]",
+        "✎" => "[*]",
+        other => other,
+    }
+}
+
+/// Status symbol to print, replaced with an ASCII marker in plain mode
+pub fn icon(symbol: &'static str) -> &'static str {
+    if is_plain() {
+        plain_icon(symbol)
+    } else {
+        symbol
+    }
+}
+
+/// Open the output destination: the given file, or stdout when `None`.
+///
+/// Writing to a file also disables `colored` styling so the file contains
+/// plain text without ANSI escape codes. (Colors are already disabled
+/// automatically when stdout is not a terminal.)
+pub fn open_output(path: Option<&Path>) -> Result<Box<dyn Write>> {
+    match path {
+        Some(path) => {
+            colored::control::set_override(false);
+            let file = File::create(path)
+                .with_context(|| format!("Failed to create output file: {}", path.display()))?;
+            Ok(Box::new(BufWriter::new(file)))
+        }
+        None => Ok(Box::new(io::stdout())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use colored::Colorize;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_plain_icon() {
+        assert_eq!(plain_icon("✓"), "[ok]");
+        assert_eq!(plain_icon("⚠️"), "[!]");
+        assert_eq!(plain_icon("→"), "->");
+        assert_eq!(plain_icon("!"), "!");
+    }
+
+    #[test]
+    fn test_open_output_writes_plain_text_to_file() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("out.txt");
+
+        {
+            let mut out = open_output(Some(&path)).unwrap();
+            writeln!(out, "{}", "status".green().bold()).unwrap();
+        }
+
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "status\n");
+        colored::control::unset_override();
+    }
+}
@@ -86,6 +86,46 @@ pub struct ConversationEntry {
     pub extra: Value,
 }
 
+/// A line of a session file that does not parse as a [`ConversationEntry`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidLine {
+    /// 1-based line number
+    pub line: usize,
+    /// Why the line failed to parse
+    pub reason: String,
+    /// Entries that [`ConversationSession::from_file`] still recovers from the line
+    pub recovered: usize,
+}
+
+/// Line-by-line check of a session file, see [`ConversationSession::validate_file`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FileValidation {
+    /// Non-empty lines checked
+    pub lines: usize,
+    /// Lines that failed to parse, in file order
+    pub invalid: Vec<InvalidLine>,
+}
+
+impl FileValidation {
+    /// Whether [`ConversationSession::from_file`] rejects the whole file
+    /// (because a line is not valid UTF-8) instead of skipping bad lines
+    pub fn unreadable(&self) -> bool {
+        self.invalid
+            .iter()
+            .any(|l| l.reason.starts_with(INVALID_UTF8))
+    }
+
+    /// Whether no line of the file yields an entry, so it syncs as an empty
+    /// session or not at all
+    pub fn no_entries(&self) -> bool {
+        let recovered: usize = self.invalid.iter().map(|l| l.recovered).sum();
+        self.invalid.len() == self.lines && recovered == 0
+    }
+}
+
+/// Reason given for lines that are not valid UTF-8
+const INVALID_UTF8: &str = "invalid UTF-8";
+
 /// Represents a complete conversation session
 #[derive(Debug, Clone)]
 pub struct ConversationSession {
@@ -213,6 +253,54 @@ impl ConversationSession {
         })
     }
 
+    /// Parse every line of a JSONL file and report the ones that fail,
+    /// without stopping at the first error
+    ///
+    /// [`Self::from_file`] skips malformed lines with only a debug log, and
+    /// fails for the whole file when a line is not valid UTF-8; this reports
+    /// both with line numbers.
+    pub fn validate_file<P: AsRef<Path>>(path: P) -> Result<FileValidation> {
+        let path = path.as_ref();
+        let data =
+            std::fs::read(path).with_context(|| format!("Failed to read {}", path.display()))?;
+
+        let mut validation = FileValidation::default();
+        for (line_num, bytes) in data.split(|&b| b == b'\n').enumerate() {
+            let line = match std::str::from_utf8(bytes) {
+                Ok(line) => line,
+                Err(e) => {
+                    validation.lines += 1;
+                    validation.invalid.push(InvalidLine {
+                        line: line_num + 1,
+                        reason: format!("{} ({})", INVALID_UTF8, e),
+                        recovered: 0,
+                    });
+                    continue;
+                }
+            };
+            if line.trim().is_empty() {
+                continue;
+            }
+            validation.lines += 1;
+
+            if let Err(e) = serde_json::from_str::<ConversationEntry>(line) {
+                // Each line is parsed on its own, so serde's "line 1" is noise
+                let message = e.to_string();
+                let location = format!(" at line {} column {}", e.line(), e.column());
+                let reason = match message.strip_suffix(&location) {
+                    Some(message) => format!("{} (column {})", message, e.column()),
+                    None => message,
+                };
+                validation.invalid.push(InvalidLine {
+                    line: line_num + 1,
+                    reason,
+                    recovered: Self::try_recover_entries(line).len(),
+                });
+            }
+        }
+        Ok(validation)
+    }
+
     /// Try to recover valid JSON entries from a corrupted line.
     ///
     /// Handles the common corruption pattern where Claude Code truncates a
@@ -261,6 +349,20 @@ impl ConversationSession {
         Ok(())
     }
 
+    /// Rough token count of the conversation: the text of all user and
+    /// assistant messages (tool inputs and outputs included, since they also
+    /// fill the context) divided by [`CHARS_PER_TOKEN`]
+    pub fn estimated_tokens(&self) -> usize {
+        let chars: usize = self
+            .entries
+            .iter()
+            .filter(|e| e.entry_type == "user" || e.entry_type == "assistant")
+            .filter_map(|e| e.message.as_ref()?.get("content"))
+            .map(content_chars)
+            .sum();
+        estimate_tokens(chars)
+    }
+
     /// Get the latest timestamp from the conversation
     pub fn latest_timestamp(&self) -> Option<String> {
         self.entries
@@ -269,6 +371,41 @@ impl ConversationSession {
             .max()
     }
 
+    /// Copy of the session with inline attachments (image and document
+    /// content blocks, including those nested in tool results) removed.
+    /// Messages left without content get a placeholder text block so they
+    /// stay valid. Used by `redact_on_push`.
+    pub fn without_attachments(&self) -> Self {
+        let mut redacted = self.clone();
+        for entry in &mut redacted.entries {
+            if let Some(content) = entry
+                .message
+                .as_mut()
+                .and_then(|m| m.get_mut("content"))
+                .and_then(|c| c.as_array_mut())
+            {
+                if strip_attachment_blocks(content) && content.is_empty() {
+                    content.push(serde_json::json!({
+                        "type": "text",
+                        "text": ATTACHMENT_PLACEHOLDER,
+                    }));
+                }
+            }
+            // Tool results for images/PDFs also keep the file inline
+            if let Some(extra) = entry.extra.as_object_mut() {
+                let is_attachment = extra
+                    .get("toolUseResult")
+                    .and_then(|r| r.get("type"))
+                    .and_then(|t| t.as_str())
+                    .is_some_and(|t| ATTACHMENT_RESULT_TYPES.contains(&t));
+                if is_attachment {
+                    extra.remove("toolUseResult");
+                }
+            }
+        }
+        redacted
+    }
+
     /// Get the number of messages (user + assistant) in the conversation
     pub fn message_count(&self) -> usize {
         self.entries
@@ -590,6 +727,65 @@ impl ConversationSession {
         None
     }
 
+    /// Extract the full transcript of a message, tool calls included.
+    /// Text is kept verbatim; `tool_use` blocks become the tool name with its
+    /// input abbreviated to one line, and `tool_result` blocks the abbreviated
+    /// output. Used by `session show --full`.
+    pub fn extract_transcript_content(message: &Value, is_user: bool) -> Option<String> {
+        let content = message.get("content")?;
+
+        if let Some(s) = content.as_str() {
+            if is_user && Self::is_system_content(s) {
+                return None;
+            }
+            return Some(s.to_string());
+        }
+
+        let parts: Vec<String> = content
+            .as_array()?
+            .iter()
+            .filter_map(|block| match block.get("type").and_then(|t| t.as_str()) {
+                Some("text") => {
+                    let text = block.get("text").and_then(|t| t.as_str()).unwrap_or("");
+                    let skip = text.trim().is_empty() || (is_user && Self::is_system_content(text));
+                    (!skip).then(|| text.to_string())
+                }
+                Some("tool_use") => {
+                    let name = block
+                        .get("name")
+                        .and_then(|n| n.as_str())
+                        .unwrap_or("unknown");
+                    let input = block
+                        .get("input")
+                        .map(|i| i.to_string())
+                        .unwrap_or_default();
+                    Some(format!(
+                        "[Tool: {}] {}",
+                        name,
+                        abbreviate(&input, TRANSCRIPT_INPUT_CHARS)
+                    ))
+                }
+                Some("tool_result") => {
+                    let label = if block.get("is_error").and_then(|e| e.as_bool()) == Some(true) {
+                        "[Error]"
+                    } else {
+                        "[Result]"
+                    };
+                    let output = tool_result_text(block);
+                    Some(format!(
+                        "{} {}",
+                        label,
+                        abbreviate(&output, TRANSCRIPT_RESULT_CHARS)
+                    ))
+                }
+                Some("image") => Some("[Image]".to_string()),
+                _ => None,
+            })
+            .collect();
+
+        (!parts.is_empty()).then(|| parts.join("\n"))
+    }
+
     /// Calculate a simple hash of the conversation content
     pub fn content_hash(&self) -> String {
         use std::collections::hash_map::DefaultHasher;
@@ -605,6 +801,97 @@ impl ConversationSession {
     }
 }
 
+/// Characters per token assumed by the token estimate (a common heuristic
+/// for English text; CJK text has fewer characters per token)
+pub const CHARS_PER_TOKEN: usize = 4;
+
+/// Estimate the token count of `chars` characters of text
+pub fn estimate_tokens(chars: usize) -> usize {
+    chars.div_ceil(CHARS_PER_TOKEN)
+}
+
+/// Characters of text in a message `content` value: plain text, text
+/// blocks, tool inputs and tool results
+fn content_chars(content: &Value) -> usize {
+    match content {
+        Value::String(s) => s.chars().count(),
+        Value::Array(blocks) => blocks
+            .iter()
+            .map(|block| match block.get("type").and_then(|t| t.as_str()) {
+                Some("text") => block
+                    .get("text")
+                    .and_then(|t| t.as_str())
+                    .map_or(0, |t| t.chars().count()),
+                Some("tool_use") => block
+                    .get("input")
+                    .map_or(0, |i| i.to_string().chars().count()),
+                Some("tool_result") => tool_result_text(block).chars().count(),
+                _ => 0,
+            })
+            .sum(),
+        _ => 0,
+    }
+}
+
+/// Content block types that carry inline file data
+const ATTACHMENT_BLOCK_TYPES: &[&str] = &["image", "document"];
+
+/// `toolUseResult` types that embed the file that was read
+const ATTACHMENT_RESULT_TYPES: &[&str] = &["image", "pdf"];
+
+/// Text left in a message whose only content was attachments
+const ATTACHMENT_PLACEHOLDER: &str = "[Attachment removed]";
+
+/// Remove attachment blocks from a content array, recursing into
+/// `tool_result` contents. Returns whether anything was removed.
+fn strip_attachment_blocks(blocks: &mut Vec<Value>) -> bool {
+    let before = blocks.len();
+    blocks.retain(|block| {
+        !block
+            .get("type")
+            .and_then(|t| t.as_str())
+            .is_some_and(|t| ATTACHMENT_BLOCK_TYPES.contains(&t))
+    });
+    let mut removed = blocks.len() != before;
+    for block in blocks.iter_mut() {
+        if let Some(nested) = block.get_mut("content").and_then(|c| c.as_array_mut()) {
+            removed |= strip_attachment_blocks(nested);
+        }
+    }
+    removed
+}
+
+/// Maximum characters of tool input shown in a transcript
+const TRANSCRIPT_INPUT_CHARS: usize = 120;
+
+/// Maximum characters of tool output shown in a transcript
+const TRANSCRIPT_RESULT_CHARS: usize = 200;
+
+/// Text of a tool_result block, whose content is either a string or an
+/// array of text blocks
+fn tool_result_text(block: &Value) -> String {
+    match block.get("content") {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Array(items)) => items
+            .iter()
+            .filter_map(|item| item.get("text").and_then(|t| t.as_str()))
+            .collect::<Vec<_>>()
+            .join(" "),
+        _ => String::new(),
+    }
+}
+
+/// Collapse whitespace to single spaces and truncate to `max_chars`
+fn abbreviate(text: &str, max_chars: usize) -> String {
+    let flat = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if flat.chars().count() > max_chars {
+        let truncated: String = flat.chars().take(max_chars).collect();
+        format!("{}...", truncated.trim_end())
+    } else {
+        flat
+    }
+}
+
 /// Extract file basename hint from a tool_use block's input.file_path.
 fn extract_file_hint(block: &Value) -> Option<&str> {
     block
@@ -1227,6 +1514,45 @@ mod tests {
         assert_eq!(session.entries.len(), 0);
     }
 
+    #[test]
+    fn test_validate_file_reports_each_bad_line() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("s1.jsonl");
+        let mut data = concat!(
+            r#"{"type":"user","sessionId":"s1"}"#,
+            "\n",
+            "NOT JSON\n",
+            "\n",
+            r#"{"type":"user","sessionId":"s1","message":{"#,
+            r#"{"parentUuid":"a","type":"assistant","sessionId":"s1"}"#,
+            "\n",
+            r#"{"sessionId":"s1"}"#,
+            "\n",
+        )
+        .as_bytes()
+        .to_vec();
+        data.extend_from_slice(b"\xff\xfe\n");
+        std::fs::write(&file_path, data).unwrap();
+
+        let validation = ConversationSession::validate_file(&file_path).unwrap();
+        assert_eq!(validation.lines, 5);
+        let lines: Vec<(usize, usize)> = validation
+            .invalid
+            .iter()
+            .map(|l| (l.line, l.recovered))
+            .collect();
+        assert_eq!(lines, vec![(2, 0), (4, 1), (5, 0), (6, 0)]);
+        assert_eq!(
+            validation.invalid[2].reason,
+            "missing field `type` (column 18)"
+        );
+        assert!(validation.unreadable());
+        assert!(!validation.no_entries());
+        assert!(ConversationSession::from_file(&file_path).is_err());
+    }
+
     #[test]
     fn test_from_file_truncated_json_line() {
         use std::fs::File;
@@ -1280,6 +1606,89 @@ mod tests {
     // Tests for extract_display_content_full (no truncation)
     // =========================================================================
 
+    #[test]
+    fn test_without_attachments_strips_inline_files() {
+        use tempfile::TempDir;
+
+        let temp_dir = TempDir::new().unwrap();
+        let path = temp_dir.path().join("s.jsonl");
+        let lines = [
+            r#"{"type":"user","sessionId":"s","message":{"role":"user","content":[{"type":"text","text":"see"},{"type":"image","source":{"data":"AAAA"}}]}}"#,
+            r#"{"type":"user","sessionId":"s","message":{"role":"user","content":[{"type":"document","source":{"data":"BBBB"}}]}}"#,
+            r#"{"type":"user","sessionId":"s","toolUseResult":{"type":"image","file":{"base64":"CCCC"}},"message":{"role":"user","content":[{"type":"tool_result","tool_use_id":"t","content":[{"type":"image","source":{"data":"DDDD"}},{"type":"text","text":"ok"}]}]}}"#,
+        ];
+        std::fs::write(&path, lines.join("\n")).unwrap();
+        let session = ConversationSession::from_file(&path).unwrap();
+
+        let redacted = session.without_attachments();
+        let out = temp_dir.path().join("out.jsonl");
+        redacted.write_to_file(&out).unwrap();
+        let written = std::fs::read_to_string(&out).unwrap();
+        for data in ["AAAA", "BBBB", "CCCC", "DDDD"] {
+            assert!(!written.contains(data), "{data} should be removed");
+        }
+        assert!(written.contains("\"see\""));
+        assert!(written.contains(ATTACHMENT_PLACEHOLDER));
+        assert!(written.contains("\"ok\""));
+
+        // The original is untouched
+        assert_eq!(
+            session.content_hash(),
+            ConversationSession::from_file(&path)
+                .unwrap()
+                .content_hash()
+        );
+        assert_ne!(session.content_hash(), redacted.content_hash());
+    }
+
+    #[test]
+    fn test_estimated_tokens_counts_text_and_tools() {
+        let content = serde_json::json!([
+            {"type": "text", "text": "abcdefgh"},
+            {"type": "tool_use", "name": "Bash", "input": {"c": "ls"}},
+            {"type": "tool_result", "content": "1234"},
+            {"type": "image", "source": {}}
+        ]);
+        // 8 text + 10 input JSON + 4 result characters
+        assert_eq!(content_chars(&content), 22);
+        assert_eq!(content_chars(&serde_json::json!("hello")), 5);
+        assert_eq!(estimate_tokens(20), 5);
+        assert_eq!(estimate_tokens(21), 6);
+        assert_eq!(estimate_tokens(0), 0);
+    }
+
+    #[test]
+    fn test_extract_transcript_content_includes_tools() {
+        let call = serde_json::json!({
+            "role": "assistant",
+            "content": [
+                {"type": "text", "text": "Let me check."},
+                {"type": "tool_use", "name": "Bash", "input": {"command": "ls -la"}}
+            ]
+        });
+        let result = ConversationSession::extract_transcript_content(&call, false).unwrap();
+        assert_eq!(
+            result,
+            "Let me check.\n[Tool: Bash] {\"command\":\"ls -la\"}"
+        );
+
+        let output = serde_json::json!({
+            "role": "user",
+            "content": [{
+                "type": "tool_result",
+                "is_error": true,
+                "content": [{"type": "text", "text": format!("no such file\n{}", "x".repeat(300))}]
+            }]
+        });
+        let result = ConversationSession::extract_transcript_content(&output, true).unwrap();
+        assert!(result.starts_with("[Error] no such file xxx"));
+        assert!(result.ends_with("..."));
+        assert_eq!(
+            result.chars().count(),
+            "[Error] ".len() + TRANSCRIPT_RESULT_CHARS + 3
+        );
+    }
+
     #[test]
     fn test_extract_display_content_full_no_truncation() {
         let long_text = "a".repeat(1000);
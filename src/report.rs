@@ -2,10 +2,18 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 use std::fs;
+use std::io::Write;
 use std::path::Path;
 
 use crate::conflict::{Conflict, ConflictResolution};
 
+/// Directory (inside the config directory) keeping every report that had
+/// conflicts, one file per sync
+const REPORT_ARCHIVE_DIR: &str = "conflict-reports";
+
+/// Number of archived reports kept; older ones are deleted
+const MAX_ARCHIVED_REPORTS: usize = 50;
+
 /// Report of sync conflicts encountered during Claude Code synchronization
 ///
 /// This structure contains a summary of all conflicts detected when syncing
@@ -184,42 +192,51 @@ impl ConflictReport {
 
     /// Print a colored console summary
     pub fn print_summary(&self) {
-        println!("\n{}", "=== Conflict Report ===".bold().cyan());
-        println!("{}: {}", "Timestamp".bold(), self.timestamp);
-        println!(
+        let _ = self.write_summary(&mut std::io::stdout());
+    }
+
+    /// Write the summary shown by [`Self::print_summary`] to `out`
+    pub fn write_summary(&self, out: &mut dyn Write) -> std::io::Result<()> {
+        writeln!(out, "\n{}", "=== Conflict Report ===".bold().cyan())?;
+        writeln!(out, "{}: {}", "Timestamp".bold(), self.timestamp)?;
+        writeln!(
+            out,
             "{}: {}",
             "Total Conflicts".bold(),
             self.total_conflicts.to_string().yellow()
-        );
+        )?;
 
         if self.conflicts.is_empty() {
-            println!("\n{}", "No conflicts detected!".green());
-            return;
+            writeln!(out, "\n{}", "No conflicts detected!".green())?;
+            return Ok(());
         }
 
-        println!("\n{}", "Conflicts:".bold());
+        writeln!(out, "\n{}", "Conflicts:".bold())?;
         for (i, conflict) in self.conflicts.iter().enumerate() {
-            println!(
+            writeln!(
+                out,
                 "\n{}. {}: {}",
                 (i + 1).to_string().cyan(),
                 "Session".bold(),
                 conflict.session_id.yellow()
-            );
-            println!(
+            )?;
+            writeln!(
+                out,
                 "   {}: {}",
                 "Resolution".bold(),
                 conflict.resolution.green()
-            );
-            println!("   {}", "Local:".bold());
-            println!("     File: {}", conflict.local_file);
-            println!("     Messages: {}", conflict.local_messages);
-            println!("     Updated: {}", conflict.local_timestamp);
-            println!("   {}", "Remote:".bold());
-            println!("     File: {}", conflict.remote_file);
-            println!("     Messages: {}", conflict.remote_messages);
-            println!("     Updated: {}", conflict.remote_timestamp);
+            )?;
+            writeln!(out, "   {}", "Local:".bold())?;
+            writeln!(out, "     File: {}", conflict.local_file)?;
+            writeln!(out, "     Messages: {}", conflict.local_messages)?;
+            writeln!(out, "     Updated: {}", conflict.local_timestamp)?;
+            writeln!(out, "   {}", "Remote:".bold())?;
+            writeln!(out, "     File: {}", conflict.remote_file)?;
+            writeln!(out, "     Messages: {}", conflict.remote_messages)?;
+            writeln!(out, "     Updated: {}", conflict.remote_timestamp)?;
         }
-        println!();
+        writeln!(out)?;
+        Ok(())
     }
 
     /// Save report to file
@@ -243,12 +260,149 @@ impl ConflictReport {
     }
 }
 
+/// One archived report, as listed by `ccs report --list`
+#[derive(Debug, Serialize, PartialEq, Eq)]
+pub struct ReportEntry {
+    /// File name inside the archive directory
+    pub file: String,
+    pub timestamp: String,
+    pub total_conflicts: usize,
+}
+
+/// Handle `ccs report`
+///
+/// Prints the report of the last sync in `format` (`--json` is short for
+/// `--format json`), with `latest` the newest archived report that had
+/// conflicts, or with `list` the archived reports, newest first.
+pub fn handle_report(
+    latest: bool,
+    list: bool,
+    json: bool,
+    format: &str,
+    output: Option<&Path>,
+) -> Result<()> {
+    let archive_dir = get_sync_state_dir()?.join(REPORT_ARCHIVE_DIR);
+    if list {
+        let entries = list_archived_reports(&archive_dir)?;
+        let mut out = crate::output::open_output(output)?;
+        return write_report_list(&entries, json, &mut *out);
+    }
+
+    let format = if json { "json" } else { format };
+    if latest {
+        // Without an archive no sync had conflicts, which is also what the
+        // report of the last sync says
+        if let Some(report) = load_newest_archived_report(&archive_dir)? {
+            return output_report(&report, format, output);
+        }
+    }
+    generate_report(format, output)
+}
+
+/// Write the archived report list as a table, or as JSON for tooling
+fn write_report_list(entries: &[ReportEntry], json: bool, out: &mut dyn Write) -> Result<()> {
+    if json {
+        writeln!(out, "{}", serde_json::to_string_pretty(entries)?)?;
+        return Ok(());
+    }
+    if entries.is_empty() {
+        writeln!(out, "{}", "No conflict reports recorded yet".green())?;
+        return Ok(());
+    }
+
+    writeln!(out, "{}", "=== Conflict Reports ===".bold().cyan())?;
+    for entry in entries {
+        let time = chrono::DateTime::parse_from_rfc3339(&entry.timestamp)
+            .map(|t| {
+                t.with_timezone(&chrono::Local)
+                    .format("%Y-%m-%d %H:%M:%S")
+                    .to_string()
+            })
+            .unwrap_or_else(|_| entry.timestamp.clone());
+        writeln!(
+            out,
+            "  {}  {:>3} conflicts  {}",
+            time,
+            entry.total_conflicts.to_string().yellow(),
+            entry.file.dimmed()
+        )?;
+    }
+    Ok(())
+}
+
+/// Archived reports in `dir`, newest first; unreadable files are skipped
+fn list_archived_reports(dir: &Path) -> Result<Vec<ReportEntry>> {
+    let Ok(read_dir) = fs::read_dir(dir) else {
+        return Ok(Vec::new());
+    };
+
+    let mut entries: Vec<ReportEntry> = read_dir
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let file = e.file_name().into_string().ok()?;
+            let content = fs::read_to_string(e.path()).ok()?;
+            let report: ConflictReport = serde_json::from_str(&content).ok()?;
+            Some(ReportEntry {
+                file,
+                timestamp: report.timestamp,
+                total_conflicts: report.total_conflicts,
+            })
+        })
+        .collect();
+    // File names start with the UTC time, so they sort chronologically
+    entries.sort_by(|a, b| b.file.cmp(&a.file));
+    Ok(entries)
+}
+
+/// The newest archived report in `dir`, if any
+fn load_newest_archived_report(dir: &Path) -> Result<Option<ConflictReport>> {
+    let Some(newest) = list_archived_reports(dir)?.into_iter().next() else {
+        return Ok(None);
+    };
+    let path = dir.join(&newest.file);
+    let content = fs::read_to_string(&path)
+        .with_context(|| format!("Failed to read report from {}", path.display()))?;
+    let report = serde_json::from_str(&content).context("Failed to parse conflict report")?;
+    Ok(Some(report))
+}
+
+/// Keep a copy of `report` in `dir`, deleting the oldest copies beyond
+/// [`MAX_ARCHIVED_REPORTS`]
+fn archive_report(dir: &Path, report: &ConflictReport) -> Result<()> {
+    fs::create_dir_all(dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+
+    let stamp = chrono::DateTime::parse_from_rfc3339(&report.timestamp)
+        .map(|t| t.with_timezone(&chrono::Utc))
+        .unwrap_or_else(|_| chrono::Utc::now())
+        .format("%Y%m%dT%H%M%S%.3fZ");
+    let path = dir.join(format!("{stamp}.json"));
+    fs::write(&path, report.to_json()?)
+        .with_context(|| format!("Failed to write report to {}", path.display()))?;
+
+    let mut files: Vec<_> = fs::read_dir(dir)?
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    if files.len() > MAX_ARCHIVED_REPORTS {
+        files.sort();
+        for old in &files[..files.len() - MAX_ARCHIVED_REPORTS] {
+            let _ = fs::remove_file(old);
+        }
+    }
+    Ok(())
+}
+
 /// Generate and output a conflict report
 pub fn generate_report(format: &str, output: Option<&Path>) -> Result<()> {
     // Load the latest conflict report from the sync state
     // For now, we'll create a placeholder implementation
     let report = load_latest_report()?;
+    output_report(&report, format, output)
+}
 
+/// Print `report` in `format`, or save it to `output`
+fn output_report(report: &ConflictReport, format: &str, output: Option<&Path>) -> Result<()> {
     if let Some(output_path) = output {
         report.save(output_path, format)?;
     } else {
@@ -286,6 +440,8 @@ pub fn load_latest_report() -> Result<ConflictReport> {
 }
 
 /// Save a conflict report to the sync state
+///
+/// Reports with conflicts are also archived for `ccs report --list`.
 pub fn save_conflict_report(report: &ConflictReport) -> Result<()> {
     let sync_state_path = get_sync_state_dir()?;
     fs::create_dir_all(&sync_state_path).context("Failed to create sync state directory")?;
@@ -296,6 +452,12 @@ pub fn save_conflict_report(report: &ConflictReport) -> Result<()> {
     fs::write(&report_path, content)
         .with_context(|| format!("Failed to write report to {}", report_path.display()))?;
 
+    if report.total_conflicts > 0 {
+        if let Err(e) = archive_report(&sync_state_path.join(REPORT_ARCHIVE_DIR), report) {
+            log::warn!("Failed to archive conflict report: {}", e);
+        }
+    }
+
     Ok(())
 }
 
@@ -339,4 +501,48 @@ mod tests {
         let json = report.to_json().unwrap();
         assert!(json.contains("total_conflicts"));
     }
+
+    #[test]
+    fn test_archive_lists_newest_first_and_prunes() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path().join(REPORT_ARCHIVE_DIR);
+        for i in 0..MAX_ARCHIVED_REPORTS + 2 {
+            let report = ConflictReport {
+                timestamp: format!("2025-01-01T00:{:02}:00Z", i),
+                total_conflicts: i,
+                conflicts: Vec::new(),
+            };
+            archive_report(&dir, &report).unwrap();
+        }
+
+        let entries = list_archived_reports(&dir).unwrap();
+        assert_eq!(entries.len(), MAX_ARCHIVED_REPORTS);
+        assert_eq!(entries[0].total_conflicts, MAX_ARCHIVED_REPORTS + 1);
+        assert_eq!(entries[0].file, "20250101T005100.000Z.json");
+        assert_eq!(entries.last().unwrap().total_conflicts, 2);
+
+        assert!(list_archived_reports(&temp.path().join("missing"))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_load_newest_archived_report() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let dir = temp.path().join(REPORT_ARCHIVE_DIR);
+        assert!(load_newest_archived_report(&dir).unwrap().is_none());
+
+        for (minute, conflicts) in [(5, 3), (7, 1)] {
+            let report = ConflictReport {
+                timestamp: format!("2025-01-01T00:{:02}:00Z", minute),
+                total_conflicts: conflicts,
+                conflicts: Vec::new(),
+            };
+            archive_report(&dir, &report).unwrap();
+        }
+
+        let newest = load_newest_archived_report(&dir).unwrap().unwrap();
+        assert_eq!(newest.timestamp, "2025-01-01T00:07:00Z");
+        assert_eq!(newest.total_conflicts, 1);
+    }
 }
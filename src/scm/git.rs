@@ -5,6 +5,7 @@ use std::path::{Path, PathBuf};
 use std::process::{Command, Output};
 
 use super::{PushError, RebaseOutcome, Scm};
+use crate::error::SyncError;
 use crate::BINARY_NAME;
 
 fn classify_push_stderr(stderr: &str) -> Option<PushError> {
@@ -20,6 +21,36 @@ fn classify_push_stderr(stderr: &str) -> Option<PushError> {
     }
 }
 
+/// Whether git failed because the remote host could not be reached at all
+fn is_unreachable_stderr(stderr: &str) -> bool {
+    let stderr = stderr.to_ascii_lowercase();
+    [
+        "could not resolve host",
+        "could not resolve hostname",
+        "connection refused",
+        "connection timed out",
+        "operation timed out",
+        "network is unreachable",
+        "no route to host",
+        "failed to connect to",
+    ]
+    .iter()
+    .any(|pattern| stderr.contains(pattern))
+}
+
+/// [`SyncError::RemoteUnreachable`] when `stderr` says so, otherwise `fallback`
+fn remote_error(remote: &str, stderr: &str, fallback: anyhow::Error) -> anyhow::Error {
+    if is_unreachable_stderr(stderr) {
+        SyncError::RemoteUnreachable {
+            remote: remote.to_string(),
+            detail: stderr.trim().to_string(),
+        }
+        .into()
+    } else {
+        fallback
+    }
+}
+
 fn is_git_repo_path(path: &Path) -> bool {
     let git_path = path.join(".git");
     git_path.is_dir() || git_path.is_file()
@@ -55,7 +86,7 @@ fn classify_rebase_failure_text(text: &str) -> Option<RebaseOutcome> {
 }
 
 fn build_push_failure(remote: &str, stderr: &str) -> anyhow::Error {
-    anyhow!(
+    let failure = anyhow!(
         "Failed to push to remote '{}': {}\n\n\
         Possible causes:\n\
         1. Authentication failed - ensure credentials are configured\n\
@@ -66,7 +97,8 @@ fn build_push_failure(remote: &str, stderr: &str) -> anyhow::Error {
         For SSH: Ensure SSH keys are set up with 'ssh -T git@github.com'",
         remote,
         stderr
-    )
+    );
+    remote_error(remote, stderr, failure)
 }
 
 fn rebase_in_progress_from_failure(git_dir: &Path, output: &Output) -> bool {
@@ -76,6 +108,8 @@ fn rebase_in_progress_from_failure(git_dir: &Path, output: &Output) -> bool {
 /// Git SCM implementation using the git CLI.
 pub struct GitScm {
     workdir: PathBuf,
+    /// `GIT_SSH_COMMAND` override applied to every git invocation
+    ssh_command: Option<String>,
 }
 
 impl GitScm {
@@ -90,7 +124,10 @@ impl GitScm {
             ));
         }
 
-        Ok(Self { workdir: path })
+        Ok(Self {
+            workdir: path,
+            ssh_command: None,
+        })
     }
 
     /// Initialize a new Git repository.
@@ -148,11 +185,21 @@ impl GitScm {
         Self::open(path)
     }
 
+    /// Base `git` command running in the workdir with the SSH override applied.
+    fn git(&self) -> Command {
+        let mut cmd = Command::new("git");
+        cmd.current_dir(&self.workdir);
+        if let Some(ref ssh) = self.ssh_command {
+            cmd.env("GIT_SSH_COMMAND", ssh);
+        }
+        cmd
+    }
+
     /// Run a git command and return stdout as a string.
     fn run_git(&self, args: &[&str]) -> Result<String> {
-        let output = Command::new("git")
+        let output = self
+            .git()
             .args(args)
-            .current_dir(&self.workdir)
             .output()
             .with_context(|| format!("Failed to run 'git {}'", args.join(" ")))?;
 
@@ -175,22 +222,27 @@ impl GitScm {
 
     /// Check if a git command succeeds (exit code 0).
     fn git_succeeds(&self, args: &[&str]) -> bool {
-        Command::new("git")
+        self.git()
             .args(args)
-            .current_dir(&self.workdir)
             .output()
             .map(|o| o.status.success())
             .unwrap_or(false)
     }
 
     fn run_git_output(&self, args: &[&str]) -> Result<Output> {
-        Command::new("git")
+        self.git()
             .args(args)
-            .current_dir(&self.workdir)
             .output()
             .with_context(|| format!("Failed to run 'git {}'", args.join(" ")))
     }
 
+    /// Like `run_git_output` for commands that contact a remote, bounded by
+    /// the remote timeout when one is set.
+    fn run_git_remote(&self, args: &[&str]) -> Result<Output> {
+        super::remote_output(self.git().args(args))
+            .with_context(|| format!("Failed to run 'git {}'", args.join(" ")))
+    }
+
     fn git_dir(&self) -> Result<PathBuf> {
         Ok(PathBuf::from(
             self.run_git(&["rev-parse", "--absolute-git-dir"])?,
@@ -262,7 +314,7 @@ impl Scm for GitScm {
 
     fn push_classified(&self, remote: &str, branch: &str) -> std::result::Result<(), PushError> {
         let output = self
-            .run_git_output(&["push", remote, branch])
+            .run_git_remote(&["push", remote, branch])
             .map_err(PushError::Other)?;
 
         if output.status.success() {
@@ -277,8 +329,28 @@ impl Scm for GitScm {
         Err(PushError::Other(build_push_failure(remote, &stderr)))
     }
 
+    fn force_push(&self, remote: &str, branch: &str) -> Result<()> {
+        let output = self.run_git_remote(&["push", "--force-with-lease", remote, branch])?;
+        if output.status.success() {
+            return Ok(());
+        }
+        Err(build_push_failure(
+            remote,
+            &String::from_utf8_lossy(&output.stderr),
+        ))
+    }
+
     fn fetch(&self, remote: &str) -> Result<()> {
-        self.run_git_ok(&["fetch", remote])
+        let output = self.run_git_remote(&["fetch", remote])?;
+        if output.status.success() {
+            return Ok(());
+        }
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        Err(remote_error(
+            remote,
+            &stderr,
+            anyhow!("git fetch {} failed: {}", remote, stderr),
+        ))
     }
 
     fn rebase(&self, upstream: &str) -> Result<RebaseOutcome> {
@@ -325,18 +397,14 @@ impl Scm for GitScm {
     }
 
     fn pull(&self, remote: &str, branch: &str) -> Result<()> {
-        let output = Command::new("git")
-            .args(["pull", remote, branch])
-            .current_dir(&self.workdir)
-            .output()
-            .context("Failed to run 'git pull'")?;
+        let output = self.run_git_remote(&["pull", remote, branch])?;
 
         if !output.status.success() {
             let stderr = String::from_utf8_lossy(&output.stderr);
-            return Err(anyhow!(
-                "Failed to pull from remote '{}': {}",
+            return Err(remote_error(
                 remote,
-                stderr
+                &stderr,
+                anyhow!("Failed to pull from remote '{}': {}", remote, stderr),
             ));
         }
 
@@ -346,6 +414,47 @@ impl Scm for GitScm {
     fn reset_soft(&self, commit: &str) -> Result<()> {
         self.run_git_ok(&["reset", "--soft", commit])
     }
+
+    fn commit_summary(&self, commit: &str) -> Result<String> {
+        self.run_git(&["log", "-1", "--format=%s", commit])
+    }
+
+    fn diff_stat(&self, from: &str, to: &str) -> Result<String> {
+        self.run_git(&["diff", "--stat", from, to])
+    }
+
+    fn stash(&self, message: &str) -> Result<()> {
+        self.run_git_ok(&["stash", "push", "--include-untracked", "-m", message])
+    }
+
+    fn check_remote(&self, remote: &str) -> Result<()> {
+        // Never block on a credential prompt; an unreachable remote should fail fast.
+        let output = super::remote_output(self.git().env("GIT_TERMINAL_PROMPT", "0").args([
+            "ls-remote",
+            "--heads",
+            remote,
+        ]))
+        .context("Failed to run 'git ls-remote'")?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(remote_error(
+                remote,
+                &stderr,
+                anyhow!("Cannot reach remote '{}': {}", remote, stderr.trim()),
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn set_ssh_identity(&mut self, identity: &Path) {
+        self.ssh_command = Some(super::ssh_command_for_identity(identity));
+    }
+
+    fn is_ancestor(&self, older: &str, newer: &str) -> Result<bool> {
+        Ok(self.git_succeeds(&["merge-base", "--is-ancestor", older, newer]))
+    }
 }
 
 #[cfg(test)]
@@ -387,6 +496,30 @@ mod tests {
         assert_eq!(hash.len(), 40); // Full SHA
     }
 
+    #[test]
+    fn test_git_commit_summary_diff_stat_and_stash() {
+        let temp = TempDir::new().unwrap();
+        let scm = GitScm::init(temp.path()).unwrap();
+
+        std::fs::write(temp.path().join("a.txt"), "one").unwrap();
+        scm.stage_all().unwrap();
+        scm.commit("First commit\n\nBody").unwrap();
+        let first = scm.current_commit_hash().unwrap();
+        std::fs::write(temp.path().join("b.txt"), "two").unwrap();
+        scm.stage_all().unwrap();
+        scm.commit("Second commit").unwrap();
+
+        assert_eq!(scm.commit_summary(&first).unwrap(), "First commit");
+        let stat = scm.diff_stat(&first, "HEAD").unwrap();
+        assert!(stat.contains("b.txt"));
+        assert!(stat.contains("1 file changed"));
+
+        std::fs::write(temp.path().join("c.txt"), "untracked").unwrap();
+        assert!(scm.has_changes().unwrap());
+        scm.stash("test stash").unwrap();
+        assert!(!scm.has_changes().unwrap());
+    }
+
     #[test]
     fn test_git_branch() {
         let temp = TempDir::new().unwrap();
@@ -424,6 +557,19 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_unreachable_remote_is_typed() {
+        let stderr = "fatal: unable to access 'https://example.invalid/r.git/': Could not resolve host: example.invalid\n";
+        let err = build_push_failure("origin", stderr);
+        assert!(matches!(
+            SyncError::find(&err),
+            Some(SyncError::RemoteUnreachable { .. })
+        ));
+
+        let err = build_push_failure("origin", "remote: Permission to r.git denied\n");
+        assert!(SyncError::find(&err).is_none());
+    }
+
     #[test]
     fn test_detect_rebase_state_paths() {
         let temp = TempDir::new().unwrap();
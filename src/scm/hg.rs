@@ -85,6 +85,23 @@ impl HgScm {
         Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     }
 
+    /// Like `run_hg` for commands that contact a remote, bounded by the
+    /// remote timeout when one is set.
+    fn run_hg_remote(&self, args: &[&str]) -> Result<String> {
+        let output = super::remote_output(Command::new("hg").args(args).current_dir(&self.path))
+            .with_context(|| format!("Failed to run 'hg {}'", args.join(" ")))?;
+
+        if !output.status.success() {
+            bail!(
+                "hg {} failed: {}",
+                args.join(" "),
+                String::from_utf8_lossy(&output.stderr)
+            );
+        }
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    }
+
     /// Run an hg command and check if it succeeds.
     fn hg_succeeds(&self, args: &[&str]) -> bool {
         Command::new("hg")
@@ -258,14 +275,28 @@ impl Scm for HgScm {
     }
 
     fn push(&self, remote: &str, _branch: &str) -> Result<()> {
-        // Mercurial push uses path name, not remote + branch
-        self.run_hg(&["push", remote])?;
-        Ok(())
+        // Mercurial push uses path name, not remote + branch.
+        // Exit code 1 means "no changes found", which is not an error.
+        let output = super::remote_output(
+            Command::new("hg")
+                .args(["push", remote])
+                .current_dir(&self.path),
+        )
+        .context("Failed to run 'hg push'")?;
+
+        match output.status.code() {
+            Some(0) | Some(1) => Ok(()),
+            _ => bail!(
+                "hg push {} failed: {}",
+                remote,
+                String::from_utf8_lossy(&output.stderr)
+            ),
+        }
     }
 
     fn pull(&self, remote: &str, _branch: &str) -> Result<()> {
         // Pull and update
-        self.run_hg(&["pull", "-u", remote])?;
+        self.run_hg_remote(&["pull", "-u", remote])?;
         Ok(())
     }
 
@@ -275,6 +306,25 @@ impl Scm for HgScm {
         self.run_hg(&["update", "-r", commit])?;
         Ok(())
     }
+
+    fn commit_summary(&self, commit: &str) -> Result<String> {
+        self.run_hg(&["log", "-r", commit, "-T", "{desc|firstline}"])
+    }
+
+    fn diff_stat(&self, from: &str, to: &str) -> Result<String> {
+        self.run_hg(&["diff", "--stat", "-r", from, "-r", to])
+    }
+
+    fn check_remote(&self, remote: &str) -> Result<()> {
+        self.run_hg_remote(&["identify", remote])?;
+        Ok(())
+    }
+
+    fn is_ancestor(&self, older: &str, newer: &str) -> Result<bool> {
+        let revset = format!("{} and ancestors({})", older, newer);
+        let output = self.run_hg(&["log", "-r", &revset, "-T", "{node}"])?;
+        Ok(!output.is_empty())
+    }
 }
 
 #[cfg(test)]
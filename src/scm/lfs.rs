@@ -9,6 +9,13 @@ use std::fs;
 use std::path::Path;
 use std::process::Command;
 
+/// First line of the `.gitattributes` block written for `lfs_patterns`
+const BLOCK_START: &str =
+    "# BEGIN ccs lfs_patterns (managed; change with `ccs config --lfs-patterns`)";
+
+/// Last line of the `.gitattributes` block written for `lfs_patterns`
+const BLOCK_END: &str = "# END ccs lfs_patterns";
+
 /// Check if git-lfs is installed on the system.
 pub fn is_installed() -> bool {
     Command::new("git-lfs")
@@ -18,6 +25,20 @@ pub fn is_installed() -> bool {
         .unwrap_or(false)
 }
 
+/// Fail with installation instructions if git-lfs is not installed.
+pub fn ensure_installed() -> Result<()> {
+    if !is_installed() {
+        bail!(
+            "git-lfs is not installed.\n\
+            Install it with:\n  \
+            - macOS: brew install git-lfs\n  \
+            - Ubuntu/Debian: apt install git-lfs\n  \
+            - Windows: https://git-lfs.github.com"
+        );
+    }
+    Ok(())
+}
+
 /// Initialize LFS in a repository.
 ///
 /// This runs `git lfs install --local` to configure the repository for LFS.
@@ -38,35 +59,117 @@ pub fn init(repo_path: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Configure .gitattributes for LFS patterns.
-///
-/// Writes a .gitattributes file that tells git to use LFS for the
-/// specified file patterns.
-pub fn configure_gitattributes(repo_path: &Path, patterns: &[String]) -> Result<()> {
-    let gitattributes_path = repo_path.join(".gitattributes");
+/// The `.gitattributes` line that stores files matching `pattern` in LFS
+fn lfs_line(pattern: &str) -> String {
+    format!("{} filter=lfs diff=lfs merge=lfs -text", pattern)
+}
 
-    let mut content = String::new();
+/// `.gitattributes` content with the managed block set to `patterns`
+///
+/// Lines outside the block are kept, except copies of the managed lines that
+/// older versions appended without a block. An empty pattern list removes
+/// the block.
+fn render_gitattributes(existing: &str, patterns: &[String]) -> String {
+    let managed: Vec<String> = patterns.iter().map(|p| lfs_line(p)).collect();
 
-    // Read existing content if file exists
-    if gitattributes_path.exists() {
-        content = fs::read_to_string(&gitattributes_path)
-            .context("Failed to read existing .gitattributes")?;
+    let mut kept: Vec<&str> = Vec::new();
+    let mut in_block = false;
+    for line in existing.lines() {
+        match line.trim() {
+            BLOCK_START => in_block = true,
+            BLOCK_END => in_block = false,
+            trimmed if in_block || managed.iter().any(|m| m == trimmed) => {}
+            _ => kept.push(line),
+        }
+    }
+    while kept.last().is_some_and(|line| line.trim().is_empty()) {
+        kept.pop();
     }
 
-    // Add LFS patterns that aren't already present
-    for pattern in patterns {
-        let lfs_line = format!("{} filter=lfs diff=lfs merge=lfs -text", pattern);
-        if !content.contains(&lfs_line) {
-            if !content.is_empty() && !content.ends_with('\n') {
-                content.push('\n');
-            }
-            content.push_str(&lfs_line);
+    let mut content = kept.join("\n");
+    if !content.is_empty() {
+        content.push('\n');
+    }
+    if !managed.is_empty() {
+        if !content.is_empty() {
             content.push('\n');
         }
+        content.push_str(BLOCK_START);
+        content.push('\n');
+        for line in &managed {
+            content.push_str(line);
+            content.push('\n');
+        }
+        content.push_str(BLOCK_END);
+        content.push('\n');
     }
+    content
+}
+
+/// Configure .gitattributes for LFS patterns.
+///
+/// Writes a managed block to .gitattributes that tells git to use LFS for
+/// the specified file patterns, replacing the block from a previous run so
+/// removed patterns disappear. Returns whether the file changed.
+pub fn configure_gitattributes(repo_path: &Path, patterns: &[String]) -> Result<bool> {
+    let gitattributes_path = repo_path.join(".gitattributes");
+
+    let existing = if gitattributes_path.exists() {
+        fs::read_to_string(&gitattributes_path).context("Failed to read existing .gitattributes")?
+    } else {
+        String::new()
+    };
 
+    let content = render_gitattributes(&existing, patterns);
+    if content == existing {
+        return Ok(false);
+    }
     fs::write(&gitattributes_path, content).context("Failed to write .gitattributes")?;
 
+    Ok(true)
+}
+
+/// Stage .gitattributes so the LFS patterns are committed with the next push
+/// and every device tracks the same files.
+fn stage_gitattributes(repo_path: &Path) -> Result<()> {
+    let output = Command::new("git")
+        .args(["add", "--", ".gitattributes"])
+        .current_dir(repo_path)
+        .output()
+        .context("Failed to run 'git add .gitattributes'")?;
+
+    if !output.status.success() {
+        bail!(
+            "Failed to stage .gitattributes: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
+    Ok(())
+}
+
+/// Rewrite the history of `branch` so files matching `patterns` are stored
+/// in LFS, using `git lfs migrate import`.
+///
+/// Every commit of the branch is rewritten, including ones already pushed,
+/// so the result has to be force-pushed. The working tree must be clean.
+pub fn migrate_import(repo_path: &Path, branch: &str, patterns: &[String]) -> Result<()> {
+    let include = format!("--include={}", patterns.join(","));
+    let include_ref = format!("--include-ref=refs/heads/{}", branch);
+    let output = Command::new("git")
+        .args(["lfs", "migrate", "import", &include, &include_ref])
+        .current_dir(repo_path)
+        .stdin(std::process::Stdio::null())
+        .output()
+        .context("Failed to run 'git lfs migrate import'")?;
+
+    if !output.status.success() {
+        bail!(
+            "git lfs migrate import failed: {}",
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+
     Ok(())
 }
 
@@ -76,19 +179,14 @@ pub fn configure_gitattributes(repo_path: &Path, patterns: &[String]) -> Result<
 /// 1. Checks if git-lfs is installed
 /// 2. Initializes LFS in the repository
 /// 3. Configures .gitattributes for the patterns
+/// 4. Stages .gitattributes if it changed
 pub fn setup(repo_path: &Path, patterns: &[String]) -> Result<()> {
-    if !is_installed() {
-        bail!(
-            "git-lfs is not installed.\n\
-            Install it with:\n  \
-            - macOS: brew install git-lfs\n  \
-            - Ubuntu/Debian: apt install git-lfs\n  \
-            - Windows: https://git-lfs.github.com"
-        );
-    }
+    ensure_installed()?;
 
     init(repo_path)?;
-    configure_gitattributes(repo_path, patterns)?;
+    if configure_gitattributes(repo_path, patterns)? {
+        stage_gitattributes(repo_path)?;
+    }
 
     Ok(())
 }
@@ -139,6 +237,34 @@ mod tests {
         assert_eq!(content.matches("*.jsonl filter=lfs").count(), 1);
     }
 
+    #[test]
+    fn test_gitattributes_block_is_replaced_when_patterns_change() {
+        let temp_dir = TempDir::new().unwrap();
+        let gitattributes = temp_dir.path().join(".gitattributes");
+
+        // A line appended by an older version, outside the managed block
+        fs::write(
+            &gitattributes,
+            "*.txt text\n*.jsonl filter=lfs diff=lfs merge=lfs -text\n",
+        )
+        .unwrap();
+
+        let patterns = vec!["*.jsonl".to_string(), "*.png".to_string()];
+        assert!(configure_gitattributes(temp_dir.path(), &patterns).unwrap());
+        assert!(!configure_gitattributes(temp_dir.path(), &patterns).unwrap());
+        let content = fs::read_to_string(&gitattributes).unwrap();
+        assert_eq!(content.matches("*.jsonl filter=lfs").count(), 1);
+        assert!(content.starts_with("*.txt text\n\n# BEGIN ccs lfs_patterns"));
+
+        configure_gitattributes(temp_dir.path(), &["*.png".to_string()]).unwrap();
+        let content = fs::read_to_string(&gitattributes).unwrap();
+        assert!(!content.contains("*.jsonl"));
+        assert!(content.contains("*.png filter=lfs"));
+
+        configure_gitattributes(temp_dir.path(), &[]).unwrap();
+        assert_eq!(fs::read_to_string(&gitattributes).unwrap(), "*.txt text\n");
+    }
+
     #[test]
     fn test_is_installed() {
         // Just verify the function doesn't panic
@@ -8,7 +8,24 @@ mod hg;
 pub mod lfs;
 
 use anyhow::{anyhow, Error, Result};
+use std::io::{self, Read};
 use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::sync::OnceLock;
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Environment variable limiting each remote operation (push, fetch, pull,
+/// remote check) to a number of seconds. Hooks set it for the `ccs push` and
+/// `ccs pull` they spawn, so a hung remote aborts inside that process and
+/// its locks are released on the normal exit path.
+pub const REMOTE_TIMEOUT_ENV: &str = "CCS_REMOTE_TIMEOUT_SECS";
+
+/// Time a timed-out remote command gets to exit after SIGTERM before it is killed
+const TERMINATE_GRACE: Duration = Duration::from_secs(5);
+
+/// Interval at which a remote command with a deadline is polled
+const REMOTE_POLL_INTERVAL: Duration = Duration::from_millis(100);
 
 /// Classified git push failures.
 #[derive(Debug)]
@@ -109,6 +126,12 @@ pub trait Scm: Send + Sync {
         self.push(remote, branch).map_err(PushError::Other)
     }
 
+    /// Overwrite a remote branch after its history was rewritten locally,
+    /// refusing if the remote moved since it was last fetched.
+    fn force_push(&self, _remote: &str, _branch: &str) -> Result<()> {
+        Err(anyhow!("force push is not supported by this SCM backend"))
+    }
+
     /// Fetch remote changes without updating the working tree.
     fn fetch(&self, _remote: &str) -> Result<()> {
         Err(anyhow!("fetch is not supported by this SCM backend"))
@@ -120,7 +143,7 @@ pub trait Scm: Send + Sync {
     }
 
     /// Continue an in-progress rebase.
-#[allow(dead_code)]
+    #[allow(dead_code)]
     fn rebase_continue(&self) -> Result<RebaseOutcome> {
         Err(anyhow!(
             "rebase continue is not supported by this SCM backend"
@@ -142,6 +165,43 @@ pub trait Scm: Send + Sync {
 
     /// Reset to a specific commit (soft reset - keeps working directory).
     fn reset_soft(&self, commit: &str) -> Result<()>;
+
+    /// Get the first line of a commit's message.
+    fn commit_summary(&self, _commit: &str) -> Result<String> {
+        Err(anyhow!(
+            "commit lookup is not supported by this SCM backend"
+        ))
+    }
+
+    /// Summarize the files changed between two commits (`diff --stat`).
+    fn diff_stat(&self, _from: &str, _to: &str) -> Result<String> {
+        Err(anyhow!("diff stat is not supported by this SCM backend"))
+    }
+
+    /// Set uncommitted changes (including untracked files) aside.
+    fn stash(&self, _message: &str) -> Result<()> {
+        Err(anyhow!("stash is not supported by this SCM backend"))
+    }
+
+    /// Check that a remote is reachable (and credentials work) without
+    /// changing any local refs.
+    fn check_remote(&self, _remote: &str) -> Result<()> {
+        Err(anyhow!(
+            "remote check is not supported by this SCM backend"
+        ))
+    }
+
+    /// Use a specific SSH private key for network operations (push/pull/fetch).
+    ///
+    /// Backends without support ignore this and use their default SSH setup.
+    fn set_ssh_identity(&mut self, _identity: &Path) {}
+
+    /// Check whether `older` is an ancestor of (or equal to) `newer`.
+    fn is_ancestor(&self, _older: &str, _newer: &str) -> Result<bool> {
+        Err(anyhow!(
+            "ancestry check is not supported by this SCM backend"
+        ))
+    }
 }
 
 /// Check if a directory is a repository (Git or Mercurial).
@@ -154,8 +214,10 @@ pub fn is_repo(path: &Path) -> bool {
 /// Automatically detects the backend based on the marker directory.
 pub fn open(path: &Path) -> Result<Box<dyn Scm>> {
     if path.join(".git").exists() {
+        ensure_available(Backend::Git, path)?;
         Ok(Box::new(GitScm::open(path)?))
     } else if path.join(".hg").exists() {
+        ensure_available(Backend::Mercurial, path)?;
         Ok(Box::new(HgScm::open(path)?))
     } else {
         Err(anyhow!(
@@ -165,6 +227,135 @@ pub fn open(path: &Path) -> Result<Box<dyn Scm>> {
     }
 }
 
+/// Fail early with an actionable message when a repository's backend binary is missing.
+///
+/// The binary is probed once per process; commands open the sync repo
+/// several times and each probe spawns `git --version` or `hg --version`.
+fn ensure_available(backend: Backend, path: &Path) -> Result<()> {
+    static GIT_AVAILABLE: OnceLock<bool> = OnceLock::new();
+    static HG_AVAILABLE: OnceLock<bool> = OnceLock::new();
+    let available = match backend {
+        Backend::Git => &GIT_AVAILABLE,
+        Backend::Mercurial => &HG_AVAILABLE,
+    };
+    if *available.get_or_init(|| backend.is_available()) {
+        return Ok(());
+    }
+    let binary = match backend {
+        Backend::Git => "git",
+        Backend::Mercurial => "hg",
+    };
+    Err(anyhow!(
+        "Found a {:?} repository at '{}', but the '{}' command is not installed or not on PATH. \
+         Install it or point the sync repo at a different backend.",
+        backend,
+        path.display(),
+        binary
+    ))
+}
+
+/// Limit for remote operations from [`REMOTE_TIMEOUT_ENV`]; `None` when unset or 0.
+fn remote_timeout() -> Option<Duration> {
+    std::env::var(REMOTE_TIMEOUT_ENV)
+        .ok()?
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .filter(|&secs| secs > 0)
+        .map(Duration::from_secs)
+}
+
+/// Run a command that talks to a remote, collecting its output like
+/// [`Command::output`] but bounded by [`REMOTE_TIMEOUT_ENV`] when set.
+pub(crate) fn remote_output(cmd: &mut Command) -> io::Result<Output> {
+    match remote_timeout() {
+        Some(timeout) => output_with_deadline(cmd, timeout),
+        None => cmd.output(),
+    }
+}
+
+/// Run a command, stopping it once `timeout` has passed.
+///
+/// An overdue command is first asked to exit with SIGTERM, on which git and
+/// hg remove their own lock files, and is only killed if it ignores that.
+/// Returns an `io::ErrorKind::TimedOut` error in that case.
+fn output_with_deadline(cmd: &mut Command, timeout: Duration) -> io::Result<Output> {
+    let mut child = cmd
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    // Drain both pipes so a chatty command cannot block on a full pipe
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let deadline = Instant::now() + timeout;
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if Instant::now() >= deadline {
+            terminate(&mut child);
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!("timed out after {}s", timeout.as_secs()),
+            ));
+        }
+        thread::sleep(REMOTE_POLL_INTERVAL);
+    };
+
+    Ok(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    })
+}
+
+fn read_in_background<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+/// Ask a child to exit, killing it if it is still running after [`TERMINATE_GRACE`].
+fn terminate(child: &mut std::process::Child) {
+    #[cfg(unix)]
+    {
+        let sent = Command::new("kill")
+            .args(["-TERM", &child.id().to_string()])
+            .status()
+            .map(|s| s.success())
+            .unwrap_or(false);
+        if sent {
+            let deadline = Instant::now() + TERMINATE_GRACE;
+            while Instant::now() < deadline {
+                if let Ok(Some(_)) = child.try_wait() {
+                    return;
+                }
+                thread::sleep(REMOTE_POLL_INTERVAL);
+            }
+        }
+    }
+    let _ = child.kill();
+    let _ = child.wait();
+}
+
+/// Build an SSH command line that forces the given private key.
+///
+/// `IdentitiesOnly=yes` stops ssh-agent keys from being offered first, which
+/// otherwise makes hosts like GitHub authenticate with the wrong account.
+pub fn ssh_command_for_identity(identity: &Path) -> String {
+    let path = identity.to_string_lossy().replace('\\', "/");
+    format!(
+        "ssh -i '{}' -o IdentitiesOnly=yes",
+        path.replace('\'', "'\\''")
+    )
+}
+
 /// Initialize a new Git repository.
 pub fn init(path: &Path) -> Result<Box<dyn Scm>> {
     Ok(Box::new(GitScm::init(path)?))
@@ -214,9 +405,40 @@ mod tests {
         assert!(is_repo(temp.path()));
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_output_with_deadline() {
+        let output = output_with_deadline(
+            Command::new("sh").args(["-c", "echo out; echo err >&2"]),
+            Duration::from_secs(10),
+        )
+        .unwrap();
+        assert!(output.status.success());
+        assert_eq!(output.stdout, b"out\n");
+        assert_eq!(output.stderr, b"err\n");
+
+        let started = Instant::now();
+        let err = output_with_deadline(Command::new("sleep").arg("30"), Duration::from_millis(200))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
     #[test]
     fn test_open_non_repo_fails() {
         let temp = TempDir::new().unwrap();
         assert!(open(temp.path()).is_err());
     }
+
+    #[test]
+    fn test_ssh_command_for_identity_quotes_path() {
+        assert_eq!(
+            ssh_command_for_identity(Path::new("/home/me/.ssh/id_work")),
+            "ssh -i '/home/me/.ssh/id_work' -o IdentitiesOnly=yes"
+        );
+        assert_eq!(
+            ssh_command_for_identity(Path::new("/keys/it's key")),
+            "ssh -i '/keys/it'\\''s key' -o IdentitiesOnly=yes"
+        );
+    }
 }
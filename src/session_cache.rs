@@ -11,7 +11,7 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::time::SystemTime;
 
-const CACHE_VERSION: u32 = 1;
+const CACHE_VERSION: u32 = 2;
 
 // ---------------------------------------------------------------------------
 // Public types
@@ -38,6 +38,7 @@ pub struct CachedEntry {
     pub assistant_message_count: usize,
     pub first_timestamp: Option<String>,
     pub last_activity: Option<String>,
+    pub estimated_tokens: usize,
 }
 
 // ---------------------------------------------------------------------------
@@ -148,6 +149,7 @@ impl SessionIndexCache {
             first_timestamp: entry.first_timestamp.clone(),
             last_activity: entry.last_activity.clone(),
             file_size,
+            estimated_tokens: entry.estimated_tokens,
         })
     }
 
@@ -177,6 +179,7 @@ impl SessionIndexCache {
                 assistant_message_count: summary.assistant_message_count,
                 first_timestamp: summary.first_timestamp.clone(),
                 last_activity: summary.last_activity.clone(),
+                estimated_tokens: summary.estimated_tokens,
             },
         );
     }
@@ -233,6 +236,7 @@ mod tests {
             first_timestamp: Some("2024-01-01T00:00:00Z".to_string()),
             last_activity: Some("2024-01-02T00:00:00Z".to_string()),
             file_size: 1234,
+            estimated_tokens: 500,
         }
     }
 
@@ -1,20 +1,60 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 use walkdir::WalkDir;
 
-use crate::filter::FilterConfig;
+use crate::filter::{CollisionStrategy, FilterConfig, FilterReason};
 use crate::parser::ConversationSession;
 
-/// Threshold for warning about large conversation files (10 MB)
-pub(crate) const LARGE_FILE_WARNING_THRESHOLD: u64 = 10 * 1024 * 1024;
+/// Environment variable Claude Code reads to relocate its config directory
+pub(crate) const CLAUDE_CONFIG_DIR_ENV: &str = "CLAUDE_CONFIG_DIR";
+
+/// Set by the global `--claude-dir` flag
+static CLAUDE_DIR_OVERRIDE: OnceLock<PathBuf> = OnceLock::new();
+
+/// Use `dir` as the Claude Code home directory for this invocation
+/// (global `--claude-dir`), taking precedence over `CLAUDE_CONFIG_DIR`
+pub fn set_claude_dir_override(dir: PathBuf) {
+    let _ = CLAUDE_DIR_OVERRIDE.set(dir);
+}
+
+/// The `--claude-dir` override, if one was given
+///
+/// Child `ccs` processes don't see the flag, so spawners pass this on as
+/// `CLAUDE_CONFIG_DIR`.
+pub(crate) fn claude_dir_override() -> Option<&'static Path> {
+    CLAUDE_DIR_OVERRIDE.get().map(PathBuf::as_path)
+}
+
+/// Get the Claude Code home directory
+///
+/// Uses `--claude-dir` when given, then `CLAUDE_CONFIG_DIR` (used for
+/// multiple profiles and sandboxed setups), falling back to `~/.claude`.
+/// Every path under the Claude directory should be built from this so all
+/// commands agree on it.
+pub(crate) fn claude_dir() -> Result<PathBuf> {
+    if let Some(dir) = claude_dir_override() {
+        return Ok(dir.to_path_buf());
+    }
+    claude_dir_from(std::env::var_os(CLAUDE_CONFIG_DIR_ENV))
+}
+
+fn claude_dir_from(custom: Option<std::ffi::OsString>) -> Result<PathBuf> {
+    match custom.filter(|dir| !dir.is_empty()) {
+        Some(dir) => crate::onboarding::expand_tilde(&dir.to_string_lossy()),
+        None => {
+            let home = dirs::home_dir().context("Failed to get home directory")?;
+            Ok(home.join(".claude"))
+        }
+    }
+}
 
 /// Get the Claude Code projects directory
 pub(crate) fn claude_projects_dir() -> Result<PathBuf> {
-    let home = dirs::home_dir().context("Failed to get home directory")?;
-    Ok(home.join(".claude").join("projects"))
+    Ok(claude_dir()?.join("projects"))
 }
 
 /// Discover all conversation sessions in Claude Code history
@@ -27,21 +67,77 @@ pub(crate) fn discover_sessions(
     base_path: &Path,
     filter: &FilterConfig,
 ) -> Result<Vec<ConversationSession>> {
+    discover_sessions_with_stats(base_path, filter).map(|(sessions, _)| sessions)
+}
+
+/// Session files that [`discover_sessions`] left out, by filter reason
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct FilteredSessions {
+    /// Files over `max_file_size_bytes`, with their sizes
+    pub oversized: Vec<(PathBuf, u64)>,
+    /// Files older than `exclude_older_than_days`
+    pub too_old: usize,
+    /// Files dropped by `.ccsignore` or the include/exclude patterns
+    pub by_pattern: usize,
+}
+
+impl FilteredSessions {
+    pub fn total(&self) -> usize {
+        self.oversized.len() + self.too_old + self.by_pattern
+    }
+}
+
+/// [`discover_sessions`], also reporting which session files the filter
+/// dropped and why
+pub(crate) fn discover_sessions_with_stats(
+    base_path: &Path,
+    filter: &FilterConfig,
+) -> Result<(Vec<ConversationSession>, FilteredSessions)> {
     let mut sessions = Vec::new();
+    let mut filtered = FilteredSessions::default();
 
     for entry in WalkDir::new(base_path)
-        .follow_links(false)
+        .follow_links(filter.follow_symlinks)
         .into_iter()
-        .filter_map(|e| e.ok())
     {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(e) => {
+                // Symlink loops only occur with follow_symlinks; report them
+                if e.loop_ancestor().is_some() {
+                    log::warn!("Skipping symlink loop: {}", e);
+                }
+                continue;
+            }
+        };
         let path = entry.path();
 
         if path.extension().and_then(|s| s.to_str()) == Some("jsonl") {
-            if !filter.should_include(path) {
-                continue;
+            let reason = filter.exclusion_reason(path);
+            if let Some(reason) = reason {
+                log::debug!("Filtered out {} ({:?})", path.display(), reason);
+            }
+            match reason {
+                None => {}
+                Some(FilterReason::Size(size)) => {
+                    filtered.oversized.push((path.to_path_buf(), size));
+                    continue;
+                }
+                Some(FilterReason::Age) => {
+                    filtered.too_old += 1;
+                    continue;
+                }
+                Some(FilterReason::Pattern | FilterReason::Attachment) => {
+                    filtered.by_pattern += 1;
+                    continue;
+                }
             }
 
             match ConversationSession::from_file(path) {
+                Ok(session) if filter.is_too_old_by_content(&session) => {
+                    log::debug!("Filtered out {} (last message too old)", path.display());
+                    filtered.too_old += 1;
+                }
                 Ok(session) => sessions.push(session),
                 Err(e) => {
                     log::warn!("Failed to parse {}: {}", path.display(), e);
@@ -79,7 +175,14 @@ pub(crate) fn discover_sessions(
             .or_insert(session);
     }
 
-    Ok(session_map.into_values().collect())
+    filtered.oversized.sort();
+    log::debug!(
+        "Discovered {} sessions in {} ({} filtered out)",
+        session_map.len(),
+        base_path.display(),
+        filtered.total()
+    );
+    Ok((session_map.into_values().collect(), filtered))
 }
 
 /// Check for large conversation files and emit warnings
@@ -90,18 +193,27 @@ pub(crate) fn discover_sessions(
 ///
 /// # Arguments
 /// * `file_paths` - Iterator of file paths to check
-pub(crate) fn warn_large_files<P, I>(file_paths: I)
+/// * `threshold_bytes` - Warn for files at least this large (0 disables warnings)
+///
+/// Returns the number of files that triggered a warning.
+pub(crate) fn warn_large_files<P, I>(file_paths: I, threshold_bytes: u64) -> usize
 where
     P: AsRef<Path>,
     I: IntoIterator<Item = P>,
 {
+    let mut warned = 0;
+    if threshold_bytes == 0 {
+        return warned;
+    }
+
     for path in file_paths {
         let path = path.as_ref();
 
         if let Ok(metadata) = fs::metadata(path) {
             let size = metadata.len();
 
-            if size >= LARGE_FILE_WARNING_THRESHOLD {
+            if size >= threshold_bytes {
+                warned += 1;
                 let size_mb = size as f64 / (1024.0 * 1024.0);
                 println!(
                     "  {} Large conversation file detected: {} ({:.1} MB)",
@@ -119,6 +231,8 @@ where
             }
         }
     }
+
+    warned
 }
 
 /// Extract project name from Claude's encoded project directory name.
@@ -140,6 +254,82 @@ pub fn extract_project_name(encoded_path: &str) -> &str {
         .unwrap_or(encoded_path)
 }
 
+/// Name of the project directory under `claude_dir` that contains a session
+/// file, e.g. `-Users-abc-myproject` for `<claude_dir>/-Users-abc-myproject/x.jsonl`
+pub(crate) fn project_dir_name(claude_dir: &Path, file_path: &Path) -> Option<String> {
+    let mut components = file_path.strip_prefix(claude_dir).ok()?.components();
+    let project = components.next()?;
+    // Files directly under `claude_dir` belong to no project
+    components.next()?;
+    Some(project.as_os_str().to_string_lossy().into_owned())
+}
+
+/// Encode a filesystem path the way Claude names its project directories.
+///
+/// Every character other than an ASCII letter, digit or `-` becomes `-`, so
+/// `/Users/abc/my_app` becomes `-Users-abc-my-app`.
+pub fn encode_project_path(path: &Path) -> String {
+    path.to_string_lossy()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '-' { c } else { '-' })
+        .collect()
+}
+
+/// Best-effort inverse of [`encode_project_path`].
+///
+/// Every `-` is read as a path separator, so hyphens and other characters
+/// that Claude also replaces (`.`, `_`, non-ASCII) cannot be recovered; use
+/// the result for display and prefix checks only.
+///
+/// # Examples
+/// - `-Users-abc-project` -> `/Users/abc/project`
+/// - `C--Users-abc-project` -> `C:/Users/abc/project`
+pub fn decode_encoded_path(dir_name: &str) -> PathBuf {
+    let bytes = dir_name.as_bytes();
+    if bytes.len() >= 3 && bytes[0].is_ascii_alphabetic() && &bytes[1..3] == b"--" {
+        let rest = dir_name[3..].replace('-', "/");
+        return PathBuf::from(format!("{}:/{}", &dir_name[..1], rest));
+    }
+    PathBuf::from(dir_name.replace('-', "/"))
+}
+
+/// Check whether an encoded full-path directory name belongs to `home`.
+pub fn is_under_home(dir_name: &str, home: &Path) -> bool {
+    let encoded_home = encode_project_path(home);
+    dir_name == encoded_home
+        || dir_name
+            .strip_prefix(&encoded_home)
+            .is_some_and(|rest| rest.starts_with('-'))
+}
+
+/// Rewrite an encoded full-path directory name from another user's home
+/// directory onto `home`.
+///
+/// Recognizes the common home layouts (`/Users/<name>`, `/home/<name>`,
+/// `C:\Users\<name>`) and swaps the first three encoded segments. Returns
+/// `None` if the name doesn't start with a known home prefix. Usernames that
+/// contain `-` are ambiguous, so callers should confirm the result with the user.
+pub fn remap_home_prefix(dir_name: &str, home: &Path) -> Option<String> {
+    let prefix_len = ["-Users-", "-home-"]
+        .iter()
+        .find(|p| dir_name.starts_with(*p))
+        .map(|p| p.len())
+        .or_else(|| {
+            let bytes = dir_name.as_bytes();
+            (bytes.len() > 9
+                && bytes[0].is_ascii_alphabetic()
+                && dir_name[1..].starts_with("--Users-"))
+            .then_some(9)
+        })?;
+
+    let after_user = dir_name[prefix_len..].find('-')? + prefix_len;
+    Some(format!(
+        "{}{}",
+        encode_project_path(home),
+        &dir_name[after_user..]
+    ))
+}
+
 /// Find a local Claude project directory that matches the given project name.
 ///
 /// Scans `~/.claude/projects/` for directories that match the specified project name.
@@ -197,6 +387,19 @@ pub fn find_local_project_by_name(
         }
     }
 
+    // A folder pushed with the `suffix` collision strategy: find the one
+    // local project of that name whose path hashes to the suffix
+    if all_matches.is_empty() {
+        if let Some((base, suffix)) = split_collision_suffix(project_name) {
+            return entries.iter().map(|e| e.path()).find(|dir| {
+                get_cwd_from_dir(dir).is_some_and(|cwd| {
+                    cwd.split(&['/', '\\']).rfind(|s| !s.is_empty()) == Some(base)
+                        && collision_suffix(&cwd) == suffix
+                })
+            });
+        }
+    }
+
     match all_matches.len() {
         1 => Some(all_matches.into_iter().next().unwrap()),
         n if n > 1 => {
@@ -213,14 +416,22 @@ pub fn find_local_project_by_name(
 }
 
 /// Extract the real project name from a local project directory by reading its JSONL files.
-fn get_project_name_from_dir(dir_path: &Path) -> Option<String> {
+pub(crate) fn get_project_name_from_dir(dir_path: &Path) -> Option<String> {
+    let cwd = get_cwd_from_dir(dir_path)?;
+    cwd.split(&['/', '\\'])
+        .rfind(|s| !s.is_empty())
+        .map(str::to_string)
+}
+
+/// The `cwd` recorded in the first session of a local project directory that has one.
+fn get_cwd_from_dir(dir_path: &Path) -> Option<String> {
     let files = std::fs::read_dir(dir_path).ok()?;
     for file_entry in files.filter_map(|f| f.ok()) {
         let file_path = file_entry.path();
         if file_path.extension().and_then(|s| s.to_str()) == Some("jsonl") {
             if let Ok(session) = crate::parser::ConversationSession::from_file(&file_path) {
-                if let Some(real_name) = session.project_name() {
-                    return Some(real_name.to_string());
+                if let Some(cwd) = session.cwd() {
+                    return Some(cwd.to_string());
                 }
             }
         }
@@ -228,6 +439,40 @@ fn get_project_name_from_dir(dir_path: &Path) -> Option<String> {
     None
 }
 
+/// Hex digits of the hash appended by the `suffix` collision strategy
+const COLLISION_SUFFIX_LEN: usize = 8;
+
+/// Suffix telling apart projects that share a name (`collision_strategy =
+/// "suffix"`): a hash of the project path relative to the home directory, so
+/// the same project gets the same suffix on devices with different usernames.
+pub fn collision_suffix(cwd: &str) -> String {
+    let normalized = cwd.replace('\\', "/");
+    let relative = dirs::home_dir()
+        .and_then(|home| {
+            let home = home.to_string_lossy().replace('\\', "/");
+            let rest = normalized.strip_prefix(home.trim_end_matches('/'))?;
+            rest.starts_with('/').then(|| rest.to_string())
+        })
+        .unwrap_or(normalized);
+
+    // FNV-1a, which unlike `DefaultHasher` is stable across Rust versions
+    let mut hash: u32 = 0x811c_9dc5;
+    for byte in relative.trim_matches('/').bytes() {
+        hash ^= u32::from(byte);
+        hash = hash.wrapping_mul(0x0100_0193);
+    }
+    format!("{hash:0width$x}", width = COLLISION_SUFFIX_LEN)
+}
+
+/// Split a sync repo folder name written by the `suffix` collision strategy
+/// into the project name and the suffix
+fn split_collision_suffix(name: &str) -> Option<(&str, &str)> {
+    let (base, suffix) = name.rsplit_once('-')?;
+    let is_suffix = suffix.len() == COLLISION_SUFFIX_LEN
+        && suffix.bytes().all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b));
+    (is_suffix && !base.is_empty()).then_some((base, suffix))
+}
+
 /// Get all project directories in Claude's projects folder that would map to the same project name.
 /// Used for collision detection when `use_project_name_only` is enabled.
 pub fn find_colliding_projects(
@@ -266,6 +511,57 @@ pub fn find_colliding_projects(
     collisions
 }
 
+/// Directory names (e.g. `-Users-abc-myproject`) of all colliding projects
+/// returned by [`find_colliding_projects`]
+pub(crate) fn colliding_dir_names(collisions: &HashMap<String, Vec<PathBuf>>) -> HashSet<String> {
+    collisions
+        .values()
+        .flatten()
+        .filter_map(|path| path.file_name()?.to_str().map(str::to_string))
+        .collect()
+}
+
+/// Sync repo folder of a session in project-name-only mode: the project
+/// name, plus a path hash for colliding projects under the suffix strategy
+pub(crate) fn session_repo_project_dir(
+    claude_dir: &Path,
+    session: &ConversationSession,
+    filter: &FilterConfig,
+    colliding_dirs: &HashSet<String>,
+) -> Option<String> {
+    let project_name = session.project_name()?;
+    let colliding = filter.collision_strategy == CollisionStrategy::Suffix
+        && project_dir_name(claude_dir, Path::new(&session.file_path))
+            .is_some_and(|dir| colliding_dirs.contains(&dir));
+    if colliding {
+        Some(format!("{}-{}", project_name, collision_suffix(session.cwd()?)))
+    } else {
+        Some(project_name.to_string())
+    }
+}
+
+/// Path of a session in the sync repo, relative to
+/// [`FilterConfig::repo_projects_dir`], as push writes it
+///
+/// Returns `None` for sessions without a `cwd` in project-name-only mode.
+pub(crate) fn session_repo_path(
+    claude_dir: &Path,
+    session: &ConversationSession,
+    filter: &FilterConfig,
+    colliding_dirs: &HashSet<String>,
+) -> Option<PathBuf> {
+    let full_relative = Path::new(&session.file_path)
+        .strip_prefix(claude_dir)
+        .unwrap_or(Path::new(&session.file_path));
+    if filter.use_project_name_only {
+        let filename = full_relative.file_name()?;
+        let project_dir = session_repo_project_dir(claude_dir, session, filter, colliding_dirs)?;
+        Some(PathBuf::from(project_dir).join(filename))
+    } else {
+        Some(full_relative.to_path_buf())
+    }
+}
+
 /// Result of checking sync repo directory structure consistency
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -368,7 +664,6 @@ pub fn check_directory_structure_consistency(
 }
 
 /// Get list of memory files that exist in a directory
-#[allow(dead_code)]
 pub fn list_memory_files(memory_dir: &Path) -> Vec<PathBuf> {
     let mut files = Vec::new();
 
@@ -390,6 +685,21 @@ mod tests {
     use std::io::Write;
     use tempfile::tempdir;
 
+    #[test]
+    fn test_claude_dir_from_env() {
+        assert_eq!(
+            claude_dir_from(Some("/opt/claude-work".into())).unwrap(),
+            PathBuf::from("/opt/claude-work")
+        );
+        let home = dirs::home_dir().unwrap();
+        assert_eq!(
+            claude_dir_from(Some("~/.claude-work".into())).unwrap(),
+            home.join(".claude-work")
+        );
+        assert_eq!(claude_dir_from(Some("".into())).unwrap(), home.join(".claude"));
+        assert_eq!(claude_dir_from(None).unwrap(), home.join(".claude"));
+    }
+
     #[test]
     fn test_extract_project_name_basic() {
         // Standard encoded path
@@ -462,6 +772,36 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_find_local_project_by_suffixed_name() {
+        let temp_dir = tempdir().unwrap();
+        let projects_dir = temp_dir.path();
+        for (dir, cwd) in [
+            ("-opt-work-myproject", "/opt/work/myproject"),
+            ("-opt-personal-myproject", "/opt/personal/myproject"),
+        ] {
+            fs::create_dir(projects_dir.join(dir)).unwrap();
+            fs::write(
+                projects_dir.join(dir).join("s.jsonl"),
+                format!(r#"{{"type":"user","sessionId":"s","cwd":"{cwd}"}}"#),
+            )
+            .unwrap();
+        }
+
+        let work = collision_suffix("/opt/work/myproject");
+        assert_eq!(work.len(), COLLISION_SUFFIX_LEN);
+        assert_ne!(work, collision_suffix("/opt/personal/myproject"));
+        assert_eq!(work, collision_suffix("\\opt\\work\\myproject"));
+
+        let name = format!("myproject-{work}");
+        assert_eq!(split_collision_suffix(&name), Some(("myproject", work.as_str())));
+        assert_eq!(split_collision_suffix("my-project"), None);
+        assert!(find_local_project_by_name(projects_dir, &name)
+            .unwrap()
+            .ends_with("-opt-work-myproject"));
+        assert!(find_local_project_by_name(projects_dir, "myproject-00000000").is_none());
+    }
+
     #[test]
     fn test_find_colliding_projects_no_collisions() {
         let temp_dir = tempdir().unwrap();
@@ -574,6 +914,34 @@ mod tests {
         assert_eq!(sessions.len(), 2, "Should have 2 distinct sessions");
     }
 
+    #[cfg(unix)]
+    #[test]
+    fn test_discover_sessions_follow_symlinks() {
+        let temp_dir = tempdir().unwrap();
+        let projects_dir = temp_dir.path().join("projects");
+        let external = temp_dir.path().join("external-drive");
+        fs::create_dir_all(&projects_dir).unwrap();
+        fs::create_dir_all(&external).unwrap();
+
+        let mut file = fs::File::create(external.join("session-ext.jsonl")).unwrap();
+        writeln!(
+            file,
+            r#"{{"type":"user","sessionId":"session-ext","uuid":"1","timestamp":"2025-01-01T00:00:00Z"}}"#,
+        )
+        .unwrap();
+        std::os::unix::fs::symlink(&external, projects_dir.join("-linked-project")).unwrap();
+        // A loop back to the projects root must not hang or duplicate sessions
+        std::os::unix::fs::symlink(&projects_dir, external.join("loop")).unwrap();
+
+        let mut filter = crate::filter::FilterConfig::default();
+        assert!(discover_sessions(&projects_dir, &filter).unwrap().is_empty());
+
+        filter.follow_symlinks = true;
+        let sessions = discover_sessions(&projects_dir, &filter).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(sessions[0].session_id, "session-ext");
+    }
+
     /// Helper: create a JSONL file with a cwd field to simulate a real session
     fn create_session_with_cwd(dir: &Path, session_id: &str, cwd: &str) {
         let file_path = dir.join(format!("{}.jsonl", session_id));
@@ -742,4 +1110,95 @@ mod tests {
             "Non-ASCII dir without cwd must not collide with parent-named project via fallback"
         );
     }
+
+    #[test]
+    fn test_warn_large_files_threshold() {
+        let dir = tempdir().unwrap();
+        let small = dir.path().join("small.jsonl");
+        let large = dir.path().join("large.jsonl");
+        fs::write(&small, vec![b'x'; 100]).unwrap();
+        fs::write(&large, vec![b'x'; 2048]).unwrap();
+
+        assert_eq!(warn_large_files([&small, &large], 1024), 1);
+        assert_eq!(warn_large_files([&small, &large], 50), 2);
+        // 0 disables the warning entirely
+        assert_eq!(warn_large_files([&small, &large], 0), 0);
+    }
+
+    #[test]
+    fn test_encode_and_decode_project_path() {
+        assert_eq!(
+            encode_project_path(Path::new("/Users/abc/my_app")),
+            "-Users-abc-my-app"
+        );
+        assert_eq!(
+            decode_encoded_path("-Users-abc-project"),
+            PathBuf::from("/Users/abc/project")
+        );
+        assert_eq!(
+            decode_encoded_path("C--Users-abc-project"),
+            PathBuf::from("C:/Users/abc/project")
+        );
+    }
+
+    #[test]
+    fn test_is_under_home() {
+        let home = Path::new("/Users/abc");
+        assert!(is_under_home("-Users-abc-project", home));
+        assert!(is_under_home("-Users-abc", home));
+        assert!(!is_under_home("-Users-abcd-project", home));
+        assert!(!is_under_home("-home-xyz-project", home));
+    }
+
+    #[test]
+    fn test_remap_home_prefix() {
+        let home = Path::new("/home/me");
+        assert_eq!(
+            remap_home_prefix("-Users-abc-Documents-app", home).as_deref(),
+            Some("-home-me-Documents-app")
+        );
+        assert_eq!(
+            remap_home_prefix("-home-xyz-app", home).as_deref(),
+            Some("-home-me-app")
+        );
+        assert_eq!(
+            remap_home_prefix("C--Users-abc-app", home).as_deref(),
+            Some("-home-me-app")
+        );
+        assert_eq!(remap_home_prefix("-opt-work-app", home), None);
+        assert_eq!(remap_home_prefix("-Users-abc", home), None);
+    }
+
+    #[test]
+    fn test_discover_sessions_reports_filtered() {
+        let dir = tempdir().unwrap();
+        let project = dir.path().join("-tmp-p");
+        fs::create_dir_all(&project).unwrap();
+        fs::write(
+            project.join("small.jsonl"),
+            r#"{"type":"user","sessionId":"s"}"#,
+        )
+        .unwrap();
+        fs::write(project.join("big.jsonl"), vec![b' '; 64]).unwrap();
+        fs::write(project.join("big.png"), vec![b' '; 64]).unwrap();
+        let excluded = dir.path().join("-tmp-excluded");
+        fs::create_dir_all(&excluded).unwrap();
+        fs::write(excluded.join("big.jsonl"), vec![b' '; 64]).unwrap();
+
+        let filter = FilterConfig {
+            max_file_size_bytes: 40,
+            exclude_patterns: vec!["*excluded*".to_string()],
+            ..Default::default()
+        };
+        let (sessions, filtered) = discover_sessions_with_stats(dir.path(), &filter).unwrap();
+        assert_eq!(sessions.len(), 1);
+        assert_eq!(
+            filtered,
+            FilteredSessions {
+                oversized: vec![(project.join("big.jsonl"), 64)],
+                too_old: 0,
+                by_pattern: 1,
+            }
+        );
+    }
 }
@@ -43,6 +43,9 @@ pub fn init_from_onboarding(
         is_cloned_repo: is_cloned,
         remote_url: remote_url.map(String::from),
         description: None,
+        last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
     };
 
     // Save multi-repo state (v2 format)
@@ -107,6 +110,9 @@ pub fn init_sync_repo(repo_path: &Path, remote_url: Option<&str>) -> Result<()>
         is_cloned_repo: false,
         remote_url: remote_url.map(String::from),
         description: None,
+        last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
     };
 
     // Save multi-repo state (v2 format)
@@ -0,0 +1,277 @@
+//! Cross-process lock serializing push and pull.
+//!
+//! The Stop hook pushes after every response while users also run manual
+//! syncs; two writers on the same sync repo can corrupt the git index. The
+//! lock is a file created atomically (`create_new`) in the config directory
+//! and removed when the guard is dropped. The file records the holder's PID
+//! and hostname; a lock whose holder process is gone is taken over right
+//! away, however long the sync has been running. Only when liveness cannot
+//! be checked (another host, an unreadable file) is a lock that has not been
+//! touched for [`STALE_AFTER`] assumed to be abandoned.
+
+use crate::config::ConfigManager;
+use anyhow::{bail, Context, Result};
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How long to wait for another sync to finish before giving up
+const WAIT_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Interval between attempts while waiting
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Age after which an existing lock file is considered abandoned when its
+/// holder cannot be checked
+const STALE_AFTER: Duration = Duration::from_secs(30 * 60);
+
+/// Held for the duration of a push or pull; releases the lock on drop
+#[derive(Debug)]
+pub struct SyncLock {
+    path: PathBuf,
+}
+
+impl SyncLock {
+    /// Acquire the global sync lock, waiting up to a minute if another
+    /// `ccs` process is syncing. `operation` is recorded in the lock file
+    /// so the error message can say who holds it.
+    pub fn acquire(operation: &str) -> Result<Self> {
+        let path = ConfigManager::sync_lock_path()?;
+        Self::acquire_at(path, operation, WAIT_TIMEOUT, STALE_AFTER)
+    }
+
+    fn acquire_at(
+        path: PathBuf,
+        operation: &str,
+        timeout: Duration,
+        stale_after: Duration,
+    ) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+
+        let deadline = Instant::now() + timeout;
+        let mut waiting = false;
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(mut file) => {
+                    // Lets other processes check whether the holder is still alive
+                    let _ = writeln!(
+                        file,
+                        "pid {} on {} ({})",
+                        std::process::id(),
+                        this_host(),
+                        operation
+                    );
+                    log::debug!("Acquired sync lock {}", path.display());
+                    return Ok(Self { path });
+                }
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if is_stale(&path, stale_after) {
+                        log::warn!("Removing stale sync lock {}", path.display());
+                        let _ = fs::remove_file(&path);
+                        continue;
+                    }
+                    if Instant::now() >= deadline {
+                        bail!(
+                            "Another sync is in progress ({}). Try again later, or delete {} \
+                             if no other ccs process is running",
+                            read_holder(&path),
+                            path.display()
+                        );
+                    }
+                    if !waiting {
+                        log::info!(
+                            "Waiting for another sync to finish ({})",
+                            read_holder(&path)
+                        );
+                        waiting = true;
+                    }
+                    std::thread::sleep(POLL_INTERVAL);
+                }
+                Err(e) => {
+                    return Err(e)
+                        .with_context(|| format!("Failed to create lock file {}", path.display()))
+                }
+            }
+        }
+    }
+}
+
+impl Drop for SyncLock {
+    fn drop(&mut self) {
+        if let Err(e) = fs::remove_file(&self.path) {
+            log::warn!("Failed to release sync lock {}: {}", self.path.display(), e);
+        }
+    }
+}
+
+/// Holder of a lock, parsed from "pid 1234 on host (push)"
+#[derive(Debug, PartialEq, Eq)]
+struct Holder {
+    pid: u32,
+    /// Missing in lock files written by older versions, which were local
+    host: Option<String>,
+}
+
+fn parse_holder(content: &str) -> Option<Holder> {
+    let mut words = content.split_whitespace();
+    if words.next() != Some("pid") {
+        return None;
+    }
+    let pid = words.next()?.parse().ok().filter(|&pid| pid != 0)?;
+    let host = (words.next() == Some("on"))
+        .then(|| words.next().map(str::to_string))
+        .flatten();
+    Some(Holder { pid, host })
+}
+
+fn this_host() -> String {
+    hostname::get()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| "unknown".to_string())
+}
+
+/// Whether process `pid` exists on this machine, or `None` if that cannot
+/// be determined
+#[cfg(unix)]
+fn process_alive(pid: u32) -> Option<bool> {
+    // Signal 0 only checks that the process exists and can be signalled
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .status()
+        .ok()
+        .map(|status| status.success())
+}
+
+#[cfg(windows)]
+fn process_alive(pid: u32) -> Option<bool> {
+    let output = std::process::Command::new("tasklist")
+        .args(["/FI", &format!("PID eq {}", pid), "/NH", "/FO", "CSV"])
+        .output()
+        .ok()?;
+    let needle = format!("\"{}\"", pid);
+    Some(String::from_utf8_lossy(&output.stdout).contains(&needle))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn process_alive(_pid: u32) -> Option<bool> {
+    None
+}
+
+/// Whether the lock is abandoned: its holder on this host has exited, or,
+/// when that cannot be checked, the file was last modified more than
+/// `stale_after` ago
+fn is_stale(path: &Path, stale_after: Duration) -> bool {
+    let holder = fs::read_to_string(path)
+        .ok()
+        .and_then(|content| parse_holder(&content));
+    if let Some(holder) = holder {
+        let local = holder.host.is_none_or(|host| host == this_host());
+        if let Some(alive) = local.then(|| process_alive(holder.pid)).flatten() {
+            return !alive;
+        }
+    }
+
+    fs::metadata(path)
+        .and_then(|m| m.modified())
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age >= stale_after)
+}
+
+/// Describe the current holder from the lock file contents
+fn read_holder(path: &Path) -> String {
+    fs::read_to_string(path)
+        .ok()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| "unknown process".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    const NEVER_STALE: Duration = Duration::from_secs(3600);
+
+    #[test]
+    fn test_second_acquire_times_out_until_released() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("sync.lock");
+
+        let lock = SyncLock::acquire_at(path.clone(), "push", Duration::ZERO, NEVER_STALE).unwrap();
+        assert!(path.exists());
+
+        let err = SyncLock::acquire_at(path.clone(), "pull", Duration::ZERO, NEVER_STALE)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Another sync is in progress"));
+        assert!(err.contains("(push)"));
+
+        drop(lock);
+        assert!(!path.exists());
+        assert!(SyncLock::acquire_at(path, "pull", Duration::ZERO, NEVER_STALE).is_ok());
+    }
+
+    #[test]
+    fn test_stale_lock_is_taken_over() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("sync.lock");
+        fs::write(&path, "pid 1 on other-host (push)\n").unwrap();
+
+        let lock =
+            SyncLock::acquire_at(path.clone(), "pull", Duration::ZERO, Duration::ZERO).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("(pull)"));
+        drop(lock);
+    }
+
+    #[test]
+    fn test_parse_holder() {
+        assert_eq!(
+            parse_holder("pid 42 on box (push)\n"),
+            Some(Holder {
+                pid: 42,
+                host: Some("box".to_string())
+            })
+        );
+        assert_eq!(
+            parse_holder("pid 42 (push)"),
+            Some(Holder {
+                pid: 42,
+                host: None
+            })
+        );
+        assert_eq!(parse_holder(""), None);
+        assert_eq!(parse_holder("pid 0 on box (push)"), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_staleness_follows_holder_process() {
+        let temp = TempDir::new().unwrap();
+        let path = temp.path().join("sync.lock");
+
+        // A live holder keeps the lock, however old the file is
+        let lock = SyncLock::acquire_at(path.clone(), "push", Duration::ZERO, NEVER_STALE).unwrap();
+        assert!(!is_stale(&path, Duration::ZERO));
+        drop(lock);
+
+        // A holder that has exited releases it at once
+        let mut child = std::process::Command::new("true").spawn().unwrap();
+        let pid = child.id();
+        child.wait().unwrap();
+        fs::write(&path, format!("pid {} on {} (push)\n", pid, this_host())).unwrap();
+        assert!(is_stale(&path, NEVER_STALE));
+
+        // Holders on other hosts fall back to the file age
+        fs::write(&path, format!("pid {} on other-host (push)\n", pid)).unwrap();
+        assert!(!is_stale(&path, NEVER_STALE));
+        assert!(is_stale(&path, Duration::ZERO));
+    }
+}
@@ -1,35 +1,65 @@
 // Module declarations
+pub mod delete_unlock;
 pub(crate) mod discovery;
 mod init;
+mod lock;
 mod pull;
 mod push;
 mod remote;
 mod state;
 mod status;
 pub mod tombstone;
-pub mod delete_unlock;
 
 // Re-export public types and functions
+pub use discovery::set_claude_dir_override;
 pub use init::{init_from_onboarding, init_sync_repo};
+pub use lock::SyncLock;
 pub use pull::pull_history;
-pub use push::push_history;
+pub use push::{push_history, PushOptions};
 pub use remote::{remove_remote, set_remote, show_remote};
-pub use state::{MultiRepoState, RepoConfig, SyncState};
+pub use state::{set_repo_override, state_parse_error, MultiRepoState, RepoConfig, SyncState};
 pub use status::show_status;
 
 use anyhow::Result;
 use colored::Colorize;
+use std::path::Path;
+
+use crate::conflict::Prefer;
+use crate::filter::FilterConfig;
+use crate::output::icon;
+use crate::scm;
 
 /// Maximum number of conversations to display per project in summary
 const MAX_CONVERSATIONS_TO_DISPLAY: usize = 10;
 
+/// Open the sync repository with repo-level options from config applied
+/// (currently the `ssh_identity` used for push/pull/fetch).
+pub(crate) fn open_sync_repo(path: &Path, filter: &FilterConfig) -> Result<Box<dyn scm::Scm>> {
+    let mut repo = scm::open(path)?;
+    if let Some(identity) = filter.ssh_identity_path() {
+        log::debug!("Using SSH identity: {}", identity.display());
+        repo.set_ssh_identity(&identity);
+    }
+    Ok(repo)
+}
+
 /// Bidirectional sync: pull remote changes, then push local changes
+///
+/// `prefer` decides how sessions changed on both sides are resolved during
+/// the pull (see [`Prefer`]). `include_projects`, `exclude_projects`,
+/// `max_file_size_mb` and `since_last` only apply to the push.
+#[allow(clippy::too_many_arguments)]
 pub fn sync_bidirectional(
     commit_message: Option<&str>,
     branch: Option<&str>,
     exclude_attachments: bool,
     interactive: bool,
+    prefer: Prefer,
     prune: bool,
+    include_projects: &[String],
+    exclude_projects: &[String],
+    max_file_size_mb: Option<u64>,
+    since_last: bool,
     verbosity: crate::VerbosityLevel,
 ) -> Result<()> {
     use crate::VerbosityLevel;
@@ -41,24 +71,27 @@ pub fn sync_bidirectional(
     }
 
     // First, pull remote changes
-    pull_history(true, branch, interactive, verbosity)?;
+    pull_history(true, branch, interactive, prefer, None, None, verbosity)?;
 
     if verbosity != VerbosityLevel::Quiet {
         println!();
         println!("{}", "Step 2: Pushing local changes...".bold());
     }
 
-    // Then, push local changes (sync_config = true by default)
-    push_history(
+    // Then, push local changes (config sync follows the settings)
+    push_history(PushOptions {
         commit_message,
-        true,
         branch,
         exclude_attachments,
-        true,
         interactive,
         prune,
+        include_projects,
+        exclude_projects,
+        max_file_size_mb,
+        since_last,
         verbosity,
-    )?;
+        ..Default::default()
+    })?;
 
     if verbosity == VerbosityLevel::Quiet {
         println!("Sync complete");
@@ -67,7 +100,7 @@ pub fn sync_bidirectional(
         println!("{}", "=== Sync Complete ===".green().bold());
         println!(
             "  {} Your local and remote histories are now in sync",
-            "✓".green()
+            icon("✓").green()
         );
     }
 
@@ -104,6 +137,8 @@ mod tests {
             has_remote: false,
             is_cloned_repo: false,
             last_synced_commit: None,
+            pushed_hashes: Default::default(),
+            last_push_at: None,
         };
 
         let state_file = crate::config::ConfigManager::state_file_path().unwrap();
@@ -1,33 +1,308 @@
 use anyhow::{Context, Result};
 use colored::Colorize;
-use inquire::Confirm;
+use inquire::Select;
 use std::collections::HashMap;
+use std::ffi::OsString;
 use std::fs;
 use std::path::{Path, PathBuf};
 
-use crate::conflict::ConflictDetector;
+use crate::conflict::{ConflictDetector, ConflictResolution, Prefer};
+use crate::error::SyncError;
 use crate::filter::FilterConfig;
 use crate::history::{
     ConversationSummary, OperationHistory, OperationRecord, OperationType, SyncOperation,
 };
 use crate::interactive_conflict;
+use crate::merge::dedup_entries_by_uuid;
+use crate::output::icon;
 use crate::parser::ConversationSession;
 use crate::report::{save_conflict_report, ConflictReport};
-use crate::scm;
 use crate::undo::Snapshot;
 use crate::BINARY_NAME;
 
 use super::discovery::{
-    claude_projects_dir, discover_sessions, find_local_project_by_name, warn_large_files,
+    claude_projects_dir, decode_encoded_path, discover_sessions, extract_project_name,
+    find_colliding_projects, find_local_project_by_name, is_under_home, list_memory_files,
+    remap_home_prefix, warn_large_files,
 };
+use super::lock::SyncLock;
 use super::state::SyncState;
 use super::MAX_CONVERSATIONS_TO_DISPLAY;
 
+/// Whether a sync-repo project directory belongs to the project named `project`
+///
+/// In project-name-only mode the directory name is the project name; in
+/// full-path mode it is Claude's encoded path, so the last segment is also
+/// accepted.
+fn sync_project_matches(dir_name: &str, project: &str, use_project_name_only: bool) -> bool {
+    dir_name == project || (!use_project_name_only && extract_project_name(dir_name) == project)
+}
+
+/// Sync-repo project directories matching `project`
+fn matching_sync_project_dirs(
+    remote_projects_dir: &Path,
+    project: &str,
+    use_project_name_only: bool,
+) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = fs::read_dir(remote_projects_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .map(|e| e.path())
+        .filter(|p| p.is_dir())
+        .filter(|p| {
+            p.file_name()
+                .and_then(|n| n.to_str())
+                .is_some_and(|name| sync_project_matches(name, project, use_project_name_only))
+        })
+        .collect();
+    dirs.sort();
+    dirs
+}
+
+/// Memory files of each sync-repo project with their content, by project
+/// directory name
+///
+/// Taken before the remote is pulled, so that files deleted on another device
+/// can be told apart from files that only exist on this one, and local edits
+/// made since the last sync can be detected.
+fn sync_repo_memory_files(
+    remote_projects_dir: &Path,
+    memory_dir_name: &str,
+) -> HashMap<String, HashMap<OsString, Vec<u8>>> {
+    fs::read_dir(remote_projects_dir)
+        .into_iter()
+        .flatten()
+        .filter_map(|e| e.ok())
+        .filter_map(|entry| {
+            let files: HashMap<OsString, Vec<u8>> =
+                list_memory_files(&entry.path().join(memory_dir_name))
+                    .into_iter()
+                    .filter_map(|path| {
+                        let content = fs::read(&path).ok()?;
+                        Some((path.file_name()?.to_os_string(), content))
+                    })
+                    .collect();
+            let name = entry.file_name().to_str()?.to_string();
+            (!files.is_empty()).then_some((name, files))
+        })
+        .collect()
+}
+
+/// Remove local memory files that another device deleted: they were in the
+/// repo before this pull (`before_pull`) and are not anymore
+///
+/// A local file is only removed while its content still equals the repo copy
+/// from before the pull; one edited on this device since the last sync is
+/// kept. Returns the number of removed files and the paths of kept ones.
+fn remove_memory_deleted_upstream(
+    local_memory_path: &Path,
+    remote_memory_path: &Path,
+    before_pull: &HashMap<OsString, Vec<u8>>,
+) -> (usize, Vec<PathBuf>) {
+    let mut removed = 0;
+    let mut kept = Vec::new();
+    for (file_name, synced_content) in before_pull {
+        let local_file = local_memory_path.join(file_name);
+        if remote_memory_path.join(file_name).exists() || !local_file.is_file() {
+            continue;
+        }
+        if fs::read(&local_file).ok().as_ref() != Some(synced_content) {
+            kept.push(local_file);
+            continue;
+        }
+        if let Err(e) = fs::remove_file(&local_file) {
+            log::warn!("Failed to remove deleted memory file: {}", e);
+        } else {
+            removed += 1;
+            log::debug!(
+                "Removed memory file deleted upstream: {}",
+                local_file.display()
+            );
+        }
+    }
+    (removed, kept)
+}
+
+/// Decide what to do with full-path project directories from another user's
+/// home (e.g. `-Users-alice-app` pulled as `bob`).
+///
+/// Returns `dir_name -> Some(target_dir_name)` to keep or remap, or `None` to
+/// skip the project. Only directories [`remap_home_prefix`] recognizes are
+/// included. Unless `prompt` is set (`pull --interactive` in a terminal), the
+/// directory is kept as-is with a warning.
+fn plan_home_remaps(
+    remote_sessions: &[ConversationSession],
+    remote_projects_dir: &Path,
+    prompt: bool,
+) -> HashMap<String, Option<String>> {
+    let mut remaps = HashMap::new();
+    let Some(home) = dirs::home_dir() else {
+        return remaps;
+    };
+
+    let mut dir_names: Vec<String> = remote_sessions
+        .iter()
+        .filter_map(|s| {
+            Path::new(&s.file_path)
+                .strip_prefix(remote_projects_dir)
+                .ok()?
+                .components()
+                .next()?
+                .as_os_str()
+                .to_str()
+                .map(String::from)
+        })
+        .collect();
+    dir_names.sort();
+    dir_names.dedup();
+
+    for dir_name in dir_names {
+        if is_under_home(&dir_name, &home) {
+            continue;
+        }
+        let Some(remapped) = remap_home_prefix(&dir_name, &home) else {
+            continue;
+        };
+
+        println!(
+            "  {} Project directory '{}' ({}) is not under your home directory",
+            icon("⚠").yellow(),
+            dir_name,
+            decode_encoded_path(&dir_name).display()
+        );
+
+        let remap_label = format!("Remap to {}", remapped);
+        let keep_label = "Keep as-is".to_string();
+        let skip_label = "Skip this project".to_string();
+        let choice = if prompt {
+            Select::new(
+                "How should this project be pulled?",
+                vec![remap_label.clone(), keep_label, skip_label.clone()],
+            )
+            .prompt()
+            .ok()
+        } else {
+            println!(
+                "    {} Run '{}' to remap or skip it",
+                icon("→").cyan(),
+                format!("{} pull --interactive", BINARY_NAME).cyan()
+            );
+            None
+        };
+
+        let target = match choice {
+            Some(c) if c == remap_label => Some(remapped),
+            Some(c) if c == skip_label => None,
+            _ => Some(dir_name.clone()),
+        };
+        remaps.insert(dir_name, target);
+    }
+
+    remaps
+}
+
+/// Apply a [`plan_home_remaps`] decision to a sync-repo relative path.
+/// Returns `None` when the project was skipped.
+fn apply_home_remap(
+    relative_path: &Path,
+    remaps: &HashMap<String, Option<String>>,
+) -> Option<PathBuf> {
+    let mut components = relative_path.components();
+    let first = components.next()?.as_os_str().to_str()?;
+    match remaps.get(first) {
+        None => Some(relative_path.to_path_buf()),
+        Some(None) => None,
+        Some(Some(target)) => Some(Path::new(target).join(components.as_path())),
+    }
+}
+
+/// Resolve every conflict by picking one version per `prefer`, without
+/// smart merging. Local files replaced by their remote version are added to
+/// `affected_conversations`.
+fn resolve_conflicts_by_preference(
+    detector: &mut ConflictDetector,
+    prefer: Prefer,
+    remote_sessions: &[ConversationSession],
+    claude_dir: &Path,
+    remote_projects_dir: &Path,
+    affected_conversations: &mut Vec<ConversationSummary>,
+) -> Result<Vec<(PathBuf, PathBuf)>> {
+    println!(
+        "  {} conflicts with --prefer {}...",
+        "Resolving".cyan(),
+        prefer.as_str()
+    );
+
+    let mut result = interactive_conflict::ResolutionResult::new();
+    for conflict in detector.conflicts_mut() {
+        conflict.resolve_by_preference(prefer);
+        if matches!(conflict.resolution, ConflictResolution::KeepRemote) {
+            result.keep_remote.push(conflict.clone());
+        } else {
+            println!(
+                "  {} Kept local: {}",
+                icon("✓").green(),
+                conflict.local_file.display()
+            );
+            result.keep_local.push(conflict.clone());
+        }
+    }
+
+    let renames = interactive_conflict::apply_resolutions(
+        &result,
+        remote_sessions,
+        claude_dir,
+        remote_projects_dir,
+    )?;
+
+    for conflict in &result.keep_remote {
+        let Some(session) = remote_sessions
+            .iter()
+            .find(|s| s.session_id == conflict.session_id)
+        else {
+            continue;
+        };
+        let relative_path = conflict
+            .local_file
+            .strip_prefix(claude_dir)
+            .unwrap_or(&conflict.local_file)
+            .to_string_lossy()
+            .to_string();
+        match ConversationSummary::new(
+            session.session_id.clone(),
+            relative_path,
+            session.latest_timestamp(),
+            session.message_count(),
+            SyncOperation::Conflict,
+        ) {
+            Ok(summary) => affected_conversations.push(summary),
+            Err(e) => log::warn!(
+                "Failed to create summary for conflict {}: {}",
+                conflict.session_id,
+                e
+            ),
+        }
+    }
+
+    let report = ConflictReport::from_conflicts(detector.conflicts());
+    save_conflict_report(&report)?;
+
+    Ok(renames)
+}
+
 /// Pull and merge history from sync repository
+///
+/// When `project` is set, only that project's sync-repo directory is merged
+/// (sessions and memory); other projects are left untouched. `prefer`
+/// decides how sessions changed on both sides are resolved.
 pub fn pull_history(
     fetch_remote: bool,
     branch: Option<&str>,
     interactive: bool,
+    prefer: Prefer,
+    subdir: Option<&str>,
+    project: Option<&str>,
     verbosity: crate::VerbosityLevel,
 ) -> Result<()> {
     use crate::VerbosityLevel;
@@ -36,9 +311,17 @@ pub fn pull_history(
         println!("{}", "Pulling Claude Code history...".cyan().bold());
     }
 
+    let _lock = SyncLock::acquire("pull")?;
     let state = SyncState::load()?;
-    let repo = scm::open(&state.sync_repo_path)?;
-    let filter = FilterConfig::load()?;
+    let mut filter = FilterConfig::load()?;
+    if let Some(subdir) = subdir {
+        filter.sync_subdirectory = subdir.trim().to_string();
+    }
+    crate::filter::validate_sync_subdirectory(&filter.sync_subdirectory)?;
+    if let Some(namespace) = &filter.device_namespace {
+        crate::filter::validate_device_namespace(namespace)?;
+    }
+    let repo = super::open_sync_repo(&state.sync_repo_path, &filter)?;
     let claude_dir = claude_projects_dir()?;
 
     // Get the current branch name for operation record
@@ -47,12 +330,19 @@ pub fn pull_history(
         .or_else(|| repo.current_branch().ok())
         .unwrap_or_else(|| "main".to_string());
 
+    let remote_projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
+    let memory_before_pull = if filter.auto_memory.enabled {
+        sync_repo_memory_files(&remote_projects_dir, &filter.auto_memory.memory_dir_name)
+    } else {
+        HashMap::new()
+    };
+
     // Fetch from remote if configured
     if fetch_remote && state.has_remote {
         println!("  {} from remote...", "Fetching".cyan());
 
         match repo.pull("origin", &branch_name) {
-            Ok(_) => println!("  {} Pulled from origin/{}", "✓".green(), branch_name),
+            Ok(_) => println!("  {} Pulled from origin/{}", icon("✓").green(), branch_name),
             Err(e) => {
                 log::warn!("Failed to pull: {}", e);
                 log::info!("Continuing with local sync repository state...");
@@ -115,16 +405,67 @@ pub fn pull_history(
             if propagated_deletes > 0 && verbosity != VerbosityLevel::Quiet {
                 println!(
                     "  {} Propagated {} intentional deletion(s) from other devices",
-                    "✓".green(),
+                    icon("✓").green(),
                     propagated_deletes
                 );
             }
         }
     }
 
+    // Project-scoped pull: only walk the matching project directories
+    let (local_roots, remote_roots) = if let Some(project) = project {
+        let remote_dirs =
+            matching_sync_project_dirs(&remote_projects_dir, project, filter.use_project_name_only);
+        if remote_dirs.is_empty() {
+            return Err(SyncError::NotFound {
+                kind: "Project",
+                name: project.to_string(),
+            }
+            .into());
+        }
+
+        let local_dirs: Vec<PathBuf> = if filter.use_project_name_only {
+            if find_colliding_projects(&claude_dir)
+                .get(project)
+                .is_some_and(|dirs| dirs.len() > 1)
+            {
+                println!(
+                    "  {} Multiple local directories map to project '{}'; using the best match",
+                    icon("⚠").yellow(),
+                    project
+                );
+            }
+            find_local_project_by_name(&claude_dir, project)
+                .into_iter()
+                .collect()
+        } else {
+            remote_dirs
+                .iter()
+                .filter_map(|d| d.file_name())
+                .map(|name| claude_dir.join(name))
+                .filter(|d| d.is_dir())
+                .collect()
+        };
+        if local_dirs.is_empty() {
+            println!(
+                "  {} No local directory for project '{}' yet; open it in Claude Code once, then pull again",
+                "!".yellow(),
+                project
+            );
+        }
+
+        println!("  {} project: {}", "Pulling".cyan(), project.cyan());
+        (local_dirs, remote_dirs)
+    } else {
+        (vec![claude_dir.clone()], vec![remote_projects_dir.clone()])
+    };
+
     // Discover local sessions
     println!("  {} local sessions...", "Discovering".cyan());
-    let local_sessions = discover_sessions(&claude_dir, &filter)?;
+    let mut local_sessions = Vec::new();
+    for root in &local_roots {
+        local_sessions.extend(discover_sessions(root, &filter)?);
+    }
     println!(
         "  {} {} local sessions",
         "Found".green(),
@@ -132,9 +473,11 @@ pub fn pull_history(
     );
 
     // Discover remote sessions
-    let remote_projects_dir = state.sync_repo_path.join(&filter.sync_subdirectory);
     println!("  {} remote sessions...", "Discovering".cyan());
-    let remote_sessions = discover_sessions(&remote_projects_dir, &filter)?;
+    let mut remote_sessions = Vec::new();
+    for root in &remote_roots {
+        remote_sessions.extend(discover_sessions(root, &filter)?);
+    }
     println!(
         "  {} {} remote sessions",
         "Found".green(),
@@ -172,7 +515,7 @@ pub fn pull_history(
             .collect();
 
         // Check for large conversation files and warn users
-        warn_large_files(&conflicting_file_paths);
+        warn_large_files(&conflicting_file_paths, filter.large_file_warning_bytes());
 
         // Create snapshot of ONLY conflicting files
         let snapshot = Snapshot::create(
@@ -190,7 +533,7 @@ pub fn pull_history(
         if verbosity != VerbosityLevel::Quiet {
             println!(
                 "  {} Snapshot created: {} ({} files)",
-                "✓".green(),
+                icon("✓").green(),
                 path.file_name()
                     .map(|n| n.to_string_lossy().to_string())
                     .unwrap_or_else(|| path.display().to_string()),
@@ -200,7 +543,7 @@ pub fn pull_history(
 
         Some(path)
     } else {
-        println!("  {} No conflicts - skipping snapshot", "✓".green());
+        println!("  {} No conflicts - skipping snapshot", icon("✓").green());
         None
     };
 
@@ -210,10 +553,10 @@ pub fn pull_history(
     if verbosity != VerbosityLevel::Quiet {
         println!();
         println!("{}", "Pull Summary:".bold().cyan());
-        println!("  {} Local sessions: {}", "•".cyan(), local_sessions.len());
+        println!("  {} Local sessions: {}", icon("•").cyan(), local_sessions.len());
         println!(
             "  {} Remote sessions: {}",
-            "•".cyan(),
+            icon("•").cyan(),
             remote_sessions.len()
         );
         println!();
@@ -242,14 +585,12 @@ pub fn pull_history(
 
     // Interactive confirmation
     if interactive && interactive_conflict::is_interactive() {
-        let confirm =
-            Confirm::new("Do you want to proceed with pulling and merging these changes?")
-                .with_default(true)
-                .with_help_message(
-                    "This will merge remote sessions into your local Claude Code history",
-                )
-                .prompt()
-                .context("Failed to get confirmation")?;
+        let confirm = interactive_conflict::confirm_with_help(
+            "Do you want to proceed with pulling and merging these changes?",
+            "This will merge remote sessions into your local Claude Code history",
+            true,
+            interactive_conflict::assume_yes(),
+        )?;
 
         if !confirm {
             println!("\n{}", "Pull cancelled.".yellow());
@@ -270,164 +611,175 @@ pub fn pull_history(
             detector.conflict_count()
         );
 
-        // ============================================================================
-        // ATTEMPT SMART MERGE FIRST
-        // ============================================================================
-        println!("  {} smart merge...", "Attempting".cyan());
-
-        let local_map: HashMap<_, _> = local_sessions
-            .iter()
-            .map(|s| (s.session_id.clone(), s))
-            .collect();
-
-        let remote_map: HashMap<_, _> = remote_sessions
-            .iter()
-            .map(|s| (s.session_id.clone(), s))
-            .collect();
-
-        let mut smart_merge_success_count = 0;
-        let mut smart_merge_failed_conflicts = Vec::new();
-
-        for conflict in detector.conflicts_mut() {
-            // Find local and remote sessions
-            if let (Some(local_session), Some(remote_session)) = (
-                local_map.get(&conflict.session_id),
-                remote_map.get(&conflict.session_id),
-            ) {
-                // Try smart merge
-                match conflict.try_smart_merge(local_session, remote_session) {
-                    Ok(()) => {
-                        smart_merge_success_count += 1;
-                        // Write merged result to local file
-                        if let crate::conflict::ConflictResolution::SmartMerge {
-                            ref merged_entries,
-                            ref stats,
-                        } = conflict.resolution
-                        {
-                            // Create a new session with merged entries
-                            let merged_session = ConversationSession {
-                                session_id: conflict.session_id.clone(),
-                                entries: merged_entries.clone(),
-                                file_path: conflict.local_file.to_string_lossy().to_string(),
-                            };
-
-                            // Write merged session to local path
-                            if let Err(e) = merged_session.write_to_file(&conflict.local_file) {
-                                log::warn!(
-                                    "Failed to write merged session {}: {}",
-                                    conflict.session_id,
-                                    e
-                                );
-                                smart_merge_failed_conflicts.push(conflict.clone());
-                            } else {
-                                println!(
-                                    "  {} Smart merged {} ({} local + {} remote = {} total, {} branches)",
-                                    "✓".green(),
-                                    conflict.session_id,
-                                    stats.local_messages,
-                                    stats.remote_messages,
-                                    stats.merged_messages,
-                                    stats.branches_detected
-                                );
+        let renames = if prefer != Prefer::Interactive {
+            resolve_conflicts_by_preference(
+                &mut detector,
+                prefer,
+                &remote_sessions,
+                &claude_dir,
+                &remote_projects_dir,
+                &mut affected_conversations,
+            )?
+        } else {
+            // ============================================================================
+            // ATTEMPT SMART MERGE FIRST
+            // ============================================================================
+            println!("  {} smart merge...", "Attempting".cyan());
+
+            let local_map: HashMap<_, _> = local_sessions
+                .iter()
+                .map(|s| (s.session_id.clone(), s))
+                .collect();
+
+            let remote_map: HashMap<_, _> = remote_sessions
+                .iter()
+                .map(|s| (s.session_id.clone(), s))
+                .collect();
+
+            let mut smart_merge_success_count = 0;
+            let mut smart_merge_failed_conflicts = Vec::new();
+
+            for conflict in detector.conflicts_mut() {
+                // Find local and remote sessions
+                if let (Some(local_session), Some(remote_session)) = (
+                    local_map.get(&conflict.session_id),
+                    remote_map.get(&conflict.session_id),
+                ) {
+                    // Try smart merge
+                    match conflict.try_smart_merge(local_session, remote_session) {
+                        Ok(()) => {
+                            smart_merge_success_count += 1;
+                            // Write merged result to local file
+                            if let crate::conflict::ConflictResolution::SmartMerge {
+                                ref merged_entries,
+                                ref stats,
+                            } = conflict.resolution
+                            {
+                                // Create a new session with merged entries
+                                let merged_session = ConversationSession {
+                                    session_id: conflict.session_id.clone(),
+                                    entries: merged_entries.clone(),
+                                    file_path: conflict.local_file.to_string_lossy().to_string(),
+                                };
+
+                                // Write merged session to local path
+                                if let Err(e) = merged_session.write_to_file(&conflict.local_file) {
+                                    log::warn!(
+                                        "Failed to write merged session {}: {}",
+                                        conflict.session_id,
+                                        e
+                                    );
+                                    smart_merge_failed_conflicts.push(conflict.clone());
+                                } else {
+                                    println!(
+                                        "  {} Smart merged {} ({} local + {} remote = {} total, {} branches)",
+                                        icon("✓").green(),
+                                        conflict.session_id,
+                                        stats.local_messages,
+                                        stats.remote_messages,
+                                        stats.merged_messages,
+                                        stats.branches_detected
+                                    );
+                                }
                             }
                         }
-                    }
-                    Err(e) => {
-                        log::warn!("Smart merge failed for {}: {}", conflict.session_id, e);
-                        log::info!("Falling back to manual resolution...");
-                        smart_merge_failed_conflicts.push(conflict.clone());
+                        Err(e) => {
+                            log::warn!("Smart merge failed for {}: {}", conflict.session_id, e);
+                            log::info!("Falling back to manual resolution...");
+                            smart_merge_failed_conflicts.push(conflict.clone());
+                        }
                     }
                 }
             }
-        }
 
-        println!(
-            "  {} Successfully smart merged {}/{} conflicts",
-            "✓".green(),
-            smart_merge_success_count,
-            detector.conflict_count()
-        );
-
-        // If some smart merges failed, handle them with interactive/keep-both resolution
-        let renames = if !smart_merge_failed_conflicts.is_empty() {
             println!(
-                "  {} {} conflicts require manual resolution",
-                "!".yellow(),
-                smart_merge_failed_conflicts.len()
+                "  {} Successfully smart merged {}/{} conflicts",
+                icon("✓").green(),
+                smart_merge_success_count,
+                detector.conflict_count()
             );
 
-            // Check if we can run interactively
-            let use_interactive = crate::interactive_conflict::is_interactive();
-
-            if use_interactive {
-                // Interactive conflict resolution for failed merges
+            // If some smart merges failed, handle them with interactive/keep-both resolution
+            if !smart_merge_failed_conflicts.is_empty() {
                 println!(
-                    "\n{} Running in interactive mode for remaining conflicts",
-                    "→".cyan()
+                    "  {} {} conflicts require manual resolution",
+                    "!".yellow(),
+                    smart_merge_failed_conflicts.len()
                 );
 
-                let resolution_result = crate::interactive_conflict::resolve_conflicts_interactive(
-                    &mut smart_merge_failed_conflicts,
-                )?;
+                // Check if we can run interactively
+                let use_interactive = crate::interactive_conflict::is_interactive();
 
-                // Apply the resolutions
-                let renames = crate::interactive_conflict::apply_resolutions(
-                    &resolution_result,
-                    &remote_sessions,
-                    &claude_dir,
-                    &remote_projects_dir,
-                )?;
+                if use_interactive {
+                    // Interactive conflict resolution for failed merges
+                    println!(
+                        "\n{} Running in interactive mode for remaining conflicts",
+                        icon("→").cyan()
+                    );
 
-                // Save conflict report
-                let report = ConflictReport::from_conflicts(detector.conflicts());
-                save_conflict_report(&report)?;
+                    let resolution_result = crate::interactive_conflict::resolve_conflicts_interactive(
+                        &mut smart_merge_failed_conflicts,
+                    )?;
 
-                renames
-            } else {
-                // Non-interactive mode: use "keep both" strategy for failed merges
-                println!(
-                    "\n{} Using automatic conflict resolution (keep both versions)",
-                    "→".cyan()
-                );
+                    // Apply the resolutions
+                    let renames = crate::interactive_conflict::apply_resolutions(
+                        &resolution_result,
+                        &remote_sessions,
+                        &claude_dir,
+                        &remote_projects_dir,
+                    )?;
 
-                let mut renames = Vec::new();
-
-                println!("\n{}", "Conflict Resolution:".yellow().bold());
-                for conflict in &smart_merge_failed_conflicts {
-                    let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
-                    let conflict_suffix = format!("conflict-{timestamp}");
-
-                    if let Ok(renamed_path) = conflict.clone().resolve_keep_both(&conflict_suffix) {
-                        let relative_renamed = renamed_path
-                            .strip_prefix(&claude_dir)
-                            .unwrap_or(&renamed_path);
-                        println!(
-                            "  {} remote version saved as: {}",
-                            "→".yellow(),
-                            relative_renamed.display().to_string().cyan()
-                        );
+                    // Save conflict report
+                    let report = ConflictReport::from_conflicts(detector.conflicts());
+                    save_conflict_report(&report)?;
 
-                        // Find and write the remote session
-                        if let Some(session) = remote_sessions
-                            .iter()
-                            .find(|s| s.session_id == conflict.session_id)
-                        {
-                            session.write_to_file(&renamed_path)?;
-                        }
+                    renames
+                } else {
+                    // Non-interactive mode: use "keep both" strategy for failed merges
+                    println!(
+                        "\n{} Using automatic conflict resolution (keep both versions)",
+                        icon("→").cyan()
+                    );
 
-                        renames.push((conflict.remote_file.clone(), renamed_path));
+                    let mut renames = Vec::new();
+
+                    println!("\n{}", "Conflict Resolution:".yellow().bold());
+                    for conflict in &smart_merge_failed_conflicts {
+                        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
+                        let conflict_suffix = format!("conflict-{timestamp}");
+
+                        if let Ok(renamed_path) = conflict.clone().resolve_keep_both(&conflict_suffix) {
+                            let relative_renamed = renamed_path
+                                .strip_prefix(&claude_dir)
+                                .unwrap_or(&renamed_path);
+                            println!(
+                                "  {} remote version saved as: {}",
+                                icon("→").yellow(),
+                                relative_renamed.display().to_string().cyan()
+                            );
+
+                            // Find and write the remote session
+                            if let Some(session) = remote_sessions
+                                .iter()
+                                .find(|s| s.session_id == conflict.session_id)
+                            {
+                                session.write_to_file(&renamed_path)?;
+                            }
+
+                            renames.push((conflict.remote_file.clone(), renamed_path));
+                        }
                     }
-                }
 
-                // Save conflict report
-                let report = ConflictReport::from_conflicts(detector.conflicts());
-                save_conflict_report(&report)?;
+                    // Save conflict report
+                    let report = ConflictReport::from_conflicts(detector.conflicts());
+                    save_conflict_report(&report)?;
 
-                renames
+                    renames
+                }
+            } else {
+                // All conflicts resolved via smart merge
+                Vec::new()
             }
-        } else {
-            // All conflicts resolved via smart merge
-            Vec::new()
         };
 
         // Track all conflicts in affected conversations
@@ -473,7 +825,7 @@ pub fn pull_history(
             BINARY_NAME
         );
     } else {
-        println!("  {} No conflicts detected", "✓".green());
+        println!("  {} No conflicts detected", icon("✓").green());
     }
 
     // ============================================================================
@@ -490,6 +842,21 @@ pub fn pull_history(
     let mut modified_count = 0;
     let mut unchanged_count = 0;
     let mut skipped_no_local_match = 0;
+    let mut skipped_archived = 0;
+    let mut skipped_foreign_home = 0;
+    let archived_ids = crate::handlers::archive::archived_session_ids();
+
+    // Full-path mode: directory names encode the absolute path on the device
+    // that pushed them. Another user's home would land in a bogus folder here.
+    let home_remaps = if filter.use_project_name_only {
+        HashMap::new()
+    } else {
+        plan_home_remaps(
+            &remote_sessions,
+            &remote_projects_dir,
+            interactive && interactive_conflict::is_interactive(),
+        )
+    };
 
     for remote_session in &remote_sessions {
         // Skip if conflicts were detected
@@ -501,6 +868,12 @@ pub fn pull_history(
             continue;
         }
 
+        // Sessions archived locally (`ccs session archive`) stay archived
+        if archived_ids.contains(&remote_session.session_id) {
+            skipped_archived += 1;
+            continue;
+        }
+
         let (dest_path, relative_path_for_tracking) = if filter.use_project_name_only {
             // Extract project name and session filename from remote path
             let remote_relative = Path::new(&remote_session.file_path)
@@ -547,7 +920,11 @@ pub fn pull_history(
                 .strip_prefix(&remote_projects_dir)
                 .ok()
                 .unwrap_or_else(|| Path::new(&remote_session.file_path));
-            (claude_dir.join(relative_path), relative_path.to_path_buf())
+            let Some(relative_path) = apply_home_remap(relative_path, &home_remaps) else {
+                skipped_foreign_home += 1;
+                continue;
+            };
+            (claude_dir.join(&relative_path), relative_path)
         };
 
         // Determine operation type based on local state
@@ -564,9 +941,24 @@ pub fn pull_history(
             SyncOperation::Added
         };
 
-        // Copy file if it's not unchanged
+        // Copy file if it's not unchanged, dropping entries repeated by uuid
+        // (e.g. left behind by an earlier conflicted pull)
         if operation != SyncOperation::Unchanged {
-            remote_session.write_to_file(&dest_path)?;
+            let (entries, duplicates) = dedup_entries_by_uuid(remote_session.entries.clone());
+            if duplicates > 0 {
+                log::info!(
+                    "Removed {} duplicate entries from {}",
+                    duplicates,
+                    remote_session.session_id
+                );
+                ConversationSession {
+                    entries,
+                    ..remote_session.clone()
+                }
+                .write_to_file(&dest_path)?;
+            } else {
+                remote_session.write_to_file(&dest_path)?;
+            }
             merged_count += 1;
         }
 
@@ -584,7 +976,7 @@ pub fn pull_history(
         }
     }
 
-    println!("  {} Merged {} sessions", "✓".green(), merged_count);
+    println!("  {} Merged {} sessions", icon("✓").green(), merged_count);
 
     // ============================================================================
     // CREATE AND SAVE OPERATION RECORD
@@ -635,6 +1027,20 @@ pub fn pull_history(
             skipped_no_local_match
         );
     }
+    if skipped_foreign_home > 0 {
+        println!(
+            "  {} Skipped (other user's home directory): {}",
+            "!".yellow(),
+            skipped_foreign_home
+        );
+    }
+    if skipped_archived > 0 {
+        println!(
+            "  {} Skipped (archived locally): {}",
+            icon("ℹ").cyan(),
+            skipped_archived
+        );
+    }
     println!();
 
     // Group conversations by project (top-level directory)
@@ -718,7 +1124,9 @@ pub fn pull_history(
         // Don't use extract_project_name() - it splits by '-' and fails
         // for project names containing hyphens (e.g. "claude-openclaw" -> "openclaw").
         // The sync repo directory names ARE the correct project names.
+        let memory_dir_name = filter.auto_memory.memory_dir_name.as_str();
         let mut synced_count = 0;
+        let mut deleted_count = 0;
 
         if let Ok(entries) = std::fs::read_dir(&remote_projects_dir) {
             for entry in entries.filter_map(|e| e.ok()) {
@@ -727,20 +1135,29 @@ pub fn pull_history(
                     continue;
                 }
 
-                let remote_memory_path = sync_project_dir.join("memory");
-                if !remote_memory_path.is_dir() {
-                    continue;
-                }
-
                 let project_name = sync_project_dir
                     .file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or_default();
 
+                // A memory directory whose files were all deleted upstream is
+                // gone from the repo, but its deletions still apply
+                let remote_memory_path = sync_project_dir.join(memory_dir_name);
+                let before_pull = memory_before_pull.get(project_name);
+                if !remote_memory_path.is_dir() && before_pull.is_none() {
+                    continue;
+                }
+
                 if project_name.starts_with('.') || project_name.is_empty() {
                     continue;
                 }
 
+                if project.is_some_and(|p| {
+                    !sync_project_matches(project_name, p, filter.use_project_name_only)
+                }) {
+                    continue;
+                }
+
                 // Find the corresponding local project directory
                 let local_project_dir = if filter.use_project_name_only {
                     find_local_project_by_name(&claude_dir, project_name)
@@ -762,7 +1179,7 @@ pub fn pull_history(
                 };
 
                 // Create local memory directory
-                let local_memory_path = local_project_dir.join("memory");
+                let local_memory_path = local_project_dir.join(memory_dir_name);
                 if let Err(e) = std::fs::create_dir_all(&local_memory_path) {
                     log::warn!("Failed to create local memory directory: {}", e);
                     continue;
@@ -781,8 +1198,35 @@ pub fn pull_history(
                     synced_count += 1;
                 }
 
+                if let Some(before_pull) = before_pull {
+                    let (removed, kept) = remove_memory_deleted_upstream(
+                        &local_memory_path,
+                        &remote_memory_path,
+                        before_pull,
+                    );
+                    deleted_count += removed;
+                    for local_file in kept {
+                        log::warn!(
+                            "Kept memory file deleted upstream but edited locally: {}",
+                            local_file.display()
+                        );
+                        if verbosity != VerbosityLevel::Quiet {
+                            println!(
+                                "  {} Kept {}: deleted on another device but changed here",
+                                icon("!").yellow(),
+                                local_file.display()
+                            );
+                        }
+                    }
+                }
+
                 if verbosity == VerbosityLevel::Verbose {
-                    println!("    {} {}/memory", "←".cyan(), project_name);
+                    println!(
+                        "    {} {}/{}",
+                        icon("←").cyan(),
+                        project_name,
+                        memory_dir_name
+                    );
                 }
             }
         }
@@ -790,9 +1234,16 @@ pub fn pull_history(
         if verbosity != VerbosityLevel::Quiet {
             println!(
                 "  {} Synced {} memory directories",
-                "✓".green(),
+                icon("✓").green(),
                 synced_count
             );
+            if deleted_count > 0 {
+                println!(
+                    "  {} Removed {} memory files deleted on other devices",
+                    icon("✓").green(),
+                    deleted_count
+                );
+            }
         }
     }
 
@@ -805,3 +1256,99 @@ pub fn pull_history(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sync_project_matches() {
+        assert!(sync_project_matches("my-app", "my-app", true));
+        assert!(!sync_project_matches("-Users-me-my-app", "my-app", true));
+
+        // Full-path mode also accepts Claude's encoded directory names
+        assert!(sync_project_matches("-Users-me-app", "app", false));
+        assert!(sync_project_matches("-Users-me-app", "-Users-me-app", false));
+        assert!(!sync_project_matches("-Users-me-other", "app", false));
+    }
+
+    #[test]
+    fn test_matching_sync_project_dirs() {
+        let temp = tempfile::TempDir::new().unwrap();
+        for name in ["alpha", "beta", ".ccs"] {
+            fs::create_dir_all(temp.path().join(name)).unwrap();
+        }
+        fs::write(temp.path().join("alpha.jsonl"), "").unwrap();
+
+        assert_eq!(
+            matching_sync_project_dirs(temp.path(), "alpha", true),
+            vec![temp.path().join("alpha")]
+        );
+        assert!(matching_sync_project_dirs(temp.path(), "gamma", true).is_empty());
+    }
+
+    #[test]
+    fn test_sync_repo_memory_files() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let memory = temp.path().join("alpha").join("memory");
+        fs::create_dir_all(&memory).unwrap();
+        fs::write(memory.join("MEMORY.md"), "notes").unwrap();
+        fs::create_dir_all(temp.path().join("beta").join("memory")).unwrap();
+
+        let files = sync_repo_memory_files(temp.path(), "memory");
+        assert_eq!(files.len(), 1);
+        assert_eq!(files["alpha"][&OsString::from("MEMORY.md")], b"notes");
+        assert!(sync_repo_memory_files(temp.path(), ".memory").is_empty());
+        assert!(sync_repo_memory_files(&temp.path().join("missing"), "memory").is_empty());
+    }
+
+    #[test]
+    fn test_remove_memory_deleted_upstream() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let local = temp.path().join("local");
+        let remote = temp.path().join("remote");
+        fs::create_dir_all(&local).unwrap();
+        fs::create_dir_all(&remote).unwrap();
+        fs::write(local.join("deleted.md"), "synced").unwrap();
+        fs::write(local.join("edited.md"), "changed here").unwrap();
+        fs::write(local.join("kept.md"), "synced").unwrap();
+        fs::write(remote.join("kept.md"), "synced").unwrap();
+        fs::write(local.join("local-only.md"), "mine").unwrap();
+
+        let before_pull: HashMap<OsString, Vec<u8>> = ["deleted.md", "edited.md", "kept.md"]
+            .into_iter()
+            .map(|name| (OsString::from(name), b"synced".to_vec()))
+            .collect();
+        let (removed, kept) = remove_memory_deleted_upstream(&local, &remote, &before_pull);
+
+        assert_eq!(removed, 1);
+        assert_eq!(kept, vec![local.join("edited.md")]);
+        assert!(!local.join("deleted.md").exists());
+        assert!(local.join("edited.md").exists());
+        assert!(local.join("kept.md").exists());
+        assert!(local.join("local-only.md").exists());
+    }
+
+    #[test]
+    fn test_apply_home_remap() {
+        let mut remaps = HashMap::new();
+        remaps.insert(
+            "-Users-alice-app".to_string(),
+            Some("-home-bob-app".to_string()),
+        );
+        remaps.insert("-Users-alice-old".to_string(), None);
+
+        assert_eq!(
+            apply_home_remap(Path::new("-Users-alice-app/s1.jsonl"), &remaps),
+            Some(PathBuf::from("-home-bob-app/s1.jsonl"))
+        );
+        assert_eq!(
+            apply_home_remap(Path::new("-Users-alice-old/s2.jsonl"), &remaps),
+            None
+        );
+        assert_eq!(
+            apply_home_remap(Path::new("-home-bob-other/s3.jsonl"), &remaps),
+            Some(PathBuf::from("-home-bob-other/s3.jsonl"))
+        );
+    }
+}
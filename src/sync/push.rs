@@ -1,25 +1,79 @@
 use anyhow::{Context, Result};
+use chrono::{DateTime, Utc};
 use colored::Colorize;
-use inquire::Confirm;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
 
-use crate::filter::FilterConfig;
+use crate::filter::{CollisionStrategy, FilterConfig};
 use crate::history::{
     ConversationSummary, OperationHistory, OperationRecord, OperationType, SyncOperation,
 };
 use crate::interactive_conflict;
+use crate::lang::t;
+use crate::output::icon;
 use crate::scm;
+use crate::secrets::{SecretMatch, SecretScanner};
 use crate::BINARY_NAME;
 
 use super::discovery::{
-    check_directory_structure_consistency, claude_projects_dir, discover_sessions,
-    find_colliding_projects,
+    check_directory_structure_consistency, claude_projects_dir, colliding_dir_names,
+    discover_sessions, discover_sessions_with_stats, find_colliding_projects, project_dir_name,
+    session_repo_path, session_repo_project_dir, warn_large_files,
 };
+use super::lock::SyncLock;
 use super::state::SyncState;
 use super::MAX_CONVERSATIONS_TO_DISPLAY;
 
+/// Tell the user about sessions left out for exceeding `max_file_size_bytes`,
+/// which would otherwise silently disappear from the sync
+///
+/// Each file is listed with its size unless `verbosity` is quiet.
+fn report_oversized_sessions(
+    oversized: &[(PathBuf, u64)],
+    filter: &FilterConfig,
+    verbosity: crate::VerbosityLevel,
+) {
+    use crate::VerbosityLevel;
+
+    if oversized.is_empty() {
+        return;
+    }
+    let limit_mb = filter.max_file_size_bytes as f64 / (1024.0 * 1024.0);
+    for (path, size) in oversized {
+        let size_mb = *size as f64 / (1024.0 * 1024.0);
+        log::debug!(
+            "Skipping {} ({:.1} MB): larger than the {:.1} MB limit",
+            path.display(),
+            size_mb,
+            limit_mb
+        );
+        if verbosity != VerbosityLevel::Quiet {
+            println!(
+                "  {} {}",
+                icon("⚠").yellow(),
+                t!(
+                    "push.oversized_session",
+                    path = path.display(),
+                    size = format!("{size_mb:.1}"),
+                    limit = format!("{limit_mb:.1}")
+                )
+            );
+        }
+    }
+    if verbosity != VerbosityLevel::Quiet {
+        println!(
+            "     {}",
+            t!(
+                "push.oversized_hint",
+                command = format!("{} push --max-file-size <MB>", BINARY_NAME)
+            )
+            .dimmed()
+        );
+    }
+}
+
 /// Scan the repo worktree for jsonl files containing git conflict markers.
 ///
 /// Called while a rebase is in progress (before aborting it), so the working
@@ -70,8 +124,10 @@ fn scan_for_conflict_files(dir: &Path, conflicts: &mut Vec<PathBuf>) {
 #[derive(Debug, Clone, PartialEq, Eq)]
 enum PushResult {
     Clean,
-    Degraded { conflicts: Vec<PathBuf> },
-#[allow(dead_code)]
+    Degraded {
+        conflicts: Vec<PathBuf>,
+    },
+    #[allow(dead_code)]
     NothingToPush,
 }
 
@@ -86,13 +142,29 @@ fn has_last_synced_commit_drift(
     }
 }
 
-fn git_is_ancestor(repo_path: &Path, older: &str, newer: &str) -> bool {
-    std::process::Command::new("git")
-        .args(["merge-base", "--is-ancestor", older, newer])
-        .current_dir(repo_path)
-        .status()
-        .map(|status| status.success())
-        .unwrap_or(false)
+/// Per-push counts available to `commit_message_template` placeholders
+struct CommitMessageStats {
+    count: usize,
+    added: usize,
+    modified: usize,
+    deleted: usize,
+}
+
+/// Expand `{count}`, `{added}`, `{modified}`, `{deleted}`, `{device}` and
+/// `{date}` in a commit message template. Unknown placeholders are kept as-is.
+fn render_commit_message(
+    template: &str,
+    stats: &CommitMessageStats,
+    device: &str,
+    date: &str,
+) -> String {
+    template
+        .replace("{count}", &stats.count.to_string())
+        .replace("{added}", &stats.added.to_string())
+        .replace("{modified}", &stats.modified.to_string())
+        .replace("{deleted}", &stats.deleted.to_string())
+        .replace("{device}", device)
+        .replace("{date}", date)
 }
 
 fn ensure_clean_rebase_state(repo: &dyn scm::Scm) -> Result<()> {
@@ -103,9 +175,16 @@ fn ensure_clean_rebase_state(repo: &dyn scm::Scm) -> Result<()> {
     Ok(())
 }
 
+/// Delay before retry number `retry` (1-based): 1s, 2s, 4s, ... capped at 30s
+fn retry_backoff(retry: u32) -> Duration {
+    Duration::from_secs(1u64 << retry.saturating_sub(1).min(5)).min(Duration::from_secs(30))
+}
+
 /// Try to push with automatic rebase-and-retry on non-fast-forward rejection.
 ///
 /// Loop up to 3 times: push -> if non-fast-forward, fetch & rebase -> retry.
+/// Other failures (network errors, remote hiccups) are retried separately,
+/// up to `max_attempts` pushes in total with exponential backoff.
 /// Returns `Clean` on success, `Degraded` if rebase conflicts were encountered,
 /// or `NothingToPush` if there was nothing to push.
 fn push_with_rebase_auto_heal(
@@ -113,6 +192,7 @@ fn push_with_rebase_auto_heal(
     repo_path: &Path,
     state: &mut SyncState,
     branch_name: &str,
+    max_attempts: u32,
     verbosity: crate::VerbosityLevel,
 ) -> Result<PushResult> {
     ensure_clean_rebase_state(repo)?;
@@ -121,29 +201,35 @@ fn push_with_rebase_auto_heal(
     let current_head = repo.current_commit_hash().ok();
     if let (Some(last), Some(head)) = (state.last_synced_commit.as_deref(), current_head.as_deref())
     {
-        let drift =
-            has_last_synced_commit_drift(Some(last), head, git_is_ancestor(repo_path, last, head));
+        // Backends without an ancestry check are treated as "no drift"
+        let is_ancestor = repo.is_ancestor(last, head).unwrap_or(true);
+        let drift = has_last_synced_commit_drift(Some(last), head, is_ancestor);
         if drift {
             log::warn!("Detected sync drift before push; auto-heal path will be used if needed");
         }
     }
 
-    // Bounded retry loop (max 3)
-    for attempt in 1..=3 {
+    let max_attempts = max_attempts.max(1);
+    let mut failures = 0;
+
+    // Bounded retry loop (max 3 rebases)
+    let mut attempt = 1;
+    while attempt <= 3 {
         match repo.push_classified("origin", branch_name) {
             Ok(()) => {
                 state.last_synced_commit = repo.current_commit_hash().ok();
                 state.save()?;
                 if verbosity != crate::VerbosityLevel::Quiet && attempt > 1 {
                     println!(
-                        "  {} Rebased and pushed on attempt {}",
-                        "✓".green(),
-                        attempt
+                        "  {} {}",
+                        icon("✓").green(),
+                        t!("push.rebased_on_attempt", attempt = attempt)
                     );
                 }
                 return Ok(PushResult::Clean);
             }
             Err(scm::PushError::NonFastForward) => {
+                attempt += 1;
                 repo.fetch("origin")?;
                 match repo.rebase(&format!("origin/{branch_name}"))? {
                     scm::RebaseOutcome::Completed => continue,
@@ -156,7 +242,33 @@ fn push_with_rebase_auto_heal(
                     }
                 }
             }
-            Err(scm::PushError::Other(e)) => return Err(e.context("Push failed")),
+            Err(scm::PushError::Other(e)) => {
+                failures += 1;
+                if failures >= max_attempts {
+                    return Err(e.context(format!("Push failed after {failures} attempt(s)")));
+                }
+                let delay = retry_backoff(failures);
+                log::warn!(
+                    "Push attempt {}/{} failed, retrying in {}s: {:#}",
+                    failures,
+                    max_attempts,
+                    delay.as_secs(),
+                    e
+                );
+                if verbosity != crate::VerbosityLevel::Quiet {
+                    println!(
+                        "  {} {}",
+                        icon("⚠").yellow(),
+                        t!(
+                            "push.retrying",
+                            attempt = failures,
+                            max = max_attempts,
+                            secs = delay.as_secs()
+                        )
+                    );
+                }
+                std::thread::sleep(delay);
+            }
         }
     }
     Err(anyhow::anyhow!(
@@ -200,28 +312,26 @@ fn collect_missing_repo_sessions(
     filter: &FilterConfig,
     sessions: &[crate::parser::ConversationSession],
     local_files_by_project: &HashMap<String, std::collections::HashSet<String>>,
+    repo_project_dir: &dyn Fn(&crate::parser::ConversationSession) -> Option<String>,
 ) -> Vec<PathBuf> {
     let mut missing = Vec::new();
 
     if filter.use_project_name_only {
-        // Map project_name -> set of local file names (union of all matching dirs).
+        // Map repo folder -> set of local file names (union of all matching dirs).
         let mut local_files_by_name: HashMap<String, std::collections::HashSet<String>> =
             HashMap::new();
         let mut project_name_has_local: std::collections::HashSet<String> =
             std::collections::HashSet::new();
 
         for session in sessions {
-            if let Some(pname) = session.project_name() {
-                project_name_has_local.insert(pname.to_string());
+            if let Some(pname) = repo_project_dir(session) {
+                project_name_has_local.insert(pname.clone());
                 let fname = Path::new(&session.file_path)
                     .file_name()
                     .and_then(|n| n.to_str())
                     .unwrap_or_default()
                     .to_string();
-                local_files_by_name
-                    .entry(pname.to_string())
-                    .or_default()
-                    .insert(fname);
+                local_files_by_name.entry(pname).or_default().insert(fname);
             }
         }
 
@@ -289,6 +399,169 @@ fn collect_missing_repo_sessions(
     missing
 }
 
+/// Whether a session changed after `since`, going by its file modification
+/// time or its newest entry. Sessions with neither count as changed.
+fn changed_since(session: &crate::parser::ConversationSession, since: DateTime<Utc>) -> bool {
+    let modified = fs::metadata(&session.file_path)
+        .and_then(|m| m.modified())
+        .ok()
+        .map(DateTime::<Utc>::from);
+    let latest = session
+        .latest_timestamp()
+        .and_then(|ts| DateTime::parse_from_rfc3339(&ts).ok())
+        .map(|ts| ts.with_timezone(&Utc));
+    match (modified, latest) {
+        (None, None) => true,
+        (modified, latest) => {
+            modified.is_some_and(|t| t > since) || latest.is_some_and(|t| t > since)
+        }
+    }
+}
+
+/// `last_push_at` to record after a push that started at `started`
+///
+/// `--since-last` compares every session with this single timestamp, so it
+/// stays at `previous` when projects were left out of the push; otherwise
+/// their edits made before `started` would count as pushed.
+fn next_last_push_at(
+    previous: Option<DateTime<Utc>>,
+    started: DateTime<Utc>,
+    skipped_dirs: &BTreeSet<String>,
+) -> Option<DateTime<Utc>> {
+    if skipped_dirs.is_empty() {
+        Some(started)
+    } else {
+        previous
+    }
+}
+
+/// Why `--include-project` / `--exclude-project` leave a session out of a push
+#[derive(Debug, PartialEq, Eq)]
+enum ProjectSkip {
+    /// An include list was given and the project is not on it
+    NotIncluded,
+    /// The project is on the exclude list
+    Excluded,
+}
+
+/// Decide whether a session of project `name` is skipped. Exclusion wins
+/// over inclusion; sessions without a project name are only skipped when an
+/// include list is given.
+fn project_skip(name: Option<&str>, include: &[String], exclude: &[String]) -> Option<ProjectSkip> {
+    let listed = |list: &[String]| name.is_some_and(|name| list.iter().any(|p| p == name));
+    if listed(exclude) {
+        Some(ProjectSkip::Excluded)
+    } else if !include.is_empty() && !listed(include) {
+        Some(ProjectSkip::NotIncluded)
+    } else {
+        None
+    }
+}
+
+/// Elapsed time per push phase, reported with `--verbose`
+#[derive(Default)]
+struct PhaseTimings(Vec<(&'static str, Duration)>);
+
+impl PhaseTimings {
+    /// Add the time since `start` to `phase` (repeated phases accumulate)
+    fn record(&mut self, phase: &'static str, start: Instant) {
+        let elapsed = start.elapsed();
+        match self.0.iter_mut().find(|(name, _)| *name == phase) {
+            Some((_, total)) => *total += elapsed,
+            None => self.0.push((phase, elapsed)),
+        }
+    }
+
+    fn print(&self) {
+        println!("\n{}", t!("push.timings").bold());
+        for (phase, elapsed) in &self.0 {
+            println!("  {:<14} {:>8} ms", phase, elapsed.as_millis());
+        }
+        let total: Duration = self.0.iter().map(|(_, elapsed)| *elapsed).sum();
+        println!("  {:<14} {:>8} ms", "total", total.as_millis());
+    }
+}
+
+/// List sessions that contain possible secrets with their masked matches
+fn print_secret_findings(findings: &[(PathBuf, Vec<SecretMatch>)]) {
+    println!();
+    println!(
+        "{} {}",
+        icon("⚠").yellow(),
+        t!("push.secrets_found", count = findings.len())
+            .yellow()
+            .bold()
+    );
+    for (path, matches) in findings {
+        println!("  {}", path.display());
+        for m in matches {
+            println!("    - {} {}", m.detector, m.masked.dimmed());
+        }
+    }
+    println!();
+}
+
+/// Options of [`push_history`], one per `ccs push` flag
+///
+/// The default is a plain `ccs push`: push to the remote, delete sessions
+/// removed through `ccs` from the sync repo, and follow the config for
+/// everything else.
+#[derive(Debug, Clone, Copy)]
+pub struct PushOptions<'a> {
+    /// Commit message instead of the generated one (`--message`)
+    pub commit_message: Option<&'a str>,
+    /// Push the commit to the remote after committing
+    pub push_remote: bool,
+    /// Branch to push instead of the current one (`--branch`)
+    pub branch: Option<&'a str>,
+    /// Skip attachments regardless of the config (`--exclude-attachments`)
+    pub exclude_attachments: bool,
+    /// Override for pushing device configuration
+    pub sync_config: Option<bool>,
+    /// Ask before committing (`--interactive`)
+    pub interactive: bool,
+    /// Delete sessions missing locally from the repo (`--prune`)
+    pub prune: bool,
+    /// Remove orphaned sessions at all; `false` for `--no-delete`
+    pub delete_missing: bool,
+    /// Abort when possible secrets are found (`--strict`)
+    pub strict: bool,
+    /// Sync subdirectory for this run (`--subdir`)
+    pub subdir: Option<&'a str>,
+    /// Only push these projects (`--include-project`)
+    pub include_projects: &'a [String],
+    /// Skip these projects (`--exclude-project`)
+    pub exclude_projects: &'a [String],
+    /// Size limit for this run (`--max-file-size`)
+    pub max_file_size_mb: Option<u64>,
+    /// Only copy sessions changed since the last push (`--since-last`)
+    pub since_last: bool,
+    /// Output level (`--quiet` / `--verbose`)
+    pub verbosity: crate::VerbosityLevel,
+}
+
+impl Default for PushOptions<'_> {
+    fn default() -> Self {
+        Self {
+            commit_message: None,
+            push_remote: true,
+            branch: None,
+            exclude_attachments: false,
+            sync_config: None,
+            interactive: false,
+            prune: false,
+            delete_missing: true,
+            strict: false,
+            subdir: None,
+            include_projects: &[],
+            exclude_projects: &[],
+            max_file_size_mb: None,
+            since_last: false,
+            verbosity: crate::VerbosityLevel::Normal,
+        }
+    }
+}
+
 /// Push local Claude Code history to sync repository
 ///
 /// `prune` controls the accidental-deletion policy:
@@ -298,36 +571,90 @@ fn collect_missing_repo_sessions(
 /// - `true`: the missing sessions are force-deleted from the repo (physical
 ///   prune), which is the escape hatch for users who deliberately removed
 ///   files outside `ccs` and want the deletion propagated.
-#[allow(clippy::too_many_arguments)]
-pub fn push_history(
-    commit_message: Option<&str>,
-    push_remote: bool,
-    branch: Option<&str>,
-    exclude_attachments: bool,
-    sync_config: bool,
-    interactive: bool,
-    prune: bool,
-    verbosity: crate::VerbosityLevel,
-) -> Result<()> {
+///
+/// `subdir` overrides `sync_subdirectory` for this run (`--subdir`).
+///
+/// `sync_config` overrides the config-sync settings for this run:
+/// `Some(false)` (`--no-config-sync`) never pushes device configuration,
+/// `Some(true)` (`--config-sync`) always does, and `None` pushes it when
+/// `config_sync.enabled` and `push_with_config` are both set.
+///
+/// With `scan_secrets` in the config, new and modified sessions are checked for
+/// API keys and tokens and a warning lists the ones that match. `strict`
+/// (`--strict`) always scans and aborts before committing if anything is found.
+///
+/// `delete_missing = false` (`--no-delete`) skips orphan removal entirely:
+/// nothing is deleted from the sync repo, even with `prune` or an active
+/// delete-unlock window, and remote memory files are never removed.
+///
+/// `since_last` (`--since-last`) only copies sessions changed since the last
+/// successful push, plus any not yet in the sync repo. The others still count
+/// as present locally for deletion detection and `ccs status`.
+pub fn push_history(options: PushOptions<'_>) -> Result<()> {
     use crate::VerbosityLevel;
 
+    let PushOptions {
+        commit_message,
+        push_remote,
+        branch,
+        exclude_attachments,
+        sync_config,
+        interactive,
+        prune,
+        delete_missing,
+        strict,
+        subdir,
+        include_projects,
+        exclude_projects,
+        max_file_size_mb,
+        since_last,
+        verbosity,
+    } = options;
+
+    // Recorded at the start so sessions written during the push are picked
+    // up by the next `--since-last`
+    let push_started = Utc::now();
+
     if verbosity != VerbosityLevel::Quiet {
-        println!("{}", "Pushing Claude Code history...".cyan().bold());
+        println!("{}", t!("push.header").cyan().bold());
     }
 
+    // Locked before loading the state so a push that waited for the lock
+    // sees what the previous holder saved
+    let _lock = SyncLock::acquire("push")?;
     let mut state = SyncState::load()?;
-    let repo = scm::open(&state.sync_repo_path)?;
     let mut filter = FilterConfig::load()?;
+    let repo = super::open_sync_repo(&state.sync_repo_path, &filter)?;
 
     // Override exclude_attachments if specified in command
     if exclude_attachments {
         filter.exclude_attachments = true;
     }
+    if let Some(subdir) = subdir {
+        filter.sync_subdirectory = subdir.trim().to_string();
+    }
+    if let Some(mb) = max_file_size_mb {
+        filter.max_file_size_bytes = mb.saturating_mul(1024 * 1024);
+    }
+    // An explicit project list replaces the configured include patterns
+    if !include_projects.is_empty() {
+        filter.include_patterns.clear();
+    }
+    crate::filter::validate_sync_subdirectory(&filter.sync_subdirectory)?;
+    if let Some(namespace) = &filter.device_namespace {
+        crate::filter::validate_device_namespace(namespace)?;
+    }
 
-    // Set up LFS if enabled
-    if filter.enable_lfs {
+    // Set up LFS if enabled (git-only; other backends skip with a warning)
+    let is_git_repo = scm::detect_backend(&state.sync_repo_path) == Some(scm::Backend::Git);
+    if filter.enable_lfs && !is_git_repo {
+        log::warn!("LFS is enabled but the sync repo is not a git repository; skipping LFS setup");
+        if verbosity != VerbosityLevel::Quiet {
+            println!("  {} {}", icon("⚠").yellow(), t!("push.lfs_git_only"));
+        }
+    } else if filter.enable_lfs {
         if verbosity != VerbosityLevel::Quiet {
-            println!("  {} Git LFS...", "Configuring".cyan());
+            println!("  {}", t!("push.configuring_lfs").cyan());
         }
         scm::lfs::setup(&state.sync_repo_path, &filter.lfs_patterns)
             .context("Failed to set up Git LFS")?;
@@ -336,7 +663,7 @@ pub fn push_history(
     let claude_dir = claude_projects_dir()?;
 
     // Check directory structure consistency before pushing
-    let projects_dir = state.sync_repo_path.join(&filter.sync_subdirectory);
+    let projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
     if projects_dir.exists() {
         let structure_check =
             check_directory_structure_consistency(&projects_dir, filter.use_project_name_only);
@@ -345,36 +672,42 @@ pub fn push_history(
             if let Some(warning) = &structure_check.warning {
                 if verbosity != VerbosityLevel::Quiet {
                     println!();
-                    println!("{}", "⚠️  目录结构不一致警告".yellow().bold());
+                    println!(
+                        "{}",
+                        format!("{}  {}", icon("⚠️"), t!("push.structure_warning"))
+                            .yellow()
+                            .bold()
+                    );
                     println!("{}", "─".repeat(50).dimmed());
                     println!("{}", warning.yellow());
                     println!();
                 }
 
                 if interactive && interactive_conflict::is_interactive() {
-                    let proceed = Confirm::new("是否继续推送？")
-                        .with_default(false)
-                        .with_help_message("建议先清理目录结构再继续")
-                        .prompt()
-                        .context("取消确认")?;
+                    let proceed = interactive_conflict::confirm_with_help(
+                        t!("push.structure_confirm"),
+                        t!("push.structure_confirm_help"),
+                        false,
+                        interactive_conflict::assume_yes(),
+                    )?;
 
                     if !proceed {
-                        println!("\n{}", "推送已取消。".yellow());
+                        println!("\n{}", t!("push.cancelled").yellow());
                         println!(
-                            "提示：使用 '{}' 可以切换同步模式",
-                            format!(
-                                "{} config --use-project-name-only <true|false>",
-                                BINARY_NAME
+                            "{}",
+                            t!(
+                                "push.structure_switch_hint",
+                                command = format!(
+                                    "{} config --use-project-name-only <true|false>",
+                                    BINARY_NAME
+                                )
+                                .cyan()
                             )
-                            .cyan()
                         );
                         return Ok(());
                     }
                 } else if verbosity != VerbosityLevel::Quiet {
-                    println!(
-                        "{}",
-                        "使用 --interactive 选项可以在不一致时选择是否继续".dimmed()
-                    );
+                    println!("{}", t!("push.structure_interactive_hint").dimmed());
                 }
             }
         }
@@ -386,28 +719,82 @@ pub fn push_history(
         .or_else(|| repo.current_branch().ok())
         .unwrap_or_else(|| "main".to_string());
 
+    // Per-phase timings, printed with --verbose (excludes time spent at prompts)
+    let mut timings = PhaseTimings::default();
+
     // Discover all sessions
     if verbosity != VerbosityLevel::Quiet {
-        println!("  {} conversation sessions...", "Discovering".cyan());
+        println!("  {}", t!("push.discovering").cyan());
     }
-    let sessions = discover_sessions(&claude_dir, &filter)?;
+    let phase_start = Instant::now();
+    let (mut sessions, filtered) = discover_sessions_with_stats(&claude_dir, &filter)?;
+    timings.record("discovery", phase_start);
     if verbosity != VerbosityLevel::Quiet {
-        println!("  {} {} sessions", "Found".green(), sessions.len());
+        println!("  {}", t!("push.found", count = sessions.len()).green());
+        warn_large_files(
+            sessions.iter().map(|s| &s.file_path),
+            filter.large_file_warning_bytes(),
+        );
+    }
+    report_oversized_sessions(&filtered.oversized, &filter, verbosity);
+
+    // One-off project selection by name; the config is left untouched.
+    // Hashes recorded for skipped projects by earlier pushes are kept.
+    let mut skipped_dirs: BTreeSet<String> = BTreeSet::new();
+    if !include_projects.is_empty() || !exclude_projects.is_empty() {
+        let mut not_included = 0;
+        let mut excluded = 0;
+        sessions.retain(|session| {
+            let skip = project_skip(session.project_name(), include_projects, exclude_projects);
+            match skip {
+                Some(ProjectSkip::NotIncluded) => not_included += 1,
+                Some(ProjectSkip::Excluded) => excluded += 1,
+                None => return true,
+            }
+            skipped_dirs.extend(project_dir_name(&claude_dir, Path::new(&session.file_path)));
+            false
+        });
+        if verbosity != VerbosityLevel::Quiet {
+            if !include_projects.is_empty() {
+                println!(
+                    "  {} {}",
+                    icon("ℹ").cyan(),
+                    t!(
+                        "push.included_projects",
+                        count = not_included,
+                        projects = include_projects.join(", ")
+                    )
+                );
+            }
+            if !exclude_projects.is_empty() {
+                println!(
+                    "  {} {}",
+                    icon("ℹ").cyan(),
+                    t!(
+                        "push.excluded_projects",
+                        count = excluded,
+                        projects = exclude_projects.join(", ")
+                    )
+                );
+            }
+        }
     }
 
-    // Check for project name collisions when using project-name-only mode
+    // Check for project name collisions when using project-name-only mode.
+    // Colliding local project dirs are resolved by `collision_strategy`.
+    let mut colliding_dirs: HashSet<String> = HashSet::new();
     if filter.use_project_name_only {
         let collisions = find_colliding_projects(&claude_dir);
+        colliding_dirs = colliding_dir_names(&collisions);
         if !collisions.is_empty() && verbosity != VerbosityLevel::Quiet {
             println!();
-            println!(
-                "{}",
-                "Warning: Multiple projects map to the same name:"
-                    .yellow()
-                    .bold()
-            );
+            println!("{}", t!("push.collision_warning").yellow().bold());
             for (name, paths) in &collisions {
-                println!("  {} -> {} locations:", name.cyan(), paths.len());
+                println!(
+                    "  {} -> {}",
+                    name.cyan(),
+                    t!("push.collision_locations", count = paths.len())
+                );
                 for path in paths.iter().take(3) {
                     let display_path = path
                         .file_name()
@@ -416,17 +803,40 @@ pub fn push_history(
                     println!("    - {}", display_path);
                 }
                 if paths.len() > 3 {
-                    println!("    ... and {} more", paths.len() - 3);
+                    println!("    {}", t!("common.and_more", count = paths.len() - 3));
                 }
             }
             println!();
-            println!(
-                "{}",
-                "Sessions from colliding projects will be merged into the same directory.".yellow()
-            );
+            match filter.collision_strategy {
+                CollisionStrategy::Merge => {
+                    println!("{}", t!("push.collision_merge").yellow());
+                    println!(
+                        "{}",
+                        t!(
+                            "push.collision_strategy_hint",
+                            command = format!("{} config --collision-strategy", BINARY_NAME)
+                        )
+                        .dimmed()
+                    );
+                }
+                CollisionStrategy::Suffix => println!("{}", t!("push.collision_suffix").yellow()),
+                CollisionStrategy::Skip => println!("{}", t!("push.collision_skip").yellow()),
+            }
             println!();
         }
     }
+    if filter.collision_strategy == CollisionStrategy::Skip && !colliding_dirs.is_empty() {
+        let before = sessions.len();
+        sessions.retain(|session| {
+            let dir = project_dir_name(&claude_dir, Path::new(&session.file_path));
+            !dir.is_some_and(|dir| colliding_dirs.contains(&dir))
+        });
+        skipped_dirs.extend(colliding_dirs.iter().cloned());
+        log::debug!(
+            "Skipped {} sessions of colliding projects",
+            before - sessions.len()
+        );
+    }
 
     // ============================================================================
     // COPY SESSIONS AND TRACK CHANGES
@@ -436,14 +846,46 @@ pub fn push_history(
 
     // Discover existing sessions in sync repo to determine operation type
     if verbosity != VerbosityLevel::Quiet {
-        println!("  {} sessions to sync repository...", "Copying".cyan());
+        println!("  {}", t!("push.copying").cyan());
     }
+    let phase_start = Instant::now();
     let existing_sessions = discover_sessions(&projects_dir, &filter)?;
+    timings.record("discovery", phase_start);
+    let phase_start = Instant::now();
     let existing_map: HashMap<_, _> = existing_sessions
         .iter()
         .map(|s| (s.session_id.clone(), s))
         .collect();
 
+    let mut unchanged_since_last = Vec::new();
+    if since_last {
+        match state.last_push_at {
+            Some(last_push) => {
+                (sessions, unchanged_since_last) = sessions.into_iter().partition(|session| {
+                    !existing_map.contains_key(&session.session_id)
+                        || changed_since(session, last_push)
+                });
+                if verbosity != VerbosityLevel::Quiet {
+                    println!(
+                        "  {} {}",
+                        icon("ℹ").cyan(),
+                        t!(
+                            "push.since_last_skipped",
+                            count = unchanged_since_last.len(),
+                            time = last_push
+                                .with_timezone(&chrono::Local)
+                                .format("%Y-%m-%d %H:%M")
+                        )
+                    );
+                }
+            }
+            None if verbosity != VerbosityLevel::Quiet => {
+                println!("  {} {}", icon("ℹ").cyan(), t!("push.since_last_first"));
+            }
+            None => {}
+        }
+    }
+
     // Track pushed conversations for operation record
     let mut pushed_conversations: Vec<ConversationSummary> = Vec::new();
     let mut added_count = 0;
@@ -453,27 +895,27 @@ pub fn push_history(
     // Track sessions skipped due to missing cwd
     let mut skipped_no_cwd = 0;
 
+    // Local content hashes of the written sessions, for `ccs status`
+    let mut pushed_hashes: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+
+    // Sessions with possible secrets, as (relative path, matches)
+    let secret_scanner = if strict || filter.scan_secrets {
+        Some(SecretScanner::new(&filter.secret_patterns)?)
+    } else {
+        None
+    };
+    let mut secret_findings: Vec<(PathBuf, Vec<SecretMatch>)> = Vec::new();
+
     // Mapping from local project dir -> sync repo project dir (for memory sync)
     let mut project_dir_to_sync: HashMap<PathBuf, PathBuf> = HashMap::new();
 
-    // Closure to compute the relative path for a session, respecting use_project_name_only
+    let repo_project_dir = |session: &crate::parser::ConversationSession| -> Option<String> {
+        session_repo_project_dir(&claude_dir, session, &filter, &colliding_dirs)
+    };
+
+    // Relative path of a session in the sync repo, respecting use_project_name_only
     let compute_relative_path = |session: &crate::parser::ConversationSession| -> Option<PathBuf> {
-        if filter.use_project_name_only {
-            let full_relative = Path::new(&session.file_path)
-                .strip_prefix(&claude_dir)
-                .unwrap_or(Path::new(&session.file_path));
-
-            let filename = full_relative.file_name()?;
-            let project_name = session.project_name()?;
-            Some(PathBuf::from(project_name).join(filename))
-        } else {
-            Some(
-                Path::new(&session.file_path)
-                    .strip_prefix(&claude_dir)
-                    .unwrap_or(Path::new(&session.file_path))
-                    .to_path_buf(),
-            )
-        }
+        session_repo_path(&claude_dir, session, &filter, &colliding_dirs)
     };
 
     for session in &sessions {
@@ -499,6 +941,18 @@ pub fn push_history(
         }
 
         let dest_path = projects_dir.join(&relative_path);
+        let local_hash = project_dir_name(&claude_dir, Path::new(&session.file_path))
+            .map(|project| (project, session.content_hash()));
+
+        // Redact before hashing so an unchanged session still compares equal
+        // to its redacted copy in the repo
+        let redacted;
+        let session = if filter.redact_on_push {
+            redacted = session.without_attachments();
+            &redacted
+        } else {
+            session
+        };
 
         // Determine operation type based on existing state
         let operation = if let Some(existing) = existing_map.get(&session.session_id) {
@@ -513,9 +967,32 @@ pub fn push_history(
             added_count += 1;
             SyncOperation::Added
         };
+        log::debug!(
+            "{:?}: {} -> {}",
+            operation,
+            session.file_path,
+            relative_path.display()
+        );
+
+        // Only new content is scanned; unchanged sessions were checked when
+        // they were first pushed
+        if let Some(scanner) = &secret_scanner {
+            if operation != SyncOperation::Unchanged {
+                let matches = scanner.scan_session(session);
+                if !matches.is_empty() {
+                    secret_findings.push((relative_path.clone(), matches));
+                    if strict {
+                        continue;
+                    }
+                }
+            }
+        }
 
         // Write the session file
         session.write_to_file(&dest_path)?;
+        if let Some((project, hash)) = local_hash {
+            pushed_hashes.entry(project).or_default().insert(hash);
+        }
 
         // Track this session in pushed conversations
         let relative_path_str = relative_path.to_string_lossy().to_string();
@@ -530,29 +1007,79 @@ pub fn push_history(
             Err(e) => log::warn!("Failed to create summary for {}: {}", relative_path_str, e),
         }
     }
+    timings.record("copy", phase_start);
+
+    // Sessions skipped by --since-last are already in the repo as they are
+    for session in &unchanged_since_last {
+        if let Some(project) = project_dir_name(&claude_dir, Path::new(&session.file_path)) {
+            pushed_hashes
+                .entry(project)
+                .or_default()
+                .insert(session.content_hash());
+        }
+    }
+    unchanged_count += unchanged_since_last.len();
+    sessions.extend(unchanged_since_last);
+
+    // Warn about possible secrets even in quiet mode
+    if !secret_findings.is_empty() {
+        print_secret_findings(&secret_findings);
+        if strict {
+            anyhow::bail!(t!("push.secrets_blocked", count = secret_findings.len()));
+        }
+    }
 
     // ============================================================================
     // SHOW SUMMARY AND INTERACTIVE CONFIRMATION
     // ============================================================================
     if verbosity != VerbosityLevel::Quiet {
         println!();
-        println!("{}", "Push Summary:".bold().cyan());
-        println!("  {} Added: {}", "•".green(), added_count);
-        println!("  {} Modified: {}", "•".yellow(), modified_count);
-        println!("  {} Unchanged: {}", "•".dimmed(), unchanged_count);
+        println!("{}", t!("push.summary_title").bold().cyan());
+        println!(
+            "  {} {}",
+            icon("•").green(),
+            t!("push.summary_added", count = added_count)
+        );
+        println!(
+            "  {} {}",
+            icon("•").yellow(),
+            t!("push.summary_modified", count = modified_count)
+        );
+        println!(
+            "  {} {}",
+            icon("•").dimmed(),
+            t!("push.summary_unchanged", count = unchanged_count)
+        );
         let total_with_cwd = sessions.len().saturating_sub(skipped_no_cwd);
-        println!("  {} Skipped (no cwd): {}", "•".dimmed(), skipped_no_cwd);
         println!(
-            "  {} Sessions (with project context): {}",
-            "•".cyan(),
-            total_with_cwd
+            "  {} {}",
+            icon("•").dimmed(),
+            t!("push.summary_skipped_no_cwd", count = skipped_no_cwd)
+        );
+        if filtered.total() > 0 {
+            println!(
+                "  {} {}",
+                icon("•").dimmed(),
+                t!(
+                    "push.summary_filtered",
+                    count = filtered.total(),
+                    size = filtered.oversized.len(),
+                    age = filtered.too_old,
+                    pattern = filtered.by_pattern
+                )
+            );
+        }
+        println!(
+            "  {} {}",
+            icon("•").cyan(),
+            t!("push.summary_with_cwd", count = total_with_cwd)
         );
         println!();
     }
 
     // Show detailed file list in verbose mode
     if verbosity == VerbosityLevel::Verbose {
-        println!("{}", "Files to be pushed:".bold());
+        println!("{}", t!("push.files_to_push").bold());
         for (idx, session) in sessions.iter().enumerate().take(20) {
             let Some(relative_path) = compute_relative_path(session) else {
                 continue;
@@ -560,32 +1087,33 @@ pub fn push_history(
 
             let status = if let Some(existing) = existing_map.get(&session.session_id) {
                 if existing.content_hash() == session.content_hash() {
-                    "unchanged".dimmed()
+                    t!("push.status_unchanged").dimmed()
                 } else {
-                    "modified".yellow()
+                    t!("push.status_modified").yellow()
                 }
             } else {
-                "new".green()
+                t!("push.status_new").green()
             };
 
             println!("  {}. {} [{}]", idx + 1, relative_path.display(), status);
         }
         if sessions.len() > 20 {
-            println!("  ... and {} more", sessions.len() - 20);
+            println!("  {}", t!("common.and_more", count = sessions.len() - 20));
         }
         println!();
     }
 
     // Interactive confirmation
     if interactive && interactive_conflict::is_interactive() {
-        let confirm = Confirm::new("Do you want to proceed with pushing these changes?")
-            .with_default(true)
-            .with_help_message("This will commit and push to the sync repository")
-            .prompt()
-            .context("Failed to get confirmation")?;
+        let confirm = interactive_conflict::confirm_with_help(
+            t!("push.confirm"),
+            t!("push.confirm_help"),
+            true,
+            interactive_conflict::assume_yes(),
+        )?;
 
         if !confirm {
-            println!("\n{}", "Push cancelled.".yellow());
+            println!("\n{}", t!("push.cancelled").yellow());
             return Ok(());
         }
     }
@@ -593,10 +1121,13 @@ pub fn push_history(
     // ============================================================================
     // SYNC DEVICE CONFIGURATION (if enabled)
     // ============================================================================
-    if sync_config && filter.config_sync.enabled && filter.config_sync.push_with_config {
+    let phase_start = Instant::now();
+    let push_config =
+        sync_config.unwrap_or(filter.config_sync.enabled && filter.config_sync.push_with_config);
+    if push_config {
         if verbosity != VerbosityLevel::Quiet {
             println!();
-            println!("  {} device configuration...", "Syncing".cyan());
+            println!("  {}", t!("push.syncing_config").cyan());
         }
 
         // Use config_sync handler to push configuration files (no commit)
@@ -604,31 +1135,34 @@ pub fn push_history(
             Ok(synced_files) => {
                 if !synced_files.is_empty() {
                     if verbosity != VerbosityLevel::Quiet {
-                        println!("  {} Device configuration synced:", "✓".green());
+                        println!("  {} {}", icon("✓").green(), t!("push.config_synced"));
                         for file in &synced_files {
                             println!("    - {}", file.dimmed());
                         }
                     }
                 } else if verbosity == VerbosityLevel::Verbose {
-                    println!("  {} No configuration files to sync", "ℹ".dimmed());
+                    println!("  {} {}", icon("ℹ").dimmed(), t!("push.no_config_files"));
                 }
             }
             Err(e) => {
                 log::warn!("Failed to sync device configuration: {}", e);
                 if verbosity != VerbosityLevel::Quiet {
                     println!(
-                        "  {} Failed to sync device configuration: {}",
-                        "⚠".yellow(),
-                        e
+                        "  {} {}",
+                        icon("⚠").yellow(),
+                        t!("push.config_sync_failed", error = e)
                     );
                 }
             }
         }
     }
 
+    timings.record("config sync", phase_start);
+
     // ============================================================================
     // DETECT LOCALLY-MISSING SESSIONS IN SYNC REPO
     // ============================================================================
+    let phase_start = Instant::now();
     // Compare sync repo files against local files to find sessions that exist
     // in the repo but are missing locally. Only consider sync-repo project
     // dirs that have a corresponding local project dir — this prevents
@@ -637,9 +1171,16 @@ pub fn push_history(
     // These missing sessions are either:
     //   * accidental local loss → protected by default (kept in repo),
     //   * force-pruned when `--prune` is set.
+    //
+    // `--no-delete` skips detection entirely so nothing can be removed.
     let mut deleted_from_repo = 0;
 
-    let missing_in_repo: Vec<PathBuf> = {
+    let missing_in_repo: Vec<PathBuf> = if !delete_missing {
+        if verbosity != VerbosityLevel::Quiet {
+            println!("  {} {}", icon("ℹ").cyan(), t!("push.no_delete_notice"));
+        }
+        Vec::new()
+    } else {
         // Build a set of local session file names grouped by project dir name
         // (the encoded directory name under ~/.claude/projects/)
         let mut local_files_by_project: HashMap<String, std::collections::HashSet<String>> =
@@ -674,7 +1215,22 @@ pub fn push_history(
             }
         }
 
-        collect_missing_repo_sessions(&projects_dir, &filter, &sessions, &local_files_by_project)
+        // Archived sessions were moved out on purpose; they are not "missing"
+        let archived_ids = crate::handlers::archive::archived_session_ids();
+        collect_missing_repo_sessions(
+            &projects_dir,
+            &filter,
+            &sessions,
+            &local_files_by_project,
+            &repo_project_dir,
+        )
+        .into_iter()
+        .filter(|path| {
+            path.file_stem()
+                .and_then(|s| s.to_str())
+                .is_none_or(|id| !archived_ids.contains(id))
+        })
+        .collect()
     };
 
     // Delete-unlock window: when active, treat locally-missing sessions as
@@ -702,17 +1258,20 @@ pub fn push_history(
                     match decide_missing_action(prune, unlock_remaining) {
                         MissingAction::PruneUnlock(mins) => {
                             println!(
-                                "  {} 删除放行窗口生效中，已同步删除 {} 个 session（剩余 {} 分钟）",
-                                "🔓".yellow(),
-                                deleted_from_repo,
-                                mins
+                                "  {} {}",
+                                icon("🔓").yellow(),
+                                t!(
+                                    "push.pruned_unlocked",
+                                    count = deleted_from_repo,
+                                    minutes = mins
+                                )
                             );
                         }
                         _ => {
                             println!(
-                                "  {} Pruned {} missing sessions from sync repo",
-                                "✓".green(),
-                                deleted_from_repo
+                                "  {} {}",
+                                icon("✓").green(),
+                                t!("push.pruned", count = deleted_from_repo)
                             );
                         }
                     }
@@ -724,15 +1283,18 @@ pub fn push_history(
                 // backup.
                 if verbosity != VerbosityLevel::Quiet {
                     println!(
-                        "  {} Detected {} session(s) missing locally but present in sync repo — protected from deletion.",
-                        "⚠".yellow(),
-                        missing_in_repo.len()
+                        "  {} {}",
+                        icon("⚠").yellow(),
+                        t!("push.missing_protected", count = missing_in_repo.len())
                     );
                     println!(
-                        "    {} Use '{}' to recover them, or '{}' to force-delete.",
-                        "→".cyan(),
-                        format!("{} session restore", BINARY_NAME).cyan(),
-                        format!("{} push --prune", BINARY_NAME).cyan()
+                        "    {} {}",
+                        icon("→").cyan(),
+                        t!(
+                            "push.missing_protected_hint",
+                            restore = format!("{} session restore", BINARY_NAME).cyan(),
+                            prune = format!("{} push --prune", BINARY_NAME).cyan()
+                        )
                     );
                 }
                 log::info!(
@@ -743,18 +1305,22 @@ pub fn push_history(
         }
     }
 
+    timings.record("missing scan", phase_start);
+
     // ============================================================================
     // SYNC AUTO MEMORY DIRECTORIES
     // ============================================================================
+    let phase_start = Instant::now();
     if filter.auto_memory.enabled {
         if verbosity != VerbosityLevel::Quiet {
             println!();
-            println!("  {} auto memory directories...", "Syncing".cyan());
+            println!("  {}", t!("push.syncing_memory").cyan());
         }
 
         // project_dir_to_sync was built during session loop above.
         // NOTE: We cannot use extract_project_name() because it splits by '-'
         // and fails for project names containing hyphens (e.g. "claude-openclaw").
+        let memory_dir_name = filter.auto_memory.memory_dir_name.as_str();
         let mut synced_count = 0;
         // Collect local memory file names per sync project during copy,
         // so we can detect deletions without re-scanning directories.
@@ -763,12 +1329,12 @@ pub fn push_history(
             std::collections::HashSet<std::ffi::OsString>,
         > = HashMap::new();
         for (local_dir, sync_project) in &project_dir_to_sync {
-            let local_memory = local_dir.join("memory");
+            let local_memory = local_dir.join(memory_dir_name);
             if !local_memory.is_dir() {
                 continue;
             }
 
-            let dest_memory_dir = projects_dir.join(sync_project).join("memory");
+            let dest_memory_dir = projects_dir.join(sync_project).join(memory_dir_name);
 
             // Create destination directory
             if let Err(e) = fs::create_dir_all(&dest_memory_dir) {
@@ -800,8 +1366,8 @@ pub fn push_history(
             if verbosity == VerbosityLevel::Verbose {
                 println!(
                     "    {} {}",
-                    "→".cyan(),
-                    sync_project.join("memory").display()
+                    icon("→").cyan(),
+                    sync_project.join(memory_dir_name).display()
                 );
             }
         }
@@ -809,52 +1375,58 @@ pub fn push_history(
         if synced_count > 0 {
             if verbosity != VerbosityLevel::Quiet {
                 println!(
-                    "  {} Synced {} memory directories",
-                    "✓".green(),
-                    synced_count
+                    "  {} {}",
+                    icon("✓").green(),
+                    t!("push.memory_synced", count = synced_count)
                 );
             }
         } else if verbosity == VerbosityLevel::Verbose {
-            println!("  {} No memory directories found", "ℹ".dimmed());
+            println!("  {} {}", icon("ℹ").dimmed(), t!("push.no_memory_dirs"));
         }
 
-        // Remove remote memory files that no longer exist locally.
+        // Remove remote memory files that no longer exist locally
+        // (skipped with --no-delete).
         // local_memory_by_sync was populated during the copy phase above.
-        let mut deleted_memory_count = 0;
-        for (sync_project, local_files) in &local_memory_by_sync {
-            let remote_memory = projects_dir.join(sync_project).join("memory");
-            if !remote_memory.is_dir() {
-                continue;
-            }
+        if delete_missing {
+            let mut deleted_memory_count = 0;
+            for (sync_project, local_files) in &local_memory_by_sync {
+                let remote_memory = projects_dir.join(sync_project).join(memory_dir_name);
+                if !remote_memory.is_dir() {
+                    continue;
+                }
 
-            if let Ok(entries) = fs::read_dir(&remote_memory) {
-                for entry in entries.filter_map(|e| e.ok()) {
-                    if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
-                        let file_name = entry.file_name();
-                        if !local_files.contains(&file_name) {
-                            if let Err(e) = fs::remove_file(entry.path()) {
-                                log::warn!("Failed to remove deleted memory file: {}", e);
-                            } else {
-                                deleted_memory_count += 1;
+                if let Ok(entries) = fs::read_dir(&remote_memory) {
+                    for entry in entries.filter_map(|e| e.ok()) {
+                        if entry.file_type().map(|t| t.is_file()).unwrap_or(false) {
+                            let file_name = entry.file_name();
+                            if !local_files.contains(&file_name) {
+                                if let Err(e) = fs::remove_file(entry.path()) {
+                                    log::warn!("Failed to remove deleted memory file: {}", e);
+                                } else {
+                                    deleted_memory_count += 1;
+                                }
                             }
                         }
                     }
                 }
             }
-        }
 
-        if deleted_memory_count > 0 && verbosity != VerbosityLevel::Quiet {
-            println!(
-                "  {} Removed {} deleted memory files from sync repo",
-                "✓".green(),
-                deleted_memory_count
-            );
+            if deleted_memory_count > 0 && verbosity != VerbosityLevel::Quiet {
+                println!(
+                    "  {} {}",
+                    icon("✓").green(),
+                    t!("push.memory_removed", count = deleted_memory_count)
+                );
+            }
         }
     }
 
+    timings.record("memory sync", phase_start);
+
     // ============================================================================
     // COMMIT AND PUSH CHANGES
     // ============================================================================
+    let phase_start = Instant::now();
     repo.stage_all()?;
 
     let has_changes = repo.has_changes()?;
@@ -868,28 +1440,47 @@ pub fn push_history(
 
         if let Some(ref hash) = commit_before_push {
             if verbosity != VerbosityLevel::Quiet {
-                println!("  {} Recorded commit {} for undo", "✓".green(), &hash[..8]);
+                println!(
+                    "  {} {}",
+                    icon("✓").green(),
+                    t!("push.recorded_commit", hash = &hash[..8])
+                );
             }
         } else if verbosity != VerbosityLevel::Quiet {
-            println!(
-                "  {} First push - no previous commit to undo to",
-                "ℹ".cyan()
-            );
+            println!("  {} {}", icon("ℹ").cyan(), t!("push.first_push"));
         }
 
-        let default_message = format!(
-            "Sync {} sessions at {}",
-            sessions.len(),
-            chrono::Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
-        );
+        let now = chrono::Utc::now();
+        let default_message = match filter.commit_message_template.as_deref() {
+            Some(template) if !template.trim().is_empty() => render_commit_message(
+                template,
+                &CommitMessageStats {
+                    count: sessions.len(),
+                    added: added_count,
+                    modified: modified_count,
+                    deleted: deleted_from_repo,
+                },
+                &filter.config_sync.get_device_name(),
+                &now.format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            ),
+            _ => format!(
+                "Sync {} sessions at {}",
+                sessions.len(),
+                now.format("%Y-%m-%d %H:%M:%S UTC")
+            ),
+        };
         let message = commit_message.unwrap_or(&default_message);
 
         if verbosity != VerbosityLevel::Quiet {
-            println!("  {} changes...", "Committing".cyan());
+            println!("  {}", t!("push.committing").cyan());
         }
         repo.commit(message)?;
         if verbosity != VerbosityLevel::Quiet {
-            println!("  {} Committed: {}", "✓".green(), message);
+            println!(
+                "  {} {}",
+                icon("✓").green(),
+                t!("push.committed", message = message)
+            );
         }
 
         // Track whether push failed so we can propagate the error
@@ -899,7 +1490,7 @@ pub fn push_history(
         // Push to remote if configured
         if push_remote && state.has_remote {
             if verbosity != VerbosityLevel::Quiet {
-                println!("  {} to remote...", "Pushing".cyan());
+                println!("  {}", t!("push.pushing_remote").cyan());
             }
 
             let repo_path = state.sync_repo_path.clone();
@@ -908,33 +1499,45 @@ pub fn push_history(
                 &repo_path,
                 &mut state,
                 &branch_name,
+                filter.push_attempts,
                 verbosity,
             ) {
                 Ok(PushResult::Clean) => {
                     if verbosity != VerbosityLevel::Quiet {
-                        println!("  {} Pushed to origin/{}", "✓".green(), branch_name);
+                        println!(
+                            "  {} {}",
+                            icon("✓").green(),
+                            t!("push.pushed_to", branch = branch_name)
+                        );
                     }
                 }
                 Ok(PushResult::Degraded { conflicts }) => {
                     if verbosity != VerbosityLevel::Quiet {
                         println!(
-                            "  {} Push degraded; kept {} conflict file(s)",
-                            "⚠".yellow(),
-                            conflicts.len()
+                            "  {} {}",
+                            icon("⚠").yellow(),
+                            t!("push.degraded", count = conflicts.len())
                         );
                     }
                 }
                 Ok(PushResult::NothingToPush) => {}
                 Err(e) => {
-                    log::warn!("Failed to push: {}", e);
-                    if verbosity != VerbosityLevel::Quiet {
-                        println!("  {} Failed to push: {}", "⚠".yellow(), e);
-                    }
+                    log::warn!("Failed to push: {:#}", e);
+                    // Shown even with --quiet: the commit is local only and
+                    // the remote is now behind until the next successful push
+                    eprintln!(
+                        "  {} {}",
+                        icon("✗").red().bold(),
+                        t!("push.push_failed", error = format!("{:#}", e)).red()
+                    );
+                    eprintln!("  {}", t!("push.remote_behind").yellow());
                     push_error = Some(e);
                 }
             }
         }
 
+        timings.record("commit/push", phase_start);
+
         // ============================================================================
         // CREATE AND SAVE OPERATION RECORD
         // ============================================================================
@@ -966,36 +1569,58 @@ pub fn push_history(
         // If push failed, propagate the error so the process exits with non-zero code.
         // The operation record is already saved above, preserving undo capability.
         if let Some(e) = push_error {
+            if verbosity == VerbosityLevel::Verbose {
+                timings.print();
+            }
             return Err(e);
         }
     } else if verbosity != VerbosityLevel::Quiet {
-        println!("  {} No changes to commit", "Note:".yellow());
+        println!("  {} {}", t!("common.note").yellow(), t!("push.no_changes"));
+    }
+
+    for project in &skipped_dirs {
+        if let Some(hashes) = state.pushed_hashes.remove(project) {
+            pushed_hashes
+                .entry(project.clone())
+                .or_default()
+                .extend(hashes);
+        }
+    }
+    state.pushed_hashes = pushed_hashes;
+    state.last_push_at = next_last_push_at(state.last_push_at, push_started, &skipped_dirs);
+    if let Err(e) = state.save() {
+        log::warn!("Failed to save pushed session hashes: {}", e);
     }
 
     // ============================================================================
     // DISPLAY SUMMARY TO USER
     // ============================================================================
     if verbosity != VerbosityLevel::Quiet {
-        println!("\n{}", "=== Push Summary ===".bold().cyan());
+        println!(
+            "\n{}",
+            format!("=== {} ===", t!("push.summary_heading"))
+                .bold()
+                .cyan()
+        );
 
         // Show operation statistics
         let stats_msg = if deleted_from_repo > 0 {
-            format!(
-                "  {} Added    {} Modified    {} Deleted    {} Unchanged",
-                format!("{added_count}").green(),
-                format!("{modified_count}").cyan(),
-                format!("{deleted_from_repo}").red(),
-                format!("{unchanged_count}").dimmed(),
+            t!(
+                "push.stats_with_deleted",
+                added = added_count.to_string().green(),
+                modified = modified_count.to_string().cyan(),
+                deleted = deleted_from_repo.to_string().red(),
+                unchanged = unchanged_count.to_string().dimmed()
             )
         } else {
-            format!(
-                "  {} Added    {} Modified    {} Unchanged",
-                format!("{added_count}").green(),
-                format!("{modified_count}").cyan(),
-                format!("{unchanged_count}").dimmed(),
+            t!(
+                "push.stats",
+                added = added_count.to_string().green(),
+                modified = modified_count.to_string().cyan(),
+                unchanged = unchanged_count.to_string().dimmed()
             )
         };
-        println!("{stats_msg}");
+        println!("  {stats_msg}");
         println!();
 
         // Group conversations by project (top-level directory)
@@ -1017,14 +1642,14 @@ pub fn push_history(
 
         // Display conversations grouped by project
         if !by_project.is_empty() {
-            println!("{}", "Pushed Conversations:".bold());
+            println!("{}", t!("push.pushed_conversations").bold());
 
             let mut projects: Vec<_> = by_project.keys().collect();
             projects.sort();
 
             for project in projects {
                 let conversations = &by_project[project];
-                println!("\n  {} {}/", "Project:".bold(), project.cyan());
+                println!("\n  {} {}/", t!("common.project").bold(), project.cyan());
 
                 for conv in conversations.iter().take(MAX_CONVERSATIONS_TO_DISPLAY) {
                     let operation_str = match conv.operation {
@@ -1054,15 +1679,22 @@ pub fn push_history(
 
                 if conversations.len() > MAX_CONVERSATIONS_TO_DISPLAY {
                     println!(
-                        "    {} ... and {} more conversations",
+                        "    {} {}",
                         "...".dimmed(),
-                        conversations.len() - MAX_CONVERSATIONS_TO_DISPLAY
+                        t!(
+                            "push.more_conversations",
+                            count = conversations.len() - MAX_CONVERSATIONS_TO_DISPLAY
+                        )
                     );
                 }
             }
         }
 
-        println!("\n{}", "Push complete!".green().bold());
+        println!("\n{}", t!("push.complete").green().bold());
+    }
+
+    if verbosity == VerbosityLevel::Verbose {
+        timings.print();
     }
 
     // Clean up old snapshots automatically
@@ -1117,6 +1749,14 @@ mod push_auto_heal_tests {
         ));
     }
 
+    #[test]
+    fn test_retry_backoff_doubles_and_caps() {
+        assert_eq!(retry_backoff(1), Duration::from_secs(1));
+        assert_eq!(retry_backoff(2), Duration::from_secs(2));
+        assert_eq!(retry_backoff(3), Duration::from_secs(4));
+        assert_eq!(retry_backoff(20), Duration::from_secs(30));
+    }
+
     #[test]
     fn test_find_rebase_conflict_files_detects_markers() {
         let dir = tempfile::TempDir::new().unwrap();
@@ -1186,7 +1826,10 @@ mod push_auto_heal_tests {
 
     #[test]
     fn test_decide_missing_action_manual_prune_wins_over_window() {
-        assert_eq!(decide_missing_action(true, None), MissingAction::PruneManual);
+        assert_eq!(
+            decide_missing_action(true, None),
+            MissingAction::PruneManual
+        );
         assert_eq!(
             decide_missing_action(true, Some(600)),
             MissingAction::PruneManual
@@ -1204,4 +1847,102 @@ mod push_auto_heal_tests {
             MissingAction::PruneUnlock(0)
         );
     }
+
+    #[test]
+    fn test_changed_since_uses_mtime_and_latest_entry() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("s.jsonl");
+        fs::write(
+            &path,
+            r#"{"type":"user","sessionId":"s","timestamp":"2020-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        let session = crate::parser::ConversationSession::from_file(&path).unwrap();
+
+        let now = Utc::now();
+        assert!(changed_since(&session, now - chrono::Duration::hours(1)));
+        assert!(!changed_since(&session, now + chrono::Duration::hours(1)));
+
+        let future = (now + chrono::Duration::days(2)).to_rfc3339();
+        fs::write(
+            &path,
+            format!(r#"{{"type":"user","sessionId":"s","timestamp":"{future}"}}"#),
+        )
+        .unwrap();
+        let session = crate::parser::ConversationSession::from_file(&path).unwrap();
+        assert!(changed_since(&session, now + chrono::Duration::days(1)));
+    }
+
+    #[test]
+    fn test_since_last_after_excluding_a_project() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("s.jsonl");
+        fs::write(
+            &path,
+            r#"{"type":"user","sessionId":"s","timestamp":"2020-01-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        let session = crate::parser::ConversationSession::from_file(&path).unwrap();
+
+        // The session was edited after the previous push; this push starts
+        // later but leaves its project out with --exclude-project
+        let now = Utc::now();
+        let previous = Some(now - chrono::Duration::hours(1));
+        let started = now + chrono::Duration::minutes(1);
+        let skipped = BTreeSet::from(["-work-p".to_string()]);
+
+        let after_exclude = next_last_push_at(previous, started, &skipped);
+        assert_eq!(after_exclude, previous);
+        assert!(changed_since(&session, after_exclude.unwrap()));
+
+        let after_full = next_last_push_at(previous, started, &BTreeSet::new());
+        assert_eq!(after_full, Some(started));
+        assert!(!changed_since(&session, after_full.unwrap()));
+    }
+
+    #[test]
+    fn test_project_skip_exclude_wins_over_include() {
+        let list = |names: &[&str]| names.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        let include = list(&["app", "lib"]);
+        let exclude = list(&["lib"]);
+
+        assert_eq!(project_skip(Some("app"), &include, &exclude), None);
+        assert_eq!(
+            project_skip(Some("lib"), &include, &exclude),
+            Some(ProjectSkip::Excluded)
+        );
+        assert_eq!(
+            project_skip(Some("other"), &include, &exclude),
+            Some(ProjectSkip::NotIncluded)
+        );
+        assert_eq!(
+            project_skip(None, &include, &[]),
+            Some(ProjectSkip::NotIncluded)
+        );
+        assert_eq!(project_skip(None, &[], &exclude), None);
+        assert_eq!(project_skip(Some("other"), &[], &exclude), None);
+    }
+
+    #[test]
+    fn test_render_commit_message_expands_placeholders() {
+        let stats = CommitMessageStats {
+            count: 12,
+            added: 3,
+            modified: 4,
+            deleted: 1,
+        };
+        assert_eq!(
+            render_commit_message(
+                "[{device}] {count} sessions (+{added} ~{modified} -{deleted}) at {date}",
+                &stats,
+                "laptop",
+                "2026-01-02 03:04:05 UTC"
+            ),
+            "[laptop] 12 sessions (+3 ~4 -1) at 2026-01-02 03:04:05 UTC"
+        );
+        assert_eq!(
+            render_commit_message("sync {unknown}", &stats, "laptop", "now"),
+            "sync {unknown}"
+        );
+    }
 }
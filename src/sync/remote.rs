@@ -1,7 +1,8 @@
 use anyhow::{anyhow, Context, Result};
 use colored::Colorize;
 
-use crate::scm;
+use crate::filter::FilterConfig;
+use crate::output::icon;
 use crate::BINARY_NAME;
 
 use super::state::SyncState;
@@ -9,7 +10,7 @@ use super::state::SyncState;
 /// Show current remote configuration
 pub fn show_remote() -> Result<()> {
     let state = SyncState::load()?;
-    let repo = scm::open(&state.sync_repo_path)?;
+    let repo = super::open_sync_repo(&state.sync_repo_path, &FilterConfig::load()?)?;
 
     println!("{}", "=== SCM Remote Configuration ===".bold().cyan());
     println!();
@@ -62,7 +63,7 @@ pub fn show_remote() -> Result<()> {
 /// Set or update remote URL
 pub fn set_remote(name: &str, url: &str) -> Result<()> {
     let state = SyncState::load()?;
-    let repo = scm::open(&state.sync_repo_path)?;
+    let repo = super::open_sync_repo(&state.sync_repo_path, &FilterConfig::load()?)?;
 
     // Validate URL format
     if !url.starts_with("http://")
@@ -91,7 +92,7 @@ pub fn set_remote(name: &str, url: &str) -> Result<()> {
 
         println!(
             "{} Updated remote '{}' to: {}",
-            "✓".green().bold(),
+            icon("✓").green().bold(),
             name.cyan(),
             url
         );
@@ -102,7 +103,7 @@ pub fn set_remote(name: &str, url: &str) -> Result<()> {
 
         println!(
             "{} Created remote '{}': {}",
-            "✓".green().bold(),
+            icon("✓").green().bold(),
             name.cyan(),
             url
         );
@@ -123,7 +124,7 @@ pub fn set_remote(name: &str, url: &str) -> Result<()> {
 /// Remove a remote
 pub fn remove_remote(name: &str) -> Result<()> {
     let state = SyncState::load()?;
-    let repo = scm::open(&state.sync_repo_path)?;
+    let repo = super::open_sync_repo(&state.sync_repo_path, &FilterConfig::load()?)?;
 
     // Check if remote exists
     if !repo.has_remote(name) {
@@ -134,7 +135,11 @@ pub fn remove_remote(name: &str) -> Result<()> {
     repo.remove_remote(name)
         .with_context(|| format!("Failed to remove remote '{name}'"))?;
 
-    println!("{} Removed remote '{}'", "✓".green().bold(), name.cyan());
+    println!(
+        "{} Removed remote '{}'",
+        icon("✓").green().bold(),
+        name.cyan()
+    );
 
     // Update state if this was the origin remote
     if name == "origin" {
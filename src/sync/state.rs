@@ -1,10 +1,36 @@
 use anyhow::{anyhow, Context, Result};
-use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
 
+use crate::error::SyncError;
 use crate::BINARY_NAME;
 
+/// Set by the global `--repo` flag
+static REPO_OVERRIDE: OnceLock<String> = OnceLock::new();
+
+/// Use the repository named `name` instead of the active one for this
+/// invocation (global `--repo`), without changing the active repository
+pub fn set_repo_override(name: String) {
+    let _ = REPO_OVERRIDE.set(name);
+}
+
+/// The `--repo` override, if one was given
+fn repo_override() -> Option<&'static str> {
+    REPO_OVERRIDE.get().map(String::as_str)
+}
+
+fn repo_not_found(name: &str) -> anyhow::Error {
+    anyhow!(
+        "Repository '{}' not found. Run '{} repo list' to see configured repositories.",
+        name,
+        BINARY_NAME
+    )
+}
+
 /// Sync state and configuration
 ///
 /// This struct stores the persistent state of the Claude Code sync system.
@@ -46,6 +72,20 @@ pub struct SyncState {
     /// Last commit hash that was synced, used for incremental push detection
     #[serde(default)]
     pub last_synced_commit: Option<String>,
+
+    /// Content hashes of the sessions written by the last push, keyed by
+    /// local project directory name
+    ///
+    /// `ccs status` compares local sessions against these to show which
+    /// projects have changes that have not been pushed yet.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pushed_hashes: BTreeMap<String, BTreeSet<String>>,
+
+    /// When the last successful push started
+    ///
+    /// `ccs push --since-last` skips sessions that have not changed since.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_push_at: Option<DateTime<Utc>>,
 }
 
 impl SyncState {
@@ -88,15 +128,13 @@ impl SyncState {
     /// Loads the sync state from the user's configuration directory.
     ///
     /// This is a compatibility wrapper that supports both v1 (SyncState) and v2 (MultiRepoState)
-    /// formats. For v2 format, it returns the active repository's state.
+    /// formats. For v2 format, it returns the active repository's state, or the one
+    /// named by `--repo`.
     pub fn load() -> Result<Self> {
         let state_path = Self::state_file_path()?;
 
         if !state_path.exists() {
-            return Err(anyhow!(
-                "Sync not initialized. Run '{} init' first.",
-                BINARY_NAME
-            ));
+            return Err(SyncError::NotInitialized.into());
         }
 
         let content = fs::read_to_string(&state_path).context("Failed to read sync state")?;
@@ -104,33 +142,61 @@ impl SyncState {
         // Try v2 format first (MultiRepoState)
         if let Ok(multi_state) = serde_json::from_str::<MultiRepoState>(&content) {
             if multi_state.version >= 2 {
-                // Get active repo and convert to SyncState
-                if let Some(active) = multi_state.repos.get(&multi_state.active_repo) {
-                    return Ok(SyncState {
-                        sync_repo_path: active.sync_repo_path.clone(),
-                        has_remote: active.has_remote,
-                        is_cloned_repo: active.is_cloned_repo,
-                        last_synced_commit: None,
-                    });
-                } else {
-                    return Err(anyhow!(
-                        "Active repository '{}' not found in state",
-                        multi_state.active_repo
-                    ));
-                }
+                // Get the selected repo and convert to SyncState
+                let selected = multi_state.selected()?;
+                return Ok(SyncState {
+                    sync_repo_path: selected.sync_repo_path.clone(),
+                    has_remote: selected.has_remote,
+                    is_cloned_repo: selected.is_cloned_repo,
+                    last_synced_commit: selected.last_synced_commit.clone(),
+                    pushed_hashes: selected.pushed_hashes.clone(),
+                    last_push_at: selected.last_push_at,
+                });
             }
         }
 
+        // A v1 state file holds a single unnamed repository
+        if let Some(name) = repo_override() {
+            return Err(repo_not_found(name));
+        }
+
         // Fall back to v1 format (direct SyncState)
         let state: SyncState =
-            serde_json::from_str(&content).context("Failed to parse sync state")?;
+            serde_json::from_str(&content).map_err(|e| SyncError::ConfigParse {
+                path: state_path.clone(),
+                message: e.to_string(),
+            })?;
 
         Ok(state)
     }
 
+    /// Persists the sync state.
+    ///
+    /// When the state file is in v2 (multi-repo) format, only the active
+    /// repository entry (or the one named by `--repo`) is updated so other
+    /// configured repos are preserved.
     pub(crate) fn save(&self) -> Result<()> {
         let state_path = Self::state_file_path()?;
 
+        if let Ok(content) = fs::read_to_string(&state_path) {
+            if let Ok(mut multi_state) = serde_json::from_str::<MultiRepoState>(&content) {
+                if multi_state.version >= 2 {
+                    let name = repo_override()
+                        .unwrap_or(&multi_state.active_repo)
+                        .to_string();
+                    if let Some(selected) = multi_state.repos.get_mut(&name) {
+                        selected.sync_repo_path = self.sync_repo_path.clone();
+                        selected.has_remote = self.has_remote;
+                        selected.is_cloned_repo = self.is_cloned_repo;
+                        selected.last_synced_commit = self.last_synced_commit.clone();
+                        selected.pushed_hashes = self.pushed_hashes.clone();
+                        selected.last_push_at = self.last_push_at;
+                        return multi_state.save();
+                    }
+                }
+            }
+        }
+
         if let Some(parent) = state_path.parent() {
             fs::create_dir_all(parent)?;
         }
@@ -138,7 +204,7 @@ impl SyncState {
         let content =
             serde_json::to_string_pretty(self).context("Failed to serialize sync state")?;
 
-        fs::write(&state_path, content).context("Failed to write sync state")?;
+        write_atomic(&state_path, &content).context("Failed to write sync state")?;
 
         Ok(())
     }
@@ -148,6 +214,28 @@ impl SyncState {
     }
 }
 
+/// Replace `path` with `content` so readers see either the old or the new
+/// file, never a partial write: the content goes to a temporary file in the
+/// same directory, is flushed to disk, then renamed over `path`.
+fn write_atomic(path: &Path, content: &str) -> std::io::Result<()> {
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let tmp_path = path.with_file_name(format!(".{}.tmp.{}", file_name, std::process::id()));
+
+    let result = fs::File::create(&tmp_path)
+        .and_then(|mut file| {
+            file.write_all(content.as_bytes())?;
+            file.sync_all()
+        })
+        .and_then(|()| fs::rename(&tmp_path, path));
+    if result.is_err() {
+        let _ = fs::remove_file(&tmp_path);
+    }
+    result
+}
+
 /// Individual repository configuration
 ///
 /// Represents a single sync repository with all its settings.
@@ -174,6 +262,18 @@ pub struct RepoConfig {
     /// Description for the repo (optional)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+
+    /// Last commit hash that was synced from this repo
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_synced_commit: Option<String>,
+
+    /// Content hashes of the sessions pushed to this repo, by local project
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub pushed_hashes: BTreeMap<String, BTreeSet<String>>,
+
+    /// When the last successful push to this repo started
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_push_at: Option<DateTime<Utc>>,
 }
 
 /// Multi-repo sync state (v2 format)
@@ -210,15 +310,31 @@ impl MultiRepoState {
         self.repos.get_mut(&self.active_repo)
     }
 
+    /// The repository commands operate on: the one named by `--repo`, or
+    /// the active one
+    pub fn selected(&self) -> Result<&RepoConfig> {
+        self.resolve(repo_override())
+    }
+
+    /// The repository called `name`, or the active one when `name` is `None`
+    fn resolve(&self, name: Option<&str>) -> Result<&RepoConfig> {
+        match name {
+            Some(name) => self.repos.get(name).ok_or_else(|| repo_not_found(name)),
+            None => self.active().ok_or_else(|| {
+                anyhow!(
+                    "Active repository '{}' not found in state",
+                    self.active_repo
+                )
+            }),
+        }
+    }
+
     /// Load the multi-repo state, with automatic migration from v1 format
     pub fn load() -> Result<Self> {
         let state_path = SyncState::state_file_path()?;
 
         if !state_path.exists() {
-            return Err(anyhow!(
-                "Sync not initialized. Run '{} init' first.",
-                BINARY_NAME
-            ));
+            return Err(SyncError::NotInitialized.into());
         }
 
         let content = fs::read_to_string(&state_path).context("Failed to read sync state")?;
@@ -231,8 +347,11 @@ impl MultiRepoState {
         }
 
         // Fall back to v1 format and migrate
-        let legacy: SyncState = serde_json::from_str(&content)
-            .context("Failed to parse sync state (neither v1 nor v2 format)")?;
+        let legacy: SyncState =
+            serde_json::from_str(&content).map_err(|e| SyncError::ConfigParse {
+                path: state_path.clone(),
+                message: format!("neither v1 nor v2 format: {e}"),
+            })?;
 
         let migrated = Self::migrate_from_v1(legacy)?;
 
@@ -255,6 +374,9 @@ impl MultiRepoState {
             is_cloned_repo: legacy.is_cloned_repo,
             remote_url: None,
             description: Some("Migrated from single-repo configuration".to_string()),
+            last_synced_commit: legacy.last_synced_commit,
+            pushed_hashes: legacy.pushed_hashes,
+            last_push_at: legacy.last_push_at,
         };
 
         let mut repos = HashMap::new();
@@ -278,7 +400,7 @@ impl MultiRepoState {
         let content =
             serde_json::to_string_pretty(self).context("Failed to serialize multi-repo state")?;
 
-        fs::write(&state_path, content).context("Failed to write multi-repo state")?;
+        write_atomic(&state_path, &content).context("Failed to write multi-repo state")?;
 
         Ok(())
     }
@@ -320,16 +442,82 @@ impl MultiRepoState {
         Ok(())
     }
 
-    /// Get list of all repo names
+    /// Get list of all repo names, sorted alphabetically
     pub fn repo_names(&self) -> Vec<&String> {
-        self.repos.keys().collect()
+        let mut names: Vec<&String> = self.repos.keys().collect();
+        names.sort();
+        names
+    }
+}
+
+/// Return the parse error if `content` is neither a v2 nor a v1 state file.
+///
+/// Used to tell a corrupted `state.json` (partial write, bad manual edit)
+/// apart from a missing one.
+pub fn state_parse_error(content: &str) -> Option<serde_json::Error> {
+    if let Ok(state) = serde_json::from_str::<MultiRepoState>(content) {
+        if state.version >= 2 {
+            return None;
+        }
     }
+    serde_json::from_str::<SyncState>(content).err()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_named_or_active_repo() {
+        let repo = |name: &str| RepoConfig {
+            name: name.to_string(),
+            sync_repo_path: PathBuf::from(format!("/tmp/{name}")),
+            has_remote: false,
+            is_cloned_repo: false,
+            remote_url: None,
+            description: None,
+            last_synced_commit: None,
+            pushed_hashes: Default::default(),
+            last_push_at: None,
+        };
+        let state = MultiRepoState {
+            version: 2,
+            active_repo: "personal".to_string(),
+            repos: HashMap::from([
+                ("personal".to_string(), repo("personal")),
+                ("work".to_string(), repo("work")),
+            ]),
+        };
+
+        assert_eq!(state.resolve(None).unwrap().name, "personal");
+        assert_eq!(state.resolve(Some("work")).unwrap().name, "work");
+        let err = state.resolve(Some("missing")).unwrap_err().to_string();
+        assert!(err.contains("Repository 'missing' not found"));
+    }
+
+    #[test]
+    fn test_state_parse_error() {
+        let v1 = r#"{"sync_repo_path": "/tmp/repo", "has_remote": false}"#;
+        let v2 = r#"{"version": 2, "active_repo": "default", "repos": {}}"#;
+        assert!(state_parse_error(v1).is_none());
+        assert!(state_parse_error(v2).is_none());
+        assert!(state_parse_error(r#"{"sync_repo_path": "/tmp/re"#).is_some());
+        assert!(state_parse_error("").is_some());
+    }
+
+    #[test]
+    fn test_write_atomic_replaces_file_without_leftovers() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let path = temp.path().join("state.json");
+        fs::write(&path, "old").unwrap();
+
+        write_atomic(&path, "new").unwrap();
+
+        assert_eq!(fs::read_to_string(&path).unwrap(), "new");
+        let entries: Vec<_> = fs::read_dir(temp.path()).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+    }
+
     #[test]
     fn test_sync_state_deserializes_without_last_synced_commit() {
         let json = r#"{
@@ -340,4 +528,60 @@ mod tests {
         let state: SyncState = serde_json::from_str(json).unwrap();
         assert_eq!(state.last_synced_commit, None);
     }
+
+    #[test]
+    #[serial_test::serial]
+    fn test_sync_state_save_preserves_other_repos() {
+        let temp = tempfile::TempDir::new().unwrap();
+        let saved = std::env::var(crate::config::CONFIG_DIR_ENV).ok();
+        std::env::set_var(crate::config::CONFIG_DIR_ENV, temp.path());
+
+        let repo = |name: &str| RepoConfig {
+            name: name.to_string(),
+            sync_repo_path: temp.path().join(name),
+            has_remote: false,
+            is_cloned_repo: false,
+            remote_url: None,
+            description: None,
+            last_synced_commit: None,
+            pushed_hashes: Default::default(),
+            last_push_at: None,
+        };
+        let mut multi = MultiRepoState {
+            version: 2,
+            active_repo: "work".to_string(),
+            repos: HashMap::new(),
+        };
+        multi.add_repo(repo("work")).unwrap();
+        multi.add_repo(repo("personal")).unwrap();
+        multi.save().unwrap();
+
+        let mut state = SyncState::load().unwrap();
+        assert_eq!(state.sync_repo_path, temp.path().join("work"));
+        state.has_remote = true;
+        state.last_synced_commit = Some("abc123".to_string());
+        state
+            .pushed_hashes
+            .entry("-tmp-p".to_string())
+            .or_default()
+            .insert("deadbeef".to_string());
+        state.save().unwrap();
+
+        let reloaded = MultiRepoState::load().unwrap();
+        assert_eq!(reloaded.repo_names(), vec!["personal", "work"]);
+        let work = reloaded.active().unwrap();
+        assert!(work.has_remote);
+        assert_eq!(work.last_synced_commit.as_deref(), Some("abc123"));
+        assert!(work.pushed_hashes["-tmp-p"].contains("deadbeef"));
+        assert!(reloaded.repos["personal"].pushed_hashes.is_empty());
+        assert_eq!(
+            SyncState::load().unwrap().last_synced_commit.as_deref(),
+            Some("abc123")
+        );
+
+        match saved {
+            Some(v) => std::env::set_var(crate::config::CONFIG_DIR_ENV, v),
+            None => std::env::remove_var(crate::config::CONFIG_DIR_ENV),
+        }
+    }
 }
@@ -1,103 +1,200 @@
 use anyhow::Result;
+use chrono::{DateTime, Utc};
 use colored::Colorize;
-use std::path::Path;
+use serde_json::json;
+use std::collections::{BTreeMap, BTreeSet};
+use std::io::Write;
+use std::path::{Path, PathBuf};
 
 use crate::config::ConfigManager;
 use crate::filter::FilterConfig;
+use crate::handlers::config_sync::{is_device_stale, list_device_sync_times};
+use crate::lang::t;
+use crate::output::icon;
+use crate::parser::ConversationSession;
 use crate::scm;
 
-use super::discovery::{claude_projects_dir, discover_sessions};
+use super::discovery::{claude_projects_dir, discover_sessions, project_dir_name};
 use super::state::SyncState;
 
+/// Session counts of one local project, compared with the last push
+#[derive(Debug, Default, PartialEq, Eq)]
+struct ProjectPushStatus {
+    sessions: usize,
+    /// Sessions that are new or changed since the last push
+    unpushed: usize,
+}
+
+/// Count, per local project directory, the sessions whose content hash is
+/// not among the hashes recorded by the last push
+fn project_push_status(
+    claude_dir: &Path,
+    sessions: &[ConversationSession],
+    pushed_hashes: &BTreeMap<String, BTreeSet<String>>,
+) -> BTreeMap<String, ProjectPushStatus> {
+    let mut projects: BTreeMap<String, ProjectPushStatus> = BTreeMap::new();
+    for session in sessions {
+        let Some(project) = project_dir_name(claude_dir, Path::new(&session.file_path)) else {
+            continue;
+        };
+        let pushed = pushed_hashes
+            .get(&project)
+            .is_some_and(|hashes| hashes.contains(&session.content_hash()));
+        let entry = projects.entry(project).or_default();
+        entry.sessions += 1;
+        if !pushed {
+            entry.unpushed += 1;
+        }
+    }
+    projects
+}
+
+/// Version of the `--porcelain` format, printed as its first line; bumped
+/// only if existing keys change meaning
+const PORCELAIN_VERSION: u32 = 1;
+
 /// Show sync status
-pub fn show_status(show_conflicts: bool, show_files: bool) -> Result<()> {
+///
+/// With `show_projects`, every local project is listed with its number of
+/// sessions that changed since the last push.
+pub fn show_status(
+    show_conflicts: bool,
+    show_files: bool,
+    show_projects: bool,
+    json: bool,
+    porcelain: bool,
+    out: &mut dyn Write,
+) -> Result<()> {
     let state = SyncState::load()?;
-    let repo = scm::open(&state.sync_repo_path)?;
     let filter = FilterConfig::load()?;
+    let repo = super::open_sync_repo(&state.sync_repo_path, &filter)?;
     let claude_dir = claude_projects_dir()?;
 
-    println!("{}", "=== Claude Code Sync Status ===".bold().cyan());
-    println!();
+    if json {
+        let snapshot = collect_status(&state, repo.as_ref(), &filter, &claude_dir)?;
+        return print_status_json(&snapshot, &filter, out);
+    }
+    if porcelain {
+        let snapshot = collect_status(&state, repo.as_ref(), &filter, &claude_dir)?;
+        return print_status_porcelain(&snapshot, &filter, out);
+    }
+
+    writeln!(out, "{}", "=== Claude Code Sync Status ===".bold().cyan())?;
+    writeln!(out)?;
 
     // Installation info
-    println!("{}", "安装信息:".bold());
+    writeln!(out, "{}", "安装信息:".bold())?;
     if let Ok(exe_path) = std::env::current_exe() {
-        println!("  二进制: {}", exe_path.display().to_string().dimmed());
+        writeln!(out, "  二进制: {}", exe_path.display().to_string().dimmed())?;
     }
     if let Ok(config_dir) = ConfigManager::config_dir() {
-        println!("  配置目录: {}", config_dir.display().to_string().dimmed());
+        writeln!(
+            out,
+            "  配置目录: {}",
+            config_dir.display().to_string().dimmed()
+        )?;
     }
-    println!();
+    writeln!(out)?;
 
     // Claude Code info
-    println!("{}", "Claude Code:".bold());
+    writeln!(out, "{}", "Claude Code:".bold())?;
     if let Some(parent) = claude_dir.parent() {
-        println!("  目录: {}", parent.display().to_string().dimmed());
+        writeln!(out, "  目录: {}", parent.display().to_string().dimmed())?;
     }
-    println!();
+    writeln!(out)?;
 
     // Repository info
-    println!("{}", "同步仓库:".bold());
-    println!("  本地路径: {}", state.sync_repo_path.display());
+    writeln!(out, "{}", "同步仓库:".bold())?;
+    writeln!(out, "  本地路径: {}", state.sync_repo_path.display())?;
     let backend = scm::detect_backend(&state.sync_repo_path)
         .map(|b| format!("{:?}", b))
         .unwrap_or_else(|| "Unknown".to_string());
-    println!("  后端: {}", backend);
+    writeln!(out, "  后端: {}", backend)?;
 
     // Show remote URL if configured
     if state.has_remote {
         if let Ok(remote_url) = repo.get_remote_url("origin") {
-            println!("  远程仓库: {}", remote_url.cyan());
+            writeln!(out, "  远程仓库: {}", remote_url.cyan())?;
         } else {
-            println!("  远程仓库: {}", "已配置".green());
+            writeln!(out, "  远程仓库: {}", "已配置".green())?;
         }
     } else {
-        println!("  远程仓库: {}", "未配置".yellow());
+        writeln!(out, "  远程仓库: {}", "未配置".yellow())?;
     }
 
     if let Ok(branch) = repo.current_branch() {
-        println!("  分支: {}", branch.cyan());
+        writeln!(out, "  分支: {}", branch.cyan())?;
     }
 
     if let Ok(has_changes) = repo.has_changes() {
-        println!(
+        writeln!(
+            out,
             "  未提交变更: {}",
             if has_changes {
                 "是".yellow()
             } else {
                 "否".green()
             }
-        );
+        )?;
     }
 
     // Session counts
-    println!();
-    println!("{}", "对话历史:".bold());
+    writeln!(out)?;
+    writeln!(out, "{}", "对话历史:".bold())?;
     let local_sessions = discover_sessions(&claude_dir, &filter)?;
-    println!("  本地: {} 个会话", local_sessions.len().to_string().cyan());
+    writeln!(
+        out,
+        "  本地: {} 个会话",
+        local_sessions.len().to_string().cyan()
+    )?;
 
-    let remote_projects_dir = state.sync_repo_path.join(&filter.sync_subdirectory);
+    // Hashes are only recorded by pushes made since this was introduced, so
+    // without any there is nothing meaningful to compare against
+    let projects = project_push_status(&claude_dir, &local_sessions, &state.pushed_hashes);
+    let tracked = !state.pushed_hashes.is_empty();
+    if tracked {
+        let (dirty_projects, unpushed) = projects
+            .values()
+            .filter(|p| p.unpushed > 0)
+            .fold((0, 0), |(n, total), p| (n + 1, total + p.unpushed));
+        if unpushed > 0 {
+            writeln!(
+                out,
+                "  未推送: {} 个项目中的 {} 个会话",
+                dirty_projects.to_string().yellow(),
+                unpushed.to_string().yellow()
+            )?;
+        } else {
+            writeln!(out, "  未推送: {}", "无".green())?;
+        }
+    } else {
+        writeln!(out, "  未推送: {}", "未知（下次 push 后开始记录）".dimmed())?;
+    }
+
+    let remote_projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
     if remote_projects_dir.exists() {
         let remote_sessions = discover_sessions(&remote_projects_dir, &filter)?;
-        println!(
+        writeln!(
+            out,
             "  同步仓库: {} 个会话",
             remote_sessions.len().to_string().cyan()
-        );
+        )?;
     }
 
     // Config sync info
-    println!();
-    println!("{}", "配置同步:".bold());
+    writeln!(out)?;
+    writeln!(out, "{}", "配置同步:".bold())?;
     let config_sync = &filter.config_sync;
-    println!(
+    writeln!(
+        out,
         "  状态: {}",
         if config_sync.enabled {
             "已启用".green()
         } else {
             "已禁用".yellow()
         }
-    );
-    println!("  设备名: {}", config_sync.get_device_name().cyan());
+    )?;
+    writeln!(out, "  设备名: {}", config_sync.get_device_name().cyan())?;
 
     // Show what's being synced
     let mut sync_items = Vec::new();
@@ -114,62 +211,439 @@ pub fn show_status(show_conflicts: bool, show_files: bool) -> Result<()> {
         sync_items.push("hooks");
     }
     if !sync_items.is_empty() {
-        println!("  同步项: {}", sync_items.join(", "));
+        writeln!(out, "  同步项: {}", sync_items.join(", "))?;
     }
-    println!(
+    writeln!(
+        out,
         "  自动应用 CLAUDE.md: {}",
         if config_sync.auto_apply_claude_md {
             "是".green()
         } else {
             "否".dimmed()
         }
-    );
-
-    // Check for configs directory
-    let configs_dir = state.sync_repo_path.join("_configs");
-    if configs_dir.exists() {
-        if let Ok(entries) = std::fs::read_dir(&configs_dir) {
-            let devices: Vec<String> = entries
-                .filter_map(|e| e.ok())
-                .filter(|e| e.path().is_dir())
-                .filter_map(|e| e.file_name().into_string().ok())
-                .collect();
-            if !devices.is_empty() {
-                println!("  可用设备: {}", devices.join(", ").dimmed());
+    )?;
+
+    // Available devices with staleness, most recent highlighted
+    let devices = list_device_sync_times(&state.sync_repo_path);
+    if !devices.is_empty() {
+        let now = chrono::Utc::now();
+        let latest = latest_device(&devices);
+        writeln!(out, "  可用设备:")?;
+        for (name, last_sync) in &devices {
+            let time_str = last_sync
+                .map(|t| t.format("%Y-%m-%d %H:%M UTC").to_string())
+                .unwrap_or_else(|| "未知".to_string());
+            if latest == Some(name.as_str()) {
+                writeln!(
+                    out,
+                    "    {} {} {}",
+                    name.green(),
+                    time_str.dimmed(),
+                    t!("config_sync.device_latest_tag").green()
+                )?;
+            } else if last_sync.is_some_and(|t| is_device_stale(t, now)) {
+                writeln!(
+                    out,
+                    "    {} {} {}",
+                    name.dimmed(),
+                    time_str.dimmed(),
+                    t!("config_sync.device_stale_tag").yellow()
+                )?;
+            } else {
+                writeln!(out, "    {} {}", name, time_str.dimmed())?;
             }
         }
     }
 
+    // Show projects if requested
+    if show_projects {
+        writeln!(out)?;
+        writeln!(out, "{}", "本地项目:".bold())?;
+        for (project, status) in &projects {
+            let marker = if !tracked {
+                String::new()
+            } else if status.unpushed > 0 {
+                format!(
+                    " {}",
+                    format!("{} {} unpushed", icon("✎"), status.unpushed).yellow()
+                )
+            } else {
+                format!(" {}", icon("✓").green())
+            };
+            writeln!(out, "  {} ({} 个会话){}", project, status.sessions, marker)?;
+        }
+    }
+
     // Show files if requested
     if show_files {
-        println!();
-        println!("{}", "本地会话文件:".bold());
+        writeln!(out)?;
+        writeln!(out, "{}", "本地会话文件:".bold())?;
         for session in local_sessions.iter().take(20) {
             let relative = Path::new(&session.file_path)
                 .strip_prefix(&claude_dir)
                 .unwrap_or(Path::new(&session.file_path));
-            println!(
+            writeln!(
+                out,
                 "  {} ({} 条消息)",
                 relative.display(),
                 session.message_count()
-            );
+            )?;
         }
         if local_sessions.len() > 20 {
-            println!("  ... 还有 {} 个", local_sessions.len() - 20);
+            writeln!(out, "  ... 还有 {} 个", local_sessions.len() - 20)?;
         }
     }
 
     // Show conflicts if requested
     if show_conflicts {
-        println!();
+        writeln!(out)?;
         if let Ok(report) = crate::report::load_latest_report() {
             if report.total_conflicts > 0 {
-                report.print_summary();
+                report.write_summary(out)?;
             } else {
-                println!("{}", "上次同步无冲突".green());
+                writeln!(out, "{}", "上次同步无冲突".green())?;
             }
         }
     }
 
     Ok(())
 }
+
+/// Name of the device with the most recent `lastSync`
+fn latest_device(devices: &[(String, Option<DateTime<Utc>>)]) -> Option<&str> {
+    devices
+        .iter()
+        .filter_map(|(name, time)| time.map(|t| (name, t)))
+        .max_by_key(|(_, t)| *t)
+        .map(|(name, _)| name.as_str())
+}
+
+/// Machine-readable status shared by `--json` and `--porcelain`
+#[derive(Debug, Default)]
+struct StatusSnapshot {
+    repo_path: PathBuf,
+    backend: Option<String>,
+    branch: Option<String>,
+    has_remote: bool,
+    remote_url: Option<String>,
+    has_uncommitted_changes: Option<bool>,
+    local_session_count: usize,
+    /// `None` when the sync repo has no projects directory yet
+    remote_session_count: Option<usize>,
+    projects: BTreeMap<String, ProjectPushStatus>,
+    /// Whether any push recorded hashes, i.e. whether `unpushed` is known
+    tracked: bool,
+    devices: Vec<(String, Option<DateTime<Utc>>)>,
+}
+
+fn collect_status(
+    state: &SyncState,
+    repo: &dyn scm::Scm,
+    filter: &FilterConfig,
+    claude_dir: &Path,
+) -> Result<StatusSnapshot> {
+    let local_sessions = discover_sessions(claude_dir, filter)?;
+    let remote_projects_dir = filter.repo_projects_dir(&state.sync_repo_path);
+    let remote_session_count = if remote_projects_dir.exists() {
+        Some(discover_sessions(&remote_projects_dir, filter)?.len())
+    } else {
+        None
+    };
+
+    Ok(StatusSnapshot {
+        repo_path: state.sync_repo_path.clone(),
+        backend: scm::detect_backend(&state.sync_repo_path).map(|b| format!("{:?}", b)),
+        branch: repo.current_branch().ok(),
+        has_remote: state.has_remote,
+        remote_url: if state.has_remote {
+            repo.get_remote_url("origin").ok()
+        } else {
+            None
+        },
+        has_uncommitted_changes: repo.has_changes().ok(),
+        local_session_count: local_sessions.len(),
+        remote_session_count,
+        projects: project_push_status(claude_dir, &local_sessions, &state.pushed_hashes),
+        tracked: !state.pushed_hashes.is_empty(),
+        devices: list_device_sync_times(&state.sync_repo_path),
+    })
+}
+
+/// Print sync status as a single JSON object (for scripts and dashboards)
+fn print_status_json(
+    snapshot: &StatusSnapshot,
+    filter: &FilterConfig,
+    out: &mut dyn Write,
+) -> Result<()> {
+    let projects_json: Vec<serde_json::Value> = snapshot
+        .projects
+        .iter()
+        .map(|(project, status)| {
+            json!({
+                "project": project,
+                "sessions": status.sessions,
+                "unpushed": snapshot.tracked.then_some(status.unpushed),
+            })
+        })
+        .collect();
+
+    let now = Utc::now();
+    let latest = latest_device(&snapshot.devices);
+    let devices_json: Vec<serde_json::Value> = snapshot
+        .devices
+        .iter()
+        .map(|(name, last_sync)| {
+            json!({
+                "name": name,
+                "last_sync": last_sync.map(|t| t.to_rfc3339()),
+                "stale": last_sync.is_some_and(|t| is_device_stale(t, now)),
+                "latest": latest == Some(name.as_str()),
+            })
+        })
+        .collect();
+
+    let config_sync = &filter.config_sync;
+    let output = json!({
+        "repo_path": snapshot.repo_path,
+        "backend": snapshot.backend,
+        "branch": snapshot.branch,
+        "has_remote": snapshot.has_remote,
+        "remote_url": snapshot.remote_url,
+        "has_uncommitted_changes": snapshot.has_uncommitted_changes,
+        "local_session_count": snapshot.local_session_count,
+        "remote_session_count": snapshot.remote_session_count,
+        "projects": projects_json,
+        "config_sync": {
+            "enabled": config_sync.enabled,
+            "device_name": config_sync.get_device_name(),
+            "sync_settings": config_sync.sync_settings,
+            "sync_claude_md": config_sync.sync_claude_md,
+            "sync_hooks": config_sync.sync_hooks,
+            "sync_skills_list": config_sync.sync_skills_list,
+            "auto_apply_claude_md": config_sync.auto_apply_claude_md,
+            "push_with_config": config_sync.push_with_config,
+        },
+        "devices": devices_json,
+    });
+
+    writeln!(out, "{}", serde_json::to_string_pretty(&output)?)?;
+    Ok(())
+}
+
+/// Print sync status as `key=value` lines (for shell scripts)
+///
+/// Keys and value formats are stable across versions; new keys may be
+/// added. Unknown values are empty, booleans are `true`/`false` and times
+/// are RFC 3339. Lists repeat their key once per item, with the item's
+/// fields separated by tabs:
+/// `project=<dir>\t<sessions>\t<unpushed>` and
+/// `device=<name>\t<last_sync>\t<stale>`.
+fn print_status_porcelain(
+    snapshot: &StatusSnapshot,
+    filter: &FilterConfig,
+    out: &mut dyn Write,
+) -> Result<()> {
+    fn opt<T: ToString>(value: &Option<T>) -> String {
+        value.as_ref().map(T::to_string).unwrap_or_default()
+    }
+
+    let config_sync = &filter.config_sync;
+    let lines = [
+        ("porcelain_version", PORCELAIN_VERSION.to_string()),
+        ("repo_path", snapshot.repo_path.display().to_string()),
+        ("backend", opt(&snapshot.backend)),
+        ("branch", opt(&snapshot.branch)),
+        ("has_remote", snapshot.has_remote.to_string()),
+        ("remote_url", opt(&snapshot.remote_url)),
+        (
+            "has_uncommitted_changes",
+            opt(&snapshot.has_uncommitted_changes),
+        ),
+        ("local_sessions", snapshot.local_session_count.to_string()),
+        ("remote_sessions", opt(&snapshot.remote_session_count)),
+        ("config_sync_enabled", config_sync.enabled.to_string()),
+        ("device_name", config_sync.get_device_name()),
+    ];
+    for (key, value) in lines {
+        writeln!(out, "{}={}", key, value)?;
+    }
+
+    for (project, status) in &snapshot.projects {
+        let unpushed = snapshot.tracked.then_some(status.unpushed);
+        writeln!(
+            out,
+            "project={}\t{}\t{}",
+            project,
+            status.sessions,
+            opt(&unpushed)
+        )?;
+    }
+
+    let now = Utc::now();
+    for (name, last_sync) in &snapshot.devices {
+        writeln!(
+            out,
+            "device={}\t{}\t{}",
+            name,
+            opt(&last_sync.map(|t| t.to_rfc3339())),
+            last_sync.is_some_and(|t| is_device_stale(t, now))
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_print_status_porcelain_lines() {
+        let mut filter = FilterConfig::default();
+        filter.config_sync.device_name = Some("laptop".to_string());
+        let snapshot = StatusSnapshot {
+            repo_path: PathBuf::from("/home/me/sync"),
+            backend: Some("Git".to_string()),
+            branch: Some("main".to_string()),
+            local_session_count: 42,
+            projects: BTreeMap::from([(
+                "-tmp-app".to_string(),
+                ProjectPushStatus {
+                    sessions: 3,
+                    unpushed: 1,
+                },
+            )]),
+            tracked: true,
+            devices: vec![("desktop".to_string(), None)],
+            ..Default::default()
+        };
+
+        let mut out = Vec::new();
+        print_status_porcelain(&snapshot, &filter, &mut out).unwrap();
+        let text = String::from_utf8(out).unwrap();
+        let lines: Vec<&str> = text.lines().collect();
+        assert_eq!(lines[0], "porcelain_version=1");
+        assert!(lines.contains(&"repo_path=/home/me/sync"));
+        assert!(lines.contains(&"branch=main"));
+        assert!(lines.contains(&"remote_url="));
+        assert!(lines.contains(&"local_sessions=42"));
+        assert!(lines.contains(&"remote_sessions="));
+        assert!(lines.contains(&"device_name=laptop"));
+        assert!(lines.contains(&"project=-tmp-app\t3\t1"));
+        assert!(lines.contains(&"device=desktop\t\tfalse"));
+        assert!(!text.contains('\u{1b}'));
+    }
+
+    #[test]
+    fn test_print_status_json_shape() {
+        let mut filter = FilterConfig::default();
+        filter.config_sync.device_name = Some("laptop".to_string());
+        let recent = Utc::now();
+        let snapshot = StatusSnapshot {
+            repo_path: PathBuf::from("/home/me/sync"),
+            backend: Some("Git".to_string()),
+            branch: Some("main".to_string()),
+            has_remote: true,
+            remote_url: Some("git@example.com:me/sync.git".to_string()),
+            has_uncommitted_changes: Some(false),
+            local_session_count: 42,
+            remote_session_count: Some(40),
+            projects: BTreeMap::from([(
+                "-tmp-app".to_string(),
+                ProjectPushStatus {
+                    sessions: 3,
+                    unpushed: 1,
+                },
+            )]),
+            tracked: false,
+            devices: vec![
+                ("desktop".to_string(), Some(recent)),
+                ("old".to_string(), Some(recent - chrono::Duration::days(90))),
+                ("never".to_string(), None),
+            ],
+        };
+
+        let mut out = Vec::new();
+        print_status_json(&snapshot, &filter, &mut out).unwrap();
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+
+        let mut keys: Vec<&str> = value
+            .as_object()
+            .unwrap()
+            .keys()
+            .map(String::as_str)
+            .collect();
+        keys.sort_unstable();
+        assert_eq!(
+            keys,
+            [
+                "backend",
+                "branch",
+                "config_sync",
+                "devices",
+                "has_remote",
+                "has_uncommitted_changes",
+                "local_session_count",
+                "projects",
+                "remote_session_count",
+                "remote_url",
+                "repo_path",
+            ]
+        );
+        assert_eq!(value["repo_path"], "/home/me/sync");
+        assert_eq!(value["local_session_count"], 42);
+        assert_eq!(value["remote_session_count"], 40);
+        assert_eq!(value["config_sync"]["device_name"], "laptop");
+
+        // Without recorded push hashes the unpushed count is unknown
+        assert_eq!(
+            value["projects"],
+            json!([{ "project": "-tmp-app", "sessions": 3, "unpushed": null }])
+        );
+
+        let devices = value["devices"].as_array().unwrap();
+        assert_eq!(devices.len(), 3);
+        assert_eq!(devices[0]["name"], "desktop");
+        assert_eq!(devices[0]["last_sync"], recent.to_rfc3339());
+        assert_eq!(devices[0]["latest"], true);
+        assert_eq!(devices[0]["stale"], false);
+        assert_eq!(devices[1]["stale"], true);
+        assert_eq!(devices[1]["latest"], false);
+        assert_eq!(devices[2]["last_sync"], serde_json::Value::Null);
+        assert_eq!(devices[2]["stale"], false);
+    }
+
+    #[test]
+    fn test_project_push_status_counts_changed_sessions() {
+        let temp = TempDir::new().unwrap();
+        let line = r#"{"type":"user","sessionId":"s","message":{"role":"user","content":"hi"}}"#;
+        let mut sessions = Vec::new();
+        for (project, name, content) in [
+            ("-tmp-a", "one", line.to_string()),
+            ("-tmp-a", "two", format!("{line}\n{line}")),
+            ("-tmp-b", "three", line.to_string()),
+        ] {
+            let path = temp.path().join(project).join(format!("{name}.jsonl"));
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(&path, content).unwrap();
+            sessions.push(ConversationSession::from_file(&path).unwrap());
+        }
+
+        let mut pushed: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        pushed
+            .entry("-tmp-a".to_string())
+            .or_default()
+            .insert(sessions[0].content_hash());
+
+        let status = project_push_status(temp.path(), &sessions, &pushed);
+        assert_eq!(
+            status["-tmp-a"],
+            ProjectPushStatus {
+                sessions: 2,
+                unpushed: 1
+            }
+        );
+        // Same content in another project was never pushed there
+        assert_eq!(status["-tmp-b"].unpushed, 1);
+    }
+}
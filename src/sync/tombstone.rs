@@ -181,6 +181,14 @@ impl TombstoneRegistry {
         self.records.iter().any(|r| r.session_id == session_id)
     }
 
+    /// Drop the tombstone for a session, e.g. when an accidental delete is
+    /// undone. Returns true if a record was removed.
+    pub fn remove(&mut self, session_id: &str) -> bool {
+        let before = self.records.len();
+        self.records.retain(|r| r.session_id != session_id);
+        self.records.len() != before
+    }
+
     /// Convenience alias for [`contains`].
 #[allow(dead_code)]
     pub fn is_deleted(&self, session_id: &str) -> bool {
@@ -283,6 +291,16 @@ mod tests {
         assert!(!registry.contains("missing-id"));
     }
 
+    #[test]
+    fn remove_drops_record() {
+        let mut registry = TombstoneRegistry::default();
+        registry.add(sample_record("abc-123", DeleteReason::Explicit));
+
+        assert!(registry.remove("abc-123"));
+        assert!(!registry.contains("abc-123"));
+        assert!(!registry.remove("abc-123"));
+    }
+
     #[test]
     fn file_path_is_under_ccs_dir() {
         let path = TombstoneRegistry::file_path(Path::new("/tmp/fake-repo"));
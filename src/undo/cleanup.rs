@@ -14,6 +14,15 @@ pub struct SnapshotCleanupConfig {
     pub max_age_days: i64,
 }
 
+/// Result of a snapshot cleanup
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SnapshotCleanupReport {
+    /// Number of snapshots deleted (or that would be, in a dry run)
+    pub deleted: usize,
+    /// Total size of those snapshot files in bytes
+    pub reclaimed_bytes: u64,
+}
+
 impl Default for SnapshotCleanupConfig {
     fn default() -> Self {
         Self {
@@ -35,12 +44,12 @@ impl Default for SnapshotCleanupConfig {
 /// * `snapshots_dir` - Optional custom snapshots directory (for testing)
 ///
 /// # Returns
-/// Number of snapshots deleted
+/// Number of snapshots deleted and the space they took up
 pub fn cleanup_old_snapshots_with_dir(
     config: Option<SnapshotCleanupConfig>,
     dry_run: bool,
     snapshots_dir: Option<&Path>,
-) -> Result<usize> {
+) -> Result<SnapshotCleanupReport> {
     let config = config.unwrap_or_default();
     let snapshots_dir = if let Some(dir) = snapshots_dir {
         dir.to_path_buf()
@@ -49,7 +58,7 @@ pub fn cleanup_old_snapshots_with_dir(
     };
 
     if !snapshots_dir.exists() {
-        return Ok(0);
+        return Ok(SnapshotCleanupReport::default());
     }
 
     // Collect all snapshots with metadata
@@ -68,7 +77,12 @@ pub fn cleanup_old_snapshots_with_dir(
         if let Ok(content) = fs::read_to_string(&path) {
             if let Ok(snapshot) = serde_json::from_str::<Snapshot>(&content) {
                 match snapshot.operation_type {
-                    OperationType::Pull => pull_snapshots.push((path, snapshot.timestamp)),
+                    // Session delete snapshots restore local files, like pull snapshots
+                    OperationType::Pull
+                    | OperationType::SessionDelete
+                    | OperationType::SessionRename => {
+                        pull_snapshots.push((path, snapshot.timestamp))
+                    }
                     OperationType::Push => push_snapshots.push((path, snapshot.timestamp)),
                 }
             }
@@ -107,22 +121,24 @@ pub fn cleanup_old_snapshots_with_dir(
     }
 
     // Delete the snapshots (or just report in dry run mode)
-    let deleted_count = to_delete.len();
+    let mut report = SnapshotCleanupReport::default();
 
     if dry_run {
-        println!("Would delete {} snapshots:", deleted_count);
-        for path in &to_delete {
+        println!("Would delete {} snapshots:", to_delete.len());
+    }
+    for path in &to_delete {
+        let size = fs::metadata(path).map_or(0, |m| m.len());
+        if dry_run {
             println!("  - {}", path.display());
+        } else if let Err(e) = fs::remove_file(path) {
+            warn!("Failed to delete snapshot {}: {}", path.display(), e);
+            continue;
         }
-    } else {
-        for path in &to_delete {
-            if let Err(e) = fs::remove_file(path) {
-                warn!("Failed to delete snapshot {}: {}", path.display(), e);
-            }
-        }
+        report.deleted += 1;
+        report.reclaimed_bytes += size;
     }
 
-    Ok(deleted_count)
+    Ok(report)
 }
 
 /// Clean up old snapshots using the default snapshots directory
@@ -134,10 +150,10 @@ pub fn cleanup_old_snapshots_with_dir(
 /// * `dry_run` - If true, show what would be deleted without actually deleting
 ///
 /// # Returns
-/// Number of snapshots deleted
+/// Number of snapshots deleted and the space they took up
 pub fn cleanup_old_snapshots(
     config: Option<SnapshotCleanupConfig>,
     dry_run: bool,
-) -> Result<usize> {
+) -> Result<SnapshotCleanupReport> {
     cleanup_old_snapshots_with_dir(config, dry_run, None)
 }
@@ -12,7 +12,10 @@ mod snapshot;
 
 // Re-export public types and functions to maintain API compatibility
 pub use cleanup::{cleanup_old_snapshots, SnapshotCleanupConfig};
-pub use operations::{undo_pull, undo_push};
+pub use operations::{
+    push_target_commit, undo_pull, undo_pull_at, undo_push, undo_push_at, undo_session_delete,
+    undo_session_delete_at,
+};
 pub use preview::{preview_undo_pull, preview_undo_push, VerbosityLevel};
 pub use snapshot::Snapshot;
 
@@ -225,6 +228,43 @@ mod tests {
         assert!(!snapshot_path.exists());
     }
 
+    #[test]
+    fn test_undo_session_delete_restores_file() {
+        let temp_dir = tempdir().unwrap();
+        let history_path = temp_dir.path().join("history.json");
+        let snapshots_dir = temp_dir.path().join("snapshots");
+
+        let session_file = create_test_file(temp_dir.path(), "session.jsonl", "session data");
+        let snapshot =
+            Snapshot::create(OperationType::SessionDelete, vec![&session_file], None).unwrap();
+        let snapshot_path = snapshot.save_to_disk(Some(&snapshots_dir)).unwrap();
+        fs::remove_file(&session_file).unwrap();
+
+        let mut history = OperationHistory::from_path(Some(history_path.clone())).unwrap();
+        let conv_summary = ConversationSummary::new(
+            "test-session".to_string(),
+            session_file.to_string_lossy().to_string(),
+            None,
+            3,
+            SyncOperation::Modified,
+        )
+        .unwrap();
+        let mut record =
+            OperationRecord::new(OperationType::SessionDelete, None, vec![conv_summary]);
+        record.snapshot_path = Some(snapshot_path.clone());
+        history.add_operation(record).unwrap();
+        history.save_to(Some(history_path.clone())).unwrap();
+
+        let result =
+            undo_session_delete(Some(history_path.clone()), Some(temp_dir.path())).unwrap();
+        assert!(result.contains("session.jsonl"));
+        assert_eq!(fs::read_to_string(&session_file).unwrap(), "session data");
+        assert!(!snapshot_path.exists());
+
+        // The delete is gone from history, so a second undo has nothing to do
+        assert!(undo_session_delete(Some(history_path), Some(temp_dir.path())).is_err());
+    }
+
     #[test]
     fn test_undo_pull_missing_snapshot() {
         let temp_dir = tempdir().unwrap();
@@ -320,6 +360,46 @@ mod tests {
         assert_eq!(current_hash, initial_hash);
     }
 
+    #[test]
+    fn test_undo_push_at_reverts_later_pushes() {
+        let (temp_dir, repo) = setup_test_repo();
+        let history_path = temp_dir.path().join("history.json");
+        let initial_hash = repo.current_commit_hash().unwrap();
+
+        let mut history = OperationHistory::from_path(Some(history_path.clone())).unwrap();
+        let mut before = initial_hash.clone();
+        for name in ["a.txt", "b.txt"] {
+            fs::write(temp_dir.path().join(name), name).unwrap();
+            repo.stage_all().unwrap();
+            repo.commit(name).unwrap();
+            let mut record = OperationRecord::new(OperationType::Push, None, Vec::new());
+            record.commit_hash = Some(before.clone());
+            history.add_operation(record).unwrap();
+            before = repo.current_commit_hash().unwrap();
+        }
+        history
+            .add_operation(OperationRecord::new(OperationType::Pull, None, Vec::new()))
+            .unwrap();
+        history.save_to(Some(history_path.clone())).unwrap();
+
+        // History is [pull, push b, push a]; undo push a (#3)
+        let result = undo_push_at(temp_dir.path(), 2, Some(history_path.clone())).unwrap();
+        assert!(result.contains("Also reverted 1 later push"));
+        assert_eq!(repo.current_commit_hash().unwrap(), initial_hash);
+
+        let history = OperationHistory::from_path(Some(history_path.clone())).unwrap();
+        let remaining: Vec<_> = history
+            .list_operations()
+            .iter()
+            .map(|op| op.operation_type)
+            .collect();
+        assert_eq!(remaining, vec![OperationType::Pull]);
+
+        // The pull is not a push
+        let err = undo_push_at(temp_dir.path(), 0, Some(history_path)).unwrap_err();
+        assert!(err.to_string().contains("is a pull"));
+    }
+
     #[test]
     fn test_undo_push_no_history() {
         let temp_dir = tempdir().unwrap();
@@ -1250,8 +1330,9 @@ mod tests {
             max_age_days: 0, // Only count matters, not age
         };
 
-        let deleted =
-            cleanup_old_snapshots_with_dir(Some(config), false, Some(&snapshots_dir)).unwrap();
+        let deleted = cleanup_old_snapshots_with_dir(Some(config), false, Some(&snapshots_dir))
+            .unwrap()
+            .deleted;
         assert_eq!(deleted, 5, "Should delete 5 old snapshots");
 
         // Count remaining snapshots
@@ -1290,8 +1371,9 @@ mod tests {
             max_age_days: 50,
         };
 
-        let deleted =
-            cleanup_old_snapshots_with_dir(Some(config), false, Some(&snapshots_dir)).unwrap();
+        let deleted = cleanup_old_snapshots_with_dir(Some(config), false, Some(&snapshots_dir))
+            .unwrap()
+            .deleted;
 
         // Snapshots are at days: 5, 15, 25, 35, 45, 55, 65, 75, 85, 95
         // Age threshold is now - 50 days
@@ -1342,8 +1424,9 @@ mod tests {
             max_age_days: 0, // Don't keep by age
         };
 
-        let deleted =
-            cleanup_old_snapshots_with_dir(Some(config), false, Some(&snapshots_dir)).unwrap();
+        let deleted = cleanup_old_snapshots_with_dir(Some(config), false, Some(&snapshots_dir))
+            .unwrap()
+            .deleted;
 
         // Should delete 7 pull + 7 push = 14 total
         assert_eq!(deleted, 14, "Should delete 14 old snapshots");
@@ -1383,9 +1466,10 @@ mod tests {
         };
 
         // Dry run should report but not delete
-        let deleted =
+        let report =
             cleanup_old_snapshots_with_dir(Some(config), true, Some(&snapshots_dir)).unwrap();
-        assert_eq!(deleted, 7, "Should report 7 snapshots would be deleted");
+        assert_eq!(report.deleted, 7, "Should report 7 snapshots would be deleted");
+        assert!(report.reclaimed_bytes > 0, "Should report the space to free");
 
         // All snapshots should still exist
         let remaining = fs::read_dir(&snapshots_dir).unwrap().count();
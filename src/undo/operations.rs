@@ -3,7 +3,7 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use super::snapshot::Snapshot;
-use crate::history::{OperationHistory, OperationType};
+use crate::history::{OperationHistory, OperationRecord, OperationType};
 use crate::scm;
 
 /// Undo the last pull operation
@@ -22,18 +22,29 @@ use crate::scm;
 /// # Returns
 /// A summary message describing what was undone
 pub fn undo_pull(history_path: Option<PathBuf>, allowed_base_dir: Option<&Path>) -> Result<String> {
-    // Load operation history
     let history = OperationHistory::from_path(history_path.clone())?;
-
-    // Find the last pull operation
-    let last_pull = history
-        .get_last_operation_by_type(OperationType::Pull)
+    let index = last_index_of(&history, OperationType::Pull)
         .ok_or_else(|| anyhow!("No pull operation found in history to undo"))?;
+    undo_pull_at(index, history_path, allowed_base_dir)
+}
+
+/// Undo the pull operation at `index` in the history (0 = most recent)
+///
+/// Restores the files from that pull's snapshot. Operations recorded after
+/// it are not reverted.
+pub fn undo_pull_at(
+    index: usize,
+    history_path: Option<PathBuf>,
+    allowed_base_dir: Option<&Path>,
+) -> Result<String> {
+    // Load operation history
+    let mut history = OperationHistory::from_path(history_path.clone())?;
+    let record = record_at(&history, index, OperationType::Pull)?;
 
     // Get the snapshot path
-    let snapshot_path = last_pull.snapshot_path.as_ref().ok_or_else(|| {
+    let snapshot_path = record.snapshot_path.as_ref().ok_or_else(|| {
         anyhow!(
-            "No snapshot found for last pull operation. \
+            "No snapshot found for this pull operation. \
                 Cannot undo without a snapshot."
         )
     })?;
@@ -68,9 +79,8 @@ pub fn undo_pull(history_path: Option<PathBuf>, allowed_base_dir: Option<&Path>)
     // remains on disk until we successfully complete the restoration.
 
     // Step 1: Remove the pull operation from history
-    let mut history = OperationHistory::from_path(history_path.clone())?;
     history
-        .remove_last_operation_by_type(OperationType::Pull, history_path.clone())
+        .remove_operation(index, history_path)
         .context("Failed to remove pull operation from history")?;
 
     // Step 2: Restore the snapshot files
@@ -90,7 +100,7 @@ pub fn undo_pull(history_path: Option<PathBuf>, allowed_base_dir: Option<&Path>)
     }
 
     Ok(format!(
-        "Successfully undone last pull operation.\n\
+        "Successfully undone pull operation.\n\
         Restored {} files to their pre-pull state.\n\
         Snapshot taken at: {}",
         file_count,
@@ -98,6 +108,88 @@ pub fn undo_pull(history_path: Option<PathBuf>, allowed_base_dir: Option<&Path>)
     ))
 }
 
+/// Undo the last session delete operation
+///
+/// Restores the deleted session file from the snapshot taken right before
+/// `ccs session delete` (or the session manager) removed it, and drops the
+/// delete from the operation history. Tombstone cleanup in the sync repo is
+/// left to the caller.
+///
+/// # Arguments
+/// * `history_path` - Optional custom path for operation history (for testing)
+/// * `allowed_base_dir` - Optional base directory for path validation (for testing)
+///
+/// # Returns
+/// A summary message describing what was restored
+pub fn undo_session_delete(
+    history_path: Option<PathBuf>,
+    allowed_base_dir: Option<&Path>,
+) -> Result<String> {
+    let history = OperationHistory::from_path(history_path.clone())?;
+    let index = last_index_of(&history, OperationType::SessionDelete)
+        .ok_or_else(|| anyhow!("No session delete found in history to undo"))?;
+    undo_session_delete_at(index, history_path, allowed_base_dir)
+}
+
+/// Undo the session delete at `index` in the history (0 = most recent)
+pub fn undo_session_delete_at(
+    index: usize,
+    history_path: Option<PathBuf>,
+    allowed_base_dir: Option<&Path>,
+) -> Result<String> {
+    let mut history = OperationHistory::from_path(history_path.clone())?;
+    let record = record_at(&history, index, OperationType::SessionDelete)?;
+
+    let snapshot_path = record
+        .snapshot_path
+        .clone()
+        .ok_or_else(|| anyhow!("No snapshot found for this session delete. Cannot undo."))?;
+
+    if !snapshot_path.exists() {
+        return Err(anyhow!(
+            "Snapshot file not found: {}. \
+            The snapshot may have been deleted.",
+            snapshot_path.display()
+        ));
+    }
+
+    let snapshot = Snapshot::load_from_disk(&snapshot_path)?;
+    if snapshot.operation_type != OperationType::SessionDelete {
+        return Err(anyhow!(
+            "Snapshot type mismatch: expected session-delete, found {}",
+            snapshot.operation_type.as_str()
+        ));
+    }
+
+    // Same ordering as undo_pull: update history first, then restore files
+    history
+        .remove_operation(index, history_path)
+        .context("Failed to remove session delete from history")?;
+
+    snapshot
+        .restore_with_base(allowed_base_dir)
+        .context("Failed to restore snapshot")?;
+
+    if let Err(e) = fs::remove_file(&snapshot_path) {
+        eprintln!(
+            "Warning: Failed to remove snapshot file {}: {}",
+            snapshot_path.display(),
+            e
+        );
+    }
+
+    let mut restored: Vec<&String> = snapshot.files.keys().collect();
+    restored.sort();
+    Ok(format!(
+        "Restored deleted session file:\n  {}",
+        restored
+            .iter()
+            .map(|p| p.as_str())
+            .collect::<Vec<_>>()
+            .join("\n  ")
+    ))
+}
+
 /// Undo the last push operation
 ///
 /// This function:
@@ -118,20 +210,20 @@ pub fn undo_pull(history_path: Option<PathBuf>, allowed_base_dir: Option<&Path>)
 /// # Returns
 /// A summary message describing what was undone and any required follow-up actions
 pub fn undo_push(repo_path: &Path, history_path: Option<PathBuf>) -> Result<String> {
-    // Load operation history
     let history = OperationHistory::from_path(history_path.clone())?;
-
-    // Find the last push operation
-    let last_push = history
-        .get_last_operation_by_type(OperationType::Push)
+    let index = last_index_of(&history, OperationType::Push)
         .ok_or_else(|| anyhow!("No push operation found in history to undo"))?;
+    undo_push_at(repo_path, index, history_path)
+}
 
-    // Get the commit hash to reset to
-    // New operations store this in commit_hash field directly
-    // Legacy operations may have it in a snapshot file
-    let target_commit = if let Some(ref hash) = last_push.commit_hash {
-        hash.clone()
-    } else if let Some(ref snapshot_path) = last_push.snapshot_path {
+/// The commit that undoing a push resets to
+///
+/// New operations store it in the `commit_hash` field directly; legacy
+/// operations may only have it in a snapshot file.
+pub fn push_target_commit(record: &OperationRecord) -> Result<String> {
+    if let Some(ref hash) = record.commit_hash {
+        Ok(hash.clone())
+    } else if let Some(ref snapshot_path) = record.snapshot_path {
         // Legacy: load from snapshot file
         if !snapshot_path.exists() {
             return Err(anyhow!(
@@ -142,12 +234,28 @@ pub fn undo_push(repo_path: &Path, history_path: Option<PathBuf>) -> Result<Stri
         let snapshot = Snapshot::load_from_disk(snapshot_path)?;
         snapshot
             .git_commit_hash
-            .ok_or_else(|| anyhow!("No commit hash found in snapshot"))?
+            .ok_or_else(|| anyhow!("No commit hash found in snapshot"))
     } else {
-        return Err(anyhow!(
-            "No commit hash found for last push operation. Cannot undo."
-        ));
-    };
+        Err(anyhow!(
+            "No commit hash found for this push operation. Cannot undo."
+        ))
+    }
+}
+
+/// Undo the push operation at `index` in the history (0 = most recent)
+///
+/// Resetting to the commit before that push also discards every later
+/// commit, so all later push records are removed from the history too.
+pub fn undo_push_at(
+    repo_path: &Path,
+    index: usize,
+    history_path: Option<PathBuf>,
+) -> Result<String> {
+    // Load operation history
+    let mut history = OperationHistory::from_path(history_path.clone())?;
+    let last_push = record_at(&history, index, OperationType::Push)?;
+
+    let target_commit = push_target_commit(&last_push)?;
 
     // Open the SCM repository
     let repo = scm::open(repo_path)
@@ -160,11 +268,20 @@ pub fn undo_push(repo_path: &Path, history_path: Option<PathBuf>) -> Result<Stri
     // TRANSACTION-LIKE ORDERING: Update history FIRST, then perform reset.
     // This ensures that if the reset fails, the history is still consistent.
 
-    // Step 1: Remove the push operation from history
-    let mut history = OperationHistory::from_path(history_path.clone())?;
-    history
-        .remove_last_operation_by_type(OperationType::Push, history_path.clone())
-        .context("Failed to remove push operation from history")?;
+    // Step 1: Remove the push operation, and the later pushes the reset
+    // discards with it, from history. Going from the oldest keeps the
+    // remaining indices valid.
+    let mut later_pushes = 0;
+    for i in (0..=index).rev() {
+        if history.list_operations()[i].operation_type == OperationType::Push {
+            history
+                .remove_operation(i, history_path.clone())
+                .context("Failed to remove push operation from history")?;
+            if i != index {
+                later_pushes += 1;
+            }
+        }
+    }
 
     // Step 2: Perform the reset
     repo.reset_soft(&target_commit)
@@ -190,7 +307,7 @@ pub fn undo_push(repo_path: &Path, history_path: Option<PathBuf>) -> Result<Stri
     };
 
     let mut summary = format!(
-        "Successfully undone last push operation.\n\
+        "Successfully undone push operation.\n\
         Reset repository to commit: {}\n\
         Branch: {}\n\
         Operation was at: {}",
@@ -199,6 +316,12 @@ pub fn undo_push(repo_path: &Path, history_path: Option<PathBuf>) -> Result<Stri
         last_push.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
     );
 
+    if later_pushes > 0 {
+        summary.push_str(&format!(
+            "\nAlso reverted {later_pushes} later push operation(s) made after it."
+        ));
+    }
+
     if needs_force_push {
         summary.push_str(&format!(
             "\n\n\
@@ -210,3 +333,32 @@ pub fn undo_push(repo_path: &Path, history_path: Option<PathBuf>) -> Result<Stri
 
     Ok(summary)
 }
+
+/// Index of the most recent operation of `op_type`
+fn last_index_of(history: &OperationHistory, op_type: OperationType) -> Option<usize> {
+    history
+        .list_operations()
+        .iter()
+        .position(|op| op.operation_type == op_type)
+}
+
+/// The record at `index`, checked to be of the expected type
+fn record_at(
+    history: &OperationHistory,
+    index: usize,
+    op_type: OperationType,
+) -> Result<OperationRecord> {
+    let record = history
+        .list_operations()
+        .get(index)
+        .ok_or_else(|| anyhow!("No operation #{} in history", index + 1))?;
+    if record.operation_type != op_type {
+        return Err(anyhow!(
+            "Operation #{} is a {}, not a {}",
+            index + 1,
+            record.operation_type.as_str(),
+            op_type.as_str()
+        ));
+    }
+    Ok(record.clone())
+}
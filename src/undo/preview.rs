@@ -1,6 +1,7 @@
 use anyhow::{anyhow, Result};
 use std::path::PathBuf;
 
+use super::operations::push_target_commit;
 use super::snapshot::Snapshot;
 use crate::history::{OperationHistory, OperationType};
 
@@ -42,6 +43,8 @@ impl UndoPreview {
                 let op_type = match self.operation_type {
                     OperationType::Pull => "Pull",
                     OperationType::Push => "Push",
+                    OperationType::SessionDelete => "Session delete",
+                    OperationType::SessionRename => "Session rename",
                 };
                 println!(
                     "Undo {}: {} conversations affected",
@@ -61,6 +64,8 @@ impl UndoPreview {
                 let op_type = match self.operation_type {
                     OperationType::Pull => "PULL".green(),
                     OperationType::Push => "PUSH".blue(),
+                    OperationType::SessionDelete => "SESSION DELETE".red(),
+                    OperationType::SessionRename => "SESSION RENAME".yellow(),
                 };
 
                 println!("\n{} {}", "Operation:".bold(), op_type);
@@ -129,6 +134,8 @@ impl UndoPreview {
                 let op_type = match self.operation_type {
                     OperationType::Pull => "PULL".green(),
                     OperationType::Push => "PUSH".blue(),
+                    OperationType::SessionDelete => "SESSION DELETE".red(),
+                    OperationType::SessionRename => "SESSION RENAME".yellow(),
                 };
 
                 println!("\n{} {}", "Operation Type:".bold(), op_type);
@@ -275,33 +282,24 @@ pub fn preview_undo_push(history_path: Option<PathBuf>) -> Result<UndoPreview> {
         .get_last_operation_by_type(OperationType::Push)
         .ok_or_else(|| anyhow!("No push operation found in history to undo"))?;
 
-    // Get the snapshot path
-    let snapshot_path = last_push.snapshot_path.as_ref().ok_or_else(|| {
-        anyhow!(
-            "No snapshot found for last push operation. \
-                Cannot undo without a snapshot."
-        )
-    })?;
-
-    // Verify snapshot exists
-    if !snapshot_path.exists() {
-        return Err(anyhow!(
-            "Snapshot file not found: {}. \
-            The snapshot may have been deleted.",
-            snapshot_path.display()
-        ));
-    }
-
-    // Load the snapshot
-    let snapshot = Snapshot::load_from_disk(snapshot_path)?;
+    // New operations record the commit to reset to directly; legacy ones
+    // only have it in a snapshot file
+    let commit_hash = push_target_commit(last_push)?;
+    let snapshot = match (&last_push.commit_hash, &last_push.snapshot_path) {
+        (None, Some(snapshot_path)) => Some(Snapshot::load_from_disk(snapshot_path)?),
+        _ => None,
+    };
 
     Ok(UndoPreview {
         operation_type: OperationType::Push,
         operation_timestamp: last_push.timestamp,
-        branch: snapshot.branch.clone(),
+        branch: last_push
+            .branch
+            .clone()
+            .or_else(|| snapshot.as_ref().and_then(|s| s.branch.clone())),
         affected_files: Vec::new(), // Push doesn't restore files, just resets git
         conversation_count: last_push.affected_conversations.len(),
-        commit_hash: snapshot.git_commit_hash.clone(),
-        snapshot_timestamp: snapshot.timestamp,
+        commit_hash: Some(commit_hash),
+        snapshot_timestamp: snapshot.map_or(last_push.timestamp, |s| s.timestamp),
     })
 }
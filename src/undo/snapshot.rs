@@ -7,6 +7,7 @@ use std::path::{Path, PathBuf};
 use uuid::Uuid;
 
 use crate::history::OperationType;
+use crate::output::icon;
 
 /// Represents a snapshot of conversation files at a point in time
 ///
@@ -441,7 +442,7 @@ impl Snapshot {
         if size_mb > 100.0 {
             println!(
                 "    {} Loading large snapshot: {} ({:.1} MB) - This may take a moment...",
-                "⚠".yellow(),
+                icon("⚠").yellow(),
                 snapshot_path.file_name().unwrap().to_string_lossy().cyan(),
                 size_mb
             );
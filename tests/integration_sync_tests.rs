@@ -59,6 +59,8 @@ fn create_test_sync_state(sync_repo_path: &Path, state_dir: &Path) -> anyhow::Re
         has_remote: false,
         is_cloned_repo: false,
         last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
     };
 
     let state_file = state_dir.join("state.json");
@@ -1,8 +1,7 @@
 //! Backend-agnostic SCM tests.
 //!
 //! These tests are parameterized to run against all available SCM backends.
-//! Currently supports Git only. When Mercurial is added, simply add
-//! `#[case::mercurial(Backend::Mercurial)]` to each test.
+//! Backends whose binary is not installed are skipped at runtime.
 
 use claude_code_sync::scm::{self, Backend};
 use rstest::rstest;
@@ -337,6 +336,97 @@ fn test_reset_soft(#[case] backend: Backend) {
     }
 }
 
+#[rstest]
+#[case::git(Backend::Git)]
+#[case::mercurial(Backend::Mercurial)]
+fn test_is_ancestor(#[case] backend: Backend) {
+    if !backend.is_available() {
+        eprintln!("Skipping: {:?} not installed", backend);
+        return;
+    }
+
+    let temp = TempDir::new().unwrap();
+    let repo = scm::init_with_backend(temp.path(), backend).unwrap();
+
+    fs::write(temp.path().join("file1.txt"), "content1").unwrap();
+    repo.stage_all().unwrap();
+    repo.commit("First commit").unwrap();
+    let first_hash = repo.current_commit_hash().unwrap();
+
+    fs::write(temp.path().join("file2.txt"), "content2").unwrap();
+    repo.stage_all().unwrap();
+    repo.commit("Second commit").unwrap();
+    let second_hash = repo.current_commit_hash().unwrap();
+
+    assert!(repo.is_ancestor(&first_hash, &second_hash).unwrap());
+    assert!(repo.is_ancestor(&second_hash, &second_hash).unwrap());
+    assert!(!repo.is_ancestor(&second_hash, &first_hash).unwrap());
+}
+
+// =============================================================================
+// Push Round-Trip Tests
+// =============================================================================
+
+/// Push from an hg repo to a local hg "remote" and pull it back into a third
+/// repo, using only the `Scm` trait (the same calls `push_history` makes).
+#[test]
+fn test_mercurial_push_roundtrip() {
+    if !Backend::Mercurial.is_available() {
+        eprintln!("Skipping: Mercurial not installed");
+        return;
+    }
+
+    let temp = TempDir::new().unwrap();
+    let remote_path = temp.path().join("remote");
+    let local_path = temp.path().join("local");
+    let other_path = temp.path().join("other");
+
+    scm::init_with_backend(&remote_path, Backend::Mercurial).unwrap();
+    scm::init_with_backend(&local_path, Backend::Mercurial).unwrap();
+    scm::init_with_backend(&other_path, Backend::Mercurial).unwrap();
+    let remote_url = remote_path.to_string_lossy().to_string();
+
+    // Open through the generic entry point, like push/pull do
+    let local = scm::open(&local_path).unwrap();
+    local.add_remote("origin", &remote_url).unwrap();
+    fs::create_dir_all(local_path.join("projects/demo")).unwrap();
+    fs::write(local_path.join("projects/demo/session.jsonl"), "{}\n").unwrap();
+    local.stage_all().unwrap();
+    local.commit("Sync 1 sessions").unwrap();
+
+    let branch = local.current_branch().unwrap();
+    local.push("origin", &branch).unwrap();
+    // Pushing again with nothing new must not be treated as a failure
+    local.push("origin", &branch).unwrap();
+
+    let other = scm::open(&other_path).unwrap();
+    other.add_remote("origin", &remote_url).unwrap();
+    other.pull("origin", &branch).unwrap();
+    assert!(other_path.join("projects/demo/session.jsonl").exists());
+    assert_eq!(
+        other.current_commit_hash().unwrap(),
+        local.current_commit_hash().unwrap()
+    );
+}
+
+#[test]
+fn test_open_hg_repo_without_hg_binary_errors() {
+    if Backend::Mercurial.is_available() {
+        eprintln!("Skipping: Mercurial is installed");
+        return;
+    }
+
+    let temp = TempDir::new().unwrap();
+    fs::create_dir(temp.path().join(".hg")).unwrap();
+
+    let err = scm::open(temp.path()).err().expect("open should fail");
+    assert!(
+        err.to_string().contains("'hg' command is not installed"),
+        "Unexpected error: {}",
+        err
+    );
+}
+
 // =============================================================================
 // Backend Detection Tests
 // =============================================================================
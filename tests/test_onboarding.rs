@@ -69,6 +69,8 @@ fn test_sync_state_with_cloned_flag() -> Result<()> {
         has_remote: true,
         is_cloned_repo: true,
         last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
     };
 
     let serialized = serde_json::to_string(&state)?;
@@ -601,6 +603,9 @@ fn test_multi_repo_state_serialization() -> Result<()> {
         is_cloned_repo: false,
         remote_url: Some("https://github.com/user/work.git".to_string()),
         description: Some("Work projects".to_string()),
+        last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
     };
 
     let mut repos = HashMap::new();
@@ -958,6 +963,9 @@ fn test_operations_use_active_repo() -> Result<()> {
         is_cloned_repo: false,
         remote_url: None,
         description: Some("Second repo".to_string()),
+        last_synced_commit: None,
+        pushed_hashes: Default::default(),
+        last_push_at: None,
     };
     multi_state.repos.insert("repo2".to_string(), repo2_config);
     multi_state.save()?;